@@ -91,7 +91,7 @@ pub fn gen_projected_data(
     expr: BoxedExpression,
 ) -> Vec<DataChunk> {
     let mut data_gen =
-        FieldGeneratorImpl::with_number_random(DataType::Int64, None, None, SEED).unwrap();
+        FieldGeneratorImpl::with_number_random(DataType::Int64, None, None, None, SEED).unwrap();
     let mut ret = Vec::<DataChunk>::with_capacity(batch_num);
 
     for i in 0..batch_num {
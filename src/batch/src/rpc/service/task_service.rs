@@ -64,6 +64,7 @@ impl TaskService for BatchServiceImpl {
             epoch,
             tracing_context,
             expr_context,
+            mem_reservation_bytes,
         } = request.into_inner();
 
         let (state_tx, state_rx) = tokio::sync::mpsc::channel(TASK_STATUS_BUFFER_SIZE);
@@ -78,6 +79,7 @@ impl TaskService for BatchServiceImpl {
                 state_reporter,
                 TracingContext::from_protobuf(&tracing_context),
                 expr_context.expect("no expression context found"),
+                mem_reservation_bytes,
             )
             .await;
         match res {
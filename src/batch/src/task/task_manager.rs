@@ -51,10 +51,27 @@ pub struct BatchManager {
     /// Memory context used for batch tasks in cn.
     mem_context: MemoryContext,
 
+    /// Memory budgets reserved by tasks before execution. See
+    /// `CreateTaskRequest::mem_reservation_bytes`.
+    mem_reservations: Arc<Mutex<MemReservations>>,
+
+    /// Total memory available for batch tasks, i.e. the limit of `mem_context`.
+    mem_limit: u64,
+
     /// Metrics for batch manager.
     metrics: Arc<BatchManagerMetrics>,
 }
 
+/// Tracks the memory budgets reserved by tasks before execution, so that a compute node can
+/// reject new tasks instead of being OOM-killed when its batch memory is fully committed.
+#[derive(Default)]
+struct MemReservations {
+    /// Reserved bytes per task.
+    reservations: HashMap<TaskId, u64>,
+    /// Sum of all reserved bytes.
+    total: u64,
+}
+
 impl BatchManager {
     pub fn new(config: BatchConfig, metrics: Arc<BatchManagerMetrics>, mem_limit: u64) -> Self {
         let runtime = {
@@ -76,6 +93,36 @@ impl BatchManager {
             config,
             metrics,
             mem_context,
+            mem_reservations: Arc::new(Mutex::new(MemReservations::default())),
+            mem_limit,
+        }
+    }
+
+    /// Reserves `bytes` of the batch memory budget for the task, failing if the node cannot
+    /// spare it. The reservation is released in [`Self::cancel_task`].
+    fn try_reserve_memory(&self, task_id: &TaskId, bytes: u64) -> Result<()> {
+        if bytes == 0 {
+            return Ok(());
+        }
+        let mut reservations = self.mem_reservations.lock();
+        if reservations.total.saturating_add(bytes) > self.mem_limit {
+            bail!(
+                "out of memory: cannot reserve {} bytes for task {:?}, {} of {} bytes already reserved",
+                bytes,
+                task_id,
+                reservations.total,
+                self.mem_limit,
+            );
+        }
+        reservations.total += bytes;
+        reservations.reservations.insert(task_id.clone(), bytes);
+        Ok(())
+    }
+
+    fn release_reserved_memory(&self, task_id: &TaskId) {
+        let mut reservations = self.mem_reservations.lock();
+        if let Some(bytes) = reservations.reservations.remove(task_id) {
+            reservations.total -= bytes;
         }
     }
 
@@ -96,6 +143,7 @@ impl BatchManager {
         state_reporter: StateReporter,
         tracing_context: TracingContext,
         expr_context: ExprContext,
+        mem_reservation_bytes: u64,
     ) -> Result<()> {
         trace!("Received task id: {:?}, plan: {:?}", tid, plan);
         let task = BatchTaskExecution::new(tid, plan, context, epoch, self.runtime())?;
@@ -105,6 +153,7 @@ impl BatchManager {
         // send `TaskStatus::Running` in `.async_execute`, the query runner may schedule next stage,
         // it's possible do not found parent task id in theory.
         let ret = if let hash_map::Entry::Vacant(e) = self.tasks.lock().entry(task_id.clone()) {
+            self.try_reserve_memory(&task_id, mem_reservation_bytes)?;
             e.insert(task.clone());
 
             let this = self.clone();
@@ -151,6 +200,7 @@ impl BatchManager {
                 time_zone: "UTC".to_owned(),
                 strict_mode: false,
             },
+            0,
         )
         .await
     }
@@ -240,6 +290,7 @@ impl BatchManager {
                 warn!("Task {:?} not found for cancel", sid)
             }
         };
+        self.release_reserved_memory(&sid);
     }
 
     /// Returns error if task is not running.
@@ -327,6 +378,35 @@ mod tests {
         assert!(error.to_string().contains("not found"), "{:?}", error);
     }
 
+    #[tokio::test]
+    async fn test_memory_reservation() {
+        let manager = Arc::new(BatchManager::new(
+            BatchConfig::default(),
+            BatchManagerMetrics::for_test(),
+            1024,
+        ));
+        let task_id = TaskId {
+            task_id: 0,
+            stage_id: 0,
+            query_id: "abc".to_owned(),
+        };
+        let other_task_id = TaskId {
+            task_id: 1,
+            stage_id: 0,
+            query_id: "abc".to_owned(),
+        };
+
+        manager.try_reserve_memory(&task_id, 1000).unwrap();
+        let error = manager
+            .try_reserve_memory(&other_task_id, 100)
+            .unwrap_err();
+        assert!(error.to_string().contains("out of memory"), "{:?}", error);
+
+        // Releasing the first reservation frees the budget for the second task.
+        manager.release_reserved_memory(&task_id);
+        manager.try_reserve_memory(&other_task_id, 100).unwrap();
+    }
+
     #[tokio::test]
     // see https://github.com/risingwavelabs/risingwave/issues/11979
     #[ignore]
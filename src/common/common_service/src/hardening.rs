@@ -0,0 +1,75 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::config::ServerConfig;
+
+/// Applies the hardening options in the `[server]` section to a gRPC server under construction,
+/// i.e., the limit on concurrent HTTP/2 streams per connection and (mutual) TLS, so that
+/// inter-node RPC is supportable on untrusted networks.
+///
+/// Should be called on every `tonic::transport::Server` we build for inter-node services.
+/// No-op in the simulation build, where RPC does not go through real network.
+pub fn apply_grpc_hardening(
+    builder: tonic::transport::Server,
+    config: &ServerConfig,
+) -> anyhow::Result<tonic::transport::Server> {
+    #[cfg(not(madsim))]
+    {
+        let mut builder = builder;
+        if let Some(max_concurrent_streams) = config.grpc_max_concurrent_streams {
+            builder = builder.max_concurrent_streams(max_concurrent_streams);
+        }
+        if let Some(tls_config) = make_tls_config(&config.grpc_tls)? {
+            builder = builder.tls_config(tls_config)?;
+        }
+        Ok(builder)
+    }
+    #[cfg(madsim)]
+    {
+        let _ = config;
+        Ok(builder)
+    }
+}
+
+/// Builds the TLS configuration for gRPC servers from `[server.grpc_tls]`, or `None` if TLS
+/// is not enabled there.
+#[cfg(not(madsim))]
+fn make_tls_config(
+    config: &risingwave_common::config::GrpcTlsConfig,
+) -> anyhow::Result<Option<tonic::transport::ServerTlsConfig>> {
+    use anyhow::Context;
+
+    let (Some(cert_file), Some(key_file)) = (&config.cert_file, &config.key_file) else {
+        if config.cert_file.is_some() || config.key_file.is_some() || config.ca_cert_file.is_some()
+        {
+            anyhow::bail!(
+                "both `server.grpc_tls.cert_file` and `server.grpc_tls.key_file` must be set \
+                 to enable TLS for gRPC servers"
+            );
+        }
+        return Ok(None);
+    };
+    let cert = std::fs::read(cert_file)
+        .with_context(|| format!("failed to read gRPC server certificate `{cert_file}`"))?;
+    let key = std::fs::read(key_file)
+        .with_context(|| format!("failed to read gRPC server private key `{key_file}`"))?;
+    let mut tls_config = tonic::transport::ServerTlsConfig::new()
+        .identity(tonic::transport::Identity::from_pem(cert, key));
+    if let Some(ca_cert_file) = &config.ca_cert_file {
+        let ca_cert = std::fs::read(ca_cert_file)
+            .with_context(|| format!("failed to read gRPC client CA certificate `{ca_cert_file}`"))?;
+        tls_config = tls_config.client_ca_root(tonic::transport::Certificate::from_pem(ca_cert));
+    }
+    Ok(Some(tls_config))
+}
@@ -17,10 +17,12 @@
 #![feature(impl_trait_in_assoc_type)]
 #![feature(error_generic_member_access)]
 
+mod hardening;
 mod metrics_manager;
 mod observer_manager;
 mod tracing;
 
+pub use hardening::apply_grpc_hardening;
 pub use metrics_manager::MetricsManager;
 pub use observer_manager::{
     Channel, NotificationClient, ObserverError, ObserverManager, ObserverState,
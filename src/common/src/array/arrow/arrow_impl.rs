@@ -913,6 +913,33 @@ macro_rules! converts {
             }
         }
     };
+    // primitive types with identical representation: when the arrow array has no
+    // nulls, construct the array directly from the raw value buffer instead of
+    // per-datum `Option` handling
+    ($ArrayType:ty, $ArrowType:ty, @primitive) => {
+        impl From<&$ArrayType> for $ArrowType {
+            fn from(array: &$ArrayType) -> Self {
+                array.iter().collect()
+            }
+        }
+        impl From<&$ArrowType> for $ArrayType {
+            fn from(array: &$ArrowType) -> Self {
+                if array.null_count() == 0 {
+                    <$ArrayType>::from_iter_bitmap(
+                        array.values().iter().copied(),
+                        Bitmap::ones(array.len()),
+                    )
+                } else {
+                    array.iter().collect()
+                }
+            }
+        }
+        impl From<&[$ArrowType]> for $ArrayType {
+            fn from(arrays: &[$ArrowType]) -> Self {
+                arrays.iter().flat_map(|a| a.iter()).collect()
+            }
+        }
+    };
     // convert values using FromIntoArrow
     ($ArrayType:ty, $ArrowType:ty, @map) => {
         impl From<&$ArrayType> for $ArrowType {
@@ -990,6 +1017,16 @@ macro_rules! converts_with_timeunit {
 
         impl From<&$ArrowType> for $ArrayType {
             fn from(array: &$ArrowType) -> Self {
+                // When there are no nulls, convert the raw value buffer directly
+                // instead of per-datum `Option` handling.
+                if array.null_count() == 0 {
+                    return <$ArrayType>::from_iter_bitmap(
+                        array.values().iter().map(|v| {
+                            <<$ArrayType as Array>::RefItem<'_> as FromIntoArrowWithUnit>::from_arrow_with_unit(*v, $time_unit)
+                        }),
+                        Bitmap::ones(array.len()),
+                    );
+                }
                 array.iter().map(|o| {
                     o.map(|v| {
                         let timestamp = <<$ArrayType as Array>::RefItem<'_> as FromIntoArrowWithUnit>::from_arrow_with_unit(v, $time_unit);
@@ -1017,9 +1054,9 @@ macro_rules! converts_with_timeunit {
 }
 
 converts!(BoolArray, arrow_array::BooleanArray);
-converts!(I16Array, arrow_array::Int16Array);
-converts!(I32Array, arrow_array::Int32Array);
-converts!(I64Array, arrow_array::Int64Array);
+converts!(I16Array, arrow_array::Int16Array, @primitive);
+converts!(I32Array, arrow_array::Int32Array, @primitive);
+converts!(I64Array, arrow_array::Int64Array, @primitive);
 converts!(F32Array, arrow_array::Float32Array, @map);
 converts!(F64Array, arrow_array::Float64Array, @map);
 converts!(BytesArray, arrow_array::BinaryArray);
@@ -1450,6 +1487,15 @@ mod tests {
         assert_eq!(I64Array::from(&arrow), array);
     }
 
+    // covers the no-null fast path, which reads the raw value buffer directly
+    #[test]
+    fn i64_no_nulls() {
+        let array = I64Array::from_iter([1, -2, 3]);
+        let arrow = arrow_array::Int64Array::from(&array);
+        assert_eq!(arrow.null_count(), 0);
+        assert_eq!(I64Array::from(&arrow), array);
+    }
+
     #[test]
     fn f32() {
         let array = F32Array::from_iter([None, Some(-7.0), Some(25.0)]);
@@ -878,6 +878,7 @@ impl DataChunkTestExt for DataChunk {
                             data_type.clone(),
                             None,
                             None,
+                            None,
                             Self::SEED,
                         )
                         .unwrap();
@@ -272,6 +272,13 @@ pub struct MetaConfig {
     #[serde(default)]
     pub dangerous_max_idle_secs: Option<u64>,
 
+    /// After specified seconds of idle (no mview or flush), all streaming jobs will be
+    /// paused to release compute resources, and automatically resumed on the next activity.
+    /// It is a safer alternative to `dangerous_max_idle_secs` for dev clusters and
+    /// serverless deployments.
+    #[serde(default)]
+    pub auto_suspend_idle_secs: Option<u64>,
+
     /// The default global parallelism for all streaming jobs, if user doesn't specify the
     /// parallelism, this value will be used. `FULL` means use all available parallelism units,
     /// otherwise it's a number.
@@ -288,6 +295,12 @@ pub struct MetaConfig {
     #[serde(default)]
     pub enable_committed_sst_sanity_check: bool,
 
+    /// Interval of the background SST integrity scrubber, which samples SST objects and
+    /// verifies them against the version manifest. Corrupt objects are quarantined from GC
+    /// and raised through the event log. Unset to disable scrubbing.
+    #[serde(default)]
+    pub periodic_sst_scrub_interval_sec: Option<u64>,
+
     #[serde(default = "default::meta::node_num_monitor_interval_sec")]
     pub node_num_monitor_interval_sec: u64,
 
@@ -565,11 +578,44 @@ pub struct ServerConfig {
     #[serde(default = "default::server::grpc_max_reset_stream_size")]
     pub grpc_max_reset_stream: u32,
 
+    /// The maximum number of concurrent HTTP/2 streams of an inter-node gRPC connection.
+    /// Unlimited if not specified.
+    #[serde(default)]
+    pub grpc_max_concurrent_streams: Option<u32>,
+
+    /// The maximum size in bytes of gRPC messages accepted by the services that intentionally
+    /// allow arbitrarily large messages, e.g. those carrying SQL definitions or query plans.
+    /// Unlimited if not specified.
+    #[serde(default)]
+    pub grpc_max_decoding_message_size: Option<u64>,
+
+    /// TLS for the inter-node gRPC servers, to support deployments on untrusted networks.
+    #[serde(default)]
+    pub grpc_tls: GrpcTlsConfig,
+
     #[serde(default, flatten)]
     #[config_doc(omitted)]
     pub unrecognized: Unrecognized<Self>,
 }
 
+/// The subsection `[server.grpc_tls]` in `risingwave.toml`.
+#[derive(Clone, Debug, Serialize, Deserialize, DefaultFromSerde, ConfigDoc)]
+pub struct GrpcTlsConfig {
+    /// Path to the PEM-encoded certificate presented by the gRPC servers of this node.
+    /// TLS is enabled iff both `cert_file` and `key_file` are set.
+    #[serde(default)]
+    pub cert_file: Option<String>,
+
+    /// Path to the PEM-encoded private key of `cert_file`.
+    #[serde(default)]
+    pub key_file: Option<String>,
+
+    /// Path to the PEM-encoded CA certificate used to verify client certificates.
+    /// If set, clients must present a certificate signed by this CA (mutual TLS).
+    #[serde(default)]
+    pub ca_cert_file: Option<String>,
+}
+
 /// The section `[batch]` in `risingwave.toml`.
 #[derive(Clone, Debug, Serialize, Deserialize, DefaultFromSerde, ConfigDoc)]
 pub struct BatchConfig {
@@ -590,6 +636,12 @@ pub struct BatchConfig {
     #[serde(default)]
     pub max_batch_queries_per_frontend_node: Option<u64>,
 
+    /// How long a distributed query may wait in the admission queue for a free slot before it is
+    /// rejected, in milliseconds. Only effective when `max_batch_queries_per_frontend_node` is
+    /// set. Unset means waiting indefinitely.
+    #[serde(default)]
+    pub distributed_query_queue_timeout_ms: Option<u64>,
+
     #[serde(default = "default::batch::enable_barrier_read")]
     pub enable_barrier_read: bool,
 
@@ -783,6 +835,16 @@ pub struct StorageConfig {
     #[serde(default = "default::storage::max_prefetch_block_number")]
     pub max_prefetch_block_number: usize,
 
+    /// Rate limit in bytes per second for backfill reads from the object store.
+    /// 0 means unthrottled.
+    #[serde(default = "default::storage::backfill_read_bytes_per_sec")]
+    pub backfill_read_bytes_per_sec: u64,
+
+    /// Rate limit in bytes per second for compaction reads from the object store.
+    /// 0 means unthrottled. It only applies to compaction running on compute nodes.
+    #[serde(default = "default::storage::compaction_read_bytes_per_sec")]
+    pub compaction_read_bytes_per_sec: u64,
+
     #[serde(default = "default::storage::disable_remote_compactor")]
     pub disable_remote_compactor: bool,
 
@@ -847,6 +909,11 @@ pub struct StorageConfig {
     pub enable_fast_compaction: bool,
     #[serde(default = "default::storage::check_compaction_result")]
     pub check_compaction_result: bool,
+    /// Whether to verify a compaction task by comparing per-table key-counts and
+    /// xor-checksums of its input and output instead of a pairwise key-value comparison.
+    /// Only effective when `check_compaction_result` is enabled.
+    #[serde(default = "default::storage::check_compaction_result_checksum")]
+    pub check_compaction_result_checksum: bool,
     #[serde(default = "default::storage::max_preload_io_retry_times")]
     pub max_preload_io_retry_times: usize,
     #[serde(default = "default::storage::compactor_fast_max_compact_delete_ratio")]
@@ -1139,6 +1206,11 @@ pub struct StreamingDeveloperConfig {
     #[serde(default = "default::developer::stream_enable_auto_schema_change")]
     pub enable_auto_schema_change: bool,
 
+    /// Threshold of CDC upstream replication lag in milliseconds above which an alert event
+    /// is reported to the meta node. 0 disables the alert.
+    #[serde(default = "default::developer::stream_cdc_source_lag_alert_threshold_ms")]
+    pub cdc_source_lag_alert_threshold_ms: u64,
+
     #[serde(default = "default::developer::enable_shared_source")]
     /// Enable shared source
     /// If false, the shared source will be disabled,
@@ -1226,6 +1298,11 @@ pub struct ObjectStoreConfig {
 
     #[serde(default = "default::object_store_config::upload_part_size")]
     pub upload_part_size: usize,
+
+    /// Whether to probe the object store with a write-read-delete roundtrip on startup,
+    /// to fail fast on misconfigured permissions instead of at runtime.
+    #[serde(default)]
+    pub startup_probe: bool,
 }
 
 impl ObjectStoreConfig {
@@ -1786,6 +1863,10 @@ pub mod default {
             false
         }
 
+        pub fn check_compaction_result_checksum() -> bool {
+            false
+        }
+
         pub fn max_preload_io_retry_times() -> usize {
             3
         }
@@ -1806,6 +1887,14 @@ pub mod default {
             16
         }
 
+        pub fn backfill_read_bytes_per_sec() -> u64 {
+            0
+        }
+
+        pub fn compaction_read_bytes_per_sec() -> u64 {
+            0
+        }
+
         pub fn compactor_concurrent_uploading_sst_count() -> Option<usize> {
             None
         }
@@ -2110,6 +2199,10 @@ pub mod default {
             true
         }
 
+        pub fn stream_cdc_source_lag_alert_threshold_ms() -> u64 {
+            0
+        }
+
         pub fn switch_jdbc_pg_to_native() -> bool {
             false
         }
@@ -2259,6 +2352,15 @@ pub mod default {
         pub fn max_overlapping_level_size() -> u64 {
             256 * MB
         }
+
+        pub fn max_concurrent_task_count() -> u32 {
+            // 0 means unlimited.
+            0
+        }
+
+        pub fn scheduling_weight() -> u32 {
+            1
+        }
     }
 
     pub mod object_store_config {
@@ -42,7 +42,8 @@ pub const DEFAULT_LENGTH: usize = 10;
 
 /// fields that can be randomly generated impl this trait
 pub trait NumericFieldRandomGenerator {
-    fn new(min: Option<String>, max: Option<String>, seed: u64) -> Result<Self>
+    fn new(min: Option<String>, max: Option<String>, skew: Option<String>, seed: u64)
+        -> Result<Self>
     where
         Self: Sized;
 
@@ -155,23 +156,24 @@ impl FieldGeneratorImpl {
         data_type: DataType,
         min: Option<String>,
         max: Option<String>,
+        skew: Option<String>,
         seed: u64,
     ) -> Result<Self> {
         match data_type {
             DataType::Int16 => Ok(FieldGeneratorImpl::I16Random(I16RandomField::new(
-                min, max, seed,
+                min, max, skew, seed,
             )?)),
             DataType::Int32 => Ok(FieldGeneratorImpl::I32Random(I32RandomField::new(
-                min, max, seed,
+                min, max, skew, seed,
             )?)),
             DataType::Int64 => Ok(FieldGeneratorImpl::I64Random(I64RandomField::new(
-                min, max, seed,
+                min, max, skew, seed,
             )?)),
             DataType::Float32 => Ok(FieldGeneratorImpl::F32Random(F32RandomField::new(
-                min, max, seed,
+                min, max, skew, seed,
             )?)),
             DataType::Float64 => Ok(FieldGeneratorImpl::F64Random(F64RandomField::new(
-                min, max, seed,
+                min, max, skew, seed,
             )?)),
             _ => Err(anyhow!("unimplemented field generator {}", data_type)),
         }
@@ -381,7 +383,10 @@ mod tests {
                 DataType::Timestamptz => {
                     FieldGeneratorImpl::with_timestamptz(None, None, None, seed).unwrap()
                 }
-                _ => FieldGeneratorImpl::with_number_random(data_type, None, None, seed).unwrap(),
+                _ => {
+                    FieldGeneratorImpl::with_number_random(data_type, None, None, None, seed)
+                        .unwrap()
+                }
             };
 
             let val1 = generator.generate_json(1);
@@ -51,6 +51,9 @@ macro_rules! impl_numeric_type {
 pub struct NumericFieldRandomConcrete<T> {
     min: T,
     max: T,
+    /// Number of uniform draws to take the minimum of. `1` yields a uniform
+    /// distribution; larger values bias the result toward `min`.
+    skew: u32,
     seed: u64,
 }
 
@@ -68,12 +71,18 @@ where
     T: NumericType + Scalar,
     <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
 {
-    fn new(min_option: Option<String>, max_option: Option<String>, seed: u64) -> Result<Self>
+    fn new(
+        min_option: Option<String>,
+        max_option: Option<String>,
+        skew_option: Option<String>,
+        seed: u64,
+    ) -> Result<Self>
     where
         Self: Sized,
     {
         let mut min = T::zero();
         let mut max = T::from(i16::MAX);
+        let mut skew = 1;
 
         if let Some(min_option) = min_option {
             min = min_option.parse::<T>()?;
@@ -81,23 +90,52 @@ where
         if let Some(max_option) = max_option {
             max = max_option.parse::<T>()?;
         }
+        if let Some(skew_option) = skew_option {
+            skew = skew_option.parse::<u32>()?;
+            if skew == 0 {
+                return Err(anyhow::anyhow!("skew must be a positive integer"));
+            }
+        }
         assert!(min <= max);
 
-        Ok(Self { min, max, seed })
+        Ok(Self {
+            min,
+            max,
+            skew,
+            seed,
+        })
     }
 
     fn generate(&mut self, offset: u64) -> serde_json::Value {
         let mut rng = StdRng::seed_from_u64(offset ^ self.seed);
-        let result = rng.gen_range(self.min..=self.max);
+        let result = self.sample(&mut rng);
         json!(result)
     }
 
     fn generate_datum(&mut self, offset: u64) -> Datum {
         let mut rng = StdRng::seed_from_u64(offset ^ self.seed);
-        let result = rng.gen_range(self.min..=self.max);
+        let result = self.sample(&mut rng);
         Some(result.to_scalar_value())
     }
 }
+
+impl<T> NumericFieldRandomConcrete<T>
+where
+    T: NumericType + Scalar,
+{
+    /// Takes the minimum of `skew` uniform draws, so larger skew factors bias the
+    /// result toward `min` while keeping the generation deterministic per offset.
+    fn sample(&self, rng: &mut StdRng) -> T {
+        let mut result = rng.gen_range(self.min..=self.max);
+        for _ in 1..self.skew {
+            let draw = rng.gen_range(self.min..=self.max);
+            if draw < result {
+                result = draw;
+            }
+        }
+        result
+    }
+}
 impl<T> NumericFieldSequenceGenerator for NumericFieldSequenceConcrete<T>
 where
     T: NumericType + Scalar,
@@ -211,7 +249,7 @@ mod tests {
     #[test]
     fn test_random_field_generator() {
         let mut i64_field =
-            I64RandomField::new(Some("5".to_owned()), Some("10".to_owned()), 114).unwrap();
+            I64RandomField::new(Some("5".to_owned()), Some("10".to_owned()), None, 114).unwrap();
         for i in 0..100 {
             let res = i64_field.generate(i as u64);
             assert!(res.is_number());
@@ -220,7 +258,7 @@ mod tests {
         }
 
         // test overflow
-        let mut i64_field = I64RandomField::new(None, None, 114).unwrap();
+        let mut i64_field = I64RandomField::new(None, None, None, 114).unwrap();
         for i in 0..100 {
             let res = i64_field.generate(i as u64);
             assert!(res.is_number());
@@ -228,6 +266,28 @@ mod tests {
             assert!(res >= 0);
         }
     }
+    #[test]
+    fn test_skewed_random_field_generator() {
+        let min = Some("0".to_owned());
+        let max = Some("1000".to_owned());
+        let mut uniform = I64RandomField::new(min.clone(), max.clone(), None, 42).unwrap();
+        let mut skewed = I64RandomField::new(min, max, Some("8".to_owned()), 42).unwrap();
+
+        let mut uniform_sum = 0;
+        let mut skewed_sum = 0;
+        for i in 0..1000 {
+            let res = skewed.generate(i as u64);
+            let res = res.as_i64().unwrap();
+            assert!((0..=1000).contains(&res));
+            skewed_sum += res;
+            uniform_sum += uniform.generate(i as u64).as_i64().unwrap();
+        }
+        // skewed generation is biased toward the minimum
+        assert!(skewed_sum < uniform_sum);
+        // and stays deterministic per offset
+        assert_eq!(skewed.generate(1), skewed.generate(1));
+    }
+
     #[test]
     fn test_sequence_datum_generator() {
         let mut f32_field =
@@ -244,7 +304,7 @@ mod tests {
     #[test]
     fn test_random_datum_generator() {
         let mut i32_field =
-            I32RandomField::new(Some("-5".to_owned()), Some("5".to_owned()), 123).unwrap();
+            I32RandomField::new(Some("-5".to_owned()), Some("5".to_owned()), None, 123).unwrap();
         let (lower, upper) = ((-5).to_scalar_value(), 5.to_scalar_value());
         for i in 0..100 {
             let res = i32_field.generate_datum(i as u64);
@@ -272,7 +332,7 @@ mod tests {
     #[test]
     fn test_random_field_generator_float() {
         let mut f64_field =
-            F64RandomField::new(Some("5".to_owned()), Some("10".to_owned()), 114).unwrap();
+            F64RandomField::new(Some("5".to_owned()), Some("10".to_owned()), None, 114).unwrap();
         for i in 0..100 {
             let res = f64_field.generate(i as u64);
             assert!(res.is_number());
@@ -281,7 +341,7 @@ mod tests {
         }
 
         // test overflow
-        let mut f64_field = F64RandomField::new(None, None, 114).unwrap();
+        let mut f64_field = F64RandomField::new(None, None, None, 114).unwrap();
         for i in 0..100 {
             let res = f64_field.generate(i as u64);
             assert!(res.is_number());
@@ -290,7 +350,7 @@ mod tests {
         }
 
         let mut f32_field =
-            F32RandomField::new(Some("5".to_owned()), Some("10".to_owned()), 114).unwrap();
+            F32RandomField::new(Some("5".to_owned()), Some("10".to_owned()), None, 114).unwrap();
         for i in 0..100 {
             let res = f32_field.generate(i as u64);
             assert!(res.is_number());
@@ -300,7 +360,7 @@ mod tests {
         }
 
         // test overflow
-        let mut f32_field = F32RandomField::new(None, None, 114).unwrap();
+        let mut f32_field = F32RandomField::new(None, None, None, 114).unwrap();
         for i in 0..100 {
             let res = f32_field.generate(i as u64);
             assert!(res.is_number());
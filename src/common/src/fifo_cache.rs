@@ -0,0 +1,428 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An [S3-FIFO](https://s3fifo.com) cache.
+//!
+//! S3-FIFO consists of three FIFO queues:
+//! - a *small* queue holding newly inserted entries, sized at a fraction of the total capacity;
+//! - a *main* queue holding entries that were re-accessed while in the small queue;
+//! - a *ghost* queue remembering the hashes of entries recently evicted from the small queue.
+//!
+//! Each entry carries a 2-bit access frequency. Entries evicted from the small queue with a
+//! non-zero frequency are promoted to the main queue; the rest are dropped but remembered in the
+//! ghost queue, so that a quick re-insertion goes straight to the main queue. The main queue
+//! evicts with a second-chance scan, decrementing the frequency instead of evicting while it is
+//! non-zero. This keeps one-hit wonders from washing out the hot working set, which is the main
+//! weakness of plain FIFO/LRU under scan-heavy workloads like block caches.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash, RandomState};
+
+use parking_lot::Mutex;
+
+/// Max value of the per-entry access frequency counter (2 bits, as in the paper).
+const MAX_FREQ: u8 = 3;
+
+/// Ratio of the total capacity assigned to the small queue.
+const SMALL_QUEUE_RATIO: f64 = 0.1;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Queue {
+    Small,
+    Main,
+}
+
+struct CacheEntry<V> {
+    value: V,
+    /// The weighted cost of the entry, e.g. its size in bytes for a block cache.
+    cost: usize,
+    freq: u8,
+    queue: Queue,
+}
+
+/// A single-shard S3-FIFO cache. Not thread-safe; see [`FifoCache`] for the sharded
+/// concurrent wrapper.
+pub struct FifoCacheShard<K: Hash + Eq + Clone, V> {
+    entries: HashMap<K, CacheEntry<V>>,
+    small: VecDeque<K>,
+    main: VecDeque<K>,
+    /// Hashes of entries recently evicted from the small queue, in eviction order.
+    ghost: VecDeque<u64>,
+    /// Occurrence counts of the hashes in `ghost`, for O(1) membership checks.
+    ghost_set: HashMap<u64, usize>,
+    /// Max number of hashes remembered in the ghost queue.
+    ghost_capacity: usize,
+    /// Total cost of the entries in the small queue.
+    small_cost: usize,
+    /// Total cost of all entries.
+    total_cost: usize,
+    capacity: usize,
+    small_capacity: usize,
+    hash_builder: RandomState,
+}
+
+impl<K: Hash + Eq + Clone, V> FifoCacheShard<K, V> {
+    pub fn new(capacity: usize, ghost_capacity: usize) -> Self {
+        let small_capacity = ((capacity as f64 * SMALL_QUEUE_RATIO) as usize).max(1);
+        Self {
+            entries: HashMap::new(),
+            small: VecDeque::new(),
+            main: VecDeque::new(),
+            ghost: VecDeque::new(),
+            ghost_set: HashMap::new(),
+            ghost_capacity,
+            small_cost: 0,
+            total_cost: 0,
+            capacity,
+            small_capacity,
+            hash_builder: RandomState::new(),
+        }
+    }
+
+    /// Returns a reference to the value of the given key and bumps its access frequency.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let entry = self.entries.get_mut(key)?;
+        entry.freq = (entry.freq + 1).min(MAX_FREQ);
+        Some(&entry.value)
+    }
+
+    /// Inserts a key-value pair with the given cost, evicting entries if necessary.
+    ///
+    /// Keys remembered in the ghost queue are inserted directly into the main queue.
+    pub fn insert(&mut self, key: K, value: V, cost: usize) {
+        self.remove(&key);
+        self.evict(cost);
+
+        let queue = if self.ghost_remove(&key) {
+            self.main.push_back(key.clone());
+            Queue::Main
+        } else {
+            self.small.push_back(key.clone());
+            self.small_cost += cost;
+            Queue::Small
+        };
+        self.total_cost += cost;
+        self.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                cost,
+                freq: 0,
+                queue,
+            },
+        );
+    }
+
+    /// Removes the entry of the given key, leaving its stale queue slot to be skipped
+    /// lazily during eviction.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.entries.remove(key)?;
+        self.total_cost -= entry.cost;
+        if entry.queue == Queue::Small {
+            self.small_cost -= entry.cost;
+        }
+        // The key is dropped from `small`/`main` lazily: eviction skips keys that are
+        // no longer present or have moved to another queue.
+        Some(entry.value)
+    }
+
+    pub fn total_cost(&self) -> usize {
+        self.total_cost
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn evict(&mut self, incoming_cost: usize) {
+        while self.total_cost + incoming_cost > self.capacity && !self.entries.is_empty() {
+            if self.small_cost > self.small_capacity || self.main.is_empty() {
+                self.evict_small();
+            } else {
+                self.evict_main();
+            }
+        }
+    }
+
+    /// Evicts one entry from the small queue, promoting re-accessed entries to the main
+    /// queue instead of dropping them.
+    fn evict_small(&mut self) {
+        while let Some(key) = self.small.pop_front() {
+            let Some(entry) = self.entries.get_mut(&key) else {
+                continue; // stale slot of a removed key
+            };
+            if entry.queue != Queue::Small {
+                continue; // stale slot of a promoted key
+            }
+            if entry.freq > 0 {
+                entry.freq = 0;
+                entry.queue = Queue::Main;
+                self.small_cost -= entry.cost;
+                self.main.push_back(key);
+            } else {
+                let entry = self.entries.remove(&key).unwrap();
+                self.small_cost -= entry.cost;
+                self.total_cost -= entry.cost;
+                self.ghost_insert(&key);
+                return;
+            }
+        }
+    }
+
+    /// Evicts one entry from the main queue with a second-chance scan.
+    fn evict_main(&mut self) {
+        while let Some(key) = self.main.pop_front() {
+            let Some(entry) = self.entries.get_mut(&key) else {
+                continue; // stale slot of a removed key
+            };
+            if entry.queue != Queue::Main {
+                continue;
+            }
+            if entry.freq > 0 {
+                entry.freq -= 1;
+                self.main.push_back(key);
+            } else {
+                let entry = self.entries.remove(&key).unwrap();
+                self.total_cost -= entry.cost;
+                return;
+            }
+        }
+    }
+
+    fn ghost_insert(&mut self, key: &K) {
+        let hash = self.hash_builder.hash_one(key);
+        self.ghost.push_back(hash);
+        *self.ghost_set.entry(hash).or_insert(0) += 1;
+        while self.ghost.len() > self.ghost_capacity {
+            let hash = self.ghost.pop_front().unwrap();
+            let count = self.ghost_set.get_mut(&hash).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                self.ghost_set.remove(&hash);
+            }
+        }
+    }
+
+    /// Returns whether the key was remembered in the ghost queue, forgetting it if so.
+    fn ghost_remove(&mut self, key: &K) -> bool {
+        let hash = self.hash_builder.hash_one(key);
+        let Some(count) = self.ghost_set.get_mut(&hash) else {
+            return false;
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.ghost_set.remove(&hash);
+        }
+        // The stale hash in `ghost` is skipped when its count in `ghost_set` runs out.
+        true
+    }
+}
+
+/// A sharded, thread-safe S3-FIFO cache with weighted costs.
+pub struct FifoCache<K: Hash + Eq + Clone, V> {
+    shards: Vec<Mutex<FifoCacheShard<K, V>>>,
+    hash_builder: RandomState,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> FifoCache<K, V> {
+    /// Creates a cache with the given total capacity (in cost units) split over
+    /// `num_shards` shards. The ghost queue of each shard remembers up to
+    /// `ghost_capacity / num_shards` evicted hashes.
+    pub fn new(capacity: usize, num_shards: usize, ghost_capacity: usize) -> Self {
+        assert!(num_shards > 0);
+        let shards = (0..num_shards)
+            .map(|_| {
+                Mutex::new(FifoCacheShard::new(
+                    capacity / num_shards,
+                    (ghost_capacity / num_shards).max(1),
+                ))
+            })
+            .collect();
+        Self {
+            shards,
+            hash_builder: RandomState::new(),
+        }
+    }
+
+    fn shard(&self, key: &K) -> &Mutex<FifoCacheShard<K, V>> {
+        let hash = self.hash_builder.hash_one(key);
+        &self.shards[hash as usize % self.shards.len()]
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.shard(key).lock().get(key).cloned()
+    }
+
+    pub fn insert(&self, key: K, value: V, cost: usize) {
+        self.shard(&key).lock().insert(key, value, cost);
+    }
+
+    pub fn remove(&self, key: &K) -> Option<V> {
+        self.shard(key).lock().remove(key)
+    }
+
+    pub fn total_cost(&self) -> usize {
+        self.shards.iter().map(|s| s.lock().total_cost()).sum()
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|s| s.lock().len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shards.iter().all(|s| s.lock().is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        let mut cache = FifoCacheShard::new(10, 10);
+        cache.insert(1, "a", 1);
+        cache.insert(2, "b", 1);
+        assert_eq!(cache.get(&1), Some(&"a"));
+        assert_eq!(cache.get(&3), None);
+        assert_eq!(cache.remove(&1), Some("a"));
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.total_cost(), 1);
+    }
+
+    #[test]
+    fn test_one_hit_wonders_do_not_evict_hot_entries() {
+        let mut cache = FifoCacheShard::new(100, 100);
+        // Fill the main queue with hot entries.
+        for k in 0..8 {
+            cache.insert(k, (), 10);
+        }
+        for k in 0..8 {
+            cache.get(&k);
+        }
+        // Promote them by churning the small queue.
+        for k in 100..200 {
+            cache.insert(k, (), 1);
+        }
+        // The hot entries survive the scan.
+        for k in 0..8 {
+            assert!(cache.get(&k).is_some(), "hot entry {k} was evicted");
+        }
+    }
+
+    #[test]
+    fn test_ghost_promotes_to_main() {
+        let mut cache = FifoCacheShard::new(10, 10);
+        cache.insert(1, (), 1);
+        // Evict key 1 from the small queue without any access.
+        for k in 2..20 {
+            cache.insert(k, (), 1);
+        }
+        assert!(cache.get(&1).is_none());
+        // Re-inserting a ghost key goes straight to the main queue.
+        cache.insert(1, (), 1);
+        assert!(matches!(cache.entries.get(&1).unwrap().queue, Queue::Main));
+    }
+
+    #[test]
+    fn test_weighted_eviction() {
+        let mut cache = FifoCacheShard::new(10, 10);
+        cache.insert(1, (), 8);
+        cache.insert(2, (), 8);
+        // The capacity only fits one of the two entries.
+        assert_eq!(cache.len(), 1);
+        assert!(cache.total_cost() <= 10);
+    }
+
+    /// Drives a shard with a random operation sequence and checks the bookkeeping
+    /// invariants after every step: cost accounting of the small and total queues,
+    /// ghost queue bounds, capacity, and that lookups never return stale values.
+    #[test]
+    fn test_random_ops_invariants() {
+        use std::collections::HashMap as Model;
+
+        use rand::rngs::SmallRng;
+        use rand::{Rng, SeedableRng};
+
+        const CAPACITY: usize = 100;
+        const GHOST_CAPACITY: usize = 50;
+        const KEY_SPACE: u64 = 64;
+        const NUM_OPS: usize = 10_000;
+
+        fn check_invariants(cache: &FifoCacheShard<u64, u64>) {
+            let total: usize = cache.entries.values().map(|e| e.cost).sum();
+            let small: usize = cache
+                .entries
+                .values()
+                .filter(|e| e.queue == Queue::Small)
+                .map(|e| e.cost)
+                .sum();
+            assert_eq!(cache.total_cost, total);
+            assert_eq!(cache.small_cost, small);
+            assert!(cache.total_cost <= cache.capacity);
+            assert!(cache.ghost.len() <= cache.ghost_capacity);
+            assert!(cache.ghost_set.values().sum::<usize>() <= cache.ghost.len());
+            assert!(cache.ghost_set.values().all(|count| *count > 0));
+        }
+
+        let mut rng = SmallRng::seed_from_u64(114514);
+        let mut cache = FifoCacheShard::new(CAPACITY, GHOST_CAPACITY);
+        // The last value inserted per key, to detect stale reads of evicted entries.
+        let mut model: Model<u64, u64> = Model::new();
+
+        for i in 0..NUM_OPS {
+            let key = rng.gen_range(0..KEY_SPACE);
+            match rng.gen_range(0..10) {
+                0..=4 => {
+                    let value = i as u64;
+                    let cost = rng.gen_range(1..=CAPACITY / 4);
+                    cache.insert(key, value, cost);
+                    model.insert(key, value);
+                }
+                5..=7 => {
+                    // The cache may have evicted the key, but it must never serve a
+                    // value other than the last one inserted.
+                    if let Some(value) = cache.get(&key) {
+                        assert_eq!(Some(value), model.get(&key));
+                    }
+                }
+                _ => {
+                    if let Some(value) = cache.remove(&key) {
+                        assert_eq!(Some(&value), model.get(&key));
+                    }
+                    // A removed key must be gone no matter what.
+                    assert_eq!(cache.get(&key), None);
+                }
+            }
+            check_invariants(&cache);
+        }
+    }
+
+    #[test]
+    fn test_sharded() {
+        let cache = FifoCache::new(1024, 4, 1024);
+        for k in 0..100 {
+            cache.insert(k, k * 2, 1);
+        }
+        for k in 0..100 {
+            assert_eq!(cache.get(&k), Some(k * 2));
+        }
+        assert_eq!(cache.len(), 100);
+    }
+}
@@ -22,6 +22,10 @@ use crate::types::DataType;
 /// An enum to help to dynamically dispatch [`HashKey`] template.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum HashKeyKind {
+    /// Specialized key for a single `int4` key column, see [`crate::hash::SingleInt32Key`].
+    SingleInt32,
+    /// Specialized key for a single `int8` key column, see [`crate::hash::SingleInt64Key`].
+    SingleInt64,
     Key8,
     Key16,
     Key32,
@@ -83,6 +87,10 @@ pub trait HashKeyDispatcher: Sized {
     /// `KeySerialized` for variable length keys.
     fn dispatch_by_key_size<T: NullBitmap>(self) -> Self::Output {
         match calc_hash_key_kind(self.data_types()) {
+            // The specialized keys do not need a null bitmap type parameter as the
+            // nullability of a single key column always fits on the stack.
+            HashKeyKind::SingleInt32 => self.dispatch_impl::<hash::SingleInt32Key>(),
+            HashKeyKind::SingleInt64 => self.dispatch_impl::<hash::SingleInt64Key>(),
             HashKeyKind::Key8 => self.dispatch_impl::<hash::Key8<T>>(),
             HashKeyKind::Key16 => self.dispatch_impl::<hash::Key16<T>>(),
             HashKeyKind::Key32 => self.dispatch_impl::<hash::Key32<T>>(),
@@ -107,6 +115,9 @@ const MAX_FIXED_SIZE_KEY_ELEMENTS: usize = 8;
 
 /// Calculate what kind of hash key should be used given the key data types.
 ///
+/// If there is a single fixed-width integer key column, we choose the specialized
+/// [`crate::hash::SingleValueKey`] that skips serialization entirely.
+///
 /// When any of following conditions is met, we choose [`crate::hash::SerializedKey`]:
 /// 1. Has variable size column.
 /// 2. Number of columns exceeds [`MAX_FIXED_SIZE_KEY_ELEMENTS`]
@@ -115,6 +126,14 @@ const MAX_FIXED_SIZE_KEY_ELEMENTS: usize = 8;
 ///
 /// Otherwise we choose smallest [`crate::hash::FixedSizeKey`] whose size can hold all data types.
 pub fn calc_hash_key_kind(data_types: &[DataType]) -> HashKeyKind {
+    if let [data_type] = data_types {
+        match data_type {
+            DataType::Int32 => return HashKeyKind::SingleInt32,
+            DataType::Int64 => return HashKeyKind::SingleInt64,
+            _ => {}
+        }
+    }
+
     if data_types.len() > MAX_FIXED_SIZE_KEY_ELEMENTS {
         return HashKeyKind::KeySerialized;
     }
@@ -176,8 +195,8 @@ mod tests {
     fn test_calc_hash_key_kind() {
         compare_key_kinds(&[0], HashKeyKind::Key8);
         compare_key_kinds(&[1], HashKeyKind::Key16);
-        compare_key_kinds(&[2], HashKeyKind::Key32);
-        compare_key_kinds(&[3], HashKeyKind::Key64);
+        compare_key_kinds(&[2], HashKeyKind::SingleInt32);
+        compare_key_kinds(&[3], HashKeyKind::SingleInt64);
         compare_key_kinds(&[8], HashKeyKind::Key128);
         compare_key_kinds(&[3, 4], HashKeyKind::Key128);
         compare_key_kinds(&[3, 4, 6], HashKeyKind::Key256);
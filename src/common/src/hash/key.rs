@@ -643,7 +643,9 @@ mod tests {
         DateArray, DecimalArray, F32Array, F64Array, I16Array, I32Array, I32ArrayBuilder, I64Array,
         TimeArray, TimestampArray, Utf8Array,
     };
-    use crate::hash::{HashKey, Key128, Key16, Key256, Key32, Key64, KeySerialized};
+    use crate::hash::{
+        HashKey, Key128, Key16, Key256, Key32, Key64, KeySerialized, SingleInt32Key, SingleInt64Key,
+    };
     use crate::test_utils::rand_array::seed_rand_array_ref;
     use crate::types::Datum;
 
@@ -806,6 +808,12 @@ mod tests {
         do_test::<Key256, _>(vec![3, 6], generate_random_data_chunk);
     }
 
+    #[test]
+    fn test_single_value_hash_key() {
+        do_test::<SingleInt32Key, _>(vec![2], generate_random_data_chunk);
+        do_test::<SingleInt64Key, _>(vec![3], generate_random_data_chunk);
+    }
+
     #[test]
     fn test_var_length_hash_key() {
         do_test::<KeySerialized, _>(vec![0, 7], generate_random_data_chunk);
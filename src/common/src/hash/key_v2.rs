@@ -23,7 +23,7 @@ use itertools::Itertools;
 use risingwave_common_estimate_size::EstimateSize;
 use tinyvec::ArrayVec;
 
-use super::{HeapNullBitmap, NullBitmap, XxHash64HashCode};
+use super::{HeapNullBitmap, NullBitmap, StackNullBitmap, XxHash64HashCode};
 use crate::array::{Array, ArrayBuilder, ArrayBuilderImpl, ArrayResult, DataChunk};
 use crate::hash::{HashKeyDe, HashKeySer};
 use crate::row::OwnedRow;
@@ -396,6 +396,120 @@ impl DataChunk {
     }
 }
 
+/// A specialized hash key for a single fixed-width key column.
+///
+/// Unlike [`HashKeyImpl`], the raw key value is stored directly instead of
+/// being serialized into a byte buffer, so that building and deserializing
+/// the key are plain copies.
+#[derive(Clone)]
+pub struct SingleValueKey<T> {
+    hash_code: XxHash64HashCode,
+    /// The raw key value. Unspecified (default) if the key is `NULL`,
+    /// so that equality can be checked on the value directly.
+    value: T,
+    null_bitmap: StackNullBitmap,
+}
+
+impl<T> Hash for SingleValueKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Caveat: this should only be used along with `PrecomputedHashBuilder`.
+        state.write_u64(self.hash_code.value());
+    }
+}
+
+impl<T: PartialEq> PartialEq for SingleValueKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare the hash code first for short-circuit.
+        self.hash_code == other.hash_code
+            && self.value == other.value
+            && self.null_bitmap == other.null_bitmap
+    }
+}
+impl<T: PartialEq> Eq for SingleValueKey<T> {}
+
+impl<T: Debug> std::fmt::Debug for SingleValueKey<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SingleValueKey")
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> EstimateSize for SingleValueKey<T> {
+    fn estimated_heap_size(&self) -> usize {
+        0
+    }
+}
+
+macro_rules! impl_single_value_hash_key {
+    ($key_ty:ident, $scalar_ty:ty, $as_array:ident, $builder_variant:ident) => {
+        pub type $key_ty = SingleValueKey<$scalar_ty>;
+
+        impl HashKey for $key_ty {
+            type Bitmap = StackNullBitmap;
+
+            fn build_many(column_indices: &[usize], data_chunk: &DataChunk) -> Vec<Self> {
+                assert_eq!(
+                    column_indices.len(),
+                    1,
+                    "{} only supports a single key column",
+                    stringify!($key_ty),
+                );
+                let hash_codes = data_chunk.get_hash_values(column_indices, XxHash64Builder);
+                let array = data_chunk.column_at(column_indices[0]).$as_array();
+
+                hash_codes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, hash_code)| {
+                        // SAFETY(value_at_unchecked): the idx is always in bound.
+                        let value = unsafe { array.value_at_unchecked(i) };
+                        let mut null_bitmap = StackNullBitmap::empty();
+                        if value.is_none() {
+                            null_bitmap.set_true(0);
+                        }
+                        Self {
+                            hash_code,
+                            value: value.unwrap_or_default(),
+                            null_bitmap,
+                        }
+                    })
+                    .collect()
+            }
+
+            fn deserialize(&self, data_types: &[DataType]) -> ArrayResult<OwnedRow> {
+                assert_eq!(data_types.len(), 1);
+                let datum = (!self.null_bitmap.contains(0)).then(|| ScalarImpl::from(self.value));
+                Ok(OwnedRow::new(vec![datum]))
+            }
+
+            fn deserialize_to_builders(
+                &self,
+                array_builders: &mut [ArrayBuilderImpl],
+                data_types: &[DataType],
+            ) -> ArrayResult<()> {
+                assert_eq!(data_types.len(), 1);
+                let ArrayBuilderImpl::$builder_variant(builder) = &mut array_builders[0] else {
+                    panic!(
+                        "data type mismatch for {}: {:?}",
+                        stringify!($key_ty),
+                        data_types,
+                    );
+                };
+                builder.append_owned((!self.null_bitmap.contains(0)).then_some(self.value));
+                Ok(())
+            }
+
+            fn null_bitmap(&self) -> &Self::Bitmap {
+                &self.null_bitmap
+            }
+        }
+    };
+}
+
+impl_single_value_hash_key!(SingleInt32Key, i32, as_int32, Int32);
+impl_single_value_hash_key!(SingleInt64Key, i64, as_int64, Int64);
+
 pub type FixedSizeKey<const N: usize, B> = HashKeyImpl<StackStorage<N>, B>;
 pub type Key8<B = HeapNullBitmap> = FixedSizeKey<1, B>;
 pub type Key16<B = HeapNullBitmap> = FixedSizeKey<2, B>;
@@ -67,6 +67,7 @@ pub mod catalog;
 pub mod config;
 pub mod constants;
 pub mod field_generator;
+pub mod fifo_cache;
 pub mod hash;
 pub mod log;
 pub mod memory;
@@ -303,6 +303,42 @@ where
         }
     }
 
+    /// Retain only the entries for which the predicate returns `true`, dropping the others.
+    /// The LRU order of the retained entries is left untouched.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        unsafe {
+            let dummy = self.dummy.as_mut() as *mut LruEntry<K, V>;
+            let mut ptr = self.dummy.next.unwrap_unchecked();
+            while ptr.as_ptr() != dummy {
+                let next = ptr.as_ref().next.unwrap_unchecked();
+                if !f(ptr.as_ref().key(), ptr.as_ref().value()) {
+                    self.detach(ptr);
+
+                    let mut entry = Box::from_raw_in(ptr.as_ptr(), self.alloc.clone());
+
+                    let hash = self.hash_builder.hash_one(entry.key());
+                    match self.map.entry(
+                        hash,
+                        |p| p.as_ref().key() == entry.key(),
+                        |p| p.as_ref().hash,
+                    ) {
+                        Entry::Occupied(o) => {
+                            o.remove();
+                        }
+                        Entry::Vacant(_) => {}
+                    }
+
+                    entry.key.assume_init_drop();
+                    entry.value.assume_init_drop();
+                }
+                ptr = next;
+            }
+        }
+    }
+
     pub fn clear(&mut self) {
         unsafe {
             let mut map = HashTable::new_in(self.alloc.clone());
@@ -366,4 +402,104 @@ where
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    /// Drives the cache with a random operation sequence against a model tracking both
+    /// the mapping and the LRU order, checking that lookups never return stale values
+    /// and that `pop_with_sequence` pops in LRU order.
+    #[test]
+    fn test_random_ops_against_model() {
+        const KEY_SPACE: u64 = 64;
+        const NUM_OPS: usize = 10_000;
+
+        let mut rng = SmallRng::seed_from_u64(114514);
+        let mut cache: LruCache<u64, u64> = LruCache::unbounded();
+        let mut model: HashMap<u64, u64> = HashMap::new();
+        // Keys from least to most recently used.
+        let mut order: VecDeque<u64> = VecDeque::new();
+
+        for i in 0..NUM_OPS {
+            let key = rng.gen_range(0..KEY_SPACE);
+            match rng.gen_range(0..10) {
+                // put
+                0..=3 => {
+                    let value = i as u64;
+                    let old = cache.put(key, value);
+                    assert_eq!(old, model.insert(key, value));
+                    order.retain(|k| *k != key);
+                    order.push_back(key);
+                }
+                // get
+                4..=6 => {
+                    assert_eq!(cache.get(&key), model.get(&key));
+                    if model.contains_key(&key) {
+                        order.retain(|k| *k != key);
+                        order.push_back(key);
+                    }
+                }
+                // peek must not touch the LRU order
+                7 => {
+                    assert_eq!(cache.peek(&key), model.get(&key));
+                    assert_eq!(cache.contains(&key), model.contains_key(&key));
+                }
+                // pop the LRU head
+                8 => match cache.pop_with_sequence(Sequence::MAX) {
+                    Some((k, v, _)) => {
+                        assert_eq!(order.pop_front(), Some(k));
+                        assert_eq!(model.remove(&k), Some(v));
+                    }
+                    None => assert!(model.is_empty()),
+                },
+                // retain a random subset, keeping the LRU order of the rest
+                _ => {
+                    let parity = rng.gen_range(0..2);
+                    cache.retain(|k, _| k % 2 == parity);
+                    model.retain(|k, _| k % 2 == parity);
+                    order.retain(|k| k % 2 == parity);
+                }
+            }
+            assert_eq!(cache.len(), model.len());
+        }
+
+        // Popping everything yields the exact LRU order.
+        while let Some((k, v, _)) = cache.pop_with_sequence(Sequence::MAX) {
+            assert_eq!(order.pop_front(), Some(k));
+            assert_eq!(model.remove(&k), Some(v));
+        }
+        assert!(model.is_empty());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    /// Popping follows the attach sequence, and touching an entry re-assigns its
+    /// sequence so it is popped last.
+    #[test]
+    fn test_pop_sequence_order() {
+        let mut cache: LruCache<u64, u64> = LruCache::unbounded();
+        for k in 0..10 {
+            cache.put(k, k);
+        }
+        // Popped sequences are strictly increasing: entries attached later can never be
+        // popped before earlier ones.
+        let mut sequences = vec![];
+        while let Some((k, _, seq)) = cache.pop_with_sequence(Sequence::MAX) {
+            sequences.push((k, seq));
+        }
+        assert!(sequences.windows(2).all(|w| w[0].1 < w[1].1));
+
+        for k in 0..10 {
+            cache.put(k, k);
+        }
+        // Touching an entry re-assigns its sequence, moving it behind the rest.
+        cache.get(&0);
+        let (first, ..) = cache.pop_with_sequence(Sequence::MAX).unwrap();
+        assert_eq!(first, 1);
+    }
+}
@@ -120,6 +120,13 @@ pub struct SessionConfig {
     #[parameter(default = true)]
     batch_expr_strict_mode: bool,
 
+    /// The memory budget in bytes that each task of a distributed batch query reserves on the
+    /// compute node before execution. Tasks are rejected when the compute node cannot spare the
+    /// budget, preventing a single query from exhausting the batch memory of serving nodes.
+    /// 0 disables the reservation.
+    #[parameter(default = 0_u64)]
+    batch_mem_reservation_bytes: u64,
+
     /// The max gap allowed to transform small range scan into multi point lookup.
     #[parameter(default = 8)]
     max_split_range_gap: i32,
@@ -183,6 +190,13 @@ pub struct SessionConfig {
     #[parameter(default = false, alias = "rw_streaming_allow_jsonb_in_stream_key")]
     streaming_allow_jsonb_in_stream_key: bool,
 
+    /// Tick granularity of the streaming `now()` source in milliseconds. When set to a
+    /// positive value, `now()` only advances after at least this interval has passed,
+    /// coalescing the per-barrier updates of temporal filters. Defaults to 0, i.e. tick
+    /// on every barrier.
+    #[parameter(default = 0u64)]
+    streaming_now_tick_interval_ms: u64,
+
     /// Enable join ordering for streaming and batch queries. Defaults to true.
     #[parameter(default = true, alias = "rw_enable_join_ordering")]
     enable_join_ordering: bool,
@@ -254,6 +268,10 @@ pub struct SessionConfig {
     #[parameter(default = 60000u32)]
     idle_in_transaction_session_timeout: u32,
 
+    /// Terminate any session that has been idle (that is, waiting for a client query), but not within an open transaction, for longer than the specified amount of time in milliseconds. A value of zero (the default) disables the timeout.
+    #[parameter(default = 0u32)]
+    idle_session_timeout: u32,
+
     /// See <https://www.postgresql.org/docs/current/runtime-config-client.html#GUC-LOCK-TIMEOUT>
     /// Unused in RisingWave, support for compatibility.
     #[parameter(default = 0)]
@@ -337,6 +355,12 @@ pub struct SessionConfig {
     // a.k.a. vnode count
     #[parameter(default = VirtualNode::COUNT_FOR_COMPAT, check_hook = check_streaming_max_parallelism)]
     streaming_max_parallelism: usize,
+
+    /// Determines the algorithm used to encrypt passwords specified in `CREATE USER` or
+    /// `ALTER USER` without a pre-encrypted password. Supports `md5` and `scram-sha-256`.
+    /// See <https://www.postgresql.org/docs/current/runtime-config-connection.html#GUC-PASSWORD-ENCRYPTION>
+    #[parameter(default = "md5", check_hook = check_password_encryption)]
+    password_encryption: String,
 }
 
 fn check_timezone(val: &str) -> Result<(), String> {
@@ -363,6 +387,13 @@ fn check_bytea_output(val: &str) -> Result<(), String> {
     }
 }
 
+fn check_password_encryption(val: &str) -> Result<(), String> {
+    match val {
+        "md5" | "scram-sha-256" => Ok(()),
+        _ => Err("Only support 'md5' and 'scram-sha-256' for PASSWORD_ENCRYPTION".to_owned()),
+    }
+}
+
 /// Check if the provided value is a valid max parallelism.
 fn check_streaming_max_parallelism(val: &usize) -> Result<(), String> {
     match val {
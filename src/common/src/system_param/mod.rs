@@ -92,6 +92,7 @@ macro_rules! for_all_params {
             { use_new_object_prefix_strategy,           bool,                           None,                           false,  "Whether to split object prefix.", },
             { license_key,                              risingwave_license::LicenseKey, Some(Default::default()),       true,   "The license key to activate enterprise features.", },
             { time_travel_retention_ms,                 u64,                            Some(600000_u64),              true,   "The data retention period for time travel.", },
+            { max_connections,                          u32,                            Some(0_u32),                    true,   "Maximum number of concurrent sessions on each frontend node. 0 means unlimited.", },
         }
     };
 }
@@ -212,4 +212,10 @@ where
             .time_travel_retention_ms
             .unwrap_or_else(default::time_travel_retention_ms)
     }
+
+    fn max_connections(&self) -> u32 {
+        self.inner()
+            .max_connections
+            .unwrap_or_else(default::max_connections)
+    }
 }
@@ -19,7 +19,8 @@ use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 use byteorder::{BigEndian, ReadBytesExt};
 use bytes::{BufMut, BytesMut};
 use num_traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Num, One, Zero,
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Num, One, ToPrimitive,
+    Zero,
 };
 use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
 use risingwave_common_estimate_size::ZeroHeapSize;
@@ -27,7 +28,7 @@ use rust_decimal::prelude::FromStr;
 use rust_decimal::{Decimal as RustDecimal, Error, MathematicalOps as _, RoundingStrategy};
 
 use super::to_text::ToText;
-use super::DataType;
+use super::{DataType, Int256};
 use crate::array::ArrayResult;
 use crate::types::ordered_float::OrderedFloat;
 use crate::types::Decimal::Normalized;
@@ -250,6 +251,32 @@ impl_convert_int!(u16);
 impl_convert_int!(u32);
 impl_convert_int!(u64);
 
+impl core::convert::TryFrom<Decimal> for Int256 {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(d: Decimal) -> Result<Self, Self::Error> {
+        match d.round_dp_ties_away(0) {
+            Decimal::Normalized(d) => d
+                .to_i128()
+                .map(Int256::from)
+                .ok_or_else(|| Error::ConversionTo(std::any::type_name::<Int256>().into())),
+            _ => Err(Error::ConversionTo(std::any::type_name::<Int256>().into())),
+        }
+    }
+}
+
+impl core::convert::TryFrom<Int256> for Decimal {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: Int256) -> Result<Self, Self::Error> {
+        let value = i128::try_from(*value.0)
+            .map_err(|_| Error::ConversionTo(std::any::type_name::<Decimal>().into()))?;
+        RustDecimal::try_from(value).map(Self::Normalized)
+    }
+}
+
 checked_proxy!(CheckedRem, checked_rem, %);
 checked_proxy!(CheckedSub, checked_sub, -);
 checked_proxy!(CheckedAdd, checked_add, +);
@@ -602,6 +602,49 @@ impl Interval {
         }
         Some(Self::from_month_day_usec(self.months, days, usecs))
     }
+
+    // Assuming 30 days = 1 month, adjust `abs(days)` to be less than 30 days, and has the same
+    // sign with `months`.
+    pub fn justify_day(self) -> Option<Self> {
+        let whole_month = self.days / 30;
+        let mut days = self.days % 30;
+        let mut months = self.months.checked_add(whole_month)?;
+        if months > 0 && days < 0 {
+            days += 30;
+            months -= 1;
+        } else if months < 0 && days > 0 {
+            days -= 30;
+            months += 1;
+        }
+        Some(Self::from_month_day_usec(months, days, self.usecs))
+    }
+
+    // Adjust the interval so that `abs(usecs)` is less than 24 hours, `abs(days)` is less than
+    // 30 days, and all units have the same sign, following PostgreSQL `justify_interval`.
+    pub fn justify_interval(self) -> Option<Self> {
+        let whole_day = (self.usecs / USECS_PER_DAY) as i32;
+        let mut usecs = self.usecs % USECS_PER_DAY;
+        let days = self.days.checked_add(whole_day)?;
+        let whole_month = days / 30;
+        let mut days = days % 30;
+        let mut months = self.months.checked_add(whole_month)?;
+
+        if months > 0 && (days < 0 || (days == 0 && usecs < 0)) {
+            days += 30;
+            months -= 1;
+        } else if months < 0 && (days > 0 || (days == 0 && usecs > 0)) {
+            days -= 30;
+            months += 1;
+        }
+        if days > 0 && usecs < 0 {
+            usecs += USECS_PER_DAY;
+            days -= 1;
+        } else if days < 0 && usecs > 0 {
+            usecs -= USECS_PER_DAY;
+            days += 1;
+        }
+        Some(Self::from_month_day_usec(months, days, usecs))
+    }
 }
 
 /// A separate mod so that `use types::*` or `use interval::*` does not `use IntervalTestExt` by
@@ -1637,6 +1680,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_justify() {
+        // (input, justify_day, justify_hour, justify_interval), all as (months, days, usecs).
+        // Expected results match PostgreSQL `justify_days/hours/interval`.
+        let cases = [
+            (
+                (0, 35, 27 * 3600 * 1_000_000),
+                (1, 5, 27 * 3600 * 1_000_000),
+                (0, 36, 3 * 3600 * 1_000_000),
+                (1, 6, 3 * 3600 * 1_000_000),
+            ),
+            (
+                (1, 0, -3600 * 1_000_000),
+                (1, 0, -3600 * 1_000_000),
+                (1, 0, -3600 * 1_000_000),
+                (0, 29, 23 * 3600 * 1_000_000),
+            ),
+            (
+                (-1, 30, 0),
+                (0, 0, 0),
+                (-1, 30, 0),
+                (0, 0, 0),
+            ),
+        ];
+        // `Interval` equality is by equivalence class, so compare the raw fields instead.
+        let fields = |v: Interval| (v.months(), v.days(), v.usecs());
+        for (input, day, hour, interval) in cases {
+            let input = Interval::from_month_day_usec(input.0, input.1, input.2);
+            assert_eq!(fields(input.justify_day().unwrap()), day);
+            assert_eq!(fields(input.justify_hour().unwrap()), hour);
+            assert_eq!(fields(input.justify_interval().unwrap()), interval);
+        }
+        // justifying i32::MAX months + 30 days overflows the months field
+        assert_eq!(
+            Interval::from_month_day_usec(i32::MAX, 30, 0).justify_day(),
+            None
+        );
+    }
+
     #[test]
     fn test_serialize_deserialize() {
         let mut serializer = memcomparable::Serializer::new(vec![]);
@@ -18,6 +18,7 @@ use std::str::FromStr;
 use itertools::Itertools;
 
 const META_ADDRESS_LOAD_BALANCE_MODE_PREFIX: &str = "load-balance+";
+const META_ADDRESS_DNS_SRV_MODE_PREFIX: &str = "dns-srv+";
 
 /// The strategy for meta client to connect to meta node.
 ///
@@ -26,6 +27,10 @@ const META_ADDRESS_LOAD_BALANCE_MODE_PREFIX: &str = "load-balance+";
 pub enum MetaAddressStrategy {
     LoadBalance(http::Uri),
     List(Vec<http::Uri>),
+    /// The name of a DNS SRV record. The meta endpoints are resolved from the record when
+    /// connecting, so the member list can be maintained in DNS instead of in every node's
+    /// command line.
+    DnsSrv(String),
 }
 
 /// Error type for parsing meta address strategy.
@@ -35,6 +40,8 @@ pub enum MetaAddressStrategyParseError {
     Empty,
     #[error("there should be only one load balance address")]
     MultipleLoadBalance,
+    #[error("there should be only one DNS SRV name")]
+    MultipleDnsSrv,
     #[error("failed to parse meta address `{1}`: {0}")]
     UrlParse(#[source] http::uri::InvalidUri, String),
 }
@@ -43,6 +50,15 @@ impl FromStr for MetaAddressStrategy {
     type Err = MetaAddressStrategyParseError;
 
     fn from_str(meta_addr: &str) -> Result<Self, Self::Err> {
+        if let Some(name) = meta_addr.strip_prefix(META_ADDRESS_DNS_SRV_MODE_PREFIX) {
+            if name.is_empty() {
+                return Err(MetaAddressStrategyParseError::Empty);
+            }
+            if name.contains(',') {
+                return Err(MetaAddressStrategyParseError::MultipleDnsSrv);
+            }
+            return Ok(Self::DnsSrv(name.to_owned()));
+        }
         if let Some(addr) = meta_addr.strip_prefix(META_ADDRESS_LOAD_BALANCE_MODE_PREFIX) {
             let addr = addr
                 .split(',')
@@ -77,6 +93,9 @@ impl fmt::Display for MetaAddressStrategy {
             MetaAddressStrategy::List(addrs) => {
                 write!(f, "{}", addrs.iter().format(","))?;
             }
+            MetaAddressStrategy::DnsSrv(name) => {
+                write!(f, "{}{}", META_ADDRESS_DNS_SRV_MODE_PREFIX, name)?;
+            }
         }
         Ok(())
     }
@@ -94,6 +113,7 @@ impl MetaAddressStrategy {
                     None
                 }
             }
+            MetaAddressStrategy::DnsSrv(_) => None,
         }
     }
 }
@@ -119,6 +139,14 @@ mod tests {
                     .parse()
                     .unwrap()])),
             ),
+            (
+                "dns-srv+_meta._tcp.risingwave.svc",
+                Some(MetaAddressStrategy::DnsSrv(
+                    "_meta._tcp.risingwave.svc".to_owned(),
+                )),
+            ),
+            ("dns-srv+", None),
+            ("dns-srv+a,b", None),
             (
                 "http://abc,http://def",
                 Some(MetaAddressStrategy::List(vec![
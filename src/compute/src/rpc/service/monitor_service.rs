@@ -23,16 +23,17 @@ use itertools::Itertools;
 use prometheus::core::Collector;
 use prometheus::proto::Metric;
 use risingwave_common::config::{MetricLevel, ServerConfig};
+use risingwave_common::monitor::GLOBAL_METRICS_REGISTRY;
 use risingwave_common_heap_profiling::{AUTO_DUMP_SUFFIX, COLLAPSED_SUFFIX, MANUALLY_DUMP_SUFFIX};
 use risingwave_hummock_sdk::HummockSstableObjectId;
 use risingwave_jni_core::jvm_runtime::dump_jvm_stack_traces;
 use risingwave_pb::monitor_service::monitor_service_server::MonitorService;
 use risingwave_pb::monitor_service::{
-    AnalyzeHeapRequest, AnalyzeHeapResponse, BackPressureInfo, FragmentStats,
-    GetBackPressureRequest, GetBackPressureResponse, HeapProfilingRequest, HeapProfilingResponse,
-    ListHeapProfilingRequest, ListHeapProfilingResponse, ProfilingRequest, ProfilingResponse,
-    RelationStats, StackTraceRequest, StackTraceResponse, TieredCacheTracingRequest,
-    TieredCacheTracingResponse,
+    ActorRuntimeStats, AnalyzeHeapRequest, AnalyzeHeapResponse, BackPressureInfo, FragmentStats,
+    GetActorRuntimeStatsRequest, GetActorRuntimeStatsResponse, GetBackPressureRequest,
+    GetBackPressureResponse, HeapProfilingRequest, HeapProfilingResponse, ListHeapProfilingRequest,
+    ListHeapProfilingResponse, ProfilingRequest, ProfilingResponse, RelationStats,
+    StackTraceRequest, StackTraceResponse, TieredCacheTracingRequest, TieredCacheTracingResponse,
 };
 use risingwave_rpc_client::error::ToTonicStatus;
 use risingwave_storage::hummock::compactor::await_tree_key::Compaction;
@@ -485,6 +486,63 @@ impl MonitorService for MonitorServiceImpl {
 
         Ok(Response::new(TieredCacheTracingResponse::default()))
     }
+
+    #[cfg_attr(coverage, coverage(off))]
+    async fn get_actor_runtime_stats(
+        &self,
+        _request: Request<GetActorRuntimeStatsRequest>,
+    ) -> Result<Response<GetActorRuntimeStatsResponse>, Status> {
+        let mut stats: BTreeMap<u32, ActorRuntimeStats> = BTreeMap::new();
+        fn stat(
+            stats: &mut BTreeMap<u32, ActorRuntimeStats>,
+            actor_id: u32,
+        ) -> &mut ActorRuntimeStats {
+            stats.entry(actor_id).or_insert_with(|| ActorRuntimeStats {
+                actor_id,
+                ..Default::default()
+            })
+        }
+
+        if let Some(reg) = self.stream_mgr.await_tree_reg() {
+            for (actor, tree) in reg.collect::<Actor>() {
+                stat(&mut stats, actor.0).await_tree_depth = await_tree_depth(&tree.to_string());
+            }
+        }
+
+        // Aggregate executor cache and memory gauges by their `actor_id` label. Metrics
+        // relabeled away at lower metric levels carry an empty `actor_id` and are skipped.
+        for family in GLOBAL_METRICS_REGISTRY.gather() {
+            let is_cache = family.get_name().ends_with("_cached_entry_count");
+            let is_memory = family.get_name() == "stream_memory_usage";
+            if !is_cache && !is_memory {
+                continue;
+            }
+            for metric in family.get_metric() {
+                let Some(actor_id) = get_label::<u32>(metric, "actor_id") else {
+                    continue;
+                };
+                let value = metric.get_gauge().get_value().max(0.0) as u64;
+                let entry = stat(&mut stats, actor_id);
+                if is_cache {
+                    entry.cache_entry_count += value;
+                } else {
+                    entry.memory_usage_bytes += value;
+                }
+            }
+        }
+
+        Ok(Response::new(GetActorRuntimeStatsResponse {
+            stats: stats.into_values().collect(),
+        }))
+    }
+}
+
+/// The formatted await-tree indents each nested span by two spaces.
+fn await_tree_depth(tree: &str) -> u32 {
+    tree.lines()
+        .map(|line| (line.len() - line.trim_start().len()) as u32 / 2 + 1)
+        .max()
+        .unwrap_or(0)
 }
 
 fn get_label<T: std::str::FromStr>(metric: &Metric, label: &str) -> Option<T> {
@@ -39,13 +39,16 @@ use risingwave_common::util::pretty_bytes::convert;
 use risingwave_common::util::tokio_util::sync::CancellationToken;
 use risingwave_common::{GIT_SHA, RW_VERSION};
 use risingwave_common_heap_profiling::HeapProfiler;
-use risingwave_common_service::{MetricsManager, ObserverManager, TracingExtractLayer};
+use risingwave_common_service::{
+    apply_grpc_hardening, MetricsManager, ObserverManager, TracingExtractLayer,
+};
 use risingwave_connector::source::monitor::GLOBAL_SOURCE_METRICS;
 use risingwave_dml::dml_manager::DmlManager;
 use risingwave_pb::common::worker_node::Property;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::compute::config_service_server::ConfigServiceServer;
 use risingwave_pb::health::health_server::HealthServer;
+use risingwave_pb::meta::update_worker_node_schedulability_request::Schedulability;
 use risingwave_pb::monitor_service::monitor_service_server::MonitorServiceServer;
 use risingwave_pb::stream_service::stream_service_server::StreamServiceServer;
 use risingwave_pb::task_service::exchange_service_server::ExchangeServiceServer;
@@ -65,6 +68,7 @@ use risingwave_storage::opts::StorageOpts;
 use risingwave_storage::StateStoreImpl;
 use risingwave_stream::executor::monitor::global_streaming_metrics;
 use risingwave_stream::task::{LocalStreamManager, StreamEnvironment};
+use thiserror_ext::AsReport;
 use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
 use tower::Layer;
@@ -429,17 +433,26 @@ pub async fn compute_node_serve(
         SpillOp::clean_spill_directory().await.unwrap();
     }
 
-    let server = tonic::transport::Server::builder()
+    // XXX: unlimited by default, to allow arbitrary large SQL input.
+    let max_message_size = config
+        .server
+        .grpc_max_decoding_message_size
+        .map_or(usize::MAX, |s| s as usize);
+    let server_builder = apply_grpc_hardening(tonic::transport::Server::builder(), &config.server)
+        .expect("failed to apply gRPC server hardening");
+    let server = server_builder
         .initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE)
         .initial_stream_window_size(STREAM_WINDOW_SIZE)
         .http2_max_pending_accept_reset_streams(Some(config.server.grpc_max_reset_stream as usize))
         .layer(TracingExtractLayer::new())
-        // XXX: unlimit the max message size to allow arbitrary large SQL input.
-        .add_service(TaskServiceServer::new(batch_srv).max_decoding_message_size(usize::MAX))
-        .add_service(ExchangeServiceServer::new(exchange_srv).max_decoding_message_size(usize::MAX))
+        .add_service(TaskServiceServer::new(batch_srv).max_decoding_message_size(max_message_size))
+        .add_service(
+            ExchangeServiceServer::new(exchange_srv).max_decoding_message_size(max_message_size),
+        )
         .add_service({
             let await_tree_reg = stream_srv.mgr.await_tree_reg().cloned();
-            let srv = StreamServiceServer::new(stream_srv).max_decoding_message_size(usize::MAX);
+            let srv =
+                StreamServiceServer::new(stream_srv).max_decoding_message_size(max_message_size);
             #[cfg(madsim)]
             {
                 srv
@@ -473,12 +486,21 @@ pub async fn compute_node_serve(
     // Wait for the shutdown signal.
     shutdown.cancelled().await;
 
+    // Ask the meta service to stop scheduling new work to this node while we drain the
+    // in-flight barriers, so that a rolling restart does not race with scheduling.
+    if let Err(e) = meta_client
+        .update_schedulability(&[meta_client.worker_id()], Schedulability::Unschedulable)
+        .await
+    {
+        tracing::warn!(error = %e.as_report(), "failed to mark the worker as unschedulable");
+    }
+    // Shutdown the streaming manager. This drains the in-flight barriers, flushing the
+    // shared buffer for their epochs, before notifying the meta service of the shutdown.
+    let _ = stream_mgr.shutdown().await;
     // Unregister from the meta service, then...
     // - batch queries will not be scheduled to this compute node,
     // - streaming actors will not be scheduled to this compute node after next recovery.
     meta_client.try_unregister().await;
-    // Shutdown the streaming manager.
-    let _ = stream_mgr.shutdown().await;
 
     // NOTE(shutdown): We can't simply join the tonic server here because it only returns when all
     // existing connections are closed, while we have long-running streaming calls that never
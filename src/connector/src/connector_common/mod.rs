@@ -35,4 +35,6 @@ mod maybe_tls_connector;
 pub mod postgres;
 
 pub use iceberg::IcebergCommon;
-pub use postgres::{create_pg_client, PostgresExternalTable, SslMode};
+pub use postgres::{
+    create_pg_client, create_pg_client_shared, PostgresExternalTable, SharedPgClient, SslMode,
+};
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use std::sync::{Arc, LazyLock, Mutex, Weak};
 
 use anyhow::anyhow;
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
@@ -362,6 +363,70 @@ pub async fn create_pg_client(
     Ok(client)
 }
 
+/// A postgres client shared by all table readers of the same upstream database.
+///
+/// `tokio_postgres` pipelines queries on a single connection, but transactions (used e.g. to
+/// read the current wal lsn) need exclusive access, hence the mutex.
+pub type SharedPgClient = Arc<tokio::sync::Mutex<PgClient>>;
+
+/// Connection properties identifying one upstream, used as the sharing key for
+/// [`create_pg_client_shared`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct PgClientKey {
+    user: String,
+    host: String,
+    port: String,
+    database: String,
+}
+
+/// Process-level cache of upstream clients. Entries are weak so a client is dropped (and its
+/// connection closed) once the last reader using it goes away.
+static SHARED_PG_CLIENTS: LazyLock<Mutex<HashMap<PgClientKey, Weak<tokio::sync::Mutex<PgClient>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Like [`create_pg_client`], but multiplexes all callers with the same connection properties
+/// onto one client, so that dozens of CDC tables from the same upstream hold a single
+/// connection instead of one each.
+pub async fn create_pg_client_shared(
+    user: &str,
+    password: &str,
+    host: &str,
+    port: &str,
+    database: &str,
+    ssl_mode: &SslMode,
+    ssl_root_cert: &Option<String>,
+) -> anyhow::Result<SharedPgClient> {
+    let key = PgClientKey {
+        user: user.to_owned(),
+        host: host.to_owned(),
+        port: port.to_owned(),
+        database: database.to_owned(),
+    };
+
+    let cached = SHARED_PG_CLIENTS
+        .lock()
+        .unwrap()
+        .get(&key)
+        .and_then(Weak::upgrade);
+    if let Some(client) = cached {
+        // the upstream may have closed the connection while it was cached
+        if !client.lock().await.is_closed() {
+            return Ok(client);
+        }
+    }
+
+    let client = Arc::new(tokio::sync::Mutex::new(
+        create_pg_client(user, password, host, port, database, ssl_mode, ssl_root_cert).await?,
+    ));
+    // A concurrent caller may have raced us here and inserted its own client; the worst case
+    // is one extra connection that goes away once its users are dropped.
+    SHARED_PG_CLIENTS
+        .lock()
+        .unwrap()
+        .insert(key, Arc::downgrade(&client));
+    Ok(client)
+}
+
 pub fn type_to_rw_type(col_type: &ColumnType) -> ConnectorResult<DataType> {
     let dtype = match col_type {
         ColumnType::SmallInt | ColumnType::SmallSerial => DataType::Int16,
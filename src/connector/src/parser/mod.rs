@@ -48,8 +48,8 @@ use crate::parser::maxwell::MaxwellParser;
 use crate::schema::schema_registry::SchemaRegistryAuth;
 use crate::source::monitor::GLOBAL_SOURCE_METRICS;
 use crate::source::{
-    BoxSourceMessageStream, SourceChunkStream, SourceColumnDesc, SourceColumnType, SourceContext,
-    SourceContextRef, SourceCtrlOpts, SourceMeta,
+    BoxSourceMessageStream, CdcLagAlert, SourceChunkStream, SourceColumnDesc, SourceColumnType,
+    SourceContext, SourceContextRef, SourceCtrlOpts, SourceMeta,
 };
 
 mod access_builder;
@@ -237,6 +237,10 @@ async fn parse_message_stream<P: ByteStreamSourceParser>(
         SourceStreamChunkBuilder::new(parser.columns().to_vec(), source_ctrl_opts);
 
     let mut direct_cdc_event_lag_latency_metrics = HashMap::new();
+    let mut cdc_current_lag_metrics = HashMap::new();
+    // Throttle lag alerts so that a lagging table reports at most one event per interval.
+    const CDC_LAG_ALERT_MIN_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+    let mut last_cdc_lag_alert: Option<std::time::Instant> = None;
 
     #[for_await]
     for batch in msg_stream {
@@ -302,6 +306,28 @@ async fn parse_message_stream<P: ByteStreamSourceParser>(
                             .with_guarded_label_values(&[&msg_meta.full_table_name])
                     });
                 direct_cdc_event_lag_latency.observe(lag_ms as f64);
+                let cdc_current_lag = cdc_current_lag_metrics
+                    .entry(msg_meta.full_table_name.clone())
+                    .or_insert_with(|| {
+                        GLOBAL_SOURCE_METRICS
+                            .cdc_source_current_lag_ms
+                            .with_guarded_label_values(&[&msg_meta.full_table_name])
+                    });
+                cdc_current_lag.set(lag_ms);
+
+                if let Some(alert) = &parser.source_ctx().cdc_lag_alert
+                    && lag_ms as u64 >= alert.threshold_ms
+                    && last_cdc_lag_alert
+                        .is_none_or(|last| last.elapsed() >= CDC_LAG_ALERT_MIN_INTERVAL)
+                {
+                    // Best effort: drop the alert if the channel is full.
+                    let _ = alert.tx.try_send(CdcLagAlert {
+                        table_name: msg_meta.full_table_name.clone(),
+                        lag_ms: lag_ms as u64,
+                        threshold_ms: alert.threshold_ms,
+                    });
+                    last_cdc_lag_alert = Some(std::time::Instant::now());
+                }
             }
 
             // Parse the message and write to the chunk builder, it's possible that the message
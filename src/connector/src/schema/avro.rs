@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use apache_avro::Schema as AvroSchema;
+use risingwave_common::catalog::Field;
+use risingwave_common::types::DataType;
 
 use super::loader::LoadedSchema;
 use super::schema_registry::Subject;
@@ -24,3 +26,93 @@ impl LoadedSchema for AvroSchema {
             .map_err(|e| SchemaFetchError::SchemaCompile(e.into()))
     }
 }
+
+/// Derives an Avro record schema (as registry-ready JSON text) from sink columns, used when
+/// `schema.registry.auto.register` is enabled and no schema has been registered out of band.
+///
+/// Only types the sink encoder can actually write (see `crate::sink::encoder::avro`) get a
+/// mapping here; every field is a nullable union so that later column additions remain
+/// backward compatible.
+pub fn fields_to_avro_definition(
+    record_name: &str,
+    fields: &[Field],
+) -> Result<String, SchemaFetchError> {
+    let fields = fields
+        .iter()
+        .map(|f| field_to_avro(record_name, &f.name, &f.data_type))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(serde_json::json!({
+        "type": "record",
+        "name": record_name,
+        "fields": fields,
+    })
+    .to_string())
+}
+
+fn field_to_avro(
+    parent: &str,
+    name: &str,
+    data_type: &DataType,
+) -> Result<serde_json::Value, SchemaFetchError> {
+    let ty = datatype_to_avro(&format!("{parent}_{name}"), name, data_type)?;
+    Ok(serde_json::json!({
+        "name": name,
+        "type": ["null", ty],
+        "default": null,
+    }))
+}
+
+fn datatype_to_avro(
+    name_hint: &str,
+    name: &str,
+    data_type: &DataType,
+) -> Result<serde_json::Value, SchemaFetchError> {
+    use serde_json::json;
+
+    let ty = match data_type {
+        DataType::Boolean => json!("boolean"),
+        DataType::Int32 => json!("int"),
+        DataType::Int64 | DataType::Serial => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Varchar => json!("string"),
+        DataType::Bytea => json!("bytes"),
+        DataType::Timestamptz => json!({"type": "long", "logicalType": "timestamp-micros"}),
+        DataType::Date => json!({"type": "int", "logicalType": "date"}),
+        DataType::Time => json!({"type": "long", "logicalType": "time-micros"}),
+        DataType::Struct(st) => {
+            let fields = st
+                .iter()
+                .map(|(n, t)| field_to_avro(name_hint, n, t))
+                .collect::<Result<Vec<_>, _>>()?;
+            json!({
+                "type": "record",
+                "name": name_hint,
+                "fields": fields,
+            })
+        }
+        DataType::List(elem) => {
+            let elem = datatype_to_avro(&format!("{name_hint}_item"), name, elem)?;
+            json!({"type": "array", "items": ["null", elem]})
+        }
+        DataType::Map(m) if *m.key() == DataType::Varchar => {
+            let value = datatype_to_avro(&format!("{name_hint}_value"), name, m.value())?;
+            json!({"type": "map", "values": ["null", value]})
+        }
+        DataType::Int16
+        | DataType::Decimal
+        | DataType::Jsonb
+        | DataType::Timestamp
+        | DataType::Interval
+        | DataType::Int256
+        | DataType::Map(_) => {
+            return Err(SchemaFetchError::SchemaCompile(
+                anyhow::anyhow!(
+                    "column {name} of type {data_type} cannot be auto-registered as avro"
+                )
+                .into(),
+            ));
+        }
+    };
+    Ok(ty)
+}
@@ -85,6 +85,50 @@ impl SchemaLoader {
         self.load_schema::<Out, false>(self.val_record_name.as_deref())
             .await
     }
+
+    /// Registers `definition` as a new version of the subject derived from the name strategy,
+    /// after checking it is compatible with the latest version already in the registry.
+    /// A subject that does not exist yet is trivially compatible.
+    async fn register_schema<const IS_KEY: bool>(
+        &self,
+        record: Option<&str>,
+        schema_type: &str,
+        definition: &str,
+    ) -> Result<i32, SchemaFetchError> {
+        let subject = get_subject_by_strategy(&self.name_strategy, &self.topic, record, IS_KEY)?;
+        match self
+            .client
+            .check_compatibility(&subject, schema_type, definition)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => return Err(SchemaFetchError::Incompatible(subject)),
+            Err(e) if e.is_subject_not_found() => {}
+            Err(e) => return Err(e.into()),
+        }
+        Ok(self
+            .client
+            .register_schema(&subject, schema_type, definition)
+            .await?)
+    }
+
+    pub async fn register_key_schema(
+        &self,
+        schema_type: &str,
+        definition: &str,
+    ) -> Result<i32, SchemaFetchError> {
+        self.register_schema::<true>(self.key_record_name.as_deref(), schema_type, definition)
+            .await
+    }
+
+    pub async fn register_val_schema(
+        &self,
+        schema_type: &str,
+        definition: &str,
+    ) -> Result<i32, SchemaFetchError> {
+        self.register_schema::<false>(self.val_record_name.as_deref(), schema_type, definition)
+            .await
+    }
 }
 
 pub trait LoadedSchema: Sized {
@@ -26,6 +26,7 @@ const KEY_MESSAGE_NAME_KEY: &str = "key.message";
 const SCHEMA_LOCATION_KEY: &str = "schema.location";
 const SCHEMA_REGISTRY_KEY: &str = "schema.registry";
 const NAME_STRATEGY_KEY: &str = "schema.registry.name.strategy";
+pub const SCHEMA_REGISTRY_AUTO_REGISTER_KEY: &str = "schema.registry.auto.register";
 pub const AWS_GLUE_SCHEMA_ARN_KEY: &str = "aws.glue.schema_arn";
 
 #[derive(Debug, thiserror::Error, thiserror_ext::Macro)]
@@ -48,6 +49,8 @@ pub enum SchemaFetchError {
         #[backtrace]
         risingwave_common::error::BoxedError,
     ),
+    #[error("schema incompatible with latest version of subject {0}")]
+    Incompatible(String),
     #[error("{0}")] // source+{0} is effectively transparent but allows backtrace
     YetToMigrate(
         #[source]
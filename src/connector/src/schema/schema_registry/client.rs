@@ -70,6 +70,21 @@ pub struct ConcurrentRequestError {
     context: String,
 }
 
+impl ConcurrentRequestError {
+    /// Whether the registry consistently reported that the subject does not exist yet,
+    /// e.g. when checking compatibility before the first registration.
+    pub fn is_subject_not_found(&self) -> bool {
+        !self.errs.is_empty()
+            && self.errs.iter().all(|e| {
+                matches!(
+                    e,
+                    itertools::Either::Left(RequestError::Unsuccessful(resp))
+                        if resp.is_subject_not_found()
+                )
+            })
+    }
+}
+
 type SrResult<T> = Result<T, ConcurrentRequestError>;
 
 impl TryFrom<&ConfluentSchemaRegistryConnection> for Client {
@@ -123,6 +138,7 @@ impl Client {
         &'a self,
         method: Method,
         path: &'a [&'a (impl AsRef<str> + ?Sized + Debug + ToString)],
+        body: Option<Vec<u8>>,
     ) -> SrResult<T>
     where
         T: DeserializeOwned + Send + Sync + 'static,
@@ -134,6 +150,7 @@ impl Client {
             password: self.password.clone(),
             client: self.inner.clone(),
             path: path.iter().map(|p| p.to_string()).collect_vec(),
+            body,
         });
         for url in &self.url {
             fut_req.push(tokio::spawn(req_inner(
@@ -165,7 +182,7 @@ impl Client {
     /// get schema by id
     pub async fn get_schema_by_id(&self, id: i32) -> SrResult<ConfluentSchema> {
         let res: GetByIdResp = self
-            .concurrent_req(Method::GET, &["schemas", "ids", &id.to_string()])
+            .concurrent_req(Method::GET, &["schemas", "ids", &id.to_string()], None)
             .await?;
         Ok(ConfluentSchema {
             id,
@@ -186,14 +203,14 @@ impl Client {
             _compatibility_level: String,
         }
 
-        let _: GetConfigResp = self.concurrent_req(Method::GET, &["config"]).await?;
+        let _: GetConfigResp = self.concurrent_req(Method::GET, &["config"], None).await?;
         Ok(())
     }
 
     /// get the latest version of the subject
     pub async fn get_subject(&self, subject: &str) -> SrResult<Subject> {
         let res: GetBySubjectResp = self
-            .concurrent_req(Method::GET, &["subjects", subject, "versions", "latest"])
+            .concurrent_req(Method::GET, &["subjects", subject, "versions", "latest"], None)
             .await?;
         tracing::debug!("update schema: {:?}", res);
         Ok(Subject {
@@ -217,7 +234,7 @@ impl Client {
         // use bfs to get all references
         while let Some((subject, version)) = queue.pop() {
             let res: GetBySubjectResp = self
-                .concurrent_req(Method::GET, &["subjects", &subject, "versions", &version])
+                .concurrent_req(Method::GET, &["subjects", &subject, "versions", &version], None)
                 .await?;
             let ref_subject = Subject {
                 schema: ConfluentSchema {
@@ -240,6 +257,50 @@ impl Client {
 
         Ok((origin_subject, subjects))
     }
+
+    /// check whether `schema` is compatible with the latest version of the subject,
+    /// without registering it
+    pub async fn check_compatibility(
+        &self,
+        subject: &str,
+        schema_type: &str,
+        schema: &str,
+    ) -> SrResult<bool> {
+        let body = serde_json::json!({
+            "schemaType": schema_type,
+            "schema": schema,
+        })
+        .to_string()
+        .into_bytes();
+        let res: CompatibilityResp = self
+            .concurrent_req(
+                Method::POST,
+                &["compatibility", "subjects", subject, "versions", "latest"],
+                Some(body),
+            )
+            .await?;
+        Ok(res.is_compatible)
+    }
+
+    /// register `schema` as a new version of the subject, returning its global schema id;
+    /// idempotent if an identical schema is already registered
+    pub async fn register_schema(
+        &self,
+        subject: &str,
+        schema_type: &str,
+        schema: &str,
+    ) -> SrResult<i32> {
+        let body = serde_json::json!({
+            "schemaType": schema_type,
+            "schema": schema,
+        })
+        .to_string()
+        .into_bytes();
+        let res: PostSubjectVersionResp = self
+            .concurrent_req(Method::POST, &["subjects", subject, "versions"], Some(body))
+            .await?;
+        Ok(res.id)
+    }
 }
 
 #[cfg(test)]
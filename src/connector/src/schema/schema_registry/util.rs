@@ -82,6 +82,8 @@ pub(crate) struct SchemaRegistryCtx {
     pub password: Option<String>,
     pub client: reqwest::Client,
     pub path: Vec<String>,
+    /// JSON body for `POST` requests, e.g. schema registration.
+    pub body: Option<Vec<u8>>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -112,9 +114,17 @@ where
     if let Some(ref username) = ctx.username {
         request_builder = request_builder.basic_auth(username, ctx.password.as_ref());
     }
+    if let Some(ref body) = ctx.body {
+        request_builder = request_builder
+            .header(reqwest::header::CONTENT_TYPE, SCHEMA_REGISTRY_CONTENT_TYPE)
+            .body(body.clone());
+    }
     request(request_builder).await
 }
 
+/// Content type expected by the Confluent schema registry for request bodies.
+const SCHEMA_REGISTRY_CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
 async fn request<T>(req: reqwest::RequestBuilder) -> Result<T, RequestError>
 where
     T: DeserializeOwned,
@@ -167,6 +177,16 @@ pub struct GetByIdResp {
     pub schema: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PostSubjectVersionResp {
+    pub id: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CompatibilityResp {
+    pub is_compatible: bool,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GetBySubjectResp {
     pub id: i32,
@@ -186,6 +206,13 @@ pub struct ErrorResp {
     message: String,
 }
 
+impl ErrorResp {
+    /// Error code 40401: subject not found.
+    pub(crate) fn is_subject_not_found(&self) -> bool {
+        self.error_code == 40401
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::handle_sr_list;
@@ -34,7 +34,8 @@ use google_cloud_googleapis::cloud::bigquery::storage::v1::append_rows_request::
     ProtoData, Rows as AppendRowsRequestRows,
 };
 use google_cloud_googleapis::cloud::bigquery::storage::v1::{
-    AppendRowsRequest, AppendRowsResponse, ProtoRows, ProtoSchema,
+    write_stream, AppendRowsRequest, AppendRowsResponse, CreateWriteStreamRequest, ProtoRows,
+    ProtoSchema, WriteStream,
 };
 use google_cloud_pubsub::client::google_cloud_auth;
 use google_cloud_pubsub::client::google_cloud_auth::credentials::CredentialsFile;
@@ -90,6 +91,13 @@ pub struct BigQueryCommon {
     #[serde(default)] // default false
     #[serde_as(as = "DisplayFromStr")]
     pub auto_create: bool,
+    /// If `true`, rows are appended through an explicitly created committed write stream
+    /// with tracked stream offsets, so that duplicate appends after a retry are rejected
+    /// by BigQuery. Only supported for append-only sinks; upsert sinks must use the
+    /// default stream, which is required by BigQuery CDC.
+    #[serde(rename = "bigquery.committed.stream", default)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub committed_stream: bool,
     #[serde(rename = "bigquery.credentials")]
     pub credentials: Option<String>,
 }
@@ -460,6 +468,11 @@ impl Sink for BigQuerySink {
             return Err(SinkError::Config(anyhow!(
                 "Primary key not defined for upsert bigquery sink (please define in `primary_key` field)")));
         }
+        if !self.is_append_only && self.config.common.committed_stream {
+            return Err(SinkError::Config(anyhow!(
+                "`bigquery.committed.stream` is only supported for append-only sinks, upsert sinks must use the default stream"
+            )));
+        }
         let client = self
             .config
             .common
@@ -540,6 +553,9 @@ pub struct BigQuerySinkWriter {
     #[expect(dead_code)]
     message_descriptor: MessageDescriptor,
     write_stream: String,
+    /// The next row offset within the committed write stream. `None` when writing to the
+    /// default stream, which does not support explicit offsets.
+    stream_offset: Option<i64>,
     proto_field: Option<FieldDescriptor>,
 }
 
@@ -612,12 +628,25 @@ impl BigQuerySinkWriter {
             message_descriptor.clone(),
             ProtoHeader::None,
         )?;
-        Ok((
-            Self {
-                write_stream: format!(
+        let (write_stream, stream_offset) = if config.common.committed_stream {
+            let parent = format!(
+                "projects/{}/datasets/{}/tables/{}",
+                config.common.project, config.common.dataset, config.common.table
+            );
+            (client.create_committed_write_stream(&parent).await?, Some(0))
+        } else {
+            (
+                format!(
                     "projects/{}/datasets/{}/tables/{}/streams/_default",
                     config.common.project, config.common.dataset, config.common.table
                 ),
+                None,
+            )
+        };
+        Ok((
+            Self {
+                write_stream,
+                stream_offset,
                 config,
                 schema,
                 pk_indices,
@@ -708,11 +737,19 @@ impl BigQuerySinkWriter {
         }
         let len = result.len();
         for serialized_rows in result {
+            let num_rows = serialized_rows.len() as i64;
+            // The offset at which the first row of this request is written. Resending the
+            // same request after a retry is rejected by BigQuery as already written.
+            let offset = self.stream_offset;
+            if let Some(next_offset) = &mut self.stream_offset {
+                *next_offset += num_rows;
+            }
             let rows = AppendRowsRequestRows::ProtoRows(ProtoData {
                 writer_schema: Some(self.writer_pb_schema.clone()),
                 rows: Some(ProtoRows { serialized_rows }),
             });
-            self.client.append_rows(rows, self.write_stream.clone())?;
+            self.client
+                .append_rows(rows, offset, self.write_stream.clone())?;
         }
         Ok(len)
     }
@@ -766,6 +803,7 @@ pub async fn resp_to_stream(
 struct StorageWriterClient {
     #[expect(dead_code)]
     environment: Environment,
+    conn: WriteConnectionManager,
     request_sender: mpsc::UnboundedSender<AppendRowsRequest>,
 }
 impl StorageWriterClient {
@@ -802,16 +840,43 @@ impl StorageWriterClient {
         Ok((
             StorageWriterClient {
                 environment,
+                conn,
                 request_sender: tx,
             },
             resp_stream,
         ))
     }
 
-    pub fn append_rows(&mut self, row: AppendRowsRequestRows, write_stream: String) -> Result<()> {
+    /// Creates a committed write stream on the table `parent` and returns its name.
+    /// Appends to it carry explicit stream offsets, so duplicate appends are rejected.
+    pub async fn create_committed_write_stream(&self, parent: &str) -> Result<String> {
+        let mut client = self.conn.conn();
+        let write_stream = client
+            .create_write_stream(
+                Request::new(CreateWriteStreamRequest {
+                    parent: parent.to_owned(),
+                    write_stream: Some(WriteStream {
+                        r#type: write_stream::Type::Committed as i32,
+                        ..Default::default()
+                    }),
+                }),
+                None,
+            )
+            .await
+            .map_err(|e| SinkError::BigQuery(e.into()))?
+            .into_inner();
+        Ok(write_stream.name)
+    }
+
+    pub fn append_rows(
+        &mut self,
+        row: AppendRowsRequestRows,
+        offset: Option<i64>,
+        write_stream: String,
+    ) -> Result<()> {
         let append_req = AppendRowsRequest {
             write_stream: write_stream.clone(),
-            offset: None,
+            offset,
             trace_id: Uuid::new_v4().hyphenated().to_string(),
             missing_value_interpretations: HashMap::default(),
             rows: Some(row),
@@ -40,7 +40,9 @@ use super::{
 };
 use crate::sink::encoder::{JsonEncoder, RowEncoder};
 use crate::sink::writer::{LogSinkerOf, SinkWriterExt};
-use crate::sink::{DummySinkCommitCoordinator, Sink, SinkParam, SinkWriter, SinkWriterParam};
+use crate::sink::{
+    DummySinkCommitCoordinator, Sink, SinkConsistency, SinkParam, SinkWriter, SinkWriterParam,
+};
 
 pub const DORIS_SINK: &str = "doris";
 
@@ -103,6 +105,7 @@ pub struct DorisSink {
     schema: Schema,
     pk_indices: Vec<usize>,
     is_append_only: bool,
+    consistency: SinkConsistency,
 }
 
 impl DorisSink {
@@ -111,12 +114,14 @@ impl DorisSink {
         schema: Schema,
         pk_indices: Vec<usize>,
         is_append_only: bool,
+        consistency: SinkConsistency,
     ) -> Result<Self> {
         Ok(Self {
             config,
             schema,
             pk_indices,
             is_append_only,
+            consistency,
         })
     }
 }
@@ -210,7 +215,10 @@ impl Sink for DorisSink {
             self.is_append_only,
         )
         .await?
-        .into_log_sinker(SinkWriterMetrics::new(&writer_param)))
+        .into_log_sinker_with_consistency(
+            SinkWriterMetrics::new(&writer_param),
+            self.consistency,
+        ))
     }
 
     async fn validate(&self) -> Result<()> {
@@ -249,12 +257,14 @@ impl TryFrom<SinkParam> for DorisSink {
 
     fn try_from(param: SinkParam) -> std::result::Result<Self, Self::Error> {
         let schema = param.schema();
+        let consistency = param.consistency()?;
         let config = DorisConfig::from_btreemap(param.properties)?;
         DorisSink::new(
             config,
             schema,
             param.downstream_pk,
             param.sink_type.is_append_only(),
+            consistency,
         )
     }
 }
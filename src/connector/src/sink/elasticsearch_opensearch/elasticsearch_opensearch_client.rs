@@ -13,13 +13,17 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::anyhow;
+use bytes::{Bytes, BytesMut};
+use elasticsearch::http::request::Body as ElasticSearchBody;
 use futures::{FutureExt, TryFuture};
-use itertools::Itertools;
+use opensearch::http::request::Body as OpenSearchBody;
 use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::Schema;
 use serde_json::{json, Value};
+use thiserror_ext::AsReport;
 
 use super::super::SinkError;
 use super::elasticsearch_opensearch_config::ElasticSearchOpenSearchConfig;
@@ -38,46 +42,35 @@ enum ElasticSearchOpenSearchBulk {
 }
 
 impl ElasticSearchOpenSearchBulk {
-    pub fn into_elasticsearch_bulk(self) -> elasticsearch::BulkOperation<serde_json::Value> {
-        if let ElasticSearchOpenSearchBulk::ElasticSearch(bulk) = self {
-            bulk
-        } else {
-            panic!("not a elasticsearch bulk")
-        }
-    }
-
-    pub fn into_opensearch_bulk(self) -> opensearch::BulkOperation<serde_json::Value> {
-        if let ElasticSearchOpenSearchBulk::OpenSearch(bulk) = self {
-            bulk
-        } else {
-            panic!("not a opensearch bulk")
+    /// Serializes the operations into an `_bulk` request body once, so that the same
+    /// body can be resent on retry.
+    fn serialize_all(bulks: Vec<Self>) -> Result<Bytes> {
+        let mut buf = BytesMut::new();
+        for bulk in bulks {
+            match bulk {
+                ElasticSearchOpenSearchBulk::ElasticSearch(bulk) => bulk.write(&mut buf)?,
+                ElasticSearchOpenSearchBulk::OpenSearch(bulk) => bulk.write(&mut buf)?,
+            }
         }
+        Ok(buf.freeze())
     }
 }
 
 impl ElasticSearchOpenSearchClient {
-    async fn send(&self, bulks: Vec<ElasticSearchOpenSearchBulk>) -> Result<Value> {
+    async fn send(&self, body: Bytes) -> Result<Value> {
         match self {
             ElasticSearchOpenSearchClient::ElasticSearch(client) => {
-                let bulks = bulks
-                    .into_iter()
-                    .map(ElasticSearchOpenSearchBulk::into_elasticsearch_bulk)
-                    .collect_vec();
                 let result = client
                     .bulk(elasticsearch::BulkParts::None)
-                    .body(bulks)
+                    .body(body)
                     .send()
                     .await?;
                 Ok(result.json::<Value>().await?)
             }
             ElasticSearchOpenSearchClient::OpenSearch(client) => {
-                let bulks = bulks
-                    .into_iter()
-                    .map(ElasticSearchOpenSearchBulk::into_opensearch_bulk)
-                    .collect_vec();
                 let result = client
                     .bulk(opensearch::BulkParts::None)
-                    .body(bulks)
+                    .body(body)
                     .send()
                     .await?;
                 Ok(result.json::<Value>().await?)
@@ -129,33 +122,107 @@ impl ElasticSearchOpenSearchClient {
         }
     }
 
+    /// A full-document `index` operation with an external version, used instead of
+    /// `update` when `external_versioning` is enabled.
+    fn new_index(
+        &self,
+        key: String,
+        index: String,
+        routing_column: Option<String>,
+        version: i64,
+        value: serde_json::Value,
+    ) -> ElasticSearchOpenSearchBulk {
+        match self {
+            ElasticSearchOpenSearchClient::ElasticSearch(_) => {
+                let mut bulk = elasticsearch::BulkOperation::index(value)
+                    .id(key)
+                    .index(index)
+                    .version(version)
+                    .version_type(elasticsearch::params::VersionType::ExternalGte);
+                if let Some(routing_column) = routing_column {
+                    bulk = bulk.routing(routing_column);
+                }
+                ElasticSearchOpenSearchBulk::ElasticSearch(bulk.into())
+            }
+            ElasticSearchOpenSearchClient::OpenSearch(_) => {
+                let mut bulk = opensearch::BulkOperation::index(value)
+                    .id(key)
+                    .index(index)
+                    .version(version)
+                    .version_type(opensearch::params::VersionType::ExternalGte);
+                if let Some(routing_column) = routing_column {
+                    bulk = bulk.routing(routing_column);
+                }
+                ElasticSearchOpenSearchBulk::OpenSearch(bulk.into())
+            }
+        }
+    }
+
     fn new_delete(
         &self,
         key: String,
         index: String,
         routing_column: Option<String>,
+        version: Option<i64>,
     ) -> ElasticSearchOpenSearchBulk {
         match self {
             ElasticSearchOpenSearchClient::ElasticSearch(_) => {
-                let bulk = elasticsearch::BulkOperation::delete(key).index(index);
+                let mut bulk = elasticsearch::BulkOperation::delete(key).index(index);
                 if let Some(routing_column) = routing_column {
-                    ElasticSearchOpenSearchBulk::ElasticSearch(bulk.routing(routing_column).into())
-                } else {
-                    ElasticSearchOpenSearchBulk::ElasticSearch(bulk.into())
+                    bulk = bulk.routing(routing_column);
+                }
+                if let Some(version) = version {
+                    bulk = bulk
+                        .version(version)
+                        .version_type(elasticsearch::params::VersionType::ExternalGte);
                 }
+                ElasticSearchOpenSearchBulk::ElasticSearch(bulk.into())
             }
             ElasticSearchOpenSearchClient::OpenSearch(_) => {
-                let bulk = opensearch::BulkOperation::delete(key).index(index);
+                let mut bulk = opensearch::BulkOperation::delete(key).index(index);
                 if let Some(routing_column) = routing_column {
-                    ElasticSearchOpenSearchBulk::OpenSearch(bulk.routing(routing_column).into())
-                } else {
-                    ElasticSearchOpenSearchBulk::OpenSearch(bulk.into())
+                    bulk = bulk.routing(routing_column);
                 }
+                if let Some(version) = version {
+                    bulk = bulk
+                        .version(version)
+                        .version_type(opensearch::params::VersionType::ExternalGte);
+                }
+                ElasticSearchOpenSearchBulk::OpenSearch(bulk.into())
             }
         }
     }
 }
 
+/// Checks the response of an `_bulk` request. When `ignore_version_conflicts` is set,
+/// item-level version conflicts are treated as success, since a stale retry losing to a
+/// newer external version is the expected outcome.
+fn check_bulk_result(result: &Value, ignore_version_conflicts: bool) -> Result<()> {
+    match result["errors"].as_bool() {
+        Some(false) => Ok(()),
+        Some(true) if ignore_version_conflicts && only_version_conflicts(result) => Ok(()),
+        _ => Err(SinkError::ElasticSearchOpenSearch(anyhow!(
+            "send bulk to elasticsearch failed: {:?}",
+            result
+        ))),
+    }
+}
+
+fn only_version_conflicts(result: &Value) -> bool {
+    result["items"].as_array().is_some_and(|items| {
+        items.iter().all(|item| {
+            item.as_object()
+                .and_then(|op| op.values().next())
+                .is_some_and(|op| match op.get("error") {
+                    None => true,
+                    Some(error) => {
+                        error["type"].as_str() == Some("version_conflict_engine_exception")
+                    }
+                })
+        })
+    })
+}
+
 pub struct ElasticSearchOpenSearchSinkWriter {
     client: Arc<ElasticSearchOpenSearchClient>,
     formatter: ElasticSearchOpenSearchFormatter,
@@ -201,6 +268,10 @@ impl AsyncTruncateSinkWriter for ElasticSearchOpenSearchSinkWriter {
         let mut all_bulks: Vec<Vec<ElasticSearchOpenSearchBulk>> = vec![];
         let mut bulks: Vec<ElasticSearchOpenSearchBulk> = Vec::with_capacity(chunk_capacity);
 
+        // The external version of all operations built from this chunk.
+        let epoch_version = (self.config.external_versioning)
+            .then(|| add_future.current_epoch() as i64);
+
         let mut bulks_size = 0;
         for build_bulk_para in self.formatter.convert_chunk(chunk)? {
             let BuildBulkPara {
@@ -213,20 +284,27 @@ impl AsyncTruncateSinkWriter for ElasticSearchOpenSearchSinkWriter {
 
             bulks_size += mem_size_b;
             if let Some(value) = value {
-                let value = json!({
-                    "doc": value,
-                    "doc_as_upsert": true
-                });
-                let bulk = self.client.new_update(
-                    key,
-                    index,
-                    self.config.retry_on_conflict,
-                    routing_column,
-                    value,
-                );
+                let bulk = if let Some(version) = epoch_version {
+                    self.client
+                        .new_index(key, index, routing_column, version, value)
+                } else {
+                    let value = json!({
+                        "doc": value,
+                        "doc_as_upsert": true
+                    });
+                    self.client.new_update(
+                        key,
+                        index,
+                        self.config.retry_on_conflict,
+                        routing_column,
+                        value,
+                    )
+                };
                 bulks.push(bulk);
             } else {
-                let bulk = self.client.new_delete(key, index, routing_column);
+                let bulk = self
+                    .client
+                    .new_delete(key, index, routing_column, epoch_version);
                 bulks.push(bulk);
             };
 
@@ -242,16 +320,33 @@ impl AsyncTruncateSinkWriter for ElasticSearchOpenSearchSinkWriter {
             all_bulks.push(bulks);
         }
         for bulks in all_bulks {
+            let body = ElasticSearchOpenSearchBulk::serialize_all(bulks)?;
             let client_clone = self.client.clone();
+            let max_retry_num = self.config.max_retry_num;
+            let retry_interval = Duration::from_millis(self.config.retry_interval_ms);
+            let ignore_version_conflicts = self.config.external_versioning;
             let future = async move {
-                let result = client_clone.send(bulks).await?;
-                if result["errors"].as_bool().is_none() || result["errors"].as_bool().unwrap() {
-                    Err(SinkError::ElasticSearchOpenSearch(anyhow!(
-                        "send bulk to elasticsearch failed: {:?}",
-                        result
-                    )))
-                } else {
-                    Ok(())
+                let mut retry_num = 0;
+                loop {
+                    let error = match client_clone.send(body.clone()).await {
+                        Ok(result) => {
+                            match check_bulk_result(&result, ignore_version_conflicts) {
+                                Ok(()) => return Ok(()),
+                                Err(e) => e,
+                            }
+                        }
+                        Err(e) => e,
+                    };
+                    retry_num += 1;
+                    if retry_num >= max_retry_num {
+                        return Err(error);
+                    }
+                    tracing::warn!(
+                        error = %error.as_report(),
+                        retry_num,
+                        "failed to send bulk request, retrying",
+                    );
+                    tokio::time::sleep(retry_interval).await;
                 }
             }
             .boxed();
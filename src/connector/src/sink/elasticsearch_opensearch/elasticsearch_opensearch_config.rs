@@ -77,6 +77,27 @@ pub struct ElasticSearchOpenSearchConfig {
     #[serde_as(as = "DisplayFromStr")]
     #[serde(default = "default_concurrent_requests")]
     pub concurrent_requests: usize,
+
+    /// If set to `true`, upserts are written as `index`/`delete` operations carrying an
+    /// external version derived from the epoch, instead of partial-document `update`s.
+    /// Out-of-order retries are then resolved by the version: a bulk carrying an older
+    /// version can no longer overwrite a newer document.
+    #[serde(rename = "external_versioning")]
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub external_versioning: bool,
+
+    /// The maximum number of attempts for a `_bulk` request before the sink fails.
+    #[serde(rename = "max_retry_num")]
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default = "default_max_retry_num")]
+    pub max_retry_num: u32,
+
+    /// The interval between two attempts of a failed `_bulk` request, in milliseconds.
+    #[serde(rename = "retry_interval_ms")]
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default = "default_retry_interval_ms")]
+    pub retry_interval_ms: u64,
 }
 
 fn default_retry_on_conflict() -> i32 {
@@ -95,6 +116,14 @@ fn default_concurrent_requests() -> usize {
     1024
 }
 
+fn default_max_retry_num() -> u32 {
+    3
+}
+
+fn default_retry_interval_ms() -> u64 {
+    1000
+}
+
 impl ElasticSearchOpenSearchConfig {
     pub fn from_btreemap(properties: BTreeMap<String, String>) -> Result<Self> {
         let config = serde_json::from_value::<ElasticSearchOpenSearchConfig>(
@@ -49,6 +49,7 @@ pub struct JsonEncoder {
     col_indices: Option<Vec<usize>>,
     kafka_connect: Option<KafkaConnectParamsRef>,
     config: JsonEncoderConfig,
+    name_mapping: Option<HashMap<String, String>>,
 }
 
 impl JsonEncoder {
@@ -74,6 +75,7 @@ impl JsonEncoder {
             col_indices,
             kafka_connect: None,
             config,
+            name_mapping: None,
         }
     }
 
@@ -91,6 +93,7 @@ impl JsonEncoder {
             col_indices,
             kafka_connect: None,
             config,
+            name_mapping: None,
         }
     }
 
@@ -112,6 +115,7 @@ impl JsonEncoder {
             col_indices,
             kafka_connect: None,
             config,
+            name_mapping: None,
         }
     }
 
@@ -129,6 +133,7 @@ impl JsonEncoder {
             col_indices,
             kafka_connect: None,
             config,
+            name_mapping: None,
         }
     }
 
@@ -138,6 +143,15 @@ impl JsonEncoder {
             ..self
         }
     }
+
+    /// Renames the listed columns in the encoded payload. Columns not in the mapping keep
+    /// their original names.
+    pub fn with_name_mapping(self, name_mapping: HashMap<String, String>) -> Self {
+        Self {
+            name_mapping: Some(name_mapping),
+            ..self
+        }
+    }
 }
 
 impl RowEncoder for JsonEncoder {
@@ -160,7 +174,12 @@ impl RowEncoder for JsonEncoder {
         let col_indices = col_indices.collect_vec();
         for idx in &col_indices {
             let field = &self.schema[*idx];
-            let key = field.name.clone();
+            let key = self
+                .name_mapping
+                .as_ref()
+                .and_then(|m| m.get(&field.name))
+                .unwrap_or(&field.name)
+                .clone();
             let value = datum_to_json_object(field, row.datum_at(*idx), &self.config)
                 .map_err(|e| SinkError::Encode(e.to_report_string()))?;
             mappings.insert(key, value);
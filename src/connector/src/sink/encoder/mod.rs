@@ -101,6 +101,22 @@ pub enum TimestampHandlingMode {
     String,
 }
 
+impl TimestampHandlingMode {
+    pub const OPTION_KEY: &'static str = "timestamp.handling.mode";
+
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(Self::OPTION_KEY).map(std::ops::Deref::deref) {
+            Some("milli") | None => Ok(Self::Milli),
+            Some("string") => Ok(Self::String),
+            Some(v) => Err(super::SinkError::Config(anyhow::anyhow!(
+                "unrecognized {} value {}",
+                Self::OPTION_KEY,
+                v
+            ))),
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum TimeHandlingMode {
     Milli,
@@ -14,13 +14,14 @@
 
 use risingwave_common::array::Op;
 
-use super::{Result, SinkFormatter, StreamChunk};
+use super::{ColumnMasker, Result, SinkFormatter, StreamChunk};
 use crate::sink::encoder::RowEncoder;
 use crate::tri;
 
 pub struct AppendOnlyFormatter<KE, VE> {
     key_encoder: Option<KE>,
     val_encoder: VE,
+    masker: Option<ColumnMasker>,
 }
 
 impl<KE, VE> AppendOnlyFormatter<KE, VE> {
@@ -28,8 +29,14 @@ impl<KE, VE> AppendOnlyFormatter<KE, VE> {
         Self {
             key_encoder,
             val_encoder,
+            masker: None,
         }
     }
+
+    pub fn with_masker(mut self, masker: Option<ColumnMasker>) -> Self {
+        self.masker = masker;
+        self
+    }
 }
 
 impl<KE: RowEncoder, VE: RowEncoder> SinkFormatter for AppendOnlyFormatter<KE, VE> {
@@ -51,7 +58,10 @@ impl<KE: RowEncoder, VE: RowEncoder> SinkFormatter for AppendOnlyFormatter<KE, V
                         Some(key_encoder) => Some(tri!(key_encoder.encode(row))),
                         None => None,
                     };
-                    let event_object = Some(tri!(self.val_encoder.encode(row)));
+                    let event_object = Some(match &self.masker {
+                        Some(masker) => tri!(self.val_encoder.encode(tri!(masker.apply(row)))),
+                        None => tri!(self.val_encoder.encode(row)),
+                    });
 
                     yield Ok((event_key_object, event_object))
                 }
@@ -19,7 +19,7 @@ use risingwave_common::catalog::{Field, Schema};
 use serde_json::{json, Map, Value};
 use tracing::warn;
 
-use super::{Result, SinkFormatter, StreamChunk};
+use super::{ColumnMasker, Result, SinkFormatter, StreamChunk};
 use crate::sink::encoder::{
     DateHandlingMode, JsonEncoder, JsonbHandlingMode, RowEncoder, TimeHandlingMode,
     TimestampHandlingMode, TimestamptzHandlingMode,
@@ -52,6 +52,7 @@ pub struct DebeziumJsonFormatter {
     opts: DebeziumAdapterOpts,
     key_encoder: JsonEncoder,
     val_encoder: JsonEncoder,
+    masker: Option<ColumnMasker>,
 }
 
 impl DebeziumJsonFormatter {
@@ -88,8 +89,14 @@ impl DebeziumJsonFormatter {
             opts,
             key_encoder,
             val_encoder,
+            masker: None,
         }
     }
+
+    pub fn with_masker(mut self, masker: Option<ColumnMasker>) -> Self {
+        self.masker = masker;
+        self
+    }
 }
 
 impl SinkFormatter for DebeziumJsonFormatter {
@@ -111,7 +118,12 @@ impl SinkFormatter for DebeziumJsonFormatter {
                     opts,
                     key_encoder,
                     val_encoder,
+                    masker,
                 } = self;
+                let encode_val = |row| match masker {
+                    Some(masker) => val_encoder.encode(masker.apply(row)?),
+                    None => val_encoder.encode(row),
+                };
                 let ts_ms = SystemTime::now()
                     .duration_since(UNIX_EPOCH)
                     .unwrap()
@@ -141,7 +153,7 @@ impl SinkFormatter for DebeziumJsonFormatter {
                             "schema": schema_to_json(schema, db_name, sink_from_name),
                             "payload": {
                                 "before": null,
-                                "after": tri!(val_encoder.encode(row)),
+                                "after": tri!(encode_val(row)),
                                 "op": "c",
                                 "ts_ms": ts_ms,
                                 "source": source_field,
@@ -151,7 +163,7 @@ impl SinkFormatter for DebeziumJsonFormatter {
                             let value_obj = Some(json!({
                                 "schema": schema_to_json(schema, db_name, sink_from_name),
                                 "payload": {
-                                    "before": tri!(val_encoder.encode(row)),
+                                    "before": tri!(encode_val(row)),
                                     "after": null,
                                     "op": "d",
                                     "ts_ms": ts_ms,
@@ -169,7 +181,7 @@ impl SinkFormatter for DebeziumJsonFormatter {
                             continue;
                         }
                         Op::UpdateDelete => {
-                            update_cache = Some(tri!(val_encoder.encode(row)));
+                            update_cache = Some(tri!(encode_val(row)));
                             continue;
                         }
                         Op::UpdateInsert => {
@@ -178,7 +190,7 @@ impl SinkFormatter for DebeziumJsonFormatter {
                                     "schema": schema_to_json(schema, db_name, sink_from_name),
                                     "payload": {
                                         "before": before,
-                                        "after": tri!(val_encoder.encode(row)),
+                                        "after": tri!(encode_val(row)),
                                         "op": "u",
                                         "ts_ms": ts_ms,
                                         "source": source_field,
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use anyhow::{anyhow, Context};
 use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::Field;
@@ -20,10 +22,12 @@ use crate::sink::{Result, SinkError};
 
 mod append_only;
 mod debezium_json;
+mod mask;
 mod upsert;
 
 pub use append_only::AppendOnlyFormatter;
 pub use debezium_json::{DebeziumAdapterOpts, DebeziumJsonFormatter};
+pub use mask::ColumnMasker;
 use risingwave_common::catalog::Schema;
 use risingwave_common::types::DataType;
 pub use upsert::UpsertFormatter;
@@ -129,16 +133,32 @@ pub trait EncoderBuild: Sized {
 impl EncoderBuild for JsonEncoder {
     async fn build(b: EncoderParams<'_>, pk_indices: Option<Vec<usize>>) -> Result<Self> {
         let timestamptz_mode = TimestamptzHandlingMode::from_options(&b.format_desc.options)?;
+        let timestamp_mode = TimestampHandlingMode::from_options(&b.format_desc.options)?;
         let jsonb_handling_mode = JsonbHandlingMode::from_options(&b.format_desc.options)?;
+        // Projection and renaming apply to the payload only. The key always carries the
+        // primary key columns under their original names.
+        let is_key = pk_indices.is_some();
+        let col_indices = match pk_indices {
+            Some(pk_indices) => Some(pk_indices),
+            None => exclude_columns_to_indices(&b.format_desc.options, &b.schema)?,
+        };
+        let name_mapping = match is_key {
+            true => None,
+            false => rename_columns_to_mapping(&b.format_desc.options, &b.schema)?,
+        };
         let encoder = JsonEncoder::new(
             b.schema,
-            pk_indices,
+            col_indices,
             DateHandlingMode::FromCe,
-            TimestampHandlingMode::Milli,
+            timestamp_mode,
             timestamptz_mode,
             TimeHandlingMode::Milli,
             jsonb_handling_mode,
         );
+        let encoder = match name_mapping {
+            Some(name_mapping) => encoder.with_name_mapping(name_mapping),
+            None => encoder,
+        };
         let encoder = if let Some(s) = b.format_desc.options.get("schemas.enable") {
             match s.to_lowercase().parse::<bool>() {
                 Ok(true) => {
@@ -178,6 +198,78 @@ impl EncoderBuild for ProtoEncoder {
     }
 }
 
+/// Parses the `exclude_columns` option, a comma-separated list of column names to drop
+/// from the payload, into the indices of the remaining columns. Returns `None` when the
+/// option is absent, i.e. all columns are encoded.
+fn exclude_columns_to_indices(
+    options: &BTreeMap<String, String>,
+    schema: &Schema,
+) -> Result<Option<Vec<usize>>> {
+    let Some(excluded) = options.get("exclude_columns") else {
+        return Ok(None);
+    };
+    let excluded: HashSet<&str> = excluded
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    for name in &excluded {
+        if !schema.fields().iter().any(|f| f.name == *name) {
+            return Err(SinkError::Config(anyhow!(
+                "exclude_columns: column {} not found in the sink schema",
+                name
+            )));
+        }
+    }
+    let indices = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| !excluded.contains(f.name.as_str()))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    if indices.is_empty() {
+        return Err(SinkError::Config(anyhow!(
+            "exclude_columns must not exclude all columns of the sink"
+        )));
+    }
+    Ok(Some(indices))
+}
+
+/// Parses the `rename_columns` option, a comma-separated list of `old:new` pairs, into a
+/// mapping from original to payload column names.
+fn rename_columns_to_mapping(
+    options: &BTreeMap<String, String>,
+    schema: &Schema,
+) -> Result<Option<HashMap<String, String>>> {
+    let Some(renames) = options.get("rename_columns") else {
+        return Ok(None);
+    };
+    let mut mapping = HashMap::new();
+    for pair in renames.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let (from, to) = pair.split_once(':').ok_or_else(|| {
+            SinkError::Config(anyhow!(
+                "rename_columns expects a comma-separated list of `old:new` pairs, got {}",
+                pair
+            ))
+        })?;
+        let (from, to) = (from.trim(), to.trim());
+        if !schema.fields().iter().any(|f| f.name == from) {
+            return Err(SinkError::Config(anyhow!(
+                "rename_columns: column {} not found in the sink schema",
+                from
+            )));
+        }
+        if mapping.insert(from.to_owned(), to.to_owned()).is_some() {
+            return Err(SinkError::Config(anyhow!(
+                "rename_columns: column {} is renamed more than once",
+                from
+            )));
+        }
+    }
+    Ok(Some(mapping))
+}
+
 fn ensure_only_one_pk<'a>(
     data_type_name: &'a str,
     params: &'a EncoderParams<'_>,
@@ -256,6 +348,42 @@ impl EncoderBuild for AvroEncoder {
             crate::schema::SchemaLoader::from_format_options(b.topic, &b.format_desc.options)
                 .map_err(|e| SinkError::Config(anyhow!(e)))?;
 
+        if b.format_desc
+            .options
+            .get(crate::schema::SCHEMA_REGISTRY_AUTO_REGISTER_KEY)
+            .map(|v| v.as_str())
+            == Some("true")
+        {
+            // Derive a schema from the sink columns and register it ourselves, instead of
+            // requiring one to be registered out of band. The registration is compatibility
+            // checked against the latest version, so an incompatible `ALTER` of the upstream
+            // is rejected here rather than surprising downstream consumers.
+            let base_name = b
+                .sink_from_name
+                .rsplit('.')
+                .next()
+                .unwrap_or(&b.sink_from_name)
+                .to_owned();
+            let (record_name, fields) = match &pk_indices {
+                Some(indices) => (
+                    format!("{base_name}_key"),
+                    indices
+                        .iter()
+                        .map(|i| b.schema.fields[*i].clone())
+                        .collect::<Vec<_>>(),
+                ),
+                None => (base_name, b.schema.fields.clone()),
+            };
+            let definition =
+                crate::schema::avro::fields_to_avro_definition(&record_name, &fields)
+                    .map_err(|e| SinkError::Config(anyhow!(e)))?;
+            match &pk_indices {
+                Some(_) => loader.register_key_schema("AVRO", &definition).await,
+                None => loader.register_val_schema("AVRO", &definition).await,
+            }
+            .map_err(|e| SinkError::Config(anyhow!(e)))?;
+        }
+
         let (schema_id, avro) = match pk_indices {
             Some(_) => loader
                 .load_key_schema()
@@ -306,24 +434,26 @@ trait FormatterBuild: Sized {
 
 impl<KE: EncoderBuild, VE: EncoderBuild> FormatterBuild for AppendOnlyFormatter<KE, VE> {
     async fn build(b: FormatterParams<'_>) -> Result<Self> {
+        let masker = ColumnMasker::from_options(&b.builder.format_desc.options, &b.builder.schema)?;
         let key_encoder = match b.pk_indices.is_empty() {
             true => None,
             false => Some(KE::build(b.builder.clone(), Some(b.pk_indices)).await?),
         };
         let val_encoder = VE::build(b.builder, None).await?;
-        Ok(AppendOnlyFormatter::new(key_encoder, val_encoder))
+        Ok(AppendOnlyFormatter::new(key_encoder, val_encoder).with_masker(masker))
     }
 }
 
 impl<KE: EncoderBuild, VE: EncoderBuild> FormatterBuild for UpsertFormatter<KE, VE> {
     async fn build(b: FormatterParams<'_>) -> Result<Self> {
+        let masker = ColumnMasker::from_options(&b.builder.format_desc.options, &b.builder.schema)?;
         let key_encoder = KE::build(b.builder.clone(), Some(b.pk_indices))
             .await
             .with_context(|| "Failed to build key encoder")?;
         let val_encoder = VE::build(b.builder, None)
             .await
             .with_context(|| "Failed to build value encoder")?;
-        Ok(UpsertFormatter::new(key_encoder, val_encoder))
+        Ok(UpsertFormatter::new(key_encoder, val_encoder).with_masker(masker))
     }
 }
 
@@ -331,13 +461,15 @@ impl FormatterBuild for DebeziumJsonFormatter {
     async fn build(b: FormatterParams<'_>) -> Result<Self> {
         assert_eq!(b.builder.format_desc.encode, SinkEncode::Json);
 
+        let masker = ColumnMasker::from_options(&b.builder.format_desc.options, &b.builder.schema)?;
         Ok(DebeziumJsonFormatter::new(
             b.builder.schema,
             b.pk_indices,
             b.builder.db_name,
             b.builder.sink_from_name,
             DebeziumAdapterOpts::default(),
-        ))
+        )
+        .with_masker(masker))
     }
 }
 
@@ -14,13 +14,14 @@
 
 use risingwave_common::array::Op;
 
-use super::{Result, SinkFormatter, StreamChunk};
+use super::{ColumnMasker, Result, SinkFormatter, StreamChunk};
 use crate::sink::encoder::RowEncoder;
 use crate::tri;
 
 pub struct UpsertFormatter<KE, VE> {
     key_encoder: KE,
     val_encoder: VE,
+    masker: Option<ColumnMasker>,
 }
 
 impl<KE, VE> UpsertFormatter<KE, VE> {
@@ -28,8 +29,14 @@ impl<KE, VE> UpsertFormatter<KE, VE> {
         Self {
             key_encoder,
             val_encoder,
+            masker: None,
         }
     }
+
+    pub fn with_masker(mut self, masker: Option<ColumnMasker>) -> Self {
+        self.masker = masker;
+        self
+    }
 }
 
 impl<KE: RowEncoder, VE: RowEncoder> SinkFormatter for UpsertFormatter<KE, VE> {
@@ -47,7 +54,12 @@ impl<KE: RowEncoder, VE: RowEncoder> SinkFormatter for UpsertFormatter<KE, VE> {
                     let event_key_object = Some(tri!(self.key_encoder.encode(row)));
 
                     let event_object = match op {
-                        Op::Insert | Op::UpdateInsert => Some(tri!(self.val_encoder.encode(row))),
+                        Op::Insert | Op::UpdateInsert => Some(match &self.masker {
+                            Some(masker) => {
+                                tri!(self.val_encoder.encode(tri!(masker.apply(row))))
+                            }
+                            None => tri!(self.val_encoder.encode(row)),
+                        }),
                         // Empty value with a key
                         Op::Delete => None,
                         Op::UpdateDelete => {
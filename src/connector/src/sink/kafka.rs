@@ -18,14 +18,18 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::anyhow;
+use async_trait::async_trait;
 use futures::{Future, FutureExt, TryFuture};
 use rdkafka::error::KafkaError;
 use rdkafka::message::ToBytes;
-use rdkafka::producer::{DeliveryFuture, FutureProducer, FutureRecord};
+use rdkafka::producer::{DeliveryFuture, FutureProducer, FutureRecord, Producer};
 use rdkafka::types::RDKafkaErrorCode;
 use rdkafka::ClientConfig;
 use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::Schema;
+use risingwave_pb::connector_service::sink_metadata::Metadata::Serialized;
+use risingwave_pb::connector_service::sink_metadata::SerializedMetadata;
+use risingwave_pb::connector_service::SinkMetadata;
 use serde_derive::Deserialize;
 use serde_with::{serde_as, DisplayFromStr};
 use strum_macros::{Display, EnumString};
@@ -38,12 +42,16 @@ use crate::connector_common::{
     AwsAuthProps, KafkaCommon, KafkaConnectionProps, KafkaPrivateLinkCommon,
     RdKafkaPropertiesCommon,
 };
+use crate::sink::coordinate::CoordinatedSinkWriter;
 use crate::sink::formatter::SinkFormatterImpl;
 use crate::sink::log_store::DeliveryFutureManagerAddFuture;
 use crate::sink::writer::{
     AsyncTruncateLogSinkerOf, AsyncTruncateSinkWriter, AsyncTruncateSinkWriterExt, FormattedSink,
+    LogSinkerOf, SinkWriter, SinkWriterExt,
+};
+use crate::sink::{
+    LogSinker, Result, SinkCommitCoordinator, SinkLogReader, SinkWriterMetrics, SinkWriterParam,
 };
-use crate::sink::{DummySinkCommitCoordinator, Result, SinkWriterParam};
 use crate::source::kafka::{
     KafkaContextCommon, KafkaProperties, KafkaSplitEnumerator, RwProducerContext,
 };
@@ -151,6 +159,13 @@ pub struct RdKafkaPropertiesProducer {
     #[serde_as(as = "Option<DisplayFromStr>")]
     message_timeout_ms: Option<usize>,
 
+    /// The maximum amount of time the transaction coordinator will wait for a transaction status
+    /// update from the producer before proactively aborting the ongoing transaction. Only takes
+    /// effect when the sink works in transactional mode, i.e. `transactional.id.prefix` is set.
+    #[serde(rename = "properties.transaction.timeout.ms")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    transaction_timeout_ms: Option<usize>,
+
     /// The maximum number of unacknowledged requests the client will send on a single connection before blocking.
     #[serde(
         rename = "properties.max.in.flight.requests.per.connection",
@@ -202,6 +217,9 @@ impl RdKafkaPropertiesProducer {
         if let Some(v) = self.message_timeout_ms {
             c.set("message.timeout.ms", v.to_string());
         }
+        if let Some(v) = self.transaction_timeout_ms {
+            c.set("transaction.timeout.ms", v.to_string());
+        }
         c.set(
             "max.in.flight.requests.per.connection",
             self.max_in_flight_requests_per_connection.to_string(),
@@ -237,6 +255,15 @@ pub struct KafkaConfig {
     /// as a string.
     pub primary_key: Option<String>,
 
+    /// Prefix of the `transactional.id` used by the producers. When set, the sink works in
+    /// transactional mode: each checkpoint epoch maps to one Kafka transaction, so
+    /// read-committed consumers never observe a partially written epoch. Delivery is still
+    /// at-least-once: a transaction is committed before the checkpoint it belongs to becomes
+    /// durable, so an epoch replayed on recovery is produced and committed again as a whole.
+    /// Each sink writer derives a unique `transactional.id` from this prefix.
+    #[serde(rename = "transactional.id.prefix")]
+    pub transactional_id_prefix: Option<String>,
+
     #[serde(flatten)]
     pub rdkafka_properties_common: RdKafkaPropertiesCommon,
 
@@ -287,6 +314,7 @@ impl From<KafkaConfig> for KafkaProperties {
 #[derive(Debug)]
 pub struct KafkaSink {
     pub config: KafkaConfig,
+    param: SinkParam,
     schema: Schema,
     pk_indices: Vec<usize>,
     format_desc: SinkFormatDesc,
@@ -299,27 +327,29 @@ impl TryFrom<SinkParam> for KafkaSink {
 
     fn try_from(param: SinkParam) -> std::result::Result<Self, Self::Error> {
         let schema = param.schema();
-        let config = KafkaConfig::from_btreemap(param.properties)?;
+        let config = KafkaConfig::from_btreemap(param.properties.clone())?;
         Ok(Self {
             config,
             schema,
-            pk_indices: param.downstream_pk,
+            pk_indices: param.downstream_pk.clone(),
             format_desc: param
                 .format_desc
+                .clone()
                 .ok_or_else(|| SinkError::Config(anyhow!("missing FORMAT ... ENCODE ...")))?,
-            db_name: param.db_name,
-            sink_from_name: param.sink_from_name,
+            db_name: param.db_name.clone(),
+            sink_from_name: param.sink_from_name.clone(),
+            param,
         })
     }
 }
 
 impl Sink for KafkaSink {
-    type Coordinator = DummySinkCommitCoordinator;
-    type LogSinker = AsyncTruncateLogSinkerOf<KafkaSinkWriter>;
+    type Coordinator = KafkaSinkCommitter;
+    type LogSinker = KafkaLogSinker;
 
     const SINK_NAME: &'static str = KAFKA_SINK;
 
-    async fn new_log_sinker(&self, _writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
+    async fn new_log_sinker(&self, writer_param: SinkWriterParam) -> Result<Self::LogSinker> {
         let formatter = SinkFormatterImpl::new(
             &self.format_desc,
             self.schema.clone(),
@@ -329,6 +359,41 @@ impl Sink for KafkaSink {
             &self.config.common.topic,
         )
         .await?;
+
+        if let Some(prefix) = &self.config.transactional_id_prefix {
+            // Transactional mode: each writer owns a transactional producer and commits one
+            // Kafka transaction per checkpoint epoch, coordinated by the sink coordinator.
+            let transactional_id = format!(
+                "{}-{}-{}",
+                prefix, writer_param.sink_id.sink_id, writer_param.executor_id
+            );
+            let metrics = SinkWriterMetrics::new(&writer_param);
+            let inner = KafkaTransactionalSinkWriter::new(
+                self.config.clone(),
+                formatter,
+                transactional_id,
+            )
+            .await?;
+            let writer = CoordinatedSinkWriter::new(
+                writer_param
+                    .meta_client
+                    .expect("should have meta client")
+                    .sink_coordinate_client()
+                    .await,
+                self.param.clone(),
+                writer_param.vnode_bitmap.ok_or_else(|| {
+                    SinkError::Coordinator(anyhow!(
+                        "sink needs coordination and should not have singleton input"
+                    ))
+                })?,
+                inner,
+            )
+            .await?;
+            return Ok(KafkaLogSinker::Transactional(
+                writer.into_log_sinker(metrics),
+            ));
+        }
+
         let max_delivery_buffer_size = (self
             .config
             .rdkafka_properties_producer
@@ -338,9 +403,15 @@ impl Sink for KafkaSink {
             .unwrap_or(KAFKA_WRITER_MAX_QUEUE_SIZE) as f32
             * KAFKA_WRITER_MAX_QUEUE_SIZE_RATIO) as usize;
 
-        Ok(KafkaSinkWriter::new(self.config.clone(), formatter)
-            .await?
-            .into_log_sinker(max_delivery_buffer_size))
+        Ok(KafkaLogSinker::NonTransactional(
+            KafkaSinkWriter::new(self.config.clone(), formatter)
+                .await?
+                .into_log_sinker(max_delivery_buffer_size),
+        ))
+    }
+
+    async fn new_coordinator(&self) -> Result<Self::Coordinator> {
+        Ok(KafkaSinkCommitter)
     }
 
     async fn validate(&self) -> Result<()> {
@@ -412,32 +483,40 @@ pub struct KafkaSinkWriter {
     config: KafkaConfig,
 }
 
-impl KafkaSinkWriter {
-    async fn new(config: KafkaConfig, formatter: SinkFormatterImpl) -> Result<Self> {
-        let inner: FutureProducer<RwProducerContext> = {
-            let mut c = ClientConfig::new();
+async fn build_kafka_producer(
+    config: &KafkaConfig,
+    transactional_id: Option<&str>,
+) -> Result<FutureProducer<RwProducerContext>> {
+    let mut c = ClientConfig::new();
 
-            // KafkaConfig configuration
-            config.connection.set_security_properties(&mut c);
-            config.set_client(&mut c);
+    // KafkaConfig configuration
+    config.connection.set_security_properties(&mut c);
+    config.set_client(&mut c);
 
-            // ClientConfig configuration
-            c.set("bootstrap.servers", &config.connection.brokers);
+    // ClientConfig configuration
+    c.set("bootstrap.servers", &config.connection.brokers);
+    if let Some(transactional_id) = transactional_id {
+        c.set("transactional.id", transactional_id);
+    }
 
-            // Create the producer context, will be used to create the producer
-            let broker_rewrite_map = config.privatelink_common.broker_rewrite_map.clone();
-            let ctx_common = KafkaContextCommon::new(
-                broker_rewrite_map,
-                None,
-                None,
-                config.aws_auth_props.clone(),
-                config.connection.is_aws_msk_iam(),
-            )
-            .await?;
-            let producer_ctx = RwProducerContext::new(ctx_common);
-            // Generate the producer
-            c.create_with_context(producer_ctx).await?
-        };
+    // Create the producer context, will be used to create the producer
+    let broker_rewrite_map = config.privatelink_common.broker_rewrite_map.clone();
+    let ctx_common = KafkaContextCommon::new(
+        broker_rewrite_map,
+        None,
+        None,
+        config.aws_auth_props.clone(),
+        config.connection.is_aws_msk_iam(),
+    )
+    .await?;
+    let producer_ctx = RwProducerContext::new(ctx_common);
+    // Generate the producer
+    Ok(c.create_with_context(producer_ctx).await?)
+}
+
+impl KafkaSinkWriter {
+    async fn new(config: KafkaConfig, formatter: SinkFormatterImpl) -> Result<Self> {
+        let inner = build_kafka_producer(&config, None).await?;
 
         Ok(KafkaSinkWriter {
             formatter,
@@ -579,6 +658,199 @@ impl<'a> FormattedSink for KafkaPayloadWriter<'a> {
     }
 }
 
+/// Timeout for the blocking transaction APIs (init/commit/abort), which only wait for the
+/// broker round trips, not for outstanding deliveries. Deliveries of the epoch are awaited
+/// explicitly before the transaction is committed.
+const KAFKA_TRANSACTION_API_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The transactional Kafka sink writer. All records of one checkpoint epoch are produced in
+/// one Kafka transaction, which is committed when the checkpoint barrier is collected, so
+/// read-committed consumers observe epochs atomically.
+///
+/// Note that the transaction is committed *before* the checkpoint becomes durable, and a
+/// Kafka transaction cannot be held open across a producer restart (`init_transactions`
+/// fences and aborts it), so a crash in between replays the epoch into a fresh transaction:
+/// delivery is at-least-once, in units of whole epochs.
+pub struct KafkaTransactionalSinkWriter {
+    formatter: SinkFormatterImpl,
+    inner: FutureProducer<RwProducerContext>,
+    config: KafkaConfig,
+    delivery_futures: Vec<DeliveryFuture>,
+    in_transaction: bool,
+}
+
+impl KafkaTransactionalSinkWriter {
+    async fn new(
+        config: KafkaConfig,
+        formatter: SinkFormatterImpl,
+        transactional_id: String,
+    ) -> Result<Self> {
+        let inner = build_kafka_producer(&config, Some(&transactional_id)).await?;
+        // Fence producers of previous incarnations of this writer and acquire the
+        // transactional state from the coordinator.
+        inner.init_transactions(KAFKA_TRANSACTION_API_TIMEOUT)?;
+
+        Ok(KafkaTransactionalSinkWriter {
+            formatter,
+            inner,
+            config,
+            delivery_futures: Vec::new(),
+            in_transaction: false,
+        })
+    }
+}
+
+struct KafkaTransactionalPayloadWriter<'a> {
+    inner: &'a FutureProducer<RwProducerContext>,
+    delivery_futures: &'a mut Vec<DeliveryFuture>,
+    config: &'a KafkaConfig,
+}
+
+impl<'w> KafkaTransactionalPayloadWriter<'w> {
+    async fn send_result<'a, K, P>(&'a mut self, mut record: FutureRecord<'a, K, P>) -> Result<()>
+    where
+        K: ToBytes + ?Sized,
+        P: ToBytes + ?Sized,
+    {
+        for i in 0..self.config.max_retry_num {
+            match self.inner.send_result(record) {
+                Ok(delivery_future) => {
+                    self.delivery_futures.push(delivery_future);
+                    return Ok(());
+                }
+                // The enqueue buffer is full, wait for sometime before the retry. Unlike the
+                // non-transactional writer, deliveries cannot be awaited here, because they
+                // only complete when the transaction progresses.
+                Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), rec)) => {
+                    tracing::warn!(
+                        "producer queue full. Delivery future buffer size={}. Sleep and retry #{}",
+                        self.delivery_futures.len(),
+                        i
+                    );
+                    record = rec;
+                    tokio::time::sleep(self.config.retry_interval).await;
+                }
+                Err((e, _)) => return Err(e.into()),
+            }
+        }
+        Err(KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull).into())
+    }
+
+    async fn write_inner(
+        &mut self,
+        event_key_object: Option<Vec<u8>>,
+        event_object: Option<Vec<u8>>,
+    ) -> Result<()> {
+        let topic = self.config.common.topic.clone();
+        let mut record = FutureRecord::<[u8], [u8]>::to(topic.as_str());
+        if let Some(key_str) = &event_key_object {
+            record = record.key(key_str);
+        }
+        if let Some(payload) = &event_object {
+            record = record.payload(payload);
+        }
+        self.send_result(record).await?;
+        Ok(())
+    }
+}
+
+impl<'a> FormattedSink for KafkaTransactionalPayloadWriter<'a> {
+    type K = Vec<u8>;
+    type V = Vec<u8>;
+
+    async fn write_one(&mut self, k: Option<Self::K>, v: Option<Self::V>) -> Result<()> {
+        self.write_inner(k, v).await
+    }
+}
+
+#[async_trait]
+impl SinkWriter for KafkaTransactionalSinkWriter {
+    type CommitMetadata = Option<SinkMetadata>;
+
+    async fn begin_epoch(&mut self, _epoch: u64) -> Result<()> {
+        if !self.in_transaction {
+            self.inner.begin_transaction()?;
+            self.in_transaction = true;
+        }
+        Ok(())
+    }
+
+    async fn write_batch(&mut self, chunk: StreamChunk) -> Result<()> {
+        let mut payload_writer = KafkaTransactionalPayloadWriter {
+            inner: &self.inner,
+            delivery_futures: &mut self.delivery_futures,
+            config: &self.config,
+        };
+        dispatch_sink_formatter_impl!(&self.formatter, formatter, {
+            payload_writer.write_chunk(chunk, formatter).await
+        })
+    }
+
+    async fn barrier(&mut self, is_checkpoint: bool) -> Result<Option<SinkMetadata>> {
+        if is_checkpoint {
+            // All records of the epoch must be delivered before the transaction is committed.
+            for delivery_future in std::mem::take(&mut self.delivery_futures) {
+                map_delivery_future(delivery_future).await?;
+            }
+            self.inner.commit_transaction(KAFKA_TRANSACTION_API_TIMEOUT)?;
+            self.in_transaction = false;
+            // Kafka transactions are committed by the producer itself, so there is nothing to
+            // pass to the coordinator besides the notification that this writer has committed.
+            Ok(Some(SinkMetadata {
+                metadata: Some(Serialized(SerializedMetadata { metadata: vec![] })),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        if self.in_transaction {
+            self.inner.abort_transaction(KAFKA_TRANSACTION_API_TIMEOUT)?;
+            self.in_transaction = false;
+        }
+        Ok(())
+    }
+}
+
+pub enum KafkaLogSinker {
+    NonTransactional(AsyncTruncateLogSinkerOf<KafkaSinkWriter>),
+    Transactional(LogSinkerOf<CoordinatedSinkWriter<KafkaTransactionalSinkWriter>>),
+}
+
+#[async_trait]
+impl LogSinker for KafkaLogSinker {
+    async fn consume_log_and_sink(self, log_reader: &mut impl SinkLogReader) -> Result<!> {
+        match self {
+            KafkaLogSinker::NonTransactional(sinker) => {
+                sinker.consume_log_and_sink(log_reader).await
+            }
+            KafkaLogSinker::Transactional(sinker) => sinker.consume_log_and_sink(log_reader).await,
+        }
+    }
+}
+
+/// The coordinator of the transactional Kafka sink. The Kafka transactions themselves are
+/// committed by the writers, so the coordinator merely aligns the writers on each checkpoint
+/// epoch: `commit` is called only after every writer has committed its transaction.
+pub struct KafkaSinkCommitter;
+
+#[async_trait]
+impl SinkCommitCoordinator for KafkaSinkCommitter {
+    async fn init(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn commit(&mut self, epoch: u64, metadata: Vec<SinkMetadata>) -> Result<()> {
+        tracing::debug!(
+            "kafka sink committed epoch {} with {} writers",
+            epoch,
+            metadata.len()
+        );
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use maplit::btreemap;
@@ -767,6 +767,14 @@ impl<F> DeliveryFutureManager<F> {
 pub struct DeliveryFutureManagerAddFuture<'a, F>(&'a mut DeliveryFutureManager<F>);
 
 impl<'a, F: TryFuture<Ok = ()> + Unpin + 'static> DeliveryFutureManagerAddFuture<'a, F> {
+    /// The epoch of the chunk that is currently being written.
+    pub fn current_epoch(&self) -> u64 {
+        match self.0.items.back() {
+            Some((epoch, DeliveryFutureManagerItem::Chunk { .. })) => *epoch,
+            _ => unreachable!("should get epoch only after add a new chunk"),
+        }
+    }
+
     /// Add a new future to the latest started written chunk.
     /// The returned bool value indicate whether we have awaited on any previous futures.
     pub async fn add_future_may_await(&mut self, future: F) -> Result<bool, F::Error> {
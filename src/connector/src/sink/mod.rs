@@ -188,6 +188,23 @@ pub const SINK_TYPE_APPEND_ONLY: &str = "append-only";
 pub const SINK_TYPE_DEBEZIUM: &str = "debezium";
 pub const SINK_TYPE_UPSERT: &str = "upsert";
 pub const SINK_USER_FORCE_APPEND_ONLY_OPTION: &str = "force_append_only";
+pub const SINK_CONSISTENCY_OPTION: &str = "consistency";
+pub const SINK_CONSISTENCY_EXACTLY_ONCE: &str = "exactly_once";
+pub const SINK_CONSISTENCY_AT_LEAST_ONCE: &str = "at_least_once";
+
+/// The consistency guarantee of a sink, configurable per sink with the `consistency` option
+/// in `WITH` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SinkConsistency {
+    /// Data is committed to the downstream system only at checkpoint barriers, so that after
+    /// a recovery the downstream never observes data that gets rolled back.
+    #[default]
+    ExactlyOnce,
+    /// Data is committed to the downstream system at every barrier, without waiting for
+    /// checkpoint alignment. This lowers the end-to-end latency, at the cost of possible
+    /// duplicates when recovery replays the epochs since the last checkpoint.
+    AtLeastOnce,
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SinkParam {
@@ -263,6 +280,26 @@ impl SinkParam {
         }
     }
 
+    /// Parse the consistency guarantee of the sink from the `consistency` option in `WITH`
+    /// options, defaulting to exactly-once when unspecified.
+    pub fn consistency(&self) -> Result<SinkConsistency> {
+        match self
+            .properties
+            .get(SINK_CONSISTENCY_OPTION)
+            .map(|s| s.as_str())
+        {
+            None | Some(SINK_CONSISTENCY_EXACTLY_ONCE) => Ok(SinkConsistency::ExactlyOnce),
+            Some(SINK_CONSISTENCY_AT_LEAST_ONCE) => Ok(SinkConsistency::AtLeastOnce),
+            Some(other) => Err(SinkError::Config(anyhow!(
+                "`{}` must be {} or {}, got {}",
+                SINK_CONSISTENCY_OPTION,
+                SINK_CONSISTENCY_EXACTLY_ONCE,
+                SINK_CONSISTENCY_AT_LEAST_ONCE,
+                other
+            ))),
+        }
+    }
+
     // `SinkParams` should only be used when there is a secret context.
     // FIXME: Use a new type for `SinkFormatDesc` with properties contain filled secrets.
     pub fn fill_secret_for_format_desc(
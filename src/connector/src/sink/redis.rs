@@ -23,7 +23,8 @@ use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::Schema;
 use serde_derive::Deserialize;
 use serde_json::Value;
-use serde_with::serde_as;
+use serde_with::{serde_as, DisplayFromStr};
+use strum_macros::{Display, EnumString};
 use with_options::WithOptions;
 
 use super::catalog::SinkFormatDesc;
@@ -85,6 +86,17 @@ impl RedisPipe {
         };
     }
 
+    pub fn set_ex(&mut self, k: String, v: Vec<u8>, ttl_sec: u64) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.set_ex(k, v, ttl_sec);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.set_ex(k, v, ttl_sec);
+            }
+        };
+    }
+
     pub fn del(&mut self, k: String) {
         match self {
             RedisPipe::Cluster(pipe) => {
@@ -95,6 +107,61 @@ impl RedisPipe {
             }
         };
     }
+
+    pub fn hset(&mut self, key: String, field: String, v: Vec<u8>) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.hset(key, field, v);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.hset(key, field, v);
+            }
+        };
+    }
+
+    pub fn hdel(&mut self, key: String, field: String) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.hdel(key, field);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.hdel(key, field);
+            }
+        };
+    }
+
+    pub fn zadd(&mut self, key: String, score: f64, member: String) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.zadd(key, member, score);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.zadd(key, member, score);
+            }
+        };
+    }
+
+    pub fn zrem(&mut self, key: String, member: String) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.zrem(key, member);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.zrem(key, member);
+            }
+        };
+    }
+
+    pub fn xadd(&mut self, key: String, items: &[(String, Vec<u8>)]) {
+        match self {
+            RedisPipe::Cluster(pipe) => {
+                pipe.xadd(key, "*", items);
+            }
+            RedisPipe::Single(pipe) => {
+                pipe.xadd(key, "*", items);
+            }
+        };
+    }
 }
 pub enum RedisConn {
     // Redis deployed as a cluster, clusters with only one node should also use this conn
@@ -142,11 +209,50 @@ impl RedisCommon {
     }
 }
 
+/// The redis data structure that rows are written into.
+#[derive(Debug, Clone, Default, PartialEq, Display, Deserialize, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum RedisDataType {
+    /// Each row becomes a `SET`/`DEL` on its own key.
+    #[default]
+    String,
+    /// Rows become fields of the hash `redis.collection.key`, keyed by the encoded key.
+    Hash,
+    /// Rows become members of the sorted set `redis.collection.key`, with the encoded key
+    /// as the member and the encoded value parsed as the score.
+    Zset,
+    /// Rows are appended to the stream `redis.collection.key`. Only for append-only sinks.
+    Stream,
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Deserialize, WithOptions)]
 pub struct RedisConfig {
     #[serde(flatten)]
     pub common: RedisCommon,
+
+    /// The target data structure, one of `string`, `hash`, `zset` or `stream`.
+    /// Defaults to `string`.
+    #[serde(rename = "redis.data.type", default)]
+    #[serde_as(as = "DisplayFromStr")]
+    pub data_type: RedisDataType,
+
+    /// The key of the hash/zset/stream that rows are written into. Required for data
+    /// types other than `string`.
+    #[serde(rename = "redis.collection.key")]
+    pub collection_key: Option<String>,
+
+    /// Expiration of each written key in seconds. Only supported for the `string` data
+    /// type; keys without a TTL live forever.
+    #[serde(rename = "redis.ttl.seconds")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub ttl_seconds: Option<u64>,
+
+    /// Number of commands after which the pipeline is flushed to the server. By default
+    /// the pipeline is flushed once per chunk.
+    #[serde(rename = "redis.pipeline.batch.size")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub pipeline_batch_size: Option<usize>,
 }
 
 impl RedisConfig {
@@ -213,6 +319,26 @@ impl Sink for RedisSink {
 
     async fn validate(&self) -> Result<()> {
         self.config.common.build_conn_and_pipe().await?;
+        if self.config.data_type != RedisDataType::String {
+            if self.config.collection_key.is_none() {
+                return Err(SinkError::Config(anyhow!(
+                    "`redis.collection.key` must be set for the `{}` data type",
+                    self.config.data_type
+                )));
+            }
+            if self.config.ttl_seconds.is_some() {
+                return Err(SinkError::Config(anyhow!(
+                    "`redis.ttl.seconds` is only supported for the `string` data type"
+                )));
+            }
+        }
+        if self.config.data_type == RedisDataType::Stream
+            && self.format_desc.format != super::catalog::SinkFormat::AppendOnly
+        {
+            return Err(SinkError::Config(anyhow!(
+                "the `stream` data type is only supported for append-only sinks"
+            )));
+        }
         let all_set: HashSet<String> = self
             .schema
             .fields()
@@ -264,6 +390,12 @@ struct RedisSinkPayloadWriter {
     conn: Option<RedisConn>,
     // the command pipeline for write-commit
     pipe: RedisPipe,
+    data_type: RedisDataType,
+    collection_key: Option<String>,
+    ttl_seconds: Option<u64>,
+    pipeline_batch_size: Option<usize>,
+    // the number of commands buffered in `pipe` since the last flush
+    cmd_count: usize,
 }
 
 impl RedisSinkPayloadWriter {
@@ -271,14 +403,39 @@ impl RedisSinkPayloadWriter {
         let (conn, pipe) = config.common.build_conn_and_pipe().await?;
         let conn = Some(conn);
 
-        Ok(Self { conn, pipe })
+        Ok(Self {
+            conn,
+            pipe,
+            data_type: config.data_type,
+            collection_key: config.collection_key,
+            ttl_seconds: config.ttl_seconds,
+            pipeline_batch_size: config.pipeline_batch_size,
+            cmd_count: 0,
+        })
     }
 
     #[cfg(test)]
     pub fn mock() -> Self {
         let conn = None;
         let pipe = RedisPipe::Single(redis::pipe());
-        Self { conn, pipe }
+        Self {
+            conn,
+            pipe,
+            data_type: RedisDataType::String,
+            collection_key: None,
+            ttl_seconds: None,
+            pipeline_batch_size: None,
+            cmd_count: 0,
+        }
+    }
+
+    fn collection_key(&self) -> Result<String> {
+        self.collection_key.clone().ok_or_else(|| {
+            SinkError::Config(anyhow!(
+                "`redis.collection.key` must be set for the `{}` data type",
+                self.data_type
+            ))
+        })
     }
 
     pub async fn commit(&mut self) -> Result<()> {
@@ -290,6 +447,7 @@ impl RedisSinkPayloadWriter {
         }
         self.pipe.query::<()>(self.conn.as_mut().unwrap()).await?;
         self.pipe.clear();
+        self.cmd_count = 0;
         Ok(())
     }
 }
@@ -300,10 +458,59 @@ impl FormattedSink for RedisSinkPayloadWriter {
 
     async fn write_one(&mut self, k: Option<Self::K>, v: Option<Self::V>) -> Result<()> {
         let k = k.ok_or_else(|| SinkError::Redis("The redis key cannot be null".to_owned()))?;
-        match v {
-            Some(v) => self.pipe.set(k, v),
-            None => self.pipe.del(k),
+        match &self.data_type {
+            RedisDataType::String => match v {
+                Some(v) => match self.ttl_seconds {
+                    Some(ttl_sec) => self.pipe.set_ex(k, v, ttl_sec),
+                    None => self.pipe.set(k, v),
+                },
+                None => self.pipe.del(k),
+            },
+            RedisDataType::Hash => {
+                let key = self.collection_key()?;
+                match v {
+                    Some(v) => self.pipe.hset(key, k, v),
+                    None => self.pipe.hdel(key, k),
+                }
+            }
+            RedisDataType::Zset => {
+                let key = self.collection_key()?;
+                match v {
+                    Some(v) => {
+                        let score = std::str::from_utf8(&v)
+                            .ok()
+                            .and_then(|s| s.trim().parse::<f64>().ok())
+                            .ok_or_else(|| {
+                                SinkError::Redis(format!(
+                                    "the encoded value of a zset sink must be a number, got {:?}",
+                                    String::from_utf8_lossy(&v)
+                                ))
+                            })?;
+                        self.pipe.zadd(key, score, k);
+                    }
+                    None => self.pipe.zrem(key, k),
+                }
+            }
+            RedisDataType::Stream => {
+                let key = self.collection_key()?;
+                match v {
+                    Some(v) => self
+                        .pipe
+                        .xadd(key, &[("key".to_owned(), k.into_bytes()), ("value".to_owned(), v)]),
+                    None => {
+                        return Err(SinkError::Redis(
+                            "the `stream` data type does not support delete".to_owned(),
+                        ));
+                    }
+                }
+            }
         };
+        self.cmd_count += 1;
+        if let Some(batch_size) = self.pipeline_batch_size
+            && self.cmd_count >= batch_size
+        {
+            self.commit().await?;
+        }
         Ok(())
     }
 }
@@ -35,7 +35,9 @@ use super::{
     SinkError, SinkWriterMetrics, SINK_TYPE_APPEND_ONLY, SINK_TYPE_OPTION, SINK_TYPE_UPSERT,
 };
 use crate::sink::writer::{LogSinkerOf, SinkWriter, SinkWriterExt};
-use crate::sink::{DummySinkCommitCoordinator, Result, Sink, SinkParam, SinkWriterParam};
+use crate::sink::{
+    DummySinkCommitCoordinator, Result, Sink, SinkConsistency, SinkParam, SinkWriterParam,
+};
 
 pub const SQLSERVER_SINK: &str = "sqlserver";
 
@@ -91,6 +93,7 @@ pub struct SqlServerSink {
     schema: Schema,
     pk_indices: Vec<usize>,
     is_append_only: bool,
+    consistency: SinkConsistency,
 }
 
 impl SqlServerSink {
@@ -99,6 +102,7 @@ impl SqlServerSink {
         schema: Schema,
         pk_indices: Vec<usize>,
         is_append_only: bool,
+        consistency: SinkConsistency,
     ) -> Result<Self> {
         // Rewrite config because tiberius allows a maximum of 2100 params in one query request.
         const TIBERIUS_PARAM_MAX: usize = 2000;
@@ -120,6 +124,7 @@ impl SqlServerSink {
             schema,
             pk_indices,
             is_append_only,
+            consistency,
         })
     }
 }
@@ -129,12 +134,14 @@ impl TryFrom<SinkParam> for SqlServerSink {
 
     fn try_from(param: SinkParam) -> std::result::Result<Self, Self::Error> {
         let schema = param.schema();
+        let consistency = param.consistency()?;
         let config = SqlServerConfig::from_btreemap(param.properties)?;
         SqlServerSink::new(
             config,
             schema,
             param.downstream_pk,
             param.sink_type.is_append_only(),
+            consistency,
         )
     }
 }
@@ -259,7 +266,10 @@ ORDER BY
             self.is_append_only,
         )
         .await?
-        .into_log_sinker(SinkWriterMetrics::new(&writer_param)))
+        .into_log_sinker_with_consistency(
+            SinkWriterMetrics::new(&writer_param),
+            self.consistency,
+        ))
     }
 }
 
@@ -15,15 +15,27 @@
 use std::sync::{Arc, OnceLock};
 
 use anyhow::anyhow;
+use async_trait::async_trait;
 use parking_lot::Mutex;
+use risingwave_pb::connector_service::SinkMetadata;
 
 use crate::sink::boxed::{BoxCoordinator, BoxWriter};
+use crate::sink::coordinate::CoordinatedSinkWriter;
 use crate::sink::writer::{LogSinkerOf, SinkWriterExt};
-use crate::sink::{Sink, SinkError, SinkParam, SinkWriterMetrics, SinkWriterParam};
+use crate::sink::{
+    LogSinker, Sink, SinkError, SinkLogReader, SinkParam, SinkWriterMetrics, SinkWriterParam,
+};
 
 pub trait BuildBoxWriterTrait = FnMut(SinkParam, SinkWriterParam) -> BoxWriter<()> + Send + 'static;
 
+pub trait BuildBoxCoordinatedWriterTrait =
+    FnMut(SinkParam, SinkWriterParam) -> BoxWriter<Option<SinkMetadata>> + Send + 'static;
+
+pub trait BuildBoxCoordinatorTrait = FnMut(SinkParam) -> BoxCoordinator + Send + 'static;
+
 pub type BuildBoxWriter = Box<dyn BuildBoxWriterTrait>;
+pub type BuildBoxCoordinatedWriter = Box<dyn BuildBoxCoordinatedWriterTrait>;
+pub type BuildBoxCoordinator = Box<dyn BuildBoxCoordinatorTrait>;
 pub const TEST_SINK_NAME: &str = "test";
 
 #[derive(Debug)]
@@ -45,7 +57,7 @@ impl TryFrom<SinkParam> for TestSink {
 
 impl Sink for TestSink {
     type Coordinator = BoxCoordinator;
-    type LogSinker = LogSinkerOf<BoxWriter<()>>;
+    type LogSinker = TestSinkLogSinker;
 
     const SINK_NAME: &'static str = "test";
 
@@ -58,18 +70,64 @@ impl Sink for TestSink {
         writer_param: SinkWriterParam,
     ) -> crate::sink::Result<Self::LogSinker> {
         let metrics = SinkWriterMetrics::new(&writer_param);
-        Ok(build_box_writer(self.param.clone(), writer_param).into_log_sinker(metrics))
+        if registry_is_coordinated() {
+            let client = writer_param
+                .meta_client
+                .clone()
+                .expect("should have meta client")
+                .sink_coordinate_client()
+                .await;
+            let vnode_bitmap = writer_param.vnode_bitmap.clone().ok_or_else(|| {
+                SinkError::Coordinator(anyhow!(
+                    "sink needs coordination should not have singleton input"
+                ))
+            })?;
+            let inner = build_box_coordinated_writer(self.param.clone(), writer_param);
+            let writer =
+                CoordinatedSinkWriter::new(client, self.param.clone(), vnode_bitmap, inner).await?;
+            Ok(TestSinkLogSinker::Coordinated(
+                writer.into_log_sinker(metrics),
+            ))
+        } else {
+            Ok(TestSinkLogSinker::Simple(
+                build_box_writer(self.param.clone(), writer_param).into_log_sinker(metrics),
+            ))
+        }
+    }
+
+    async fn new_coordinator(&self) -> crate::sink::Result<Self::Coordinator> {
+        Ok(build_box_coordinator(self.param.clone()))
+    }
+}
+
+pub enum TestSinkLogSinker {
+    Simple(LogSinkerOf<BoxWriter<()>>),
+    Coordinated(LogSinkerOf<CoordinatedSinkWriter<BoxWriter<Option<SinkMetadata>>>>),
+}
+
+#[async_trait]
+impl LogSinker for TestSinkLogSinker {
+    async fn consume_log_and_sink(
+        self,
+        log_reader: &mut impl SinkLogReader,
+    ) -> crate::sink::Result<!> {
+        match self {
+            TestSinkLogSinker::Simple(inner) => inner.consume_log_and_sink(log_reader).await,
+            TestSinkLogSinker::Coordinated(inner) => inner.consume_log_and_sink(log_reader).await,
+        }
     }
 }
 
 struct TestSinkRegistry {
     build_box_writer: Arc<Mutex<Option<BuildBoxWriter>>>,
+    build_coordinated: Arc<Mutex<Option<(BuildBoxCoordinatedWriter, BuildBoxCoordinator)>>>,
 }
 
 impl TestSinkRegistry {
     fn new() -> Self {
         TestSinkRegistry {
             build_box_writer: Arc::new(Mutex::new(None)),
+            build_coordinated: Arc::new(Mutex::new(None)),
         }
     }
 }
@@ -83,11 +141,20 @@ pub struct TestSinkRegistryGuard;
 
 impl Drop for TestSinkRegistryGuard {
     fn drop(&mut self) {
-        assert!(get_registry().build_box_writer.lock().take().is_some());
+        assert!(
+            get_registry().build_box_writer.lock().take().is_some()
+                || get_registry().build_coordinated.lock().take().is_some()
+        );
     }
 }
 
+fn assert_registry_empty() {
+    assert!(get_registry().build_box_writer.lock().is_none());
+    assert!(get_registry().build_coordinated.lock().is_none());
+}
+
 pub fn registry_build_sink(build_box_writer: impl BuildBoxWriterTrait) -> TestSinkRegistryGuard {
+    assert_registry_empty();
     assert!(get_registry()
         .build_box_writer
         .lock()
@@ -96,6 +163,29 @@ pub fn registry_build_sink(build_box_writer: impl BuildBoxWriterTrait) -> TestSi
     TestSinkRegistryGuard
 }
 
+/// Register a sink that writes through [`CoordinatedSinkWriter`]: each writer returns its
+/// metadata on checkpoint barriers, and the coordinator built by `build_box_coordinator`
+/// commits the collected metadata on the meta node.
+pub fn registry_build_coordinated_sink(
+    build_box_coordinated_writer: impl BuildBoxCoordinatedWriterTrait,
+    build_box_coordinator: impl BuildBoxCoordinatorTrait,
+) -> TestSinkRegistryGuard {
+    assert_registry_empty();
+    assert!(get_registry()
+        .build_coordinated
+        .lock()
+        .replace((
+            Box::new(build_box_coordinated_writer),
+            Box::new(build_box_coordinator),
+        ))
+        .is_none());
+    TestSinkRegistryGuard
+}
+
+fn registry_is_coordinated() -> bool {
+    get_registry().build_coordinated.lock().is_some()
+}
+
 pub fn build_box_writer(param: SinkParam, writer_param: SinkWriterParam) -> BoxWriter<()> {
     (get_registry()
         .build_box_writer
@@ -103,3 +193,24 @@ pub fn build_box_writer(param: SinkParam, writer_param: SinkWriterParam) -> BoxW
         .as_mut()
         .expect("should not be empty"))(param, writer_param)
 }
+
+pub fn build_box_coordinated_writer(
+    param: SinkParam,
+    writer_param: SinkWriterParam,
+) -> BoxWriter<Option<SinkMetadata>> {
+    (get_registry()
+        .build_coordinated
+        .lock()
+        .as_mut()
+        .expect("should not be empty")
+        .0)(param, writer_param)
+}
+
+pub fn build_box_coordinator(param: SinkParam) -> BoxCoordinator {
+    (get_registry()
+        .build_coordinated
+        .lock()
+        .as_mut()
+        .expect("should not be empty")
+        .1)(param)
+}
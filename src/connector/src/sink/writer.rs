@@ -29,7 +29,9 @@ use crate::sink::formatter::SinkFormatter;
 use crate::sink::log_store::{
     DeliveryFutureManager, DeliveryFutureManagerAddFuture, LogStoreReadItem, TruncateOffset,
 };
-use crate::sink::{LogSinker, Result, SinkError, SinkLogReader, SinkWriterMetrics};
+use crate::sink::{
+    LogSinker, Result, SinkConsistency, SinkError, SinkLogReader, SinkWriterMetrics,
+};
 
 #[async_trait]
 pub trait SinkWriter: Send + 'static {
@@ -113,6 +115,7 @@ pub trait FormattedSink {
 pub struct LogSinkerOf<W> {
     writer: W,
     sink_writer_metrics: SinkWriterMetrics,
+    consistency: SinkConsistency,
 }
 
 impl<W> LogSinkerOf<W> {
@@ -120,6 +123,7 @@ impl<W> LogSinkerOf<W> {
         LogSinkerOf {
             writer,
             sink_writer_metrics,
+            consistency: SinkConsistency::default(),
         }
     }
 }
@@ -129,6 +133,7 @@ impl<W: SinkWriter<CommitMetadata = ()>> LogSinker for LogSinkerOf<W> {
     async fn consume_log_and_sink(self, log_reader: &mut impl SinkLogReader) -> Result<!> {
         let mut sink_writer = self.writer;
         let metrics = self.sink_writer_metrics;
+        let consistency = self.consistency;
         #[derive(Debug)]
         enum LogConsumerState {
             /// Mark that the log consumer is not initialized yet
@@ -193,16 +198,23 @@ impl<W: SinkWriter<CommitMetadata = ()>> LogSinker for LogSinkerOf<W> {
                         LogConsumerState::EpochBegun { curr_epoch } => curr_epoch,
                         _ => unreachable!("epoch must have begun before handling barrier"),
                     };
-                    if is_checkpoint {
+                    // Under at-least-once consistency, every barrier is a commit point: we
+                    // do not wait for checkpoint alignment, at the cost of duplicates when
+                    // recovery replays the epochs since the last checkpoint. Truncation
+                    // remains checkpoint-aligned so that the log store can still replay.
+                    let commit = is_checkpoint || consistency == SinkConsistency::AtLeastOnce;
+                    if commit {
                         let start_time = Instant::now();
                         sink_writer.barrier(true).await?;
                         metrics
                             .sink_commit_duration
                             .observe(start_time.elapsed().as_millis() as f64);
-                        log_reader.truncate(TruncateOffset::Barrier { epoch })?;
                     } else {
                         sink_writer.barrier(false).await?;
                     }
+                    if is_checkpoint {
+                        log_reader.truncate(TruncateOffset::Barrier { epoch })?;
+                    }
                     state = LogConsumerState::BarrierReceived { prev_epoch }
                 }
                 LogStoreReadItem::UpdateVnodeBitmap(vnode_bitmap) => {
@@ -222,6 +234,19 @@ where
         LogSinkerOf {
             writer: self,
             sink_writer_metrics,
+            consistency: SinkConsistency::default(),
+        }
+    }
+
+    pub fn into_log_sinker_with_consistency(
+        self,
+        sink_writer_metrics: SinkWriterMetrics,
+        consistency: SinkConsistency,
+    ) -> LogSinkerOf<Self> {
+        LogSinkerOf {
+            writer: self,
+            sink_writer_metrics,
+            consistency,
         }
     }
 }
@@ -198,6 +198,24 @@ pub struct SourceContext {
     // source parser put schema change event into this channel
     pub schema_change_tx:
         Option<mpsc::Sender<(SchemaChangeEnvelope, tokio::sync::oneshot::Sender<()>)>>,
+    // source parser put cdc lag alerts into this channel
+    pub cdc_lag_alert: Option<CdcLagAlertSender>,
+}
+
+/// An alert emitted by the CDC parser when the upstream replication lag of a table exceeds
+/// the configured threshold. Forwarded to the meta node as an event log.
+#[derive(Debug, Clone)]
+pub struct CdcLagAlert {
+    pub table_name: String,
+    pub lag_ms: u64,
+    pub threshold_ms: u64,
+}
+
+/// Threshold and channel for reporting [`CdcLagAlert`]s.
+#[derive(Debug, Clone)]
+pub struct CdcLagAlertSender {
+    pub threshold_ms: u64,
+    pub tx: mpsc::Sender<CdcLagAlert>,
 }
 
 impl SourceContext {
@@ -212,6 +230,7 @@ impl SourceContext {
         schema_change_channel: Option<
             mpsc::Sender<(SchemaChangeEnvelope, tokio::sync::oneshot::Sender<()>)>,
         >,
+        cdc_lag_alert: Option<CdcLagAlertSender>,
     ) -> Self {
         Self {
             actor_id,
@@ -222,6 +241,7 @@ impl SourceContext {
             source_ctrl_opts,
             connector_props,
             schema_change_tx: schema_change_channel,
+            cdc_lag_alert,
         }
     }
 
@@ -240,6 +260,7 @@ impl SourceContext {
             },
             ConnectorProperties::default(),
             None,
+            None,
         )
     }
 }
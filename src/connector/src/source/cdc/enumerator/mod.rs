@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
@@ -24,24 +26,127 @@ use risingwave_common::util::addr::HostAddr;
 use risingwave_jni_core::call_static_method;
 use risingwave_jni_core::jvm_runtime::{execute_with_jni_env, JVM};
 use risingwave_pb::connector_service::{SourceType, ValidateSourceRequest, ValidateSourceResponse};
+use thiserror_ext::AsReport;
+use tokio::time::Instant;
 
+use crate::connector_common::{create_pg_client, SslMode};
 use crate::error::ConnectorResult;
 use crate::source::cdc::{
-    table_schema_exclude_additional_columns, CdcProperties, CdcSourceTypeTrait, Citus,
-    DebeziumCdcSplit, Mongodb, Mysql, Postgres, SqlServer,
+    table_schema_exclude_additional_columns, CdcProperties, CdcSourceType, CdcSourceTypeTrait,
+    Citus, DebeziumCdcSplit, Mongodb, Mysql, Postgres, SqlServer,
 };
 use crate::source::{SourceEnumeratorContextRef, SplitEnumerator};
 
 pub const DATABASE_SERVERS_KEY: &str = "database.servers";
+pub const PARTITION_DISCOVERY_ENABLE_KEY: &str = "partition.discovery.enable";
+
+/// Minimal interval between two upstream partition discovery runs, to avoid querying the
+/// upstream catalog on every enumerator tick.
+const PARTITION_DISCOVERY_INTERVAL: Duration = Duration::from_secs(60);
 
 #[derive(Debug)]
 pub struct DebeziumSplitEnumerator<T: CdcSourceTypeTrait> {
     /// The `source_id` in the catalog
     source_id: u32,
     worker_node_addrs: Vec<HostAddr>,
+    /// Periodically extends the publication with newly created partitions of the upstream
+    /// partitioned tables. Postgres only.
+    partition_discovery: Option<PostgresPartitionDiscovery>,
     _phantom: PhantomData<T>,
 }
 
+/// Discovers newly created partitions of the published partitioned tables and adds them to
+/// the publication, so that their changes start to be captured. Note that changes are
+/// captured from the point a partition is added to the publication onwards.
+#[derive(Debug)]
+pub struct PostgresPartitionDiscovery {
+    host: String,
+    port: String,
+    username: String,
+    password: String,
+    database: String,
+    publication: String,
+    ssl_mode: SslMode,
+    ssl_root_cert: Option<String>,
+    next_check: Instant,
+}
+
+impl PostgresPartitionDiscovery {
+    fn from_properties(properties: &BTreeMap<String, String>) -> ConnectorResult<Option<Self>> {
+        if properties.get(PARTITION_DISCOVERY_ENABLE_KEY).map(String::as_str) != Some("true") {
+            return Ok(None);
+        }
+        let get = |key: &str| {
+            properties.get(key).cloned().ok_or_else(|| {
+                anyhow!("missing config: {} for postgres cdc partition discovery", key)
+            })
+        };
+        let ssl_mode = match properties.get("ssl.mode") {
+            Some(v) => serde_json::from_value(serde_json::Value::String(v.clone()))
+                .with_context(|| format!("invalid ssl.mode: {v}"))?,
+            None => SslMode::Disabled,
+        };
+        Ok(Some(Self {
+            host: get("hostname")?,
+            port: get("port")?,
+            username: get("username")?,
+            password: get("password")?,
+            database: get("database.name")?,
+            publication: get("publication.name")?,
+            ssl_mode,
+            ssl_root_cert: properties.get("ssl.root.cert").cloned(),
+            next_check: Instant::now(),
+        }))
+    }
+
+    /// Adds partitions of the published tables that are not yet part of the publication.
+    async fn discover_new_partitions(&mut self) -> ConnectorResult<()> {
+        if Instant::now() < self.next_check {
+            return Ok(());
+        }
+        self.next_check = Instant::now() + PARTITION_DISCOVERY_INTERVAL;
+
+        let client = create_pg_client(
+            &self.username,
+            &self.password,
+            &self.host,
+            &self.port,
+            &self.database,
+            &self.ssl_mode,
+            &self.ssl_root_cert,
+        )
+        .await?;
+
+        let rows = client
+            .query(
+                "SELECT n.nspname, c.relname                  FROM pg_inherits i                  JOIN pg_class c ON c.oid = i.inhrelid                  JOIN pg_namespace n ON n.oid = c.relnamespace                  JOIN pg_publication_rel pr ON pr.prrelid = i.inhparent                  JOIN pg_publication p ON p.oid = pr.prpubid                  WHERE p.pubname = $1                  AND NOT EXISTS (                     SELECT 1 FROM pg_publication_rel pr2                     WHERE pr2.prpubid = p.oid AND pr2.prrelid = c.oid                  )",
+                &[&self.publication],
+            )
+            .await?;
+
+        for row in rows {
+            let schema: String = row.get(0);
+            let table: String = row.get(1);
+            tracing::info!(
+                publication = self.publication,
+                schema,
+                table,
+                "adding newly created partition to publication"
+            );
+            client
+                .execute(
+                    &format!(
+                        "ALTER PUBLICATION \"{}\" ADD TABLE \"{}\".\"{}\"",
+                        self.publication, schema, table
+                    ),
+                    &[],
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl<T: CdcSourceTypeTrait> SplitEnumerator for DebeziumSplitEnumerator<T>
 where
@@ -70,6 +175,11 @@ where
             SourceType::from(T::source_type())
         );
 
+        let partition_discovery = match T::source_type() {
+            CdcSourceType::Postgres => PostgresPartitionDiscovery::from_properties(&props.properties)?,
+            _ => None,
+        };
+
         let jvm = JVM.get_or_init()?;
         let source_id = context.info.source_id;
         tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
@@ -116,11 +226,21 @@ where
         Ok(Self {
             source_id,
             worker_node_addrs: server_addrs,
+            partition_discovery,
             _phantom: PhantomData,
         })
     }
 
     async fn list_splits(&mut self) -> ConnectorResult<Vec<DebeziumCdcSplit<T>>> {
+        if let Some(discovery) = &mut self.partition_discovery {
+            // Discovery failures must not break split assignment.
+            if let Err(e) = discovery.discover_new_partitions().await {
+                tracing::warn!(
+                    error = %e.as_report(),
+                    "postgres cdc partition discovery failed"
+                );
+            }
+        }
         Ok(self.list_cdc_splits())
     }
 }
@@ -120,6 +120,7 @@ impl ExternalTableReader for MockExternalTableReader {
             Ok(CdcOffset::MySql(MySqlOffset {
                 filename: "1.binlog".to_owned(),
                 position: u64::MAX,
+                gtid_set: None,
             }))
         }
     }
@@ -183,6 +183,8 @@ pub struct DebeziumSourceOffset {
     // mysql binlog offset
     pub file: Option<String>,
     pub pos: Option<u64>,
+    // mysql executed gtid set
+    pub gtids: Option<String>,
 
     // postgres offset
     pub lsn: Option<u64>,
@@ -246,6 +248,23 @@ pub struct ExternalTableConfig {
     /// Only "true" means using SSL. All other values are treated as "false".
     #[serde(rename = "database.encrypt", default = "Default::default")]
     pub encrypt: String,
+
+    /// Comma-separated list of `host:port` candidates in the same replication topology
+    /// (MySQL only). When the primary host is unreachable, the connection fails over to
+    /// the first healthy candidate.
+    #[serde(rename = "candidate.hosts", default)]
+    pub candidate_hosts: Option<String>,
+
+    /// Session timezone used for snapshot reads, so that `TIMESTAMP` columns mapped to
+    /// `timestamptz` are interpreted consistently with the changelog produced by Debezium.
+    /// Accepts an offset (`+00:00`) or a named timezone. Defaults to UTC.
+    #[serde(rename = "timezone", default = "timezone_default")]
+    #[serde(alias = "debezium.database.connectionTimeZone")]
+    pub timezone: String,
+}
+
+fn timezone_default() -> String {
+    "+00:00".to_owned()
 }
 
 fn postgres_ssl_mode_default() -> SslMode {
@@ -47,11 +47,20 @@ use crate::source::cdc::external::{
 pub struct MySqlOffset {
     pub filename: String,
     pub position: u64,
+    /// The executed GTID set of the upstream server. It identifies the same stream
+    /// position across all replicas of a replication topology, so the source can resume
+    /// from a different host after a failover.
+    #[serde(default)]
+    pub gtid_set: Option<String>,
 }
 
 impl MySqlOffset {
     pub fn new(filename: String, position: u64) -> Self {
-        Self { filename, position }
+        Self {
+            filename,
+            position,
+            gtid_set: None,
+        }
     }
 }
 
@@ -69,6 +78,7 @@ impl MySqlOffset {
                 .source_offset
                 .pos
                 .context("binlog position not found in offset")?,
+            gtid_set: dbz_offset.source_offset.gtids,
         })
     }
 }
@@ -352,6 +362,7 @@ pub fn mysql_type_to_rw_type(col_type: &ColumnType) -> ConnectorResult<DataType>
 pub struct MySqlExternalTableReader {
     rw_schema: Schema,
     field_names: String,
+    timezone: String,
     // use mutex to provide shared mutable access to the connection
     conn: tokio::sync::Mutex<mysql_async::Conn>,
 }
@@ -371,6 +382,9 @@ impl ExternalTableReader for MySqlExternalTableReader {
         Ok(CdcOffset::MySql(MySqlOffset {
             filename: row.take("File").unwrap(),
             position: row.take("Position").unwrap(),
+            gtid_set: row
+                .take::<String, _>("Executed_Gtid_Set")
+                .filter(|s| !s.is_empty()),
         }))
     }
 
@@ -387,25 +401,36 @@ impl ExternalTableReader for MySqlExternalTableReader {
 
 impl MySqlExternalTableReader {
     pub async fn new(config: ExternalTableConfig, rw_schema: Schema) -> ConnectorResult<Self> {
-        let mut opts_builder = mysql_async::OptsBuilder::default()
-            .user(Some(config.username))
-            .pass(Some(config.password))
-            .ip_or_hostname(config.host)
-            .tcp_port(config.port.parse::<u16>().unwrap())
-            .db_name(Some(config.database));
-
-        opts_builder = match config.ssl_mode {
-            SslMode::Disabled | SslMode::Preferred => opts_builder.ssl_opts(None),
-            // verify-ca and verify-full are same as required for mysql now
-            SslMode::Required | SslMode::VerifyCa | SslMode::VerifyFull => {
-                let ssl_without_verify = mysql_async::SslOpts::default()
-                    .with_danger_accept_invalid_certs(true)
-                    .with_danger_skip_domain_validation(true);
-                opts_builder.ssl_opts(Some(ssl_without_verify))
+        // The primary host always comes first, candidates are only tried on failure.
+        let mut candidates = vec![(config.host.clone(), config.port.clone())];
+        if let Some(hosts) = &config.candidate_hosts {
+            for addr in hosts.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+                let (host, port) = addr
+                    .split_once(':')
+                    .with_context(|| format!("invalid candidate host address: {}", addr))?;
+                candidates.push((host.to_owned(), port.to_owned()));
             }
-        };
+        }
 
-        let conn = mysql_async::Conn::new(mysql_async::Opts::from(opts_builder)).await?;
+        let mut conn = None;
+        for (host, port) in &candidates {
+            match Self::connect(&config, host, port).await {
+                Ok(c) => {
+                    conn = Some(c);
+                    break;
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e.as_report(),
+                        host,
+                        port,
+                        "failed to connect to mysql host, trying next candidate"
+                    );
+                }
+            }
+        }
+        let conn =
+            conn.ok_or_else(|| anyhow!("failed to connect to any of the mysql hosts"))?;
 
         let field_names = rw_schema
             .fields
@@ -417,10 +442,40 @@ impl MySqlExternalTableReader {
         Ok(Self {
             rw_schema,
             field_names,
+            timezone: config.timezone,
             conn: tokio::sync::Mutex::new(conn),
         })
     }
 
+    async fn connect(
+        config: &ExternalTableConfig,
+        host: &str,
+        port: &str,
+    ) -> ConnectorResult<mysql_async::Conn> {
+        let mut opts_builder = mysql_async::OptsBuilder::default()
+            .user(Some(config.username.clone()))
+            .pass(Some(config.password.clone()))
+            .ip_or_hostname(host.to_owned())
+            .tcp_port(port.parse::<u16>().unwrap())
+            .db_name(Some(config.database.clone()));
+
+        opts_builder = match config.ssl_mode {
+            SslMode::Disabled | SslMode::Preferred => opts_builder.ssl_opts(None),
+            // verify-ca and verify-full are same as required for mysql now
+            SslMode::Required | SslMode::VerifyCa | SslMode::VerifyFull => {
+                let ssl_without_verify = mysql_async::SslOpts::default()
+                    .with_danger_accept_invalid_certs(true)
+                    .with_danger_skip_domain_validation(true);
+                opts_builder.ssl_opts(Some(ssl_without_verify))
+            }
+        };
+
+        let mut conn = mysql_async::Conn::new(mysql_async::Opts::from(opts_builder)).await?;
+        // health check before handing out the connection
+        conn.query_drop("SELECT 1").await?;
+        Ok(conn)
+    }
+
     pub fn get_normalized_table_name(table_name: &SchemaTableName) -> String {
         // schema name is the database name in mysql
         format!("`{}`.`{}`", table_name.schema_name, table_name.table_name)
@@ -466,8 +521,10 @@ impl MySqlExternalTableReader {
 
         let mut conn = self.conn.lock().await;
 
-        // Set session timezone to UTC
-        conn.exec_drop("SET time_zone = \"+00:00\"", ()).await?;
+        // Set the session timezone so that naive datetime values are interpreted
+        // consistently with the configured source timezone (UTC by default).
+        conn.exec_drop("SET time_zone = ?", (self.timezone.as_str(),))
+            .await?;
 
         if start_pk_row.is_none() {
             let rs_stream = sql.stream::<mysql_async::Row, _>(&mut *conn).await?;
@@ -619,6 +676,8 @@ mod tests {
             ssl_mode: Default::default(),
             ssl_root_cert: None,
             encrypt: "false".to_owned(),
+            candidate_hosts: None,
+            timezone: "+00:00".to_owned(),
         };
 
         let table = MySqlExternalTable::connect(config).await.unwrap();
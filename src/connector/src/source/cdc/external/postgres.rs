@@ -25,7 +25,7 @@ use risingwave_common::util::iter_util::ZipEqFast;
 use serde_derive::{Deserialize, Serialize};
 use tokio_postgres::types::PgLsn;
 
-use crate::connector_common::create_pg_client;
+use crate::connector_common::{create_pg_client_shared, SharedPgClient};
 use crate::error::{ConnectorError, ConnectorResult};
 use crate::parser::postgres_row_to_owned_row;
 use crate::parser::scalar_adapter::ScalarAdapter;
@@ -71,7 +71,9 @@ pub struct PostgresExternalTableReader {
     rw_schema: Schema,
     field_names: String,
     pk_indices: Vec<usize>,
-    client: tokio::sync::Mutex<tokio_postgres::Client>,
+    /// Shared with other readers of the same upstream, so that many tables multiplex one
+    /// connection instead of each holding their own.
+    client: SharedPgClient,
 }
 
 impl ExternalTableReader for PostgresExternalTableReader {
@@ -118,7 +120,7 @@ impl PostgresExternalTableReader {
             "create postgres external table reader"
         );
 
-        let client = create_pg_client(
+        let client = create_pg_client_shared(
             &config.username,
             &config.password,
             &config.host,
@@ -139,7 +141,7 @@ impl PostgresExternalTableReader {
             rw_schema,
             field_names,
             pk_indices,
-            client: tokio::sync::Mutex::new(client),
+            client,
         })
     }
 
@@ -286,6 +288,8 @@ mod tests {
             ssl_mode: Default::default(),
             ssl_root_cert: None,
             encrypt: "false".to_owned(),
+            candidate_hosts: None,
+            timezone: "+00:00".to_owned(),
         };
 
         let table = PostgresExternalTable::connect(
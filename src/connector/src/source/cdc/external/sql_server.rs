@@ -186,7 +186,7 @@ impl SqlServerExternalTable {
 fn type_to_rw_type(col_type: &str, col_name: &str) -> ConnectorResult<DataType> {
     let dtype = match col_type.to_lowercase().as_str() {
         "bit" => DataType::Boolean,
-        "binary" | "varbinary" => DataType::Bytea,
+        "binary" | "varbinary" | "image" => DataType::Bytea,
         "tinyint" | "smallint" => DataType::Int16,
         "integer" | "int" => DataType::Int32,
         "bigint" => DataType::Int64,
@@ -355,7 +355,9 @@ impl SqlServerExternalTableReader {
 
         let mut client = self.client.lock().await;
 
-        // FIXME(kexiang): Set session timezone to UTC
+        // SQL Server has no session timezone to set: `datetimeoffset` values carry their own
+        // offset and are normalized to UTC when converted to `timestamptz`, while
+        // `datetime`/`datetime2` are naive and mapped to `timestamp` as-is.
         if let Some(pk_row) = start_pk_row {
             let params: Vec<Option<ScalarImpl>> = pk_row.into_iter().collect();
             for param in params {
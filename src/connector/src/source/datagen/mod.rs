@@ -53,6 +53,7 @@ pub struct DatagenProperties {
     /// 'fields.v2.kind'='random',
     /// datagen will create v1 by self-incrementing from 1 to 1000
     /// datagen will create v2 by randomly generating from default_min to default_max
+    /// 'fields.v2.skew'='4' biases random values toward the minimum (1 = uniform)
     #[serde(flatten)]
     pub fields: HashMap<String, String>,
 }
@@ -331,10 +331,18 @@ fn generator_from_data_type(
             } else {
                 let min_key = format!("fields.{}.min", name);
                 let max_key = format!("fields.{}.max", name);
+                let skew_key = format!("fields.{}.skew", name);
                 let min_value = fields_option_map.get(&min_key).map(|s| s.to_string());
                 let max_value = fields_option_map.get(&max_key).map(|s| s.to_string());
-                FieldGeneratorImpl::with_number_random(data_type, min_value, max_value, random_seed)
-                    .map_err(Into::into)
+                let skew_value = fields_option_map.get(&skew_key).map(|s| s.to_string());
+                FieldGeneratorImpl::with_number_random(
+                    data_type,
+                    min_value,
+                    max_value,
+                    skew_value,
+                    random_seed,
+                )
+                .map_err(Into::into)
             }
         }
     }
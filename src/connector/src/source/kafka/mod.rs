@@ -38,6 +38,7 @@ use crate::source::SourceProperties;
 pub const KAFKA_CONNECTOR: &str = "kafka";
 pub const KAFKA_PROPS_BROKER_KEY: &str = "properties.bootstrap.server";
 pub const KAFKA_PROPS_BROKER_KEY_ALIAS: &str = "kafka.brokers";
+pub const KAFKA_PROPS_HEADER_FILTER_PREFIX: &str = "properties.filter.header.";
 pub const PRIVATELINK_CONNECTION: &str = "privatelink";
 
 /// Properties for the rdkafka library. Leave a field as `None` to use the default value.
@@ -172,7 +173,13 @@ impl SourceProperties for KafkaProperties {
 
 impl crate::source::UnknownFields for KafkaProperties {
     fn unknown_fields(&self) -> HashMap<String, String> {
-        self.unknown_fields.clone()
+        // Header filter rules carry user-defined header names in the option key, so they
+        // cannot be modeled as regular fields and are recognized here instead.
+        self.unknown_fields
+            .iter()
+            .filter(|(k, _)| !k.starts_with(KAFKA_PROPS_HEADER_FILTER_PREFIX))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
     }
 }
 
@@ -181,6 +188,23 @@ impl KafkaProperties {
         self.rdkafka_properties_common.set_client(c);
         self.rdkafka_properties_consumer.set_client(c);
     }
+
+    /// Header-based filter rules parsed from options of the form
+    /// `properties.filter.header.<name> = <value>`. A message is read only when, for every
+    /// rule, it carries a header `<name>` whose value equals `<value>`; other messages are
+    /// skipped by the reader before parsing.
+    pub fn header_filters(&self) -> Vec<(String, String)> {
+        let mut filters: Vec<_> = self
+            .unknown_fields
+            .iter()
+            .filter_map(|(k, v)| {
+                k.strip_prefix(KAFKA_PROPS_HEADER_FILTER_PREFIX)
+                    .map(|name| (name.to_owned(), v.clone()))
+            })
+            .collect();
+        filters.sort();
+        filters
+    }
 }
 
 const KAFKA_ISOLATION_LEVEL: &str = "read_committed";
@@ -120,13 +120,15 @@ pub fn insert_privatelink_broker_rewrite_map(
     let mut broker_rewrite_map = HashMap::new();
     let servers = get_property_required(with_options, kafka_props_broker_key(with_options))?;
     let broker_addrs = servers.split(',').collect_vec();
-    let link_target_value = get_property_required(with_options, PRIVATE_LINK_TARGETS_KEY)?;
-    let link_targets: Vec<AwsPrivateLinkItem> =
-        serde_json::from_str(link_target_value.as_str()).map_err(|e| anyhow!(e))?;
     // remove the private link targets from WITH options, as they are useless after we constructed the rewrite mapping
-    with_options.remove(PRIVATE_LINK_TARGETS_KEY);
+    let link_targets: Option<Vec<AwsPrivateLinkItem>> = with_options
+        .remove(PRIVATE_LINK_TARGETS_KEY)
+        .map(|v| serde_json::from_str(v.to_lowercase().as_str()).map_err(|e| anyhow!(e)))
+        .transpose()?;
 
-    if broker_addrs.len() != link_targets.len() {
+    if let Some(link_targets) = &link_targets
+        && broker_addrs.len() != link_targets.len()
+    {
         bail!(
             "The number of broker addrs {} does not match the number of private link targets {}",
             broker_addrs.len(),
@@ -135,15 +137,34 @@ pub fn insert_privatelink_broker_rewrite_map(
     }
 
     if let Some(endpoint) = privatelink_endpoint {
-        for (link, broker) in link_targets.iter().zip_eq_fast(broker_addrs.into_iter()) {
-            // rewrite the broker address to endpoint:port
-            broker_rewrite_map.insert(broker.to_owned(), format!("{}:{}", &endpoint, link.port));
+        match link_targets {
+            // AWS PrivateLink exposes each broker on a dedicated endpoint port, given
+            // by the per-broker `privatelink.targets` entries.
+            Some(link_targets) => {
+                for (link, broker) in link_targets.iter().zip_eq_fast(broker_addrs.into_iter()) {
+                    // rewrite the broker address to endpoint:port
+                    broker_rewrite_map
+                        .insert(broker.to_owned(), format!("{}:{}", &endpoint, link.port));
+                }
+            }
+            // GCP Private Service Connect and Azure Private Link endpoints forward the
+            // original broker ports, so no `privatelink.targets` is needed: rewrite each
+            // broker to the endpoint while keeping its own port.
+            None => {
+                for broker in broker_addrs {
+                    let port = HostAddr::from_str(broker)?.port;
+                    broker_rewrite_map.insert(broker.to_owned(), format!("{}:{}", &endpoint, port));
+                }
+            }
         }
     } else {
         if svc.is_none() {
             bail!("Privatelink endpoint not found.");
         }
         let svc = svc.unwrap();
+        let link_targets = link_targets.with_context(|| {
+            format!("Required property \"{PRIVATE_LINK_TARGETS_KEY}\" is not provided")
+        })?;
         for (link, broker) in link_targets.iter().zip_eq_fast(broker_addrs.into_iter()) {
             if svc.dns_entries.is_empty() {
                 bail!(
@@ -24,6 +24,7 @@ use futures_async_stream::try_stream;
 use rdkafka::config::RDKafkaLogLevel;
 use rdkafka::consumer::{Consumer, StreamConsumer};
 use rdkafka::error::KafkaError;
+use rdkafka::message::{BorrowedMessage, Headers};
 use rdkafka::{ClientConfig, Message, Offset, TopicPartitionList};
 use risingwave_common::metrics::LabelGuardedIntGauge;
 use risingwave_pb::plan_common::additional_column::ColumnType as AdditionalColumnType;
@@ -47,6 +48,7 @@ pub struct KafkaSplitReader {
     sync_call_timeout: Duration,
     bytes_per_second: usize,
     max_num_messages: usize,
+    header_filters: Vec<(String, String)>,
     parser_config: ParserConfig,
     source_ctx: SourceContextRef,
 }
@@ -67,6 +69,7 @@ impl SplitReader for KafkaSplitReader {
 
         let bootstrap_servers = &properties.connection.brokers;
         let broker_rewrite_map = properties.privatelink_common.broker_rewrite_map.clone();
+        let header_filters = properties.header_filters();
 
         // disable partition eof
         config.set("enable.partition.eof", "false");
@@ -172,6 +175,7 @@ impl SplitReader for KafkaSplitReader {
             bytes_per_second,
             sync_call_timeout: properties.common.sync_call_timeout,
             max_num_messages,
+            header_filters,
             parser_config,
             source_ctx,
         })
@@ -210,6 +214,22 @@ impl SplitReader for KafkaSplitReader {
     }
 }
 
+/// Returns whether the message satisfies all the header filter rules, i.e. carries, for
+/// every rule, a header with the given name whose value equals the given value.
+fn match_header_filters(msg: &BorrowedMessage<'_>, filters: &[(String, String)]) -> bool {
+    if filters.is_empty() {
+        return true;
+    }
+    let Some(headers) = msg.headers() else {
+        return false;
+    };
+    filters.iter().all(|(name, value)| {
+        headers
+            .iter()
+            .any(|h| h.key == name && h.value.is_some_and(|v| v == value.as_bytes()))
+    })
+}
+
 impl KafkaSplitReader {
     #[try_stream(ok = Vec<SourceMessage>, error = crate::error::ConnectorError)]
     async fn into_data_stream(self) {
@@ -286,10 +306,14 @@ impl KafkaSplitReader {
                     Some(payload) => payload.len(),
                 };
                 num_messages += 1;
-                let source_message =
-                    SourceMessage::from_kafka_message(&msg, require_message_header);
-                let split_id = source_message.split_id.clone();
-                res.push(source_message);
+                // Header-based routing: skip irrelevant messages before parsing. Skipped
+                // messages still count towards the rate limits and the stop offset below.
+                if match_header_filters(&msg, &self.header_filters) {
+                    let source_message =
+                        SourceMessage::from_kafka_message(&msg, require_message_header);
+                    res.push(source_message);
+                }
+                let split_id: SplitId = msg.partition().to_string().into();
 
                 if let Entry::Occupied(o) = stop_offsets.entry(split_id) {
                     let stop_offset = *o.get();
@@ -84,11 +84,23 @@ impl SplitEnumerator for KinesisSplitEnumerator {
         }
         Ok(shard_collect
             .into_iter()
-            .map(|x| KinesisSplit {
-                shard_id: x.shard_id().to_owned().into(),
-                // handle start with position in reader part
-                next_offset: KinesisOffset::None,
-                end_offset: KinesisOffset::None,
+            .map(|x| {
+                // After a shard split/merge the parent shard is closed and carries an
+                // ending sequence number; record it so readers know where the shard ends.
+                // The child shards show up in the same listing and are assigned as new
+                // splits, so both halves of a split (or the merged shard) keep being read.
+                let end_offset = match (x.sequence_number_range())
+                    .and_then(|r| r.ending_sequence_number())
+                {
+                    Some(seq) => KinesisOffset::AfterSequenceNumber(seq.to_owned()),
+                    None => KinesisOffset::None,
+                };
+                KinesisSplit {
+                    shard_id: x.shard_id().to_owned().into(),
+                    // handle start with position in reader part
+                    next_offset: KinesisOffset::None,
+                    end_offset,
+                }
             })
             .collect())
     }
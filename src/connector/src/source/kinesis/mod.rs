@@ -31,6 +31,9 @@ use crate::source::SourceProperties;
 
 pub const KINESIS_CONNECTOR: &str = "kinesis";
 
+/// Default name of the stream consumer registered for enhanced fan-out.
+pub const DEFAULT_EFO_CONSUMER_NAME: &str = "rw-efo-consumer";
+
 #[serde_as]
 #[derive(Clone, Debug, Deserialize, WithOptions)]
 pub struct KinesisProperties {
@@ -42,6 +45,18 @@ pub struct KinesisProperties {
     #[serde_as(as = "Option<DisplayFromStr>")]
     pub start_timestamp_millis: Option<i64>,
 
+    /// Whether to consume the stream with enhanced fan-out (EFO), i.e. `SubscribeToShard`
+    /// push delivery with dedicated 2 MB/s per-shard throughput, instead of polling
+    /// `GetRecords`. The stream consumer is registered automatically on startup.
+    #[serde(rename = "enhanced.fanout.enabled")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub enhanced_fanout_enabled: Option<bool>,
+
+    /// Name of the stream consumer registered for enhanced fan-out.
+    /// Defaults to `rw-efo-consumer`.
+    #[serde(rename = "enhanced.fanout.consumer.name")]
+    pub efo_consumer_name: Option<String>,
+
     #[serde(flatten)]
     pub common: KinesisCommon,
 
@@ -19,7 +19,9 @@ use async_trait::async_trait;
 use aws_sdk_kinesis::error::{ProvideErrorMetadata, SdkError};
 use aws_sdk_kinesis::operation::get_records::{GetRecordsError, GetRecordsOutput};
 use aws_sdk_kinesis::primitives::DateTime;
-use aws_sdk_kinesis::types::ShardIteratorType;
+use aws_sdk_kinesis::types::{
+    ConsumerStatus, ShardIteratorType, StartingPosition, SubscribeToShardEventStream,
+};
 use aws_sdk_kinesis::Client as KinesisClient;
 use futures_async_stream::try_stream;
 use risingwave_common::bail;
@@ -29,7 +31,7 @@ use crate::error::ConnectorResult as Result;
 use crate::parser::ParserConfig;
 use crate::source::kinesis::source::message::from_kinesis_record;
 use crate::source::kinesis::split::{KinesisOffset, KinesisSplit};
-use crate::source::kinesis::KinesisProperties;
+use crate::source::kinesis::{KinesisProperties, DEFAULT_EFO_CONSUMER_NAME};
 use crate::source::{
     into_chunk_stream, BoxSourceChunkStream, Column, SourceContextRef, SourceMessage, SplitId,
     SplitMetaData, SplitReader,
@@ -45,6 +47,9 @@ pub struct KinesisSplitReader {
     next_offset: KinesisOffset,
     #[expect(dead_code)]
     end_offset: KinesisOffset,
+    /// ARN of the enhanced fan-out consumer to read through. `None` means the polling
+    /// `GetRecords` path is used.
+    consumer_arn: Option<String>,
 
     split_id: SplitId,
     parser_config: ParserConfig,
@@ -100,6 +105,14 @@ impl SplitReader for KinesisSplitReader {
         let stream_name = properties.common.stream_name.clone();
         let client = properties.common.build_client().await?;
 
+        let consumer_arn = if properties.enhanced_fanout_enabled.unwrap_or(false) {
+            let consumer_name = (properties.efo_consumer_name.clone())
+                .unwrap_or_else(|| DEFAULT_EFO_CONSUMER_NAME.to_owned());
+            Some(resolve_efo_consumer(&client, &stream_name, &consumer_name).await?)
+        } else {
+            None
+        };
+
         let split_id = split.id();
         Ok(Self {
             client,
@@ -109,6 +122,7 @@ impl SplitReader for KinesisSplitReader {
             latest_offset: None,
             next_offset,
             end_offset: split.end_offset,
+            consumer_arn,
             split_id,
             parser_config,
             source_ctx,
@@ -118,7 +132,73 @@ impl SplitReader for KinesisSplitReader {
     fn into_stream(self) -> BoxSourceChunkStream {
         let parser_config = self.parser_config.clone();
         let source_context = self.source_ctx.clone();
-        into_chunk_stream(self.into_data_stream(), parser_config, source_context)
+        if self.consumer_arn.is_some() {
+            into_chunk_stream(self.into_efo_data_stream(), parser_config, source_context)
+        } else {
+            into_chunk_stream(self.into_data_stream(), parser_config, source_context)
+        }
+    }
+}
+
+/// Registers the enhanced fan-out consumer `consumer_name` on the stream if it does not
+/// exist yet, waits until it becomes `ACTIVE` and returns its ARN.
+async fn resolve_efo_consumer(
+    client: &KinesisClient,
+    stream_name: &str,
+    consumer_name: &str,
+) -> Result<String> {
+    let stream_arn = client
+        .describe_stream_summary()
+        .stream_name(stream_name)
+        .send()
+        .await
+        .context("failed to describe kinesis stream")?
+        .stream_description_summary
+        .context("stream description summary is none")?
+        .stream_arn;
+
+    match client
+        .register_stream_consumer()
+        .stream_arn(&stream_arn)
+        .consumer_name(consumer_name)
+        .send()
+        .await
+    {
+        Ok(_) => {}
+        Err(e)
+            if e.as_service_error()
+                .is_some_and(|e| e.is_resource_in_use_exception()) =>
+        {
+            // Already registered, possibly by another split reader.
+        }
+        Err(e) => {
+            return Err(anyhow!(e)
+                .context("failed to register kinesis stream consumer")
+                .into());
+        }
+    }
+
+    loop {
+        let consumer = client
+            .describe_stream_consumer()
+            .stream_arn(&stream_arn)
+            .consumer_name(consumer_name)
+            .send()
+            .await
+            .context("failed to describe kinesis stream consumer")?
+            .consumer_description
+            .context("consumer description is none")?;
+        match consumer.consumer_status {
+            ConsumerStatus::Active => return Ok(consumer.consumer_arn),
+            ConsumerStatus::Creating => {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            status => bail!(
+                "kinesis consumer {:?} is in unexpected status {:?}",
+                consumer_name,
+                status
+            ),
+        }
     }
 }
 
@@ -240,6 +320,120 @@ impl KinesisSplitReader {
             }
         }
     }
+
+    /// The enhanced fan-out path. Instead of polling `GetRecords`, subscribes to the shard
+    /// through the registered stream consumer and receives pushed events. Each subscription
+    /// lasts at most 5 minutes, after which the event stream ends and we resubscribe from
+    /// the latest seen sequence number.
+    #[try_stream(ok = Vec < SourceMessage >, error = crate::error::ConnectorError)]
+    async fn into_efo_data_stream(mut self) {
+        let consumer_arn = self.consumer_arn.clone().unwrap();
+        'resubscribe: loop {
+            let mut event_stream = match self
+                .client
+                .subscribe_to_shard()
+                .consumer_arn(&consumer_arn)
+                .shard_id(self.shard_id.as_ref())
+                .starting_position(self.starting_position()?)
+                .send()
+                .await
+            {
+                Ok(output) => output.event_stream,
+                Err(e)
+                    if e.as_service_error().is_some_and(|e| {
+                        // A subscription of a failed reader may linger for up to 5 seconds.
+                        e.is_resource_in_use_exception() || e.is_limit_exceeded_exception()
+                    }) =>
+                {
+                    tracing::warn!(
+                        "failed to subscribe to stream {:?} shard {:?}: {}, retrying in one second",
+                        self.stream_name,
+                        self.shard_id,
+                        e.as_report()
+                    );
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    continue 'resubscribe;
+                }
+                Err(e) => {
+                    return Err(anyhow!(e)
+                        .context(format!(
+                            "failed to subscribe to kinesis stream {:?}, shard {:?}",
+                            self.stream_name, self.shard_id
+                        ))
+                        .into());
+                }
+            };
+
+            loop {
+                match event_stream.recv().await {
+                    Ok(Some(SubscribeToShardEventStream::SubscribeToShardEvent(event))) => {
+                        let chunk = (event.records.iter())
+                            .map(|r| from_kinesis_record(r, self.split_id.clone()))
+                            .collect::<Vec<SourceMessage>>();
+                        if !chunk.is_empty() {
+                            self.latest_offset = Some(chunk.last().unwrap().offset.clone());
+                            yield chunk;
+                        }
+                        // Like `GetRecordsOutput`, child shards are returned only when the
+                        // end of a closed shard is reached. Other executors are going to
+                        // read the child shards.
+                        if !event.child_shards().is_empty() {
+                            tracing::info!(
+                                "shard {:?} reaches the end and is inactive, stop reading",
+                                self.shard_id
+                            );
+                            break 'resubscribe;
+                        }
+                    }
+                    Ok(Some(event)) => {
+                        tracing::warn!(
+                            "unexpected event on stream {:?} shard {:?}: {:?}, resubscribing",
+                            self.stream_name,
+                            self.shard_id,
+                            event
+                        );
+                        continue 'resubscribe;
+                    }
+                    // The subscription expired after 5 minutes.
+                    Ok(None) => continue 'resubscribe,
+                    Err(e) => {
+                        tracing::warn!(
+                            "stream {:?} shard {:?} event stream error: {}, resubscribing",
+                            self.stream_name,
+                            self.shard_id,
+                            e.as_report()
+                        );
+                        tokio::time::sleep(Duration::from_millis(200)).await;
+                        continue 'resubscribe;
+                    }
+                }
+            }
+        }
+    }
+
+    fn starting_position(&self) -> Result<StartingPosition> {
+        let builder = StartingPosition::builder();
+        let builder = if let Some(seq) = &self.latest_offset {
+            builder
+                .r#type(ShardIteratorType::AfterSequenceNumber)
+                .sequence_number(seq)
+        } else {
+            match &self.next_offset {
+                KinesisOffset::Earliest => builder.r#type(ShardIteratorType::TrimHorizon),
+                KinesisOffset::AfterSequenceNumber(seq) => builder
+                    .r#type(ShardIteratorType::AfterSequenceNumber)
+                    .sequence_number(seq),
+                KinesisOffset::Latest => builder.r#type(ShardIteratorType::Latest),
+                KinesisOffset::Timestamp(ts) => builder
+                    .r#type(ShardIteratorType::AtTimestamp)
+                    .timestamp(DateTime::from_millis(*ts)),
+                _ => unreachable!(),
+            }
+        };
+        Ok(builder
+            .build()
+            .context("failed to build kinesis starting position")?)
+    }
 }
 impl KinesisSplitReader {
     async fn new_shard_iter(&mut self) -> Result<()> {
@@ -357,6 +551,8 @@ mod tests {
 
             scan_startup_mode: None,
             start_timestamp_millis: None,
+            enhanced_fanout_enabled: None,
+            efo_consumer_name: None,
 
             unknown_fields: Default::default(),
         };
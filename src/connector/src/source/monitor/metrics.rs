@@ -69,6 +69,8 @@ pub struct SourceMetrics {
     pub rdkafka_native_metric: Arc<RdKafkaStats>,
 
     pub direct_cdc_event_lag_latency: LabelGuardedHistogramVec<1>,
+    /// Latest observed lag between the upstream event time and processing time per CDC table
+    pub cdc_source_current_lag_ms: LabelGuardedIntGaugeVec<1>,
 }
 
 pub static GLOBAL_SOURCE_METRICS: LazyLock<SourceMetrics> =
@@ -118,6 +120,14 @@ impl SourceMetrics {
         let direct_cdc_event_lag_latency =
             register_guarded_histogram_vec_with_registry!(opts, &["table_name"], registry).unwrap();
 
+        let cdc_source_current_lag_ms = register_guarded_int_gauge_vec_with_registry!(
+            "source_cdc_current_lag_milliseconds",
+            "Latest lag between the upstream event time and processing time per CDC table",
+            &["table_name"],
+            registry,
+        )
+        .unwrap();
+
         let rdkafka_native_metric = Arc::new(RdKafkaStats::new(registry.clone()));
         SourceMetrics {
             partition_input_count,
@@ -125,6 +135,7 @@ impl SourceMetrics {
             latest_message_id,
             rdkafka_native_metric,
             direct_cdc_event_lag_latency,
+            cdc_source_current_lag_ms,
         }
     }
 }
@@ -29,11 +29,13 @@ use crate::error::ConnectorResult;
 use crate::source::{SourceEnumeratorContextRef, SplitEnumerator};
 
 pub struct MqttSplitEnumerator {
-    #[expect(dead_code)]
     topic: String,
     #[expect(dead_code)]
     client: rumqttc::v5::AsyncClient,
     topics: Arc<RwLock<HashSet<String>>>,
+    /// `Some` when reading through a shared subscription group, in which case splits are
+    /// identical load-balanced consumers instead of discovered concrete topics.
+    shared_split_count: Option<u32>,
     connected: Arc<AtomicBool>,
     stopped: Arc<AtomicBool>,
 }
@@ -49,6 +51,16 @@ impl SplitEnumerator for MqttSplitEnumerator {
     ) -> ConnectorResult<MqttSplitEnumerator> {
         let (client, mut eventloop) = properties.common.build_client(context.info.source_id, 0)?;
 
+        let shared_split_count = if properties.shared_subscription_group.is_some() {
+            let count = properties.shared_subscription_parallelism.unwrap_or(1);
+            if count < 1 {
+                bail!("shared.subscription.parallelism must be >= 1");
+            }
+            Some(count)
+        } else {
+            None
+        };
+
         let topic = properties.topic.clone();
         let mut topics = HashSet::new();
         if !topic.contains('#') && !topic.contains('+') {
@@ -112,6 +124,7 @@ impl SplitEnumerator for MqttSplitEnumerator {
             client,
             topics,
             topic: properties.topic,
+            shared_split_count,
             connected,
             stopped,
         })
@@ -133,6 +146,15 @@ impl SplitEnumerator for MqttSplitEnumerator {
             }
         }
 
+        if let Some(count) = self.shared_split_count {
+            // The broker load-balances messages among the members of the shared
+            // subscription group, so the splits are identical consumers of the
+            // (possibly wildcard) topic rather than discovered concrete topics.
+            return Ok((0..count)
+                .map(|index| MqttSplit::new_shared(self.topic.clone(), index))
+                .collect());
+        }
+
         let topics = self.topics.read().await;
         Ok(topics.iter().cloned().map(MqttSplit::new).collect())
     }
@@ -46,7 +46,8 @@ pub struct MqttProperties {
     #[serde(flatten)]
     pub common: MqttCommon,
 
-    /// The topic name to subscribe or publish to. When subscribing, it can be a wildcard topic. e.g /topic/#
+    /// The topic name to subscribe or publish to. When subscribing, it can be a wildcard topic. e.g /topic/#.
+    /// The concrete topic a message arrived on can be ingested as a column via `INCLUDE partition`.
     pub topic: String,
 
     /// The quality of service to use when publishing messages. Defaults to at_most_once.
@@ -54,6 +55,18 @@ pub struct MqttProperties {
     #[serde_as(as = "Option<DisplayFromStr>")]
     pub qos: Option<MqttQualityOfService>,
 
+    /// Shared subscription group name. When set, all source readers subscribe through the
+    /// MQTT v5 shared subscription `$share/{group}/{topic}` and the broker load-balances
+    /// messages among them, instead of every reader receiving the full stream.
+    #[serde(rename = "shared.subscription.group")]
+    pub shared_subscription_group: Option<String>,
+
+    /// Number of parallel consumers in the shared subscription group. Defaults to 1.
+    /// Only effective when `shared.subscription.group` is set.
+    #[serde(rename = "shared.subscription.parallelism")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub shared_subscription_parallelism: Option<u32>,
+
     #[serde(flatten)]
     pub unknown_fields: HashMap<String, String>,
 }
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use futures_async_stream::try_stream;
 use rumqttc::v5::mqttbytes::v5::Filter;
@@ -25,19 +28,29 @@ use crate::error::ConnectorResult as Result;
 use crate::parser::ParserConfig;
 use crate::source::common::into_chunk_stream;
 use crate::source::mqtt::MqttProperties;
-use crate::source::{BoxSourceChunkStream, Column, SourceContextRef, SourceMessage, SplitReader};
+use crate::source::{
+    BoxSourceChunkStream, Column, SourceContextRef, SourceMessage, SplitId, SplitMetaData,
+    SplitReader,
+};
 
 pub struct MqttSplitReader {
     eventloop: rumqttc::v5::EventLoop,
     client: rumqttc::v5::AsyncClient,
     qos: QoS,
     splits: Vec<MqttSplit>,
-    #[expect(dead_code)]
     properties: MqttProperties,
     parser_config: ParserConfig,
     source_ctx: SourceContextRef,
 }
 
+/// Wraps `topic` into an MQTT v5 shared subscription filter when a group is configured.
+fn subscription_filter(topic: String, group: Option<&str>) -> String {
+    match group {
+        Some(group) => format!("$share/{group}/{topic}"),
+        None => topic,
+    }
+}
+
 #[async_trait]
 impl SplitReader for MqttSplitReader {
     type Properties = MqttProperties;
@@ -57,12 +70,13 @@ impl SplitReader for MqttSplitReader {
 
         let qos = properties.common.qos();
 
+        let group = properties.shared_subscription_group.as_deref();
         client
             .subscribe_many(
                 splits
                     .iter()
                     .cloned()
-                    .map(|split| Filter::new(split.topic, qos)),
+                    .map(|split| Filter::new(subscription_filter(split.topic, group), qos)),
             )
             .await?;
 
@@ -91,11 +105,41 @@ impl MqttSplitReader {
         let client = self.client;
         let qos = self.qos;
         let splits = self.splits;
+        let group = self.properties.shared_subscription_group;
+        // In a shared subscription the broker does not tell which member a message was
+        // balanced to, so all messages are attributed to the (single) assigned split.
+        let shared_split_id: Option<SplitId> = group.as_ref().map(|_| splits[0].id());
+        // The last QoS 1 packet id seen per split, used to skip the broker's redelivery
+        // of the messages ingested right before a recovery.
+        let mut last_packet_ids: HashMap<SplitId, u16> = splits
+            .iter()
+            .filter_map(|split| split.last_packet_id.map(|pkid| (split.id(), pkid)))
+            .collect();
         loop {
             match eventloop.poll().await {
                 Ok(Event::Incoming(Incoming::Publish(p))) => {
+                    let (dup, pkid, msg_qos) = (p.dup, p.pkid, p.qos);
                     let msg = MqttMessage::new(p);
-                    yield vec![SourceMessage::from(msg)];
+                    let mut msg = SourceMessage::from(msg);
+                    if let Some(split_id) = &shared_split_id {
+                        msg.split_id = split_id.clone();
+                    }
+                    if msg_qos == QoS::AtLeastOnce && pkid != 0 {
+                        match last_packet_ids.entry(msg.split_id.clone()) {
+                            Entry::Occupied(mut o) => {
+                                if dup && *o.get() == pkid {
+                                    // The offset of this packet is already persisted,
+                                    // so the redelivery is a duplicate.
+                                    continue;
+                                }
+                                o.insert(pkid);
+                            }
+                            Entry::Vacant(v) => {
+                                v.insert(pkid);
+                            }
+                        }
+                    }
+                    yield vec![msg];
                 }
                 Ok(_) => (),
                 Err(e) => {
@@ -104,12 +148,9 @@ impl MqttSplitReader {
                     }
                     tracing::error!("Failed to poll mqtt eventloop: {}", e.as_report());
                     client
-                        .subscribe_many(
-                            splits
-                                .iter()
-                                .cloned()
-                                .map(|split| Filter::new(split.topic, qos)),
-                        )
+                        .subscribe_many(splits.iter().cloned().map(|split| {
+                            Filter::new(subscription_filter(split.topic, group.as_deref()), qos)
+                        }))
                         .await?;
                 }
             }
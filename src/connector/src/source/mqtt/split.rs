@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyhow::Context;
 use risingwave_common::types::JsonbVal;
 use serde::{Deserialize, Serialize};
 
@@ -22,12 +23,25 @@ use crate::source::{SplitId, SplitMetaData};
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Hash)]
 pub struct MqttSplit {
     pub(crate) topic: String,
+
+    /// Index of this split within a shared subscription group. `None` for regular
+    /// (non-shared) subscriptions, where the topic itself identifies the split.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) index: Option<u32>,
+
+    /// The last QoS 1 packet id seen on this split, persisted so that the broker's
+    /// redelivery of already-ingested messages can be skipped after recovery.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) last_packet_id: Option<u16>,
 }
 
 impl SplitMetaData for MqttSplit {
     fn id(&self) -> SplitId {
         // TODO: should avoid constructing a string every time
-        self.topic.clone().into()
+        match self.index {
+            Some(index) => format!("{}-{}", self.topic, index).into(),
+            None => self.topic.clone().into(),
+        }
     }
 
     fn restore_from_json(value: JsonbVal) -> ConnectorResult<Self> {
@@ -38,13 +52,30 @@ impl SplitMetaData for MqttSplit {
         serde_json::to_value(self.clone()).unwrap().into()
     }
 
-    fn update_offset(&mut self, _last_seen_offset: String) -> ConnectorResult<()> {
+    fn update_offset(&mut self, last_seen_offset: String) -> ConnectorResult<()> {
+        self.last_packet_id = Some(
+            last_seen_offset
+                .parse()
+                .context("failed to parse mqtt packet id offset")?,
+        );
         Ok(())
     }
 }
 
 impl MqttSplit {
     pub fn new(topic: String) -> Self {
-        Self { topic }
+        Self {
+            topic,
+            index: None,
+            last_packet_id: None,
+        }
+    }
+
+    pub fn new_shared(topic: String, index: u32) -> Self {
+        Self {
+            topic,
+            index: Some(index),
+            last_packet_id: None,
+        }
     }
 }
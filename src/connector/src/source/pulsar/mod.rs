@@ -84,6 +84,13 @@ pub struct PulsarProperties {
     #[serde(rename = "subscription.name.prefix")]
     pub subscription_name_prefix: Option<String>,
 
+    /// Messages that are not acknowledged within this window are redelivered by the broker,
+    /// which also serves as the redelivery backoff for negatively acknowledged messages,
+    /// e.g. chunked messages dropped half-way due to an inconsistent chunk sequence.
+    #[serde(rename = "ack.timeout.ms")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub ack_timeout_ms: Option<u64>,
+
     #[serde(flatten)]
     pub unknown_fields: HashMap<String, String>,
 }
@@ -35,3 +35,28 @@ impl From<Message<Vec<u8>>> for SourceMessage {
         }
     }
 }
+
+/// Builds a `SourceMessage` from a reassembled chunked message. The message id of the last
+/// chunk is used as the offset, so that resuming from it skips all chunks of the message.
+pub fn chunked_source_message(last_chunk: &Message<Vec<u8>>, payload: Vec<u8>) -> SourceMessage {
+    let message_id = &last_chunk.message_id.id;
+
+    SourceMessage {
+        key: last_chunk
+            .payload
+            .metadata
+            .partition_key
+            .clone()
+            .map(|k| k.into()),
+        payload: Some(payload),
+        offset: format!(
+            "{}:{}:{}:{}",
+            message_id.ledger_id,
+            message_id.entry_id,
+            message_id.partition.unwrap_or(-1),
+            message_id.batch_index.unwrap_or(-1)
+        ),
+        split_id: last_chunk.topic.clone().into(),
+        meta: SourceMeta::Empty,
+    }
+}
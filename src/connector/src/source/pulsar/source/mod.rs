@@ -14,3 +14,5 @@
 
 mod message;
 pub mod reader;
+
+pub(crate) use message::chunked_source_message;
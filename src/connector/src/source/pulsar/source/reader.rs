@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use anyhow::Context;
 use async_trait::async_trait;
@@ -35,6 +36,7 @@ use thiserror_ext::AsReport;
 
 use crate::error::ConnectorResult;
 use crate::parser::ParserConfig;
+use crate::source::pulsar::source::chunked_source_message;
 use crate::source::pulsar::split::PulsarSplit;
 use crate::source::pulsar::{PulsarEnumeratorOffset, PulsarProperties};
 use crate::source::{
@@ -184,6 +186,13 @@ impl SplitReader for PulsarBrokerReader {
                 source_ctx.actor_id
             ));
 
+        // Redeliver messages that are not acknowledged in time, e.g. chunked messages whose
+        // reassembly was dropped half-way.
+        let builder = match props.ack_timeout_ms {
+            Some(v) => builder.with_unacked_message_resend_delay(Some(Duration::from_millis(v))),
+            None => builder,
+        };
+
         let builder = match split.start_offset.clone() {
             PulsarEnumeratorOffset::Earliest => {
                 if topic.starts_with("non-persistent://") {
@@ -247,16 +256,63 @@ impl SplitReader for PulsarBrokerReader {
     }
 }
 
+/// Reassembly state of one chunked message, keyed by the producer-assigned uuid.
+struct ChunkedMessageBuffer {
+    total_chunks: i32,
+    next_chunk_id: i32,
+    payload: Vec<u8>,
+}
+
 impl PulsarBrokerReader {
     #[try_stream(ok = Vec<SourceMessage>, error = crate::error::ConnectorError)]
     async fn into_data_stream(self) {
         let max_chunk_size = self.source_ctx.source_ctrl_opts.chunk_size;
+        let mut chunked_messages: HashMap<String, ChunkedMessageBuffer> = HashMap::new();
         #[for_await]
         for msgs in self.consumer.ready_chunks(max_chunk_size) {
             let mut res = Vec::with_capacity(msgs.len());
             for msg in msgs {
-                let msg = SourceMessage::from(msg?);
-                res.push(msg);
+                let msg = msg?;
+                let metadata = &msg.payload.metadata;
+                if let (Some(uuid), Some(num_chunks), Some(chunk_id)) = (
+                    metadata.uuid.as_ref(),
+                    metadata.num_chunks_from_msg,
+                    metadata.chunk_id,
+                ) {
+                    // A chunk of a large message that was split by the producer. Buffer it
+                    // until all chunks are received, then emit the reassembled message.
+                    let buffer =
+                        chunked_messages
+                            .entry(uuid.clone())
+                            .or_insert_with(|| ChunkedMessageBuffer {
+                                total_chunks: num_chunks,
+                                next_chunk_id: 0,
+                                payload: Vec::with_capacity(
+                                    metadata.total_chunk_msg_size.unwrap_or(0) as usize,
+                                ),
+                            });
+                    if chunk_id != buffer.next_chunk_id || num_chunks != buffer.total_chunks {
+                        // A chunk is missing, duplicated or inconsistent. Drop the partial
+                        // message; since its chunks are never acknowledged, the broker
+                        // redelivers them after the ack timeout.
+                        tracing::warn!(
+                            uuid = %uuid,
+                            chunk_id,
+                            expected_chunk_id = buffer.next_chunk_id,
+                            "inconsistent chunk sequence, dropping partial chunked message"
+                        );
+                        chunked_messages.remove(uuid);
+                        continue;
+                    }
+                    buffer.payload.extend_from_slice(&msg.payload.data);
+                    buffer.next_chunk_id += 1;
+                    if buffer.next_chunk_id == buffer.total_chunks {
+                        let buffer = chunked_messages.remove(uuid).unwrap();
+                        res.push(chunked_source_message(&msg, buffer.payload));
+                    }
+                } else {
+                    res.push(SourceMessage::from(msg));
+                }
             }
             yield res;
         }
@@ -14,7 +14,9 @@
 
 pub mod await_tree;
 pub mod bench;
+pub mod catalog;
 pub mod compute;
+pub mod debug;
 pub mod hummock;
 pub mod meta;
 pub mod profile;
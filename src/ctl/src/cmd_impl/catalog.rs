@@ -0,0 +1,735 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use anyhow::{bail, Context, Result};
+use inquire::Confirm;
+use itertools::Itertools;
+use risingwave_common::catalog::{
+    is_system_schema, DEFAULT_SCHEMA_NAME, DEFAULT_SUPER_USER, DEFAULT_SUPER_USER_FOR_PG,
+};
+use risingwave_pb::catalog::table::TableType;
+use risingwave_pb::meta::subscribe_response::Info;
+use risingwave_pb::meta::{MetaSnapshot, SubscribeType};
+use risingwave_pb::user::grant_privilege::{Action, Object};
+use risingwave_sqlparser::ast::{ObjectName, Statement};
+use risingwave_sqlparser::parser::Parser;
+use serde::Deserialize;
+use thiserror_ext::AsReport;
+use tokio_postgres::NoTls;
+
+use crate::CtlContext;
+
+/// Dump the catalog of the cluster as a DDL script that re-creates all user-defined objects,
+/// for promoting a catalog from one environment to another (e.g. dev -> staging -> prod).
+///
+/// Objects are emitted in dependency order: catalog ids are assigned monotonically at creation
+/// time and an object can only depend on objects created before it, so sorting by id within each
+/// database yields a valid creation order. The script can be applied with `psql -f`, or with
+/// `risectl catalog import` which additionally skips objects that already exist.
+///
+/// Secret values and user passwords are stored encrypted and cannot be exported; placeholders are
+/// emitted as comments instead.
+pub async fn dump(context: &CtlContext, format: String, output: Option<String>) -> Result<()> {
+    if format != "sql" {
+        bail!("unsupported dump format `{}`, only `sql` is supported", format);
+    }
+
+    let snapshot = fetch_snapshot(context).await?;
+    let script = generate_dump(&snapshot);
+    match output {
+        Some(path) => {
+            tokio::fs::write(&path, script).await?;
+            println!("catalog dumped to {}", path);
+        }
+        None => print!("{}", script),
+    }
+    Ok(())
+}
+
+async fn fetch_snapshot(context: &CtlContext) -> Result<MetaSnapshot> {
+    let meta_client = context.meta_client().await?;
+    // The first response of a frontend subscription carries a full catalog snapshot. Reuse it
+    // instead of introducing a dedicated RPC.
+    let mut stream = meta_client.subscribe(SubscribeType::Frontend).await?;
+    let resp = (stream.message().await?)
+        .context("the subscription stream to the meta node closed unexpectedly")?;
+    let Some(Info::Snapshot(snapshot)) = resp.info else {
+        bail!("expected a catalog snapshot as the first notification");
+    };
+    Ok(snapshot)
+}
+
+fn generate_dump(snapshot: &MetaSnapshot) -> String {
+    let mut sql = String::new();
+    let w = &mut sql;
+
+    writeln!(w, "-- RisingWave catalog dump, generated by `risectl catalog dump`.").unwrap();
+    writeln!(
+        w,
+        "-- Apply with `psql -f <file>`, or with `risectl catalog import <file>` to skip objects that already exist."
+    )
+    .unwrap();
+    writeln!(
+        w,
+        "-- User passwords, secret values, connections and object ownership are not exported and must be restored manually."
+    )
+    .unwrap();
+
+    // Cluster-level objects first: users, then databases.
+    let users = snapshot
+        .users
+        .iter()
+        .filter(|user| user.name != DEFAULT_SUPER_USER && user.name != DEFAULT_SUPER_USER_FOR_PG)
+        .sorted_by_key(|user| user.id)
+        .collect_vec();
+    if !users.is_empty() {
+        writeln!(w).unwrap();
+        writeln!(w, "-- users").unwrap();
+        for user in &users {
+            writeln!(
+                w,
+                "CREATE USER \"{}\" WITH {} {} {} {};",
+                user.name,
+                if user.is_super { "SUPERUSER" } else { "NOSUPERUSER" },
+                if user.can_create_db { "CREATEDB" } else { "NOCREATEDB" },
+                if user.can_create_user { "CREATEUSER" } else { "NOCREATEUSER" },
+                if user.can_login { "LOGIN" } else { "NOLOGIN" },
+            )
+            .unwrap();
+        }
+    }
+
+    let schema_names: HashMap<u32, &str> = snapshot
+        .schemas
+        .iter()
+        .map(|schema| (schema.id, schema.name.as_str()))
+        .collect();
+    let qualified = |schema_id: u32, name: &str| -> String {
+        match schema_names.get(&schema_id) {
+            Some(&schema) if schema != DEFAULT_SCHEMA_NAME => format!("\"{}\".\"{}\"", schema, name),
+            _ => format!("\"{}\"", name),
+        }
+    };
+
+    for database in snapshot.databases.iter().sorted_by_key(|db| db.id) {
+        writeln!(w).unwrap();
+        writeln!(w, "-- database \"{}\"", database.name).unwrap();
+        writeln!(w, "CREATE DATABASE \"{}\";", database.name).unwrap();
+        writeln!(w, "\\connect {}", database.name).unwrap();
+
+        for schema in snapshot
+            .schemas
+            .iter()
+            .filter(|schema| schema.database_id == database.id)
+            .filter(|schema| !is_system_schema(&schema.name) && schema.name != DEFAULT_SCHEMA_NAME)
+            .sorted_by_key(|schema| schema.id)
+        {
+            writeln!(w, "CREATE SCHEMA \"{}\";", schema.name).unwrap();
+        }
+
+        for secret in snapshot
+            .secrets
+            .iter()
+            .filter(|secret| secret.database_id == database.id)
+            .sorted_by_key(|secret| secret.id)
+        {
+            writeln!(
+                w,
+                "-- CREATE SECRET {} WITH (backend = 'meta') AS '<redacted>'; -- value not exported",
+                qualified(secret.schema_id, &secret.name)
+            )
+            .unwrap();
+        }
+        for connection in snapshot
+            .connections
+            .iter()
+            .filter(|connection| connection.database_id == database.id)
+            .sorted_by_key(|connection| connection.id)
+        {
+            writeln!(
+                w,
+                "-- CREATE CONNECTION {}; -- definition not exported, may contain credentials",
+                qualified(connection.schema_id, &connection.name)
+            )
+            .unwrap();
+        }
+
+        // Relations of all kinds, merged and sorted by id so that dependencies come first.
+        // Stored definitions may not qualify the schema, so switch the search path whenever the
+        // schema of the next relation differs from the previous one.
+        let mut relations: Vec<(u32, u32, &str, String)> = vec![];
+        for source in &snapshot.sources {
+            if source.database_id == database.id
+                // Sources with an associated table are dumped as part of the table.
+                && source.optional_associated_table_id.is_none()
+            {
+                relations.push((source.id, source.schema_id, &source.name, source.definition.clone()));
+            }
+        }
+        for table in &snapshot.tables {
+            if table.database_id == database.id
+                && matches!(
+                    table.table_type(),
+                    TableType::Table | TableType::MaterializedView | TableType::Index
+                )
+                && !table.definition.is_empty()
+            {
+                relations.push((table.id, table.schema_id, &table.name, table.definition.clone()));
+            }
+        }
+        for view in &snapshot.views {
+            if view.database_id == database.id {
+                // Views store only the query; reconstruct the `CREATE` statement.
+                let definition = format!(
+                    "CREATE VIEW {} AS {}",
+                    qualified(view.schema_id, &view.name),
+                    view.sql
+                );
+                relations.push((view.id, view.schema_id, &view.name, definition));
+            }
+        }
+        for sink in &snapshot.sinks {
+            if sink.database_id == database.id {
+                relations.push((sink.id, sink.schema_id, &sink.name, sink.definition.clone()));
+            }
+        }
+        for subscription in &snapshot.subscriptions {
+            if subscription.database_id == database.id {
+                relations.push((
+                    subscription.id,
+                    subscription.schema_id,
+                    &subscription.name,
+                    subscription.definition.clone(),
+                ));
+            }
+        }
+        relations.sort_by_key(|(id, ..)| *id);
+
+        let mut current_search_path = DEFAULT_SCHEMA_NAME;
+        for (_, schema_id, _, definition) in &relations {
+            let schema = schema_names.get(schema_id).copied().unwrap_or(DEFAULT_SCHEMA_NAME);
+            if schema != current_search_path {
+                writeln!(w, "SET search_path TO \"{}\";", schema).unwrap();
+                current_search_path = schema;
+            }
+            writeln!(w, "{};", definition.trim_end_matches(';')).unwrap();
+        }
+        if current_search_path != DEFAULT_SCHEMA_NAME {
+            writeln!(w, "SET search_path TO \"{}\";", DEFAULT_SCHEMA_NAME).unwrap();
+        }
+
+        // Grants on objects in this database, which must be applied while connected to it.
+        for user in &users {
+            for privilege in &user.grant_privileges {
+                let Some(object) = grant_object_sql(privilege.object.as_ref(), database.id, snapshot, &schema_names, &qualified) else {
+                    continue;
+                };
+                for with_grant_option in [false, true] {
+                    let actions = privilege
+                        .action_with_opts
+                        .iter()
+                        .filter(|a| a.with_grant_option == with_grant_option)
+                        .map(|a| action_sql(a.action()))
+                        .collect_vec();
+                    if actions.is_empty() {
+                        continue;
+                    }
+                    writeln!(
+                        w,
+                        "GRANT {} ON {} TO \"{}\"{};",
+                        actions.join(", "),
+                        object,
+                        user.name,
+                        if with_grant_option { " WITH GRANT OPTION" } else { "" },
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+
+    sql
+}
+
+fn action_sql(action: Action) -> &'static str {
+    match action {
+        Action::Select => "SELECT",
+        Action::Insert => "INSERT",
+        Action::Update => "UPDATE",
+        Action::Delete => "DELETE",
+        Action::Create => "CREATE",
+        Action::Connect => "CONNECT",
+        Action::Usage => "USAGE",
+        Action::Execute => "EXECUTE",
+        Action::Unspecified => unreachable!(),
+    }
+}
+
+/// Render the object clause of a `GRANT` statement, or `None` if the object does not belong to
+/// the given database or cannot be re-granted from a dump (e.g. functions).
+fn grant_object_sql(
+    object: Option<&Object>,
+    database_id: u32,
+    snapshot: &MetaSnapshot,
+    schema_names: &HashMap<u32, &str>,
+    qualified: &impl Fn(u32, &str) -> String,
+) -> Option<String> {
+    let in_database =
+        |schema_id: u32| snapshot.schemas.iter().any(|s| s.id == schema_id && s.database_id == database_id);
+    match object? {
+        Object::DatabaseId(id) => {
+            let database = snapshot.databases.iter().find(|db| db.id == *id)?;
+            (database.id == database_id).then(|| format!("DATABASE \"{}\"", database.name))
+        }
+        Object::SchemaId(id) => {
+            let schema = snapshot
+                .schemas
+                .iter()
+                .find(|s| s.id == *id && s.database_id == database_id)?;
+            (!is_system_schema(&schema.name)).then(|| format!("SCHEMA \"{}\"", schema.name))
+        }
+        Object::TableId(id) => {
+            let table = snapshot
+                .tables
+                .iter()
+                .find(|t| t.id == *id && t.database_id == database_id)?;
+            match table.table_type() {
+                TableType::Table => Some(format!("TABLE {}", qualified(table.schema_id, &table.name))),
+                TableType::MaterializedView => Some(format!(
+                    "MATERIALIZED VIEW {}",
+                    qualified(table.schema_id, &table.name)
+                )),
+                _ => None,
+            }
+        }
+        Object::SourceId(id) => {
+            let source = snapshot
+                .sources
+                .iter()
+                .find(|s| s.id == *id && s.database_id == database_id)?;
+            Some(format!("SOURCE {}", qualified(source.schema_id, &source.name)))
+        }
+        Object::SinkId(id) => {
+            let sink = snapshot
+                .sinks
+                .iter()
+                .find(|s| s.id == *id && s.database_id == database_id)?;
+            Some(format!("SINK {}", qualified(sink.schema_id, &sink.name)))
+        }
+        Object::ViewId(id) => {
+            let view = snapshot
+                .views
+                .iter()
+                .find(|v| v.id == *id && v.database_id == database_id)?;
+            Some(format!("VIEW {}", qualified(view.schema_id, &view.name)))
+        }
+        Object::AllTablesSchemaId(id) => {
+            (in_database(*id) && !is_system_schema(schema_names.get(id)?))
+                .then(|| format!("ALL TABLES IN SCHEMA \"{}\"", schema_names.get(id).unwrap()))
+        }
+        Object::AllSourcesSchemaId(id) => {
+            (in_database(*id) && !is_system_schema(schema_names.get(id)?))
+                .then(|| format!("ALL SOURCES IN SCHEMA \"{}\"", schema_names.get(id).unwrap()))
+        }
+        Object::FunctionId(_) | Object::AllDmlRelationsSchemaId(_) | Object::SubscriptionId(_) => {
+            None
+        }
+    }
+}
+
+/// Apply a DDL script produced by [`dump`] through a frontend node, statement by statement.
+/// Statements that fail because the object already exists are skipped, so a dump can be applied
+/// repeatedly to converge an environment onto the dumped catalog.
+pub async fn import(
+    input: String,
+    host: String,
+    port: u16,
+    user: String,
+    database: String,
+) -> Result<()> {
+    let script = tokio::fs::read_to_string(&input)
+        .await
+        .with_context(|| format!("failed to read {}", input))?;
+
+    let mut client = connect(&host, port, &user, &database).await?;
+    let mut statement = String::new();
+    let (mut applied, mut skipped) = (0, 0);
+
+    for line in script.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("--") {
+            continue;
+        }
+        if let Some(db) = trimmed.strip_prefix("\\connect ") {
+            if !statement.trim().is_empty() {
+                bail!("incomplete statement before `\\connect`: {}", statement.trim());
+            }
+            client = connect(&host, port, &user, db.trim()).await?;
+            continue;
+        }
+        statement.push_str(line);
+        statement.push('\n');
+        if !trimmed.ends_with(';') {
+            continue;
+        }
+
+        let stmt = std::mem::take(&mut statement);
+        let stmt = stmt.trim();
+        match client.simple_query(stmt).await {
+            Ok(_) => applied += 1,
+            Err(e) if already_exists(&e) => {
+                println!("skipped (already exists): {}", summarize(stmt));
+                skipped += 1;
+            }
+            Err(e) => {
+                return Err(e).with_context(|| format!("failed to apply: {}", summarize(stmt)));
+            }
+        }
+    }
+    if !statement.trim().is_empty() {
+        bail!("incomplete statement at end of script: {}", statement.trim());
+    }
+
+    println!("applied {} statements, skipped {}", applied, skipped);
+    Ok(())
+}
+
+/// A declarative specification of the objects that should exist in one database, for
+/// CI/CD-driven schema management.
+#[derive(Debug, Deserialize)]
+struct CatalogSpec {
+    /// The database the specification describes.
+    database: String,
+    /// DDL statements describing the desired objects, in creation order.
+    objects: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ObjectKind {
+    Schema,
+    Table,
+    MaterializedView,
+    View,
+    Index,
+    Source,
+    Sink,
+    Subscription,
+}
+
+impl ObjectKind {
+    fn sql_keyword(self) -> &'static str {
+        match self {
+            ObjectKind::Schema => "SCHEMA",
+            ObjectKind::Table => "TABLE",
+            ObjectKind::MaterializedView => "MATERIALIZED VIEW",
+            ObjectKind::View => "VIEW",
+            ObjectKind::Index => "INDEX",
+            ObjectKind::Source => "SOURCE",
+            ObjectKind::Sink => "SINK",
+            ObjectKind::Subscription => "SUBSCRIPTION",
+        }
+    }
+}
+
+/// `(kind, schema, name)`, identifying an object within a database. The schema is empty for
+/// schemas themselves.
+type ObjectKey = (ObjectKind, String, String);
+
+struct ExistingObject {
+    id: u32,
+    definition: String,
+}
+
+/// Diff a declarative object specification against the current catalog and emit (or, after
+/// confirmation, execute) a minimal DDL migration plan.
+///
+/// Objects in the specification that are missing from the cluster are created; objects whose
+/// definition differs are dropped and re-created; objects of managed kinds that exist in the
+/// cluster but not in the specification are dropped. Objects are compared by their parsed
+/// definition, so formatting differences do not cause churn.
+pub async fn apply(
+    context: &CtlContext,
+    plan_path: String,
+    host: String,
+    port: u16,
+    user: String,
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    let spec: CatalogSpec = serde_yaml::from_str(
+        &tokio::fs::read_to_string(&plan_path)
+            .await
+            .with_context(|| format!("failed to read {}", plan_path))?,
+    )
+    .context("failed to parse the catalog specification")?;
+
+    // Parse the desired objects, keeping the specification order.
+    let mut desired: Vec<(ObjectKey, String)> = vec![];
+    for sql in &spec.objects {
+        let stmt = parse_single_statement(sql)
+            .with_context(|| format!("in catalog specification: {}", summarize(sql)))?;
+        let key = spec_object_key(&stmt)?;
+        if desired.iter().any(|(k, _)| *k == key) {
+            bail!("duplicate object in catalog specification: {} \"{}\"", key.0.sql_keyword(), key.2);
+        }
+        desired.push((key, stmt.to_string()));
+    }
+
+    let snapshot = fetch_snapshot(context).await?;
+    let database = snapshot
+        .databases
+        .iter()
+        .find(|db| db.name == spec.database)
+        .with_context(|| format!("database \"{}\" does not exist", spec.database))?;
+    let existing = collect_existing_objects(&snapshot, database.id);
+
+    // Build the plan: first drop objects that are not in the specification (newest first, so
+    // that dependents are dropped before their dependencies), then create or re-create the
+    // specified objects in specification order.
+    let mut plan: Vec<String> = vec![];
+    let mut unchanged = 0;
+    for (key, _) in existing
+        .iter()
+        .filter(|(key, _)| !desired.iter().any(|(k, _)| k == *key))
+        .sorted_by_key(|(_, object)| std::cmp::Reverse(object.id))
+    {
+        let (kind, schema, name) = key;
+        plan.push(format!(
+            "DROP {} {}",
+            kind.sql_keyword(),
+            object_sql_name(*kind, schema, name)
+        ));
+    }
+    for (key, desired_sql) in &desired {
+        let (kind, schema, name) = key;
+        match existing.get(key) {
+            None => plan.push(desired_sql.clone()),
+            // Schemas have no definition to compare.
+            Some(_) if *kind == ObjectKind::Schema => unchanged += 1,
+            Some(object) if normalize_definition(&object.definition) == *desired_sql => {
+                unchanged += 1;
+            }
+            Some(_) => {
+                plan.push(format!(
+                    "DROP {} {}",
+                    kind.sql_keyword(),
+                    object_sql_name(*kind, schema, name)
+                ));
+                plan.push(desired_sql.clone());
+            }
+        }
+    }
+
+    if plan.is_empty() {
+        println!("No changes, {} objects up to date.", unchanged);
+        return Ok(());
+    }
+    println!("Migration plan ({} objects unchanged):", unchanged);
+    for statement in &plan {
+        println!("    {};", statement);
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    if !yes {
+        match Confirm::new("Will apply the migration plan above to the cluster, are you sure?")
+            .with_default(false)
+            .with_help_message("Use the --yes or -y option to skip this prompt")
+            .with_placeholder("no")
+            .prompt()
+        {
+            Ok(true) => println!("Processing..."),
+            Ok(false) => {
+                println!("Abort.");
+                return Ok(());
+            }
+            Err(_) => {
+                println!("Error with questionnaire, try again later");
+                return Ok(());
+            }
+        }
+    }
+
+    let client = connect(&host, port, &user, &spec.database).await?;
+    for statement in &plan {
+        client
+            .simple_query(statement)
+            .await
+            .with_context(|| format!("failed to apply: {}", summarize(statement)))?;
+        println!("applied: {}", summarize(statement));
+    }
+    Ok(())
+}
+
+fn parse_single_statement(sql: &str) -> Result<Statement> {
+    let mut stmts = Parser::parse_sql(sql).map_err(|e| anyhow::anyhow!(e))?;
+    if stmts.len() != 1 {
+        bail!("expected exactly one statement, found {}", stmts.len());
+    }
+    Ok(stmts.remove(0))
+}
+
+/// Extract the identity of the object a `CREATE` statement defines.
+fn spec_object_key(stmt: &Statement) -> Result<ObjectKey> {
+    let schema_and_name = |name: &ObjectName| -> (String, String) {
+        let mut parts = name.0.iter().map(|ident| ident.real_value()).collect_vec();
+        let name = parts.pop().unwrap();
+        let schema = parts.pop().unwrap_or_else(|| DEFAULT_SCHEMA_NAME.to_owned());
+        (schema, name)
+    };
+    let (kind, name) = match stmt {
+        Statement::CreateSchema { schema_name, .. } => {
+            let (_, name) = schema_and_name(schema_name);
+            return Ok((ObjectKind::Schema, String::new(), name));
+        }
+        Statement::CreateTable { name, .. } => (ObjectKind::Table, name),
+        Statement::CreateView { materialized: true, name, .. } => (ObjectKind::MaterializedView, name),
+        Statement::CreateView { materialized: false, name, .. } => (ObjectKind::View, name),
+        Statement::CreateIndex { name, .. } => (ObjectKind::Index, name),
+        Statement::CreateSource { stmt } => (ObjectKind::Source, &stmt.source_name),
+        Statement::CreateSink { stmt } => (ObjectKind::Sink, &stmt.sink_name),
+        Statement::CreateSubscription { stmt } => (ObjectKind::Subscription, &stmt.subscription_name),
+        _ => bail!("unsupported statement in catalog specification: {}", summarize(&stmt.to_string())),
+    };
+    let (schema, name) = schema_and_name(name);
+    Ok((kind, schema, name))
+}
+
+fn collect_existing_objects(snapshot: &MetaSnapshot, database_id: u32) -> HashMap<ObjectKey, ExistingObject> {
+    let schema_names: HashMap<u32, &str> = snapshot
+        .schemas
+        .iter()
+        .map(|schema| (schema.id, schema.name.as_str()))
+        .collect();
+    let mut existing = HashMap::new();
+    let mut insert = |kind: ObjectKind, schema_id: u32, name: &str, id: u32, definition: &str| {
+        let schema = schema_names.get(&schema_id).copied().unwrap_or(DEFAULT_SCHEMA_NAME);
+        if is_system_schema(schema) {
+            return;
+        }
+        existing.insert(
+            (kind, schema.to_owned(), name.to_owned()),
+            ExistingObject {
+                id,
+                definition: definition.to_owned(),
+            },
+        );
+    };
+
+    for source in &snapshot.sources {
+        if source.database_id == database_id && source.optional_associated_table_id.is_none() {
+            insert(ObjectKind::Source, source.schema_id, &source.name, source.id, &source.definition);
+        }
+    }
+    for table in &snapshot.tables {
+        if table.database_id == database_id {
+            let kind = match table.table_type() {
+                TableType::Table => ObjectKind::Table,
+                TableType::MaterializedView => ObjectKind::MaterializedView,
+                TableType::Index => ObjectKind::Index,
+                TableType::Internal | TableType::Unspecified => continue,
+            };
+            insert(kind, table.schema_id, &table.name, table.id, &table.definition);
+        }
+    }
+    for view in &snapshot.views {
+        if view.database_id == database_id {
+            let definition = format!("CREATE VIEW \"{}\" AS {}", view.name, view.sql);
+            insert(ObjectKind::View, view.schema_id, &view.name, view.id, &definition);
+        }
+    }
+    for sink in &snapshot.sinks {
+        if sink.database_id == database_id {
+            insert(ObjectKind::Sink, sink.schema_id, &sink.name, sink.id, &sink.definition);
+        }
+    }
+    for subscription in &snapshot.subscriptions {
+        if subscription.database_id == database_id {
+            insert(
+                ObjectKind::Subscription,
+                subscription.schema_id,
+                &subscription.name,
+                subscription.id,
+                &subscription.definition,
+            );
+        }
+    }
+    for schema in &snapshot.schemas {
+        if schema.database_id == database_id
+            && !is_system_schema(&schema.name)
+            && schema.name != DEFAULT_SCHEMA_NAME
+        {
+            existing.insert(
+                (ObjectKind::Schema, String::new(), schema.name.clone()),
+                ExistingObject {
+                    id: schema.id,
+                    definition: String::new(),
+                },
+            );
+        }
+    }
+    existing
+}
+
+fn object_sql_name(kind: ObjectKind, schema: &str, name: &str) -> String {
+    if kind == ObjectKind::Schema || schema == DEFAULT_SCHEMA_NAME {
+        format!("\"{}\"", name)
+    } else {
+        format!("\"{}\".\"{}\"", schema, name)
+    }
+}
+
+/// Normalize a stored definition for comparison against the specification by round-tripping it
+/// through the parser, so that whitespace and case differences do not register as changes.
+fn normalize_definition(definition: &str) -> String {
+    match parse_single_statement(definition) {
+        Ok(stmt) => stmt.to_string(),
+        Err(_) => definition.trim().trim_end_matches(';').to_owned(),
+    }
+}
+
+async fn connect(host: &str, port: u16, user: &str, database: &str) -> Result<tokio_postgres::Client> {
+    let (client, connection) = tokio_postgres::Config::new()
+        .host(host)
+        .port(port)
+        .user(user)
+        .dbname(database)
+        .connect(NoTls)
+        .await
+        .with_context(|| format!("failed to connect to {}:{}, database {}", host, port, database))?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::error!(error = %e.as_report(), "postgres connection error");
+        }
+    });
+    Ok(client)
+}
+
+fn already_exists(e: &tokio_postgres::Error) -> bool {
+    e.as_db_error()
+        .is_some_and(|db_error| db_error.message().contains("already exists"))
+}
+
+fn summarize(stmt: &str) -> String {
+    let first_line = stmt.lines().next().unwrap_or_default();
+    if first_line.len() > 80 {
+        format!("{}...", &first_line[..80])
+    } else {
+        first_line.to_owned()
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use risingwave_common::util::addr::HostAddr;
+use risingwave_common::util::StackTraceResponseExt;
+use risingwave_pb::common::WorkerType;
+use risingwave_pb::monitor_service::StackTraceResponse;
+use risingwave_rpc_client::{CompactorClient, ComputeClientPool};
+use thiserror_ext::AsReport;
+
+use crate::CtlContext;
+
+/// Collects diagnostics from all nodes of the cluster into a single `tar.gz` archive,
+/// to be attached to support tickets.
+///
+/// Each piece of information is collected best-effort: a failure to collect one of them
+/// (e.g. an unreachable node) is recorded in the bundle instead of failing the whole command.
+/// Sensitive information is redacted: the license key is masked by the system params reader,
+/// and no catalog definitions or connector properties are collected.
+pub async fn bundle(context: &CtlContext, output: String) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+
+    let mut bundle = Bundle::default();
+
+    // Worker nodes, including their versions and states.
+    match meta_client.list_worker_nodes(None).await {
+        Ok(workers) => bundle.add("cluster_info.txt", format!("{:#?}", workers)),
+        Err(e) => bundle.add_error("cluster_info.txt", &e),
+    }
+
+    // System params, with sensitive params redacted by the reader.
+    match meta_client.get_system_params().await {
+        Ok(params) => bundle.add("system_params.txt", format!("{:#?}", params)),
+        Err(e) => bundle.add_error("system_params.txt", &e),
+    }
+
+    // Recent event logs, including barrier failures, job aborts and recovery events.
+    match meta_client.list_event_log().await {
+        Ok(mut event_logs) => {
+            event_logs.sort_by_key(|e| e.timestamp);
+            let mut content = String::new();
+            for event_log in event_logs {
+                writeln!(content, "{:?}", event_log).unwrap();
+            }
+            bundle.add("event_logs.txt", content);
+        }
+        Err(e) => bundle.add_error("event_logs.txt", &e),
+    }
+
+    // In-progress DDLs, if any.
+    match meta_client.get_ddl_progress().await {
+        Ok(progress) => bundle.add("ddl_progress.txt", format!("{:#?}", progress)),
+        Err(e) => bundle.add_error("ddl_progress.txt", &e),
+    }
+
+    // Stack traces of all compute nodes and compactors, including in-flight barrier traces.
+    match collect_stack_traces(context).await {
+        Ok(all) => bundle.add("stack_traces.txt", all.output().to_string()),
+        Err(e) => bundle.add_error("stack_traces.txt", &*e),
+    }
+
+    // Summary of the current hummock version, without per-SST key ranges.
+    match meta_client.get_current_version().await {
+        Ok(version) => {
+            let mut content = String::new();
+            writeln!(content, "Version {}", version.id).unwrap();
+            for (cg, levels) in &version.levels {
+                writeln!(content, "CompactionGroup {}", cg).unwrap();
+                for sub_level in levels.l0.sub_levels.iter().rev() {
+                    writeln!(
+                        content,
+                        "sub_level_id {} type {} sst_num {} size {}",
+                        sub_level.sub_level_id,
+                        sub_level.level_type.as_str_name(),
+                        sub_level.table_infos.len(),
+                        sub_level.total_file_size
+                    )
+                    .unwrap();
+                }
+                for level in &levels.levels {
+                    writeln!(
+                        content,
+                        "level_idx {} type {} sst_num {} size {}",
+                        level.level_idx,
+                        level.level_type.as_str_name(),
+                        level.table_infos.len(),
+                        level.total_file_size
+                    )
+                    .unwrap();
+                }
+            }
+            bundle.add("hummock_version.txt", content);
+        }
+        Err(e) => bundle.add_error("hummock_version.txt", &e),
+    }
+
+    // Batch and streaming configs of each compute node.
+    if let Ok(compute_nodes) = meta_client
+        .list_worker_nodes(Some(WorkerType::ComputeNode))
+        .await
+    {
+        let clients = ComputeClientPool::adhoc();
+        for cn in compute_nodes {
+            let host: HostAddr = cn.get_host().unwrap().into();
+            let file_name = format!("config/compute-{}-{}.txt", host.host, host.port);
+            let config = async {
+                let client = clients.get(&cn).await?;
+                let config_response = client.show_config().await?;
+                Ok::<_, anyhow::Error>(format!(
+                    "--- batch config ---\n{}\n--- streaming config ---\n{}\n",
+                    config_response.batch_config, config_response.stream_config
+                ))
+            };
+            match config.await {
+                Ok(content) => bundle.add(file_name, content),
+                Err(e) => bundle.add_error(file_name, &*e),
+            }
+        }
+    }
+
+    bundle.write_to(&output)?;
+    println!("Debug bundle written to {}", output);
+
+    Ok(())
+}
+
+async fn collect_stack_traces(context: &CtlContext) -> anyhow::Result<StackTraceResponse> {
+    let meta_client = context.meta_client().await?;
+    let mut all = StackTraceResponse::default();
+
+    let compute_nodes = meta_client
+        .list_worker_nodes(Some(WorkerType::ComputeNode))
+        .await?;
+    let clients = ComputeClientPool::adhoc();
+    for cn in compute_nodes {
+        let client = clients.get(&cn).await?;
+        let response = client.stack_trace().await?;
+        all.merge_other(response);
+    }
+
+    let compactor_nodes = meta_client
+        .list_worker_nodes(Some(WorkerType::Compactor))
+        .await?;
+    for compactor in compactor_nodes {
+        let addr: HostAddr = compactor.get_host().unwrap().into();
+        let client = CompactorClient::new(addr).await?;
+        let response = client.stack_trace().await?;
+        all.merge_other(response);
+    }
+
+    Ok(all)
+}
+
+/// An in-memory collection of named text files to be archived into a `tar.gz`.
+#[derive(Default)]
+struct Bundle {
+    files: Vec<(String, String)>,
+}
+
+impl Bundle {
+    fn add(&mut self, name: impl Into<String>, content: String) {
+        self.files.push((name.into(), content));
+    }
+
+    fn add_error(&mut self, name: impl Into<String>, error: &(impl std::error::Error + ?Sized)) {
+        let name = name.into();
+        eprintln!("failed to collect {}: {}", name, error.as_report());
+        self.add(name, format!("failed to collect: {}", error.as_report()));
+    }
+
+    fn write_to(self, output: &str) -> anyhow::Result<()> {
+        let file = std::fs::File::create(output)?;
+        let mut encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+        let mtime = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+        for (name, content) in self.files {
+            write_tar_entry(&mut encoder, &name, content.as_bytes(), mtime.as_secs())?;
+        }
+        // An archive is terminated by two zero-filled blocks.
+        encoder.write_all(&[0u8; 1024])?;
+        encoder.finish()?;
+
+        Ok(())
+    }
+}
+
+/// Writes one regular-file entry in `ustar` format. The bundle only needs this trivial
+/// subset of the format (regular files with short names), which spares a dependency on
+/// a tar crate.
+fn write_tar_entry(
+    w: &mut impl std::io::Write,
+    name: &str,
+    content: &[u8],
+    mtime: u64,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        name.len() <= 100,
+        "file name too long for a ustar header: {}",
+        name
+    );
+
+    let mut header = [0u8; 512];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    header[100..108].copy_from_slice(b"0000644\0"); // mode
+    header[108..116].copy_from_slice(b"0000000\0"); // uid
+    header[116..124].copy_from_slice(b"0000000\0"); // gid
+    header[124..136].copy_from_slice(format!("{:011o}\0", content.len()).as_bytes());
+    header[136..148].copy_from_slice(format!("{:011o}\0", mtime).as_bytes());
+    // The checksum is computed with the checksum field itself filled with spaces.
+    header[148..156].copy_from_slice(b"        ");
+    header[156] = b'0'; // regular file
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    let checksum = header.iter().map(|b| *b as u32).sum::<u32>();
+    header[148..155].copy_from_slice(format!("{:06o}\0", checksum).as_bytes());
+
+    w.write_all(&header)?;
+    w.write_all(content)?;
+    let padding = content.len().next_multiple_of(512) - content.len();
+    w.write_all(&vec![0u8; padding])?;
+    Ok(())
+}
@@ -19,8 +19,10 @@ pub use list_kv::*;
 mod sst_dump;
 pub use sst_dump::*;
 mod compaction_group;
+mod compaction_replay;
 mod list_version_deltas;
 mod migrate_legacy_object;
+mod orphan_report;
 mod pause_resume;
 mod resize_cache;
 mod tiered_cache_tracing;
@@ -29,8 +31,10 @@ mod trigger_manual_compaction;
 mod validate_version;
 
 pub use compaction_group::*;
+pub use compaction_replay::*;
 pub use list_version_deltas::*;
 pub use migrate_legacy_object::migrate_legacy_object;
+pub use orphan_report::*;
 pub use pause_resume::*;
 pub use resize_cache::*;
 pub use tiered_cache_tracing::*;
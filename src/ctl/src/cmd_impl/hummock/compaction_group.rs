@@ -69,6 +69,8 @@ pub fn build_compaction_config_vec(
     sst_allowed_trivial_move_min_size: Option<u64>,
     disable_auto_group_scheduling: Option<bool>,
     max_overlapping_level_size: Option<u64>,
+    max_concurrent_task_count: Option<u32>,
+    scheduling_weight: Option<u32>,
 ) -> Vec<MutableConfig> {
     let mut configs = vec![];
     if let Some(c) = max_bytes_for_level_base {
@@ -131,6 +133,12 @@ pub fn build_compaction_config_vec(
     if let Some(c) = max_overlapping_level_size {
         configs.push(MutableConfig::MaxOverlappingLevelSize(c))
     }
+    if let Some(c) = max_concurrent_task_count {
+        configs.push(MutableConfig::MaxConcurrentTaskCount(c))
+    }
+    if let Some(c) = scheduling_weight {
+        configs.push(MutableConfig::SchedulingWeight(c))
+    }
 
     configs
 }
@@ -199,11 +207,16 @@ pub async fn list_compaction_status(context: &CtlContext, verbose: bool) -> anyh
             row
         });
         let mut assignment_lite: HashMap<HummockContextId, Vec<u64>> = HashMap::new();
+        let mut assignment_by_group: HashMap<CompactionGroupId, usize> = HashMap::new();
         for a in assignment {
+            let task = a.compact_task.unwrap();
             assignment_lite
                 .entry(a.context_id)
                 .or_default()
-                .push(a.compact_task.unwrap().task_id);
+                .push(task.task_id);
+            *assignment_by_group
+                .entry(task.compaction_group_id)
+                .or_default() += 1;
         }
         for (k, v) in assignment_lite {
             let mut row = Row::new();
@@ -220,6 +233,43 @@ pub async fn list_compaction_status(context: &CtlContext, verbose: bool) -> anyh
         }
         println!("{table}");
 
+        // Per-group scheduling view: running task count against the group's quota and weight.
+        let group_infos = meta_client.risectl_list_compaction_group().await?;
+        let mut table = Table::new();
+        table.set_header({
+            let mut row = Row::new();
+            row.add_cell("Compaction Group".into());
+            row.add_cell("Running Task Count".into());
+            row.add_cell("Max Concurrent Task Count".into());
+            row.add_cell("Scheduling Weight".into());
+            row
+        });
+        for group_info in group_infos {
+            let cg_id = group_info.id;
+            let running_task_count = assignment_by_group.get(&cg_id).copied().unwrap_or(0);
+            let (quota, weight) = group_info
+                .compaction_config
+                .as_ref()
+                .map(|config| {
+                    (
+                        config.max_concurrent_task_count.unwrap_or(0),
+                        config.scheduling_weight.unwrap_or(1),
+                    )
+                })
+                .unwrap_or((0, 1));
+            let mut row = Row::new();
+            row.add_cell(cg_id.into());
+            row.add_cell(running_task_count.into());
+            row.add_cell(if quota == 0 {
+                "unlimited".into()
+            } else {
+                quota.into()
+            });
+            row.add_cell(weight.into());
+            table.add_row(row);
+        }
+        println!("{table}");
+
         let mut table = Table::new();
         table.set_header({
             let mut row = Row::new();
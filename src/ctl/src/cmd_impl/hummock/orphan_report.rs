@@ -0,0 +1,116 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use comfy_table::{Row, Table};
+
+use crate::CtlContext;
+
+/// Age buckets used to aggregate orphan objects in the report.
+const AGE_BUCKETS_DAY: [u64; 4] = [1, 7, 30, 90];
+
+pub async fn orphan_report(
+    context: &CtlContext,
+    sst_retention_time_sec: u64,
+    verbose: bool,
+) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let orphan_objects = meta_client
+        .list_orphan_objects(sst_retention_time_sec)
+        .await?;
+    let now_sec = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock may have gone backwards")
+        .as_secs();
+
+    if verbose {
+        let mut table = Table::new();
+        table.set_header({
+            let mut row = Row::new();
+            row.add_cell("Object Id".into());
+            row.add_cell("Size (bytes)".into());
+            row.add_cell("Age (days)".into());
+            row.add_cell("Held".into());
+            row
+        });
+        for o in &orphan_objects {
+            let mut row = Row::new();
+            row.add_cell(o.object_id.into());
+            row.add_cell(o.total_size.into());
+            row.add_cell((now_sec.saturating_sub(o.last_modified_sec) / 86400).into());
+            row.add_cell(o.held.into());
+            table.add_row(row);
+        }
+        println!("--- Orphan Objects ---");
+        println!("{table}");
+    }
+
+    let mut table = Table::new();
+    table.set_header({
+        let mut row = Row::new();
+        row.add_cell("Age".into());
+        row.add_cell("Object Count".into());
+        row.add_cell("Total Size (bytes)".into());
+        row
+    });
+    let mut prev_bound_sec = 0;
+    for bucket_day in AGE_BUCKETS_DAY
+        .iter()
+        .map(|d| Some(*d))
+        .chain(std::iter::once(None))
+    {
+        let bound_sec = bucket_day.map(|d| d * 86400).unwrap_or(u64::MAX);
+        let (count, size) = orphan_objects
+            .iter()
+            .filter(|o| {
+                let age_sec = now_sec.saturating_sub(o.last_modified_sec);
+                age_sec >= prev_bound_sec && age_sec < bound_sec
+            })
+            .fold((0u64, 0u64), |(count, size), o| {
+                (count + 1, size + o.total_size)
+            });
+        let mut row = Row::new();
+        row.add_cell(
+            match bucket_day {
+                Some(d) => format!("< {} days", d),
+                None => format!(">= {} days", AGE_BUCKETS_DAY.last().unwrap()),
+            }
+            .into(),
+        );
+        row.add_cell(count.into());
+        row.add_cell(size.into());
+        table.add_row(row);
+        prev_bound_sec = bound_sec;
+    }
+    println!("--- Orphan Objects by Age ---");
+    println!("{table}");
+
+    let total_size: u64 = orphan_objects.iter().map(|o| o.total_size).sum();
+    let held_count = orphan_objects.iter().filter(|o| o.held).count();
+    println!(
+        "{} orphan objects in total, {} bytes, {} held back from GC",
+        orphan_objects.len(),
+        total_size,
+        held_count
+    );
+    Ok(())
+}
+
+pub async fn hold_objects(context: &CtlContext, object_ids: Vec<u64>) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    meta_client.update_object_holds(object_ids.clone()).await?;
+    println!("{} objects held back from GC", object_ids.len());
+    Ok(())
+}
@@ -28,6 +28,16 @@ pub async fn disable_commit_epoch(context: &CtlContext) -> anyhow::Result<()> {
     Ok(())
 }
 
+pub async fn checkpoint_now(context: &CtlContext) -> anyhow::Result<()> {
+    let meta_client = context.meta_client().await?;
+    let response = meta_client.risectl_checkpoint_hummock_version().await?;
+    println!(
+        "Hummock version checkpoint is created, covering {} new version deltas",
+        response.checkpointed_delta_log_num
+    );
+    Ok(())
+}
+
 pub async fn pause_version_checkpoint(context: &CtlContext) -> anyhow::Result<()> {
     let meta_client = context.meta_client().await?;
     meta_client
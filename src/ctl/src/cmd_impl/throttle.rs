@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use anyhow::Context;
 use risingwave_pb::meta::PbThrottleTarget;
 
 use crate::common::CtlContext;
@@ -22,9 +23,17 @@ pub async fn apply_throttle(
     kind: PbThrottleTarget,
     params: ThrottleCommandArgs,
 ) -> anyhow::Result<()> {
+    let rate = match params.rate.as_deref() {
+        None => None,
+        Some(s) if s.eq_ignore_ascii_case("none") => None,
+        Some(s) => Some(
+            s.parse::<u32>()
+                .with_context(|| format!("invalid rate \"{s}\", expect a number or `none`"))?,
+        ),
+    };
     let meta_client = context.meta_client().await?;
     meta_client
-        .apply_throttle(kind, params.id, params.rate)
+        .apply_throttle(kind, params.id, rate, None)
         .await?;
     Ok(())
 }
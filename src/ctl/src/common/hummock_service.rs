@@ -194,6 +194,8 @@ impl HummockServiceOpts {
             path: opts.data_directory,
             prefetch_buffer_capacity: opts.block_cache_capacity_mb * (1 << 20),
             max_prefetch_block_number: opts.max_prefetch_block_number,
+            backfill_read_bytes_per_sec: 0,
+            compaction_read_bytes_per_sec: 0,
             recent_filter: None,
             state_store_metrics: Arc::new(global_hummock_state_store_metrics(
                 MetricLevel::Disabled,
@@ -73,12 +73,82 @@ enum Commands {
     /// Dump the await-tree of compute nodes and compactors
     #[clap(visible_alias("trace"))]
     AwaitTree,
+    /// Commands for debugging
+    #[clap(subcommand)]
+    Debug(DebugCommands),
     // TODO(yuhao): profile other nodes
     /// Commands for profilng the compute nodes
     #[clap(subcommand)]
     Profile(ProfileCommands),
     #[clap(subcommand)]
     Throttle(ThrottleCommands),
+    /// Commands for exporting and importing the catalog
+    #[clap(subcommand)]
+    Catalog(CatalogCommands),
+}
+
+#[derive(Subcommand, Clone, Debug)]
+enum CatalogCommands {
+    /// Dump the catalog as a dependency-ordered DDL script for re-creating it in another cluster
+    Dump {
+        /// Format of the dump, currently only `sql`
+        #[clap(long, default_value = "sql")]
+        format: String,
+        /// Path of the output script; print to stdout if not specified
+        #[clap(long)]
+        output: Option<String>,
+    },
+    /// Diff a declarative object specification (YAML with a `database` and a list of DDL
+    /// statements under `objects`) against the current catalog and apply a minimal DDL
+    /// migration plan
+    Apply {
+        /// Path of the specification file
+        plan: String,
+        /// Host of the frontend node
+        #[clap(long, default_value = "127.0.0.1")]
+        host: String,
+        /// SQL port of the frontend node
+        #[clap(long, default_value_t = 4566)]
+        port: u16,
+        /// User to connect as
+        #[clap(long, default_value = "root")]
+        user: String,
+        /// Print the migration plan without executing it
+        #[clap(long, default_value_t = false)]
+        dry_run: bool,
+        /// Skip the confirmation prompt
+        #[clap(long, short = 'y', default_value_t = false)]
+        yes: bool,
+    },
+    /// Apply a script produced by `catalog dump` through a frontend node, skipping objects that
+    /// already exist
+    Import {
+        /// Path of the script to apply
+        input: String,
+        /// Host of the frontend node
+        #[clap(long, default_value = "127.0.0.1")]
+        host: String,
+        /// SQL port of the frontend node
+        #[clap(long, default_value_t = 4566)]
+        port: u16,
+        /// User to connect as
+        #[clap(long, default_value = "root")]
+        user: String,
+        /// Database of the initial connection
+        #[clap(long, default_value = "dev")]
+        database: String,
+    },
+}
+
+#[derive(Subcommand, Clone, Debug)]
+enum DebugCommands {
+    /// Collect diagnostics (configs, versions, event logs, stack traces and hummock version
+    /// summaries) from all nodes into a single tar.gz for support tickets
+    Bundle {
+        /// Path of the output archive
+        #[clap(long, default_value = "risingwave-debug-bundle.tar.gz")]
+        output: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -146,6 +216,20 @@ enum HummockCommands {
         #[clap(short, long = "prefix", required = false)]
         prefix: Option<String>,
     },
+    /// Report SST objects in the object store that are not referenced by any retained version,
+    /// without deleting anything. Objects newer than `sst_retention_time_sec` are excluded.
+    OrphanReport {
+        #[clap(short, long = "sst_retention_time_sec", default_value_t = 259200)]
+        sst_retention_time_sec: u64,
+        /// Also print each orphan object, in addition to the aggregated report.
+        #[clap(short, long, default_value_t = false)]
+        verbose: bool,
+    },
+    /// Hold the given objects back from GC, e.g. for manual inspection.
+    HoldObjects {
+        #[clap(long, value_delimiter = ',')]
+        object_ids: Vec<u64>,
+    },
     /// List pinned versions of each worker.
     ListPinnedVersions {},
     /// List all compaction groups.
@@ -196,6 +280,10 @@ enum HummockCommands {
         disable_auto_group_scheduling: Option<bool>,
         #[clap(long)]
         max_overlapping_level_size: Option<u64>,
+        #[clap(long)]
+        max_concurrent_task_count: Option<u32>,
+        #[clap(long)]
+        scheduling_weight: Option<u32>,
     },
     /// Split given compaction group into two. Moves the given tables to the new group.
     SplitCompactionGroup {
@@ -206,6 +294,9 @@ enum HummockCommands {
         #[clap(long, default_value_t = 0)]
         partition_vnode_count: u32,
     },
+    /// Trigger a version checkpoint immediately, beyond the periodic one, to prune the
+    /// delta log when it balloons.
+    CheckpointNow,
     /// Pause version checkpoint, which subsequently pauses GC of delta log and SST object.
     PauseVersionCheckpoint,
     /// Resume version checkpoint, which subsequently resumes GC of delta log and SST object.
@@ -217,6 +308,24 @@ enum HummockCommands {
         #[clap(short, long = "verbose", default_value_t = false)]
         verbose: bool,
     },
+    /// Dump an assigned compaction task (input SST list and group config) into a JSON bundle
+    /// for later replay.
+    DumpCompactionTask {
+        #[clap(short, long)]
+        task_id: u64,
+        #[clap(short, long)]
+        output: String,
+    },
+    /// Re-run the read path of a dumped compaction task against object storage in read-only
+    /// mode.
+    ReplayCompactionTask {
+        #[clap(short, long)]
+        bundle: String,
+        #[clap(short = 'd')]
+        data_dir: Option<String>,
+        #[clap(short, long = "use-new-object-prefix-strategy", default_value = "true")]
+        use_new_object_prefix_strategy: bool,
+    },
     GetCompactionScore {
         #[clap(long)]
         compaction_group_id: u64,
@@ -463,7 +572,8 @@ enum ThrottleCommands {
 #[derive(Clone, Debug, Args)]
 pub struct ThrottleCommandArgs {
     id: u32,
-    rate: Option<u32>,
+    /// The rate limit in rows per second, or `none` to remove the limit.
+    rate: Option<String>,
 }
 
 #[derive(Subcommand, Clone, Debug)]
@@ -576,6 +686,13 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             sst_retention_time_sec,
             prefix,
         }) => cmd_impl::hummock::trigger_full_gc(context, sst_retention_time_sec, prefix).await?,
+        Commands::Hummock(HummockCommands::OrphanReport {
+            sst_retention_time_sec,
+            verbose,
+        }) => cmd_impl::hummock::orphan_report(context, sst_retention_time_sec, verbose).await?,
+        Commands::Hummock(HummockCommands::HoldObjects { object_ids }) => {
+            cmd_impl::hummock::hold_objects(context, object_ids).await?
+        }
         Commands::Hummock(HummockCommands::ListPinnedVersions {}) => {
             list_pinned_versions(context).await?
         }
@@ -605,6 +722,8 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             sst_allowed_trivial_move_min_size,
             disable_auto_group_scheduling,
             max_overlapping_level_size,
+            max_concurrent_task_count,
+            scheduling_weight,
         }) => {
             cmd_impl::hummock::update_compaction_config(
                 context,
@@ -638,6 +757,8 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
                     sst_allowed_trivial_move_min_size,
                     disable_auto_group_scheduling,
                     max_overlapping_level_size,
+                    max_concurrent_task_count,
+                    scheduling_weight,
                 ),
             )
             .await?
@@ -655,6 +776,9 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             )
             .await?;
         }
+        Commands::Hummock(HummockCommands::CheckpointNow) => {
+            cmd_impl::hummock::checkpoint_now(context).await?;
+        }
         Commands::Hummock(HummockCommands::PauseVersionCheckpoint) => {
             cmd_impl::hummock::pause_version_checkpoint(context).await?;
         }
@@ -667,6 +791,21 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Hummock(HummockCommands::ListCompactionStatus { verbose }) => {
             cmd_impl::hummock::list_compaction_status(context, verbose).await?;
         }
+        Commands::Hummock(HummockCommands::DumpCompactionTask { task_id, output }) => {
+            cmd_impl::hummock::dump_compaction_task(context, task_id, output).await?;
+        }
+        Commands::Hummock(HummockCommands::ReplayCompactionTask {
+            bundle,
+            data_dir,
+            use_new_object_prefix_strategy,
+        }) => {
+            cmd_impl::hummock::replay_compaction_task(
+                bundle,
+                data_dir,
+                use_new_object_prefix_strategy,
+            )
+            .await?;
+        }
         Commands::Hummock(HummockCommands::GetCompactionScore {
             compaction_group_id,
         }) => {
@@ -829,6 +968,9 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
             cmd_impl::meta::graph_check(endpoint).await?
         }
         Commands::AwaitTree => cmd_impl::await_tree::dump(context).await?,
+        Commands::Debug(DebugCommands::Bundle { output }) => {
+            cmd_impl::debug::bundle(context, output).await?
+        }
         Commands::Profile(ProfileCommands::Cpu { sleep }) => {
             cmd_impl::profile::cpu_profile(context, sleep).await?
         }
@@ -849,6 +991,24 @@ async fn start_impl(opts: CliOpts, context: &CtlContext) -> Result<()> {
         Commands::Throttle(ThrottleCommands::Mv(args)) => {
             apply_throttle(context, risingwave_pb::meta::PbThrottleTarget::Mv, args).await?;
         }
+        Commands::Catalog(CatalogCommands::Dump { format, output }) => {
+            cmd_impl::catalog::dump(context, format, output).await?
+        }
+        Commands::Catalog(CatalogCommands::Apply {
+            plan,
+            host,
+            port,
+            user,
+            dry_run,
+            yes,
+        }) => cmd_impl::catalog::apply(context, plan, host, port, user, dry_run, yes).await?,
+        Commands::Catalog(CatalogCommands::Import {
+            input,
+            host,
+            port,
+            user,
+            database,
+        }) => cmd_impl::catalog::import(input, host, port, user, database).await?,
     }
     Ok(())
 }
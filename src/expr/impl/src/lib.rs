@@ -21,6 +21,7 @@
 //! ```
 
 #![allow(non_snake_case)] // for `ctor` generated code
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![feature(let_chains)]
 #![feature(assert_matches)]
 #![feature(iterator_try_collect)]
@@ -21,7 +21,7 @@ use itertools::Itertools;
 use risingwave_common::array::{ArrayImpl, DataChunk, ListRef, ListValue, StructRef, StructValue};
 use risingwave_common::cast;
 use risingwave_common::row::OwnedRow;
-use risingwave_common::types::{Int256, JsonbRef, MapRef, MapValue, ToText, F64};
+use risingwave_common::types::{Decimal, Int256, Int256Ref, JsonbRef, MapRef, MapValue, ToText, F64};
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_expr::expr::{build_func, Context, ExpressionBoxExt, InputRefExpression};
 use risingwave_expr::{function, ExprError, Result};
@@ -138,6 +138,22 @@ where
     elem.into()
 }
 
+/// Cast a decimal to int256, rounding ties away from zero like the decimal to bigint cast.
+#[function("cast(decimal) -> int256")]
+pub fn decimal_to_int256(elem: Decimal) -> Result<Int256> {
+    elem.try_into()
+        .map_err(|_| ExprError::CastOutOfRange("int256"))
+}
+
+/// Cast an int256 to decimal. The range of decimal is smaller than int256, so the cast may
+/// overflow.
+#[function("cast(int256) -> decimal")]
+pub fn int256_to_decimal(elem: Int256Ref<'_>) -> Result<Decimal> {
+    Int256::from(elem)
+        .try_into()
+        .map_err(|_| ExprError::CastOutOfRange("decimal"))
+}
+
 #[function("cast(varchar) -> boolean")]
 pub fn str_to_bool(input: &str) -> Result<bool> {
     cast::str_to_bool(input).map_err(|err| ExprError::Parse(err.into()))
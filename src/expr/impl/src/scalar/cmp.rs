@@ -15,23 +15,31 @@
 use std::fmt::Debug;
 
 use constant_time_eq::constant_time_eq;
-use risingwave_common::array::{Array, BoolArray};
+use risingwave_common::array::{Array, BoolArray, PrimitiveArray, PrimitiveArrayItemType};
 use risingwave_common::bitmap::Bitmap;
 use risingwave_common::row::Row;
-use risingwave_common::types::{Scalar, ScalarRef, ScalarRefImpl};
+use risingwave_common::types::{Decimal, Scalar, ScalarRef, ScalarRefImpl, Timestamp, Timestamptz};
 use risingwave_expr::function;
 
 #[function("equal(boolean, boolean) -> boolean", batch_fn = "boolarray_eq")]
-#[function("equal(*int, *int) -> boolean")]
-#[function("equal(decimal, decimal) -> boolean")]
+#[function("equal(int2, int2) -> boolean", batch_fn = "int2array_eq")]
+#[function("equal(int2, int4) -> boolean")]
+#[function("equal(int2, int8) -> boolean")]
+#[function("equal(int4, int2) -> boolean")]
+#[function("equal(int4, int4) -> boolean", batch_fn = "int4array_eq")]
+#[function("equal(int4, int8) -> boolean")]
+#[function("equal(int8, int2) -> boolean")]
+#[function("equal(int8, int4) -> boolean")]
+#[function("equal(int8, int8) -> boolean", batch_fn = "int8array_eq")]
+#[function("equal(decimal, decimal) -> boolean", batch_fn = "decimalarray_eq")]
 #[function("equal(*float, *float) -> boolean")]
 #[function("equal(int256, int256) -> boolean")]
 #[function("equal(serial, serial) -> boolean")]
 #[function("equal(date, date) -> boolean")]
 #[function("equal(time, time) -> boolean")]
 #[function("equal(interval, interval) -> boolean")]
-#[function("equal(timestamp, timestamp) -> boolean")]
-#[function("equal(timestamptz, timestamptz) -> boolean")]
+#[function("equal(timestamp, timestamp) -> boolean", batch_fn = "timestamparray_eq")]
+#[function("equal(timestamptz, timestamptz) -> boolean", batch_fn = "timestamptzarray_eq")]
 #[function("equal(date, timestamp) -> boolean")]
 #[function("equal(timestamp, date) -> boolean")]
 #[function("equal(time, interval) -> boolean")]
@@ -51,16 +59,24 @@ where
 }
 
 #[function("not_equal(boolean, boolean) -> boolean", batch_fn = "boolarray_ne")]
-#[function("not_equal(*int, *int) -> boolean")]
-#[function("not_equal(decimal, decimal) -> boolean")]
+#[function("not_equal(int2, int2) -> boolean", batch_fn = "int2array_ne")]
+#[function("not_equal(int2, int4) -> boolean")]
+#[function("not_equal(int2, int8) -> boolean")]
+#[function("not_equal(int4, int2) -> boolean")]
+#[function("not_equal(int4, int4) -> boolean", batch_fn = "int4array_ne")]
+#[function("not_equal(int4, int8) -> boolean")]
+#[function("not_equal(int8, int2) -> boolean")]
+#[function("not_equal(int8, int4) -> boolean")]
+#[function("not_equal(int8, int8) -> boolean", batch_fn = "int8array_ne")]
+#[function("not_equal(decimal, decimal) -> boolean", batch_fn = "decimalarray_ne")]
 #[function("not_equal(*float, *float) -> boolean")]
 #[function("not_equal(int256, int256) -> boolean")]
 #[function("not_equal(serial, serial) -> boolean")]
 #[function("not_equal(date, date) -> boolean")]
 #[function("not_equal(time, time) -> boolean")]
 #[function("not_equal(interval, interval) -> boolean")]
-#[function("not_equal(timestamp, timestamp) -> boolean")]
-#[function("not_equal(timestamptz, timestamptz) -> boolean")]
+#[function("not_equal(timestamp, timestamp) -> boolean", batch_fn = "timestamparray_ne")]
+#[function("not_equal(timestamptz, timestamptz) -> boolean", batch_fn = "timestamptzarray_ne")]
 #[function("not_equal(date, timestamp) -> boolean")]
 #[function("not_equal(timestamp, date) -> boolean")]
 #[function("not_equal(time, interval) -> boolean")]
@@ -83,16 +99,24 @@ where
     "greater_than_or_equal(boolean, boolean) -> boolean",
     batch_fn = "boolarray_ge"
 )]
-#[function("greater_than_or_equal(*int, *int) -> boolean")]
-#[function("greater_than_or_equal(decimal, decimal) -> boolean")]
+#[function("greater_than_or_equal(int2, int2) -> boolean", batch_fn = "int2array_ge")]
+#[function("greater_than_or_equal(int2, int4) -> boolean")]
+#[function("greater_than_or_equal(int2, int8) -> boolean")]
+#[function("greater_than_or_equal(int4, int2) -> boolean")]
+#[function("greater_than_or_equal(int4, int4) -> boolean", batch_fn = "int4array_ge")]
+#[function("greater_than_or_equal(int4, int8) -> boolean")]
+#[function("greater_than_or_equal(int8, int2) -> boolean")]
+#[function("greater_than_or_equal(int8, int4) -> boolean")]
+#[function("greater_than_or_equal(int8, int8) -> boolean", batch_fn = "int8array_ge")]
+#[function("greater_than_or_equal(decimal, decimal) -> boolean", batch_fn = "decimalarray_ge")]
 #[function("greater_than_or_equal(*float, *float) -> boolean")]
 #[function("greater_than_or_equal(serial, serial) -> boolean")]
 #[function("greater_than_or_equal(int256, int256) -> boolean")]
 #[function("greater_than_or_equal(date, date) -> boolean")]
 #[function("greater_than_or_equal(time, time) -> boolean")]
 #[function("greater_than_or_equal(interval, interval) -> boolean")]
-#[function("greater_than_or_equal(timestamp, timestamp) -> boolean")]
-#[function("greater_than_or_equal(timestamptz, timestamptz) -> boolean")]
+#[function("greater_than_or_equal(timestamp, timestamp) -> boolean", batch_fn = "timestamparray_ge")]
+#[function("greater_than_or_equal(timestamptz, timestamptz) -> boolean", batch_fn = "timestamptzarray_ge")]
 #[function("greater_than_or_equal(date, timestamp) -> boolean")]
 #[function("greater_than_or_equal(timestamp, date) -> boolean")]
 #[function("greater_than_or_equal(time, interval) -> boolean")]
@@ -111,16 +135,24 @@ where
 }
 
 #[function("greater_than(boolean, boolean) -> boolean", batch_fn = "boolarray_gt")]
-#[function("greater_than(*int, *int) -> boolean")]
-#[function("greater_than(decimal, decimal) -> boolean")]
+#[function("greater_than(int2, int2) -> boolean", batch_fn = "int2array_gt")]
+#[function("greater_than(int2, int4) -> boolean")]
+#[function("greater_than(int2, int8) -> boolean")]
+#[function("greater_than(int4, int2) -> boolean")]
+#[function("greater_than(int4, int4) -> boolean", batch_fn = "int4array_gt")]
+#[function("greater_than(int4, int8) -> boolean")]
+#[function("greater_than(int8, int2) -> boolean")]
+#[function("greater_than(int8, int4) -> boolean")]
+#[function("greater_than(int8, int8) -> boolean", batch_fn = "int8array_gt")]
+#[function("greater_than(decimal, decimal) -> boolean", batch_fn = "decimalarray_gt")]
 #[function("greater_than(*float, *float) -> boolean")]
 #[function("greater_than(serial, serial) -> boolean")]
 #[function("greater_than(int256, int256) -> boolean")]
 #[function("greater_than(date, date) -> boolean")]
 #[function("greater_than(time, time) -> boolean")]
 #[function("greater_than(interval, interval) -> boolean")]
-#[function("greater_than(timestamp, timestamp) -> boolean")]
-#[function("greater_than(timestamptz, timestamptz) -> boolean")]
+#[function("greater_than(timestamp, timestamp) -> boolean", batch_fn = "timestamparray_gt")]
+#[function("greater_than(timestamptz, timestamptz) -> boolean", batch_fn = "timestamptzarray_gt")]
 #[function("greater_than(date, timestamp) -> boolean")]
 #[function("greater_than(timestamp, date) -> boolean")]
 #[function("greater_than(time, interval) -> boolean")]
@@ -142,16 +174,24 @@ where
     "less_than_or_equal(boolean, boolean) -> boolean",
     batch_fn = "boolarray_le"
 )]
-#[function("less_than_or_equal(*int, *int) -> boolean")]
-#[function("less_than_or_equal(decimal, decimal) -> boolean")]
+#[function("less_than_or_equal(int2, int2) -> boolean", batch_fn = "int2array_le")]
+#[function("less_than_or_equal(int2, int4) -> boolean")]
+#[function("less_than_or_equal(int2, int8) -> boolean")]
+#[function("less_than_or_equal(int4, int2) -> boolean")]
+#[function("less_than_or_equal(int4, int4) -> boolean", batch_fn = "int4array_le")]
+#[function("less_than_or_equal(int4, int8) -> boolean")]
+#[function("less_than_or_equal(int8, int2) -> boolean")]
+#[function("less_than_or_equal(int8, int4) -> boolean")]
+#[function("less_than_or_equal(int8, int8) -> boolean", batch_fn = "int8array_le")]
+#[function("less_than_or_equal(decimal, decimal) -> boolean", batch_fn = "decimalarray_le")]
 #[function("less_than_or_equal(*float, *float) -> boolean")]
 #[function("less_than_or_equal(serial, serial) -> boolean")]
 #[function("less_than_or_equal(int256, int256) -> boolean")]
 #[function("less_than_or_equal(date, date) -> boolean")]
 #[function("less_than_or_equal(time, time) -> boolean")]
 #[function("less_than_or_equal(interval, interval) -> boolean")]
-#[function("less_than_or_equal(timestamp, timestamp) -> boolean")]
-#[function("less_than_or_equal(timestamptz, timestamptz) -> boolean")]
+#[function("less_than_or_equal(timestamp, timestamp) -> boolean", batch_fn = "timestamparray_le")]
+#[function("less_than_or_equal(timestamptz, timestamptz) -> boolean", batch_fn = "timestamptzarray_le")]
 #[function("less_than_or_equal(date, timestamp) -> boolean")]
 #[function("less_than_or_equal(timestamp, date) -> boolean")]
 #[function("less_than_or_equal(time, interval) -> boolean")]
@@ -170,16 +210,24 @@ where
 }
 
 #[function("less_than(boolean, boolean) -> boolean", batch_fn = "boolarray_lt")]
-#[function("less_than(*int, *int) -> boolean")]
-#[function("less_than(decimal, decimal) -> boolean")]
+#[function("less_than(int2, int2) -> boolean", batch_fn = "int2array_lt")]
+#[function("less_than(int2, int4) -> boolean")]
+#[function("less_than(int2, int8) -> boolean")]
+#[function("less_than(int4, int2) -> boolean")]
+#[function("less_than(int4, int4) -> boolean", batch_fn = "int4array_lt")]
+#[function("less_than(int4, int8) -> boolean")]
+#[function("less_than(int8, int2) -> boolean")]
+#[function("less_than(int8, int4) -> boolean")]
+#[function("less_than(int8, int8) -> boolean", batch_fn = "int8array_lt")]
+#[function("less_than(decimal, decimal) -> boolean", batch_fn = "decimalarray_lt")]
 #[function("less_than(*float, *float) -> boolean")]
 #[function("less_than(serial, serial) -> boolean")]
 #[function("less_than(int256, int256) -> boolean")]
 #[function("less_than(date, date) -> boolean")]
 #[function("less_than(time, time) -> boolean")]
 #[function("less_than(interval, interval) -> boolean")]
-#[function("less_than(timestamp, timestamp) -> boolean")]
-#[function("less_than(timestamptz, timestamptz) -> boolean")]
+#[function("less_than(timestamp, timestamp) -> boolean", batch_fn = "timestamparray_lt")]
+#[function("less_than(timestamptz, timestamptz) -> boolean", batch_fn = "timestamptzarray_lt")]
 #[function("less_than(date, timestamp) -> boolean")]
 #[function("less_than(timestamp, date) -> boolean")]
 #[function("less_than(time, interval) -> boolean")]
@@ -418,6 +466,105 @@ fn boolarray_is_not_false(a: &BoolArray) -> BoolArray {
     BoolArray::new(a.data() | !a.null_bitmap(), Bitmap::ones(a.len()))
 }
 
+
+/// Compare two primitive arrays element-wise into a `BoolArray`.
+///
+/// Comparing the raw value slices compiles down to a tight loop that LLVM can
+/// auto-vectorize, and the resulting `bool`s are packed into the data bitmap
+/// with SIMD. Values at null positions are compared as well, but masked out by
+/// the null bitmap afterwards.
+fn primitive_cmp<T: PrimitiveArrayItemType>(
+    l: &PrimitiveArray<T>,
+    r: &PrimitiveArray<T>,
+    f: impl Fn(&T, &T) -> bool,
+) -> BoolArray {
+    // allow using `zip` for performance: the raw slices have the same length
+    #[allow(clippy::disallowed_methods)]
+    let bools: Vec<bool> = (l.as_slice().iter())
+        .zip(r.as_slice())
+        .map(|(a, b)| f(a, b))
+        .collect();
+    BoolArray::new(
+        Bitmap::from_bool_slice(&bools),
+        l.null_bitmap() & r.null_bitmap(),
+    )
+}
+
+/// Generates a batch comparison kernel over `PrimitiveArray<$ty>`.
+///
+/// When the `simd` feature is enabled and the element type supports it, the
+/// kernel compares with explicit portable SIMD instead of relying on
+/// auto-vectorization of the scalar loop.
+macro_rules! gen_cmp_kernel {
+    ($fn_name:ident, $ty:ty, $simd_fn:ident, $op:tt) => {
+        #[cfg(feature = "simd")]
+        fn $fn_name(l: &PrimitiveArray<$ty>, r: &PrimitiveArray<$ty>) -> BoolArray {
+            use std::simd::prelude::*;
+            const LANES: usize = 8;
+            let (lhs, rhs) = (l.as_slice(), r.as_slice());
+            let mut bools = vec![false; lhs.len()];
+            let mut i = 0;
+            while i + LANES <= lhs.len() {
+                let mask = Simd::<$ty, LANES>::from_slice(&lhs[i..i + LANES])
+                    .$simd_fn(Simd::from_slice(&rhs[i..i + LANES]));
+                bools[i..i + LANES].copy_from_slice(&mask.to_array());
+                i += LANES;
+            }
+            for j in i..lhs.len() {
+                bools[j] = lhs[j] $op rhs[j];
+            }
+            BoolArray::new(
+                Bitmap::from_bool_slice(&bools),
+                l.null_bitmap() & r.null_bitmap(),
+            )
+        }
+        #[cfg(not(feature = "simd"))]
+        gen_cmp_kernel!($fn_name, $ty, $op);
+    };
+    ($fn_name:ident, $ty:ty, $op:tt) => {
+        fn $fn_name(l: &PrimitiveArray<$ty>, r: &PrimitiveArray<$ty>) -> BoolArray {
+            primitive_cmp(l, r, |a, b| a $op b)
+        }
+    };
+}
+
+gen_cmp_kernel!(int2array_eq, i16, simd_eq, ==);
+gen_cmp_kernel!(int2array_ne, i16, simd_ne, !=);
+gen_cmp_kernel!(int2array_lt, i16, simd_lt, <);
+gen_cmp_kernel!(int2array_le, i16, simd_le, <=);
+gen_cmp_kernel!(int2array_gt, i16, simd_gt, >);
+gen_cmp_kernel!(int2array_ge, i16, simd_ge, >=);
+gen_cmp_kernel!(int4array_eq, i32, simd_eq, ==);
+gen_cmp_kernel!(int4array_ne, i32, simd_ne, !=);
+gen_cmp_kernel!(int4array_lt, i32, simd_lt, <);
+gen_cmp_kernel!(int4array_le, i32, simd_le, <=);
+gen_cmp_kernel!(int4array_gt, i32, simd_gt, >);
+gen_cmp_kernel!(int4array_ge, i32, simd_ge, >=);
+gen_cmp_kernel!(int8array_eq, i64, simd_eq, ==);
+gen_cmp_kernel!(int8array_ne, i64, simd_ne, !=);
+gen_cmp_kernel!(int8array_lt, i64, simd_lt, <);
+gen_cmp_kernel!(int8array_le, i64, simd_le, <=);
+gen_cmp_kernel!(int8array_gt, i64, simd_gt, >);
+gen_cmp_kernel!(int8array_ge, i64, simd_ge, >=);
+gen_cmp_kernel!(decimalarray_eq, Decimal, ==);
+gen_cmp_kernel!(decimalarray_ne, Decimal, !=);
+gen_cmp_kernel!(decimalarray_lt, Decimal, <);
+gen_cmp_kernel!(decimalarray_le, Decimal, <=);
+gen_cmp_kernel!(decimalarray_gt, Decimal, >);
+gen_cmp_kernel!(decimalarray_ge, Decimal, >=);
+gen_cmp_kernel!(timestamparray_eq, Timestamp, ==);
+gen_cmp_kernel!(timestamparray_ne, Timestamp, !=);
+gen_cmp_kernel!(timestamparray_lt, Timestamp, <);
+gen_cmp_kernel!(timestamparray_le, Timestamp, <=);
+gen_cmp_kernel!(timestamparray_gt, Timestamp, >);
+gen_cmp_kernel!(timestamparray_ge, Timestamp, >=);
+gen_cmp_kernel!(timestamptzarray_eq, Timestamptz, ==);
+gen_cmp_kernel!(timestamptzarray_ne, Timestamptz, !=);
+gen_cmp_kernel!(timestamptzarray_lt, Timestamptz, <);
+gen_cmp_kernel!(timestamptzarray_le, Timestamptz, <=);
+gen_cmp_kernel!(timestamptzarray_gt, Timestamptz, >);
+gen_cmp_kernel!(timestamptzarray_ge, Timestamptz, >=);
+
 fn batch_is_null(a: &impl Array) -> BoolArray {
     BoolArray::new(!a.null_bitmap(), Bitmap::ones(a.len()))
 }
@@ -440,6 +587,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_primitive_cmp_kernels() {
+        use risingwave_common::array::I32Array;
+
+        let l: I32Array = [Some(1), None, Some(3), Some(4)].into_iter().collect();
+        let r: I32Array = [Some(2), Some(2), None, Some(4)].into_iter().collect();
+        assert_eq!(
+            int4array_lt(&l, &r).iter().collect::<Vec<_>>(),
+            vec![Some(true), None, None, Some(false)]
+        );
+        assert_eq!(
+            int4array_eq(&l, &r).iter().collect::<Vec<_>>(),
+            vec![Some(false), None, None, Some(true)]
+        );
+    }
+
     #[test]
     fn test_comparison() {
         assert!(general_eq::<Decimal, i32, Decimal>(dec("1.0"), 1));
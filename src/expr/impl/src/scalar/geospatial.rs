@@ -0,0 +1,147 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use risingwave_common::types::F64;
+use risingwave_expr::{function, ExprError, Result};
+
+/// Parses a WKT `POINT(x y)` literal into its coordinates.
+///
+/// Only 2-dimensional points are supported for now. Other WKT geometry types
+/// (e.g. `LINESTRING`, `POLYGON`) are rejected.
+fn parse_point(wkt: &str) -> Result<(f64, f64)> {
+    let invalid = || ExprError::InvalidParam {
+        name: "wkt",
+        reason: format!("Invalid WKT point: {}", wkt).into(),
+    };
+    let s = wkt.trim();
+    let rest = s
+        .get(..5)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("POINT"))
+        .map(|_| s[5..].trim_start())
+        .ok_or_else(invalid)?;
+    let inner = rest
+        .strip_prefix('(')
+        .and_then(|r| r.strip_suffix(')'))
+        .ok_or_else(invalid)?;
+    let mut coords = inner.split_whitespace();
+    let x = coords
+        .next()
+        .and_then(|c| c.parse::<f64>().ok())
+        .ok_or_else(invalid)?;
+    let y = coords
+        .next()
+        .and_then(|c| c.parse::<f64>().ok())
+        .ok_or_else(invalid)?;
+    if coords.next().is_some() {
+        return Err(invalid());
+    }
+    Ok((x, y))
+}
+
+/// Constructs a point from the given coordinates, in WKT representation.
+///
+/// # Example
+///
+/// ```slt
+/// query T
+/// select st_point(1.5, 2.5);
+/// ----
+/// POINT(1.5 2.5)
+/// ```
+#[function("st_point(float8, float8) -> varchar")]
+pub fn st_point(x: F64, y: F64, writer: &mut impl Write) {
+    write!(writer, "POINT({} {})", x.0, y.0).unwrap();
+}
+
+/// Returns the X coordinate of a WKT point.
+///
+/// # Example
+///
+/// ```slt
+/// query R
+/// select st_x('POINT(1.5 2.5)');
+/// ----
+/// 1.5
+/// ```
+#[function("st_x(varchar) -> float8")]
+pub fn st_x(wkt: &str) -> Result<F64> {
+    let (x, _) = parse_point(wkt)?;
+    Ok(x.into())
+}
+
+/// Returns the Y coordinate of a WKT point.
+///
+/// # Example
+///
+/// ```slt
+/// query R
+/// select st_y('POINT(1.5 2.5)');
+/// ----
+/// 2.5
+/// ```
+#[function("st_y(varchar) -> float8")]
+pub fn st_y(wkt: &str) -> Result<F64> {
+    let (_, y) = parse_point(wkt)?;
+    Ok(y.into())
+}
+
+/// Returns the Euclidean distance between two WKT points on a plane.
+///
+/// # Example
+///
+/// ```slt
+/// query R
+/// select st_distance('POINT(0 0)', 'POINT(3 4)');
+/// ----
+/// 5
+/// ```
+#[function("st_distance(varchar, varchar) -> float8")]
+pub fn st_distance(lhs: &str, rhs: &str) -> Result<F64> {
+    let (x1, y1) = parse_point(lhs)?;
+    let (x2, y2) = parse_point(rhs)?;
+    Ok(f64::hypot(x2 - x1, y2 - y1).into())
+}
+
+/// Returns whether two WKT points are within the given planar distance of each
+/// other. This is the primitive for streaming geofencing queries.
+///
+/// # Example
+///
+/// ```slt
+/// query B
+/// select st_dwithin('POINT(0 0)', 'POINT(3 4)', 5);
+/// ----
+/// t
+/// ```
+#[function("st_dwithin(varchar, varchar, float8) -> boolean")]
+pub fn st_dwithin(lhs: &str, rhs: &str, distance: F64) -> Result<bool> {
+    Ok(st_distance(lhs, rhs)? <= distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_point() {
+        assert_eq!(parse_point("POINT(1 2)").unwrap(), (1.0, 2.0));
+        assert_eq!(parse_point("  point ( -1.5 2e3 )  ").unwrap(), (-1.5, 2e3));
+        assert!(parse_point("POINT(1)").is_err());
+        assert!(parse_point("POINT(1 2 3)").is_err());
+        assert!(parse_point("LINESTRING(0 0, 1 1)").is_err());
+        assert!(parse_point("POINT(a b)").is_err());
+    }
+}
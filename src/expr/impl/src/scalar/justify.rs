@@ -0,0 +1,62 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Interval;
+use risingwave_expr::{function, ExprError, Result};
+
+/// Adjusts the interval so 30-day time periods are represented as months.
+///
+/// # Example
+///
+/// ```slt
+/// query T
+/// select justify_days(interval '35 days');
+/// ----
+/// 1 mon 5 days
+/// ```
+#[function("justify_days(interval) -> interval")]
+pub fn justify_days(v: Interval) -> Result<Interval> {
+    v.justify_day().ok_or(ExprError::NumericOutOfRange)
+}
+
+/// Adjusts the interval so 24-hour time periods are represented as days.
+///
+/// # Example
+///
+/// ```slt
+/// query T
+/// select justify_hours(interval '27 hours');
+/// ----
+/// 1 day 03:00:00
+/// ```
+#[function("justify_hours(interval) -> interval")]
+pub fn justify_hours(v: Interval) -> Result<Interval> {
+    v.justify_hour().ok_or(ExprError::NumericOutOfRange)
+}
+
+/// Adjusts the interval using `justify_days` and `justify_hours`, with additional sign
+/// adjustments so that all units have the same sign.
+///
+/// # Example
+///
+/// ```slt
+/// query T
+/// select justify_interval(interval '1 mon -1 hour');
+/// ----
+/// 29 days 23:00:00
+/// ```
+#[function("justify_interval(interval) -> interval")]
+pub fn justify_interval(v: Interval) -> Result<Interval> {
+    v.justify_interval().ok_or(ExprError::NumericOutOfRange)
+}
@@ -47,6 +47,7 @@ mod extract;
 mod field;
 mod format;
 mod format_type;
+mod geospatial;
 mod hmac;
 mod in_;
 mod int256;
@@ -60,6 +61,7 @@ mod jsonb_object;
 mod jsonb_path;
 mod jsonb_record;
 mod jsonb_set;
+mod justify;
 mod length;
 mod lower;
 mod make_time;
@@ -80,6 +82,7 @@ mod substr;
 mod timestamptz;
 mod to_char;
 mod to_jsonb;
+mod vector_distance;
 mod vnode;
 pub use to_jsonb::*;
 mod encrypt;
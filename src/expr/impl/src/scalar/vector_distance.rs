@@ -0,0 +1,111 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::ListRef;
+use risingwave_common::types::F64;
+use risingwave_expr::{function, ExprError, Result};
+
+/// Folds over the element pairs of two equal-dimension float8 vectors.
+///
+/// Vectors must have the same dimension and must not contain NULL elements.
+fn fold_pairs<T>(
+    lhs: ListRef<'_>,
+    rhs: ListRef<'_>,
+    init: T,
+    mut f: impl FnMut(T, f64, f64) -> T,
+) -> Result<T> {
+    if lhs.len() != rhs.len() {
+        return Err(ExprError::InvalidParam {
+            name: "vector",
+            reason: format!(
+                "different vector dimensions {} and {}",
+                lhs.len(),
+                rhs.len()
+            )
+            .into(),
+        });
+    }
+    let mut acc = init;
+    for (a, b) in lhs.iter().zip(rhs.iter()) {
+        let (Some(a), Some(b)) = (a, b) else {
+            return Err(ExprError::InvalidParam {
+                name: "vector",
+                reason: "vector must not contain NULL elements".into(),
+            });
+        };
+        let a: F64 = a.try_into()?;
+        let b: F64 = b.try_into()?;
+        acc = f(acc, a.0, b.0);
+    }
+    Ok(acc)
+}
+
+/// Returns the Euclidean (L2) distance between two float8 vectors.
+///
+/// # Example
+///
+/// ```slt
+/// query R
+/// select l2_distance(array[0, 0]::float8[], array[3, 4]::float8[]);
+/// ----
+/// 5
+/// ```
+#[function("l2_distance(float8[], float8[]) -> float8")]
+pub fn l2_distance(lhs: ListRef<'_>, rhs: ListRef<'_>) -> Result<F64> {
+    let sum = fold_pairs(lhs, rhs, 0.0, |acc, a, b| acc + (a - b) * (a - b))?;
+    Ok(sum.sqrt().into())
+}
+
+/// Returns the inner product of two float8 vectors.
+///
+/// # Example
+///
+/// ```slt
+/// query R
+/// select inner_product(array[1, 2]::float8[], array[3, 4]::float8[]);
+/// ----
+/// 11
+/// ```
+#[function("inner_product(float8[], float8[]) -> float8")]
+pub fn inner_product(lhs: ListRef<'_>, rhs: ListRef<'_>) -> Result<F64> {
+    Ok(fold_pairs(lhs, rhs, 0.0, |acc, a, b| acc + a * b)?.into())
+}
+
+/// Returns the cosine distance (1 - cosine similarity) between two float8
+/// vectors. Errors if either vector has zero magnitude.
+///
+/// # Example
+///
+/// ```slt
+/// query R
+/// select cosine_distance(array[1, 0]::float8[], array[0, 1]::float8[]);
+/// ----
+/// 1
+/// ```
+#[function("cosine_distance(float8[], float8[]) -> float8")]
+pub fn cosine_distance(lhs: ListRef<'_>, rhs: ListRef<'_>) -> Result<F64> {
+    let (dot, norm_l, norm_r) = fold_pairs(
+        lhs,
+        rhs,
+        (0.0, 0.0, 0.0),
+        |(dot, norm_l, norm_r), a, b| (dot + a * b, norm_l + a * a, norm_r + b * b),
+    )?;
+    if norm_l == 0.0 || norm_r == 0.0 {
+        return Err(ExprError::InvalidParam {
+            name: "vector",
+            reason: "cosine distance with zero vector is undefined".into(),
+        });
+    }
+    Ok((1.0 - dot / (norm_l.sqrt() * norm_r.sqrt())).into())
+}
@@ -224,6 +224,9 @@ impl Binder {
                 ("make_time", raw_call(ExprType::MakeTime)),
                 ("make_timestamp", raw_call(ExprType::MakeTimestamp)),
                 ("make_timestamptz", raw_call(ExprType::MakeTimestamptz)),
+                ("justify_days", raw_call(ExprType::JustifyDays)),
+                ("justify_hours", raw_call(ExprType::JustifyHours)),
+                ("justify_interval", raw_call(ExprType::JustifyInterval)),
                 ("timezone", rewrite(ExprType::AtTimeZone, |mut inputs|{
                     if inputs.len() == 2 {
                         inputs.swap(0, 1);
@@ -422,6 +425,16 @@ impl Binder {
                 ("map_delete", raw_call(ExprType::MapDelete)),
                 ("map_insert", raw_call(ExprType::MapInsert)),
                 ("map_length", raw_call(ExprType::MapLength)),
+                // Geospatial functions
+                ("st_point", raw_call(ExprType::StPoint)),
+                ("st_x", raw_call(ExprType::StX)),
+                ("st_y", raw_call(ExprType::StY)),
+                ("st_distance", raw_call(ExprType::StDistance)),
+                ("st_dwithin", raw_call(ExprType::StDwithin)),
+                // Vector similarity functions
+                ("l2_distance", raw_call(ExprType::L2Distance)),
+                ("cosine_distance", raw_call(ExprType::CosineDistance)),
+                ("inner_product", raw_call(ExprType::InnerProduct)),
                 // Functions that return a constant value
                 ("pi", pi()),
                 // greatest and least
@@ -600,7 +613,7 @@ impl Binder {
                 ("format_type", raw_call(ExprType::FormatType)),
                 ("pg_table_is_visible", raw_literal(ExprImpl::literal_bool(true))),
                 ("pg_type_is_visible", raw_literal(ExprImpl::literal_bool(true))),
-                ("pg_get_constraintdef", raw_literal(ExprImpl::literal_null(DataType::Varchar))),
+                ("pg_get_constraintdef", raw_call(ExprType::PgGetConstraintdef)),
                 ("pg_get_partkeydef", raw_literal(ExprImpl::literal_null(DataType::Varchar))),
                 ("pg_encoding_to_char", raw_literal(ExprImpl::literal_varchar("UTF8".into()))),
                 ("has_database_privilege", raw_literal(ExprImpl::literal_bool(true))),
@@ -654,15 +667,8 @@ impl Binder {
                     // FIXME: the session id is not global unique in multi-frontend env.
                     Ok(ExprImpl::literal_int(binder.session_id.0))
                 })),
-                ("pg_cancel_backend", guard_by_len(1, raw(|_binder, _inputs| {
-                        // TODO: implement real cancel rather than just return false as an workaround.
-                        Ok(ExprImpl::literal_bool(false))
-                }))),
-                ("pg_terminate_backend", guard_by_len(1, raw(|_binder, _inputs|{
-                        // TODO: implement real terminate rather than just return false as an
-                        // workaround.
-                        Ok(ExprImpl::literal_bool(false))
-                }))),
+                ("pg_cancel_backend", raw_call(ExprType::PgCancelBackend)),
+                ("pg_terminate_backend", raw_call(ExprType::PgTerminateBackend)),
                 ("pg_tablespace_location", guard_by_len(1, raw_literal(ExprImpl::literal_null(DataType::Varchar)))),
                 ("pg_postmaster_start_time", guard_by_len(0, raw(|_binder, _inputs|{
                     let server_start_time = risingwave_variables::get_server_start_time();
@@ -679,6 +685,9 @@ impl Binder {
                 ("pg_is_in_recovery", raw_call(ExprType::PgIsInRecovery)),
                 ("rw_recovery_status", raw_call(ExprType::RwRecoveryStatus)),
                 ("rw_epoch_to_ts", raw_call(ExprType::RwEpochToTs)),
+                ("rw_epoch_to_timestamp", raw_call(ExprType::RwEpochToTs)),
+                ("rw_timestamp_to_epoch", raw_call(ExprType::RwTimestampToEpoch)),
+                ("pause_and_snapshot", raw_call(ExprType::PauseAndSnapshot)),
                 // internal
                 ("rw_vnode", raw_call(ExprType::VnodeUser)),
                 ("rw_test_paid_tier", raw_call(ExprType::TestPaidTier)), // for testing purposes
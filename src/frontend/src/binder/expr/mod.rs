@@ -953,8 +953,10 @@ impl Binder {
     }
 
     pub fn bind_collate(&mut self, expr: Expr, collation: ObjectName) -> Result<ExprImpl> {
-        if !["C", "POSIX"].contains(&collation.real_value().as_str()) {
-            bail_not_implemented!("Collate collation other than `C` or `POSIX` is not implemented");
+        if !["C", "POSIX", "default", "ucs_basic"].contains(&collation.real_value().as_str()) {
+            bail_not_implemented!(
+                "Collate collation other than the built-in byte-order collations (see `pg_collation`) is not implemented"
+            );
         }
 
         let bound_inner = self.bind_expr_inner(expr)?;
@@ -31,6 +31,8 @@ pub struct DatabaseCatalog {
     schema_by_name: HashMap<String, SchemaCatalog>,
     schema_name_by_id: HashMap<SchemaId, String>,
     pub owner: u32,
+    /// Overrides the system-wide `checkpoint_frequency` for streaming jobs in this database.
+    pub barrier_checkpoint_frequency: Option<u64>,
 }
 
 impl DatabaseCatalog {
@@ -157,6 +159,7 @@ impl From<&PbDatabase> for DatabaseCatalog {
             schema_by_name: HashMap::new(),
             schema_name_by_id: HashMap::new(),
             owner: db.owner,
+            barrier_checkpoint_frequency: db.barrier_checkpoint_frequency,
         }
     }
 }
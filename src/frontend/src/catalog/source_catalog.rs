@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::catalog::{ColumnCatalog, SourceVersionId};
+use risingwave_common::catalog::{max_column_id, ColumnCatalog, SourceVersionId};
 use risingwave_common::util::epoch::Epoch;
 use risingwave_connector::{WithOptionsSecResolved, WithPropertiesExt};
 use risingwave_pb::catalog::source::OptionalAssociatedTableId;
@@ -45,6 +45,9 @@ pub struct SourceCatalog {
     pub created_at_cluster_version: Option<String>,
     pub initialized_at_cluster_version: Option<String>,
     pub rate_limit: Option<u32>,
+    /// The persisted per-source column ID allocator. `None` for sources created before this
+    /// field was introduced.
+    pub next_column_id: Option<ColumnId>,
 }
 
 impl SourceCatalog {
@@ -79,9 +82,19 @@ impl SourceCatalog {
             initialized_at_cluster_version: self.initialized_at_cluster_version.clone(),
             secret_refs,
             rate_limit: self.rate_limit,
+            next_column_id: self.next_column_id.map(|id| id.get_id()),
         }
     }
 
+    /// Returns the next column ID to assign for a schema change.
+    ///
+    /// Sources created before the allocator was persisted fall back to one past the maximum
+    /// existing column ID, which is only correct as long as no column has ever been dropped.
+    pub fn next_column_id_for_schema_change(&self) -> ColumnId {
+        self.next_column_id
+            .unwrap_or_else(|| max_column_id(&self.columns).next())
+    }
+
     /// Get a reference to the source catalog's version.
     pub fn version(&self) -> SourceVersionId {
         self.version
@@ -142,6 +155,7 @@ impl From<&PbSource> for SourceCatalog {
             created_at_cluster_version: prost.created_at_cluster_version.clone(),
             initialized_at_cluster_version: prost.initialized_at_cluster_version.clone(),
             rate_limit,
+            next_column_id: prost.next_column_id.map(ColumnId::from),
         }
     }
 }
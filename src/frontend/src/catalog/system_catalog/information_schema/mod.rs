@@ -14,6 +14,9 @@
 
 mod columns;
 mod key_column_usage;
+mod parameters;
+mod referential_constraints;
+mod routines;
 mod schemata;
 mod table_constraints;
 mod tables;
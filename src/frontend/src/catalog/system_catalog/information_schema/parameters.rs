@@ -0,0 +1,45 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+/// The view `parameters` contains one row for each parameter of each function in the current
+/// database. All parameters are input parameters, as output and variadic parameters are not
+/// supported.
+/// Ref: [`https://www.postgresql.org/docs/current/infoschema-parameters.html`]
+#[system_catalog(
+    view,
+    "information_schema.parameters",
+    "SELECT CURRENT_DATABASE() AS specific_catalog,
+            s.name AS specific_schema,
+            f.name || '_' || f.id AS specific_name,
+            a.ordinal_position::int AS ordinal_position,
+            'IN' AS parameter_mode,
+            t.name AS data_type
+        FROM rw_catalog.rw_functions f
+        CROSS JOIN unnest(f.arg_type_ids) WITH ORDINALITY AS a(arg_type_id, ordinal_position)
+        JOIN rw_catalog.rw_schemas s ON f.schema_id = s.id
+        LEFT JOIN rw_catalog.rw_types t ON a.arg_type_id = t.id
+        ORDER BY specific_catalog, specific_schema, specific_name, ordinal_position"
+)]
+#[derive(Fields)]
+struct Parameters {
+    specific_catalog: String,
+    specific_schema: String,
+    specific_name: String,
+    ordinal_position: i32,
+    parameter_mode: String,
+    data_type: String,
+}
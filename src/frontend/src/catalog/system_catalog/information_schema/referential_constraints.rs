@@ -0,0 +1,47 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+/// The view `referential_constraints` contains all referential (foreign key) constraints in the
+/// current database. It is always empty since foreign key constraints are not supported, and
+/// exists only so that ORM introspection queries do not fail.
+/// Ref: [`https://www.postgresql.org/docs/current/infoschema-referential-constraints.html`]
+#[system_catalog(
+    view,
+    "information_schema.referential_constraints",
+    "SELECT NULL::varchar AS constraint_catalog,
+            NULL::varchar AS constraint_schema,
+            NULL::varchar AS constraint_name,
+            NULL::varchar AS unique_constraint_catalog,
+            NULL::varchar AS unique_constraint_schema,
+            NULL::varchar AS unique_constraint_name,
+            NULL::varchar AS match_option,
+            NULL::varchar AS update_rule,
+            NULL::varchar AS delete_rule
+        WHERE 1 != 1"
+)]
+#[derive(Fields)]
+struct ReferentialConstraints {
+    constraint_catalog: String,
+    constraint_schema: String,
+    constraint_name: String,
+    unique_constraint_catalog: String,
+    unique_constraint_schema: String,
+    unique_constraint_name: String,
+    match_option: String,
+    update_rule: String,
+    delete_rule: String,
+}
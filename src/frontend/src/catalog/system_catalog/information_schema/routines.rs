@@ -0,0 +1,61 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+/// The view `routines` contains all functions in the current database.
+/// Ref: [`https://www.postgresql.org/docs/current/infoschema-routines.html`]
+///
+/// Only the columns used by common ORM introspection queries are provided. The `specific_name`
+/// is made unique by appending the function id, following the PG convention of `name_oid`.
+#[system_catalog(
+    view,
+    "information_schema.routines",
+    "SELECT CURRENT_DATABASE() AS specific_catalog,
+            s.name AS specific_schema,
+            f.name || '_' || f.id AS specific_name,
+            CURRENT_DATABASE() AS routine_catalog,
+            s.name AS routine_schema,
+            f.name AS routine_name,
+            'FUNCTION' AS routine_type,
+            t.name AS data_type,
+            'EXTERNAL' AS routine_body,
+            NULL::varchar AS routine_definition,
+            f.link AS external_name,
+            upper(f.language) AS external_language,
+            'GENERAL' AS parameter_style,
+            'NO' AS is_deterministic
+        FROM rw_catalog.rw_functions f
+        JOIN rw_catalog.rw_schemas s ON f.schema_id = s.id
+        LEFT JOIN rw_catalog.rw_types t ON f.return_type_id = t.id
+        ORDER BY specific_catalog, specific_schema, routine_name"
+)]
+#[derive(Fields)]
+struct Routines {
+    specific_catalog: String,
+    specific_schema: String,
+    specific_name: String,
+    routine_catalog: String,
+    routine_schema: String,
+    routine_name: String,
+    routine_type: String,
+    data_type: String,
+    routine_body: String,
+    routine_definition: Option<String>,
+    external_name: Option<String>,
+    external_language: String,
+    parameter_style: String,
+    is_deterministic: String,
+}
@@ -30,6 +30,7 @@ use risingwave_frontend_macro::system_catalog;
             pg_class.relname AS table_name,
             CASE
                 WHEN contype = 'p' THEN 'PRIMARY KEY'
+                WHEN contype = 'f' THEN 'FOREIGN KEY'
                 WHEN contype = 'u' THEN 'UNIQUE'
                 WHEN contype = 'c' THEN 'CHECK'
                 WHEN contype = 'x' THEN 'EXCLUDE'
@@ -39,7 +39,7 @@ use risingwave_pb::user::grant_privilege::Object;
 use crate::catalog::catalog_service::CatalogReader;
 use crate::catalog::view_catalog::ViewCatalog;
 use crate::meta_client::FrontendMetaClient;
-use crate::session::AuthContext;
+use crate::session::{AuthContext, SessionMapRef};
 use crate::user::user_catalog::UserCatalog;
 use crate::user::user_privilege::available_prost_privilege;
 use crate::user::user_service::UserInfoReader;
@@ -109,6 +109,8 @@ pub struct SysCatalogReaderImpl {
     config: Arc<RwLock<SessionConfig>>,
     // Read system params.
     system_params: SystemParamsReaderRef,
+    // Read active sessions on this frontend node.
+    sessions_map: SessionMapRef,
 }
 
 impl SysCatalogReaderImpl {
@@ -119,6 +121,7 @@ impl SysCatalogReaderImpl {
         auth_context: Arc<AuthContext>,
         config: Arc<RwLock<SessionConfig>>,
         system_params: SystemParamsReaderRef,
+        sessions_map: SessionMapRef,
     ) -> Self {
         Self {
             catalog_reader,
@@ -127,6 +130,7 @@ impl SysCatalogReaderImpl {
             auth_context,
             config,
             system_params,
+            sessions_map,
         }
     }
 }
@@ -16,8 +16,36 @@ use risingwave_common::types::Fields;
 use risingwave_frontend_macro::system_catalog;
 
 /// Mapping from sql name to system locale groups.
+///
+/// Only the built-in byte-order collations are available: collating with any of them is
+/// equivalent to the default behavior. `collprovider` holds the ascii code of the
+/// single-char provider ('d' for default, 'c' for libc).
 /// Reference: [`https://www.postgresql.org/docs/current/catalog-pg-collation.html`].
-#[system_catalog(view, "pg_catalog.pg_collation")]
+#[system_catalog(
+    view,
+    "pg_catalog.pg_collation",
+    "SELECT c.oid,
+        c.collname,
+        s.id AS collnamespace,
+        s.owner AS collowner,
+        c.collprovider,
+        c.collisdeterministic,
+        c.collencoding,
+        c.collcollate,
+        c.collctype,
+        c.colliculocale,
+        c.collversion
+    FROM (
+        SELECT 100 AS oid, 'default' AS collname, 100 AS collprovider,
+            true AS collisdeterministic, -1 AS collencoding, '' AS collcollate,
+            '' AS collctype, '' AS colliculocale, '' AS collversion
+        UNION ALL SELECT 950, 'C', 99, true, -1, 'C', 'C', '', ''
+        UNION ALL SELECT 951, 'POSIX', 99, true, -1, 'POSIX', 'POSIX', '', ''
+        UNION ALL SELECT 12554, 'ucs_basic', 99, true, 6, 'C', 'C', '', ''
+    ) c
+    CROSS JOIN rw_catalog.rw_schemas s
+    WHERE s.name = 'pg_catalog'"
+)]
 #[derive(Fields)]
 struct PgCollation {
     oid: i32,
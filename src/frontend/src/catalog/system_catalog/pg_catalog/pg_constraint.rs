@@ -14,6 +14,8 @@
 
 use risingwave_common::types::Fields;
 use risingwave_frontend_macro::system_catalog;
+use risingwave_sqlparser::ast::{ReferentialAction, Statement, TableConstraint};
+use risingwave_sqlparser::parser::Parser;
 
 use crate::catalog::schema_catalog::SchemaCatalog;
 use crate::catalog::system_catalog::{SysCatalogReaderImpl, SystemTableCatalog};
@@ -123,6 +125,141 @@ impl PgConstraint {
             conbin: None,
         }
     }
+
+    /// Recover the informational foreign key constraints of a table from its definition.
+    /// They are accepted in `CREATE TABLE` but not enforced, so they only exist in the
+    /// definition and are exposed here for introspection.
+    fn foreign_keys_from_table(schema: &SchemaCatalog, table: &TableCatalog) -> Vec<PgConstraint> {
+        let Ok(stmts) = Parser::parse_sql(&table.definition) else {
+            return vec![];
+        };
+        let Some(Statement::CreateTable {
+            columns: column_defs,
+            constraints,
+            ..
+        }) = stmts.into_iter().next()
+        else {
+            return vec![];
+        };
+
+        let mut foreign_keys = vec![];
+        for constraint in &constraints {
+            if let TableConstraint::ForeignKey {
+                name,
+                columns,
+                foreign_table,
+                referred_columns,
+                on_delete,
+                on_update,
+            } = constraint
+            {
+                foreign_keys.push((
+                    name.as_ref().map(|name| name.real_value()),
+                    columns.iter().map(|c| c.real_value()).collect::<Vec<_>>(),
+                    foreign_table,
+                    referred_columns.iter().map(|c| c.real_value()).collect(),
+                    on_delete.clone(),
+                    on_update.clone(),
+                ));
+            }
+        }
+        for column_def in &column_defs {
+            for option_def in &column_def.options {
+                if let risingwave_sqlparser::ast::ColumnOption::ForeignKey {
+                    foreign_table,
+                    referred_columns,
+                    on_delete,
+                    on_update,
+                } = &option_def.option
+                {
+                    foreign_keys.push((
+                        None,
+                        vec![column_def.name.real_value()],
+                        foreign_table,
+                        referred_columns.iter().map(|c| c.real_value()).collect(),
+                        on_delete.clone(),
+                        on_update.clone(),
+                    ));
+                }
+            }
+        }
+
+        foreign_keys
+            .into_iter()
+            .enumerate()
+            .map(|(i, (name, columns, foreign_table, referred_columns, on_delete, on_update))| {
+                let column_position = |table: &TableCatalog, name: &str| {
+                    table
+                        .columns
+                        .iter()
+                        .position(|c| c.name() == name)
+                        .map(|i| (i + 1) as i16)
+                        .unwrap_or(0)
+                };
+                let conkey = columns.iter().map(|c| column_position(table, c)).collect();
+                // The referenced table is resolved within the same schema only, as this is the
+                // common case for informational constraints. Unresolved references are exposed
+                // with a zero `confrelid`.
+                let referenced = foreign_table
+                    .0
+                    .last()
+                    .and_then(|name| schema.get_created_table_by_name(&name.real_value()));
+                let (confrelid, confkey) = match referenced {
+                    Some(referenced) => (
+                        referenced.id.table_id() as i32,
+                        Some(
+                            referred_columns
+                                .iter()
+                                .map(|c: &String| column_position(referenced, c))
+                                .collect(),
+                        ),
+                    ),
+                    None => (0, None),
+                };
+                PgConstraint {
+                    // Mock a constraint oid distinct from the `_pkey` one, which uses the plain
+                    // table id.
+                    oid: ((i + 1) as i32) << 24 | table.id.table_id() as i32,
+                    conname: name.unwrap_or_else(|| {
+                        format!("{}_{}_fkey", &table.name, columns.join("_"))
+                    }),
+                    connamespace: schema.id() as i32,
+                    contype: "f".to_owned(), // f = foreign key constraint
+                    condeferrable: false,
+                    convalidated: false, // informational only, never validated
+                    conrelid: table.id.table_id() as i32,
+                    contypid: 0,
+                    conindid: 0,
+                    conparentid: 0,
+                    confrelid,
+                    confupdtype: referential_action_type(on_update),
+                    confdeltype: referential_action_type(on_delete),
+                    confmatchtype: "s".to_owned(), // s = simple match
+                    conislocal: true,
+                    coninhcount: 0,
+                    connoinherit: true,
+                    conkey: Some(conkey),
+                    confkey,
+                    conpfeqop: None,
+                    conppeqop: None,
+                    conffeqop: None,
+                    confdelsetcols: None,
+                    conexclop: None,
+                    conbin: None,
+                }
+            })
+            .collect()
+    }
+}
+
+fn referential_action_type(action: Option<ReferentialAction>) -> String {
+    match action {
+        None | Some(ReferentialAction::NoAction) => "a".to_owned(),
+        Some(ReferentialAction::Restrict) => "r".to_owned(),
+        Some(ReferentialAction::Cascade) => "c".to_owned(),
+        Some(ReferentialAction::SetNull) => "n".to_owned(),
+        Some(ReferentialAction::SetDefault) => "d".to_owned(),
+    }
 }
 
 #[system_catalog(table, "pg_catalog.pg_constraint")]
@@ -134,7 +271,8 @@ fn read_pg_constraint(reader: &SysCatalogReaderImpl) -> Result<Vec<PgConstraint>
 }
 
 fn read_pg_constraint_in_schema(schema: &SchemaCatalog) -> Vec<PgConstraint> {
-    // Note: We only support primary key constraints now.
+    // Note: Only primary key constraints are enforced. Foreign key constraints are
+    // informational and recovered from the table definitions.
     let system_table_rows = schema
         .iter_system_tables()
         .map(|table| PgConstraint::from_system_table(schema, table.as_ref()));
@@ -143,5 +281,12 @@ fn read_pg_constraint_in_schema(schema: &SchemaCatalog) -> Vec<PgConstraint> {
         .iter_table_mv_indices()
         .map(|table| PgConstraint::from_table(schema, table.as_ref()));
 
-    system_table_rows.chain(table_rows).collect()
+    let foreign_key_rows = schema
+        .iter_user_table()
+        .flat_map(|table| PgConstraint::foreign_keys_from_table(schema, table.as_ref()));
+
+    system_table_rows
+        .chain(table_rows)
+        .chain(foreign_key_rows)
+        .collect()
 }
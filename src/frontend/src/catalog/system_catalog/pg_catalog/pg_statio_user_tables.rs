@@ -0,0 +1,60 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+/// The `pg_statio_user_tables` view will contain one row for each user table in the current
+/// database, showing statistics about I/O on that specific table. All statistics are `NULL` as
+/// they do not apply to our storage engine; the view exists to back the introspection queries of
+/// BI tools.
+/// Ref: [`https://www.postgresql.org/docs/current/monitoring-stats.html#MONITORING-PG-STATIO-ALL-TABLES-VIEW`]
+#[system_catalog(
+    view,
+    "pg_catalog.pg_statio_user_tables",
+    "SELECT
+        rr.id as relid,
+        rs.name as schemaname,
+        rr.name as relname,
+        NULL::bigint as heap_blks_read,
+        NULL::bigint as heap_blks_hit,
+        NULL::bigint as idx_blks_read,
+        NULL::bigint as idx_blks_hit,
+        NULL::bigint as toast_blks_read,
+        NULL::bigint as toast_blks_hit,
+        NULL::bigint as tidx_blks_read,
+        NULL::bigint as tidx_blks_hit
+    FROM
+        rw_relations rr
+        join rw_schemas rs on schema_id = rs.id
+    WHERE
+        rs.name != 'rw_catalog'
+        AND rs.name != 'pg_catalog'
+        AND rs.name != 'information_schema'
+"
+)]
+#[derive(Fields)]
+struct PgStatioUserTables {
+    relid: i32,
+    schemaname: String,
+    relname: String,
+    heap_blks_read: i64,
+    heap_blks_hit: i64,
+    idx_blks_read: i64,
+    idx_blks_hit: i64,
+    toast_blks_read: i64,
+    toast_blks_hit: i64,
+    tidx_blks_read: i64,
+    tidx_blks_hit: i64,
+}
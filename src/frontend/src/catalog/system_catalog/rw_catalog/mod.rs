@@ -13,7 +13,10 @@
 // limitations under the License.
 
 mod rw_actor_infos;
+mod rw_actor_runtime_stats;
 mod rw_actors;
+mod rw_audit_log;
+mod rw_checkpoint_history;
 mod rw_columns;
 mod rw_connections;
 mod rw_databases;
@@ -28,6 +31,7 @@ mod rw_hummock_branched_objects;
 mod rw_hummock_compact_task_assignment;
 mod rw_hummock_compact_task_progress;
 mod rw_hummock_compaction_group_configs;
+mod rw_hummock_compaction_stats;
 mod rw_hummock_meta_configs;
 mod rw_hummock_pinned_versions;
 mod rw_hummock_version;
@@ -39,11 +43,14 @@ mod rw_indexes;
 mod rw_internal_tables;
 mod rw_materialized_views;
 mod rw_meta_snapshot;
+mod rw_processlist;
 mod rw_rate_limit;
 mod rw_relation_info;
 mod rw_relations;
 mod rw_schemas;
 mod rw_secrets;
+mod rw_sessions;
+mod rw_sink_metrics;
 mod rw_sinks;
 mod rw_sources;
 mod rw_streaming_parallelism;
@@ -0,0 +1,51 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+/// Per-actor runtime stats, collected on demand from the monitor service of each compute
+/// node. `cache_entry_count` sums the executor cache gauges of the actor (e.g.
+/// `stream_agg_cached_entry_count`); `memory_usage_bytes` is nonzero only when the metrics
+/// level is `Debug`, since the memory gauge is aggregated away otherwise.
+#[derive(Fields)]
+struct RwActorRuntimeStats {
+    #[primary_key]
+    actor_id: i32,
+    worker_id: i32,
+    await_tree_depth: i32,
+    cache_entry_count: i64,
+    memory_usage_bytes: i64,
+}
+
+#[system_catalog(table, "rw_catalog.rw_actor_runtime_stats")]
+async fn read_rw_actor_runtime_stats(
+    reader: &SysCatalogReaderImpl,
+) -> Result<Vec<RwActorRuntimeStats>> {
+    let stats = reader.meta_client.list_actor_runtime_stats().await?;
+
+    Ok(stats
+        .into_iter()
+        .map(|s| RwActorRuntimeStats {
+            actor_id: s.actor_id as i32,
+            worker_id: s.worker_id as i32,
+            await_tree_depth: s.await_tree_depth as i32,
+            cache_entry_count: s.cache_entry_count as i64,
+            memory_usage_bytes: s.memory_usage_bytes as i64,
+        })
+        .collect())
+}
@@ -0,0 +1,62 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::types::{Fields, Timestamptz};
+use risingwave_frontend_macro::system_catalog;
+use risingwave_pb::meta::event_log::Event;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+/// Audit records of dangerous operations, e.g. drop, cancel, throttle and reschedule,
+/// with the initiating user if the operation was issued on behalf of a user session.
+///
+/// This is the subset of `rw_event_logs` with event type `AUDIT_LOG`, projected into
+/// dedicated columns for easier querying.
+#[derive(Fields)]
+struct RwAuditLog {
+    #[primary_key]
+    unique_id: String,
+    timestamp: Timestamptz,
+    user_name: String,
+    operation: String,
+    arguments: String,
+}
+
+#[system_catalog(table, "rw_catalog.rw_audit_log")]
+async fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwAuditLog>> {
+    let audit_logs = reader
+        .meta_client
+        .list_event_log()
+        .await?
+        .into_iter()
+        .sorted_by(|a, b| a.timestamp.cmp(&b.timestamp))
+        .filter_map(|mut e| {
+            let Some(Event::AuditLog(audit)) = e.event else {
+                return None;
+            };
+            let id = e.unique_id.take().unwrap();
+            let ts = Timestamptz::from_millis(e.timestamp.take().unwrap() as i64).unwrap();
+            Some(RwAuditLog {
+                unique_id: id,
+                timestamp: ts,
+                user_name: audit.user_name,
+                operation: audit.operation,
+                arguments: audit.arguments,
+            })
+        })
+        .collect();
+    Ok(audit_logs)
+}
@@ -0,0 +1,49 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{Fields, Timestamptz};
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+/// Recent checkpoints committed by the meta node, newest last. The history is kept in
+/// memory on the meta node, so it starts empty after a meta restart. Use
+/// `rw_epoch_to_timestamp` / `rw_timestamp_to_epoch` to translate between the epoch and
+/// wallclock time of the data it covers.
+#[derive(Fields)]
+struct RwCheckpointHistory {
+    #[primary_key]
+    epoch: i64,
+    committed_at: Timestamptz,
+    duration_ms: i64,
+    command: String,
+}
+
+#[system_catalog(table, "rw_catalog.rw_checkpoint_history")]
+async fn read_rw_checkpoint_history(
+    reader: &SysCatalogReaderImpl,
+) -> Result<Vec<RwCheckpointHistory>> {
+    let entries = reader.meta_client.list_checkpoint_history().await?;
+
+    Ok(entries
+        .into_iter()
+        .map(|e| RwCheckpointHistory {
+            epoch: e.epoch as i64,
+            committed_at: Timestamptz::from_millis(e.committed_at_ms as i64).unwrap(),
+            duration_ms: (e.duration_sec * 1000.0) as i64,
+            command: e.command,
+        })
+        .collect())
+}
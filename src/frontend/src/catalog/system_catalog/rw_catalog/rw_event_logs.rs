@@ -64,6 +64,10 @@ fn event_type(e: &Event) -> String {
         Event::WorkerNodePanic(_) => "WORKER_NODE_PANIC",
         Event::AutoSchemaChangeFail(_) => "AUTO_SCHEMA_CHANGE_FAIL",
         Event::SinkFail(_) => "SINK_FAIL",
+        Event::SstCorruption(_) => "SST_CORRUPTION",
+        Event::CdcSourceLag(_) => "CDC_SOURCE_LAG",
+        Event::StreamJobQuarantine(_) => "STREAM_JOB_QUARANTINE",
+        Event::AuditLog(_) => "AUDIT_LOG",
     }
     .into()
 }
@@ -0,0 +1,49 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+/// Per-compaction-group compaction stats accumulated since the meta node started, along with
+/// the write/read amplification estimated from them.
+#[derive(Fields)]
+struct RwHummockCompactionStats {
+    #[primary_key]
+    compaction_group_id: i64,
+    ingest_bytes: i64,
+    compact_read_bytes: i64,
+    compact_write_bytes: i64,
+    write_amplification: f64,
+    read_amplification: f64,
+}
+
+#[system_catalog(table, "rw_catalog.rw_hummock_compaction_stats")]
+async fn read(reader: &SysCatalogReaderImpl) -> Result<Vec<RwHummockCompactionStats>> {
+    let stats = reader.meta_client.list_compaction_group_stats().await?;
+
+    Ok(stats
+        .into_iter()
+        .map(|s| RwHummockCompactionStats {
+            compaction_group_id: s.compaction_group_id as _,
+            ingest_bytes: s.ingest_bytes as _,
+            compact_read_bytes: s.compact_read_bytes as _,
+            compact_write_bytes: s.compact_write_bytes as _,
+            write_amplification: s.write_amplification,
+            read_amplification: s.read_amplification,
+        })
+        .collect())
+}
@@ -0,0 +1,59 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+/// The active sessions of all frontend nodes in the cluster, aggregated via the meta node.
+/// Unlike `rw_sessions`, sessions on other frontend nodes are included as well.
+///
+/// A session can be terminated with `KILL <process_id>`, on whichever frontend node it lives.
+#[derive(Fields)]
+#[primary_key(process_id, frontend_addr)]
+struct RwProcess {
+    /// The id of the session. Only unique within one frontend node.
+    process_id: i32,
+    /// The address of the frontend node the session is connected to.
+    frontend_addr: String,
+    user_name: String,
+    database: String,
+    /// The address of the client.
+    host: String,
+    /// How long the current query has been running, in milliseconds. `NULL` if the session is
+    /// idle.
+    elapsed_ms: Option<i64>,
+    /// The running query, truncated. `NULL` if the session is idle.
+    running_sql: Option<String>,
+}
+
+#[system_catalog(table, "rw_catalog.rw_processlist")]
+async fn read_processlist(reader: &SysCatalogReaderImpl) -> Result<Vec<RwProcess>> {
+    let processes = reader.meta_client.list_process_list().await?;
+
+    Ok(processes
+        .into_iter()
+        .map(|process| RwProcess {
+            process_id: process.process_id,
+            frontend_addr: process.frontend_addr,
+            user_name: process.user_name,
+            database: process.database,
+            host: process.host,
+            elapsed_ms: process.elapsed_millis.map(|millis| millis as i64),
+            running_sql: process.running_sql,
+        })
+        .collect())
+}
@@ -0,0 +1,58 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_message::TransactionStatus;
+use pgwire::pg_server::Session;
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+/// The sessions that are currently active on this frontend node. Unlike `pg_stat_activity`,
+/// sessions on other frontend nodes are not included.
+#[derive(Fields)]
+struct RwSession {
+    #[primary_key]
+    process_id: i32,
+    user_name: String,
+    database: String,
+    client_addr: String,
+    in_transaction: bool,
+    /// How long the session has been idle, in milliseconds. `NULL` if a query is running.
+    idle_ms: Option<i64>,
+}
+
+#[system_catalog(table, "rw_catalog.rw_sessions")]
+fn read_sessions(reader: &SysCatalogReaderImpl) -> Result<Vec<RwSession>> {
+    let sessions = reader
+        .sessions_map
+        .read()
+        .values()
+        .map(|session| RwSession {
+            process_id: session.id().0,
+            user_name: session.user_name(),
+            database: session.database(),
+            client_addr: session.peer_addr().to_string(),
+            in_transaction: matches!(
+                session.transaction_status(),
+                TransactionStatus::InTransaction
+            ),
+            idle_ms: session
+                .elapse_since_last_idle_instant()
+                .map(|ms| ms as i64),
+        })
+        .collect();
+    Ok(sessions)
+}
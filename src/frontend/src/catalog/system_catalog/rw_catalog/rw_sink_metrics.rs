@@ -0,0 +1,52 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::Fields;
+use risingwave_frontend_macro::system_catalog;
+
+use crate::catalog::system_catalog::SysCatalogReaderImpl;
+use crate::error::Result;
+
+/// Per-sink commit metrics, sourced from the sink coordinators on the meta node. Only
+/// coordinated sinks (e.g. iceberg) report here; percentiles cover a recent window of
+/// commits. For per-writer throughput and retry metrics, refer to Prometheus.
+#[derive(Fields)]
+struct RwSinkMetrics {
+    #[primary_key]
+    sink_id: i32,
+    commit_count: i64,
+    avg_commit_latency_ms: i64,
+    max_commit_latency_ms: i64,
+    p50_commit_latency_ms: i64,
+    p90_commit_latency_ms: i64,
+    p99_commit_latency_ms: i64,
+}
+
+#[system_catalog(table, "rw_catalog.rw_sink_metrics")]
+async fn read_rw_sink_metrics(reader: &SysCatalogReaderImpl) -> Result<Vec<RwSinkMetrics>> {
+    let metrics = reader.meta_client.list_sink_commit_metrics().await?;
+
+    Ok(metrics
+        .into_iter()
+        .map(|m| RwSinkMetrics {
+            sink_id: m.sink_id as i32,
+            commit_count: m.commit_count as i64,
+            avg_commit_latency_ms: m.avg_latency_ms as i64,
+            max_commit_latency_ms: m.max_latency_ms as i64,
+            p50_commit_latency_ms: m.p50_latency_ms as i64,
+            p90_commit_latency_ms: m.p90_latency_ms as i64,
+            p99_commit_latency_ms: m.p99_latency_ms as i64,
+        })
+        .collect())
+}
@@ -16,11 +16,14 @@ mod cast_regclass;
 mod col_description;
 pub mod context;
 mod has_privilege;
+mod pause_and_snapshot;
+mod pg_get_constraintdef;
 mod pg_get_indexdef;
 mod pg_get_userbyid;
 mod pg_get_viewdef;
 mod pg_index_column_has_property;
 mod pg_indexes_size;
 mod pg_relation_size;
+mod pg_terminate_backend;
 mod rw_epoch_to_ts;
 mod rw_recovery_status;
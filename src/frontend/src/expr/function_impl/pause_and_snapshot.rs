@@ -0,0 +1,84 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use risingwave_common::session_config::SearchPath;
+use risingwave_expr::{capture_context, function, ExprError, Result};
+use risingwave_sqlparser::parser::Parser;
+use thiserror_ext::AsReport;
+
+use super::context::{AUTH_CONTEXT, CATALOG_READER, DB_NAME, META_CLIENT, SEARCH_PATH};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::CatalogReader;
+use crate::meta_client::FrontendMetaClient;
+use crate::session::AuthContext;
+
+/// How long the meta node keeps the snapshot pinned before releasing it, if the
+/// exporter never finishes.
+const DEFAULT_EXPORT_TTL_SEC: u64 = 3600;
+
+/// Pins a consistent snapshot of the given materialized view for external export and
+/// returns the committed epoch at which it can be read, e.g. via batch queries with
+/// `FOR SYSTEM_TIME AS OF`. The snapshot is released automatically after a timeout.
+#[function("pause_and_snapshot(varchar) -> varchar", volatile)]
+async fn pause_and_snapshot(name: &str, writer: &mut impl Write) -> Result<()> {
+    let table_id = resolve_table_id_impl_captured(name)?;
+    let export_epoch = pin_snapshot_for_export_impl_captured(table_id).await?;
+    write!(writer, "{}", export_epoch).unwrap();
+    Ok(())
+}
+
+#[capture_context(CATALOG_READER, AUTH_CONTEXT, SEARCH_PATH, DB_NAME)]
+fn resolve_table_id_impl(
+    catalog: &CatalogReader,
+    auth_context: &AuthContext,
+    search_path: &SearchPath,
+    db_name: &str,
+    name: &str,
+) -> Result<u32> {
+    let obj = Parser::parse_object_name_str(name)
+        .map_err(|e| ExprError::Parse(e.to_report_string().into()))?;
+
+    let id = if obj.0.len() == 1 {
+        let class_name = obj.0[0].real_value();
+        let schema_path = SchemaPath::Path(search_path, &auth_context.user_name);
+        catalog
+            .read_guard()
+            .get_id_by_class_name(db_name, schema_path, &class_name)
+    } else {
+        let schema = obj.0[0].real_value();
+        let class_name = obj.0[1].real_value();
+        let schema_path = SchemaPath::Name(&schema);
+        catalog
+            .read_guard()
+            .get_id_by_class_name(db_name, schema_path, &class_name)
+    };
+    id.map_err(|e| ExprError::InvalidParam {
+        name: "name",
+        reason: e.to_report_string().into(),
+    })
+}
+
+#[capture_context(META_CLIENT)]
+async fn pin_snapshot_for_export_impl(
+    meta_client: &Arc<dyn FrontendMetaClient>,
+    table_id: u32,
+) -> Result<u64> {
+    meta_client
+        .pin_snapshot_for_export(vec![table_id], DEFAULT_EXPORT_TTL_SEC)
+        .await
+        .map_err(|e| ExprError::Internal(e.into()))
+}
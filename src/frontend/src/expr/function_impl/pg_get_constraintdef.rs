@@ -0,0 +1,58 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use risingwave_common::catalog::TableId;
+use risingwave_expr::{capture_context, function, ExprError, Result};
+use thiserror_ext::AsReport;
+
+use super::context::CATALOG_READER;
+use crate::catalog::CatalogReader;
+
+/// Reconstruct the definition of a constraint, to back the introspection queries of pg_dump and
+/// BI tools. `pg_constraint` exposes only primary key constraints, using the table id as the
+/// constraint oid, so the definition is always of the form `PRIMARY KEY (...)`.
+#[function("pg_get_constraintdef(int4) -> varchar")]
+fn pg_get_constraintdef(oid: i32, writer: &mut impl Write) -> Result<()> {
+    pg_get_constraintdef_impl_captured(oid, writer)
+}
+
+#[function("pg_get_constraintdef(int4, boolean) -> varchar")]
+fn pg_get_constraintdef_pretty(oid: i32, _pretty_bool: bool, writer: &mut impl Write) -> Result<()> {
+    pg_get_constraintdef_impl_captured(oid, writer)
+}
+
+#[capture_context(CATALOG_READER)]
+fn pg_get_constraintdef_impl(
+    catalog: &CatalogReader,
+    oid: i32,
+    writer: &mut impl Write,
+) -> Result<()> {
+    let catalog_reader = catalog.read_guard();
+    let table = catalog_reader
+        .get_any_table_by_id(&TableId::new(oid as u32))
+        .map_err(|e| ExprError::InvalidParam {
+            name: "oid",
+            reason: e.to_report_string().into(),
+        })?;
+    let columns = table
+        .pk
+        .iter()
+        .map(|order| table.columns[order.column_index].name())
+        .collect::<Vec<_>>()
+        .join(", ");
+    write!(writer, "PRIMARY KEY ({})", columns).unwrap();
+    Ok(())
+}
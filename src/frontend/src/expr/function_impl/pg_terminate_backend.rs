@@ -0,0 +1,50 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_expr::{capture_context, function, Result};
+use thiserror_ext::AsReport;
+
+use super::context::META_CLIENT;
+use crate::meta_client::FrontendMetaClient;
+
+/// Terminates the session with the given process id, on whichever frontend node it lives.
+/// Returns `false` if the session does not exist, following PostgreSQL.
+#[function("pg_terminate_backend(int4) -> boolean", volatile)]
+async fn pg_terminate_backend(process_id: i32) -> Result<bool> {
+    kill_process_impl_captured(process_id).await
+}
+
+/// Cancels the running queries of the session with the given process id. Since RisingWave does
+/// not distinguish cancelling a query from terminating a session, this is currently an alias of
+/// [`pg_terminate_backend`].
+#[function("pg_cancel_backend(int4) -> boolean", volatile)]
+async fn pg_cancel_backend(process_id: i32) -> Result<bool> {
+    kill_process_impl_captured(process_id).await
+}
+
+#[capture_context(META_CLIENT)]
+async fn kill_process_impl(
+    meta_client: &Arc<dyn FrontendMetaClient>,
+    process_id: i32,
+) -> Result<bool> {
+    match meta_client.kill_process(process_id).await {
+        Ok(()) => Ok(true),
+        Err(e) => {
+            tracing::warn!(error = %e.as_report(), process_id, "failed to kill session via meta");
+            Ok(false)
+        }
+    }
+}
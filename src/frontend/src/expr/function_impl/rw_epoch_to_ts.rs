@@ -14,9 +14,21 @@
 
 use risingwave_common::types::Timestamptz;
 use risingwave_common::util::epoch::Epoch;
-use risingwave_expr::{function, Result};
+use risingwave_expr::{function, ExprError, Result};
 
 #[function("rw_epoch_to_ts(int8) -> timestamptz")]
 fn rw_epoch_to_ts(epoch: i64) -> Result<Timestamptz> {
     Ok(Epoch(epoch as u64).as_timestamptz())
 }
+
+#[function("rw_timestamp_to_epoch(timestamptz) -> int8")]
+fn rw_timestamp_to_epoch(ts: Timestamptz) -> Result<i64> {
+    let unix_millis = ts.timestamp_millis();
+    if unix_millis < Epoch(0).as_unix_millis() as i64 {
+        return Err(ExprError::InvalidParam {
+            name: "ts",
+            reason: "timestamp is earlier than the earliest possible epoch".into(),
+        });
+    }
+    Ok(Epoch::from_unix_millis(unix_millis as u64).0 as i64)
+}
@@ -265,7 +265,8 @@ impl ExprVisitor for ImpureAnalyzer {
             | Type::MapInsert
             | Type::MapLength
             | Type::VnodeUser
-            |Type::RwEpochToTs =>
+            |Type::RwEpochToTs
+            | Type::RwTimestampToEpoch =>
             // expression output is deterministic(same result for the same input)
             {
                 func_call
@@ -294,7 +295,8 @@ impl ExprVisitor for ImpureAnalyzer {
             | Type::HasSchemaPrivilege
             | Type::MakeTimestamptz
             | Type::PgIsInRecovery
-            | Type::RwRecoveryStatus => self.impure = true,
+            | Type::RwRecoveryStatus
+            | Type::PauseAndSnapshot => self.impure = true,
         }
     }
 }
@@ -302,7 +302,7 @@ pub static CAST_TABLE: LazyLock<CastTable> = LazyLock::new(|| {
     // 3. jsonb -> bool/number is explicit
     // 4. int32 <-> bool is explicit
     // 5. timestamp/timestamptz -> time is assign
-    // 6. int2/int4/int8 -> int256 is implicit and int256 -> float8 is explicit
+    // 6. int2/int4/int8 -> int256 is implicit, and int256 <-> float8/decimal is explicit
     use DataTypeName::*;
     const CAST_TABLE: &[(&str, DataTypeName)] = &[
         // 123456789ABCDEF
@@ -310,10 +310,10 @@ pub static CAST_TABLE: LazyLock<CastTable> = LazyLock::new(|| {
         (" .iiiiii       a ", Int16),       // 1
         ("ea.iiiii       a ", Int32),       // 2
         (" aa.iiii       ae", Int64),       // 3
-        (" aaa.ii        a ", Decimal),     // 4
+        (" aaa.iie       a ", Decimal),     // 4
         (" aaaa.i        a ", Float32),     // 5
         (" aaaaa.        a ", Float64),     // 6
-        ("      e.       a ", Int256),      // 7
+        ("    e e.       a ", Int256),      // 7
         ("        .ii    a ", Date),        // 8
         ("        a.ia   a ", Timestamp),   // 9
         ("        aa.a   a ", Timestamptz), // A
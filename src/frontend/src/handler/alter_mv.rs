@@ -0,0 +1,134 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::hash::VnodeCount;
+use risingwave_common::util::column_index_mapping::ColIndexMapping;
+use risingwave_sqlparser::ast::{ObjectName, Query, Statement};
+
+use super::create_mv::gen_create_mv_plan;
+use super::{HandlerArgs, RwPgResponse};
+use crate::catalog::root_catalog::SchemaPath;
+use crate::catalog::table_catalog::TableType;
+use crate::error::{ErrorCode, Result};
+use crate::optimizer::OptimizerContext;
+use crate::session::SessionImpl;
+use crate::stream_fragmenter::build_graph;
+use crate::Binder;
+use crate::TableCatalog;
+
+/// Handle `ALTER MATERIALIZED VIEW <name> REPLACE AS <query>`.
+///
+/// The new plan is built and backfilled side-by-side under a temporary job id while the
+/// old materialized view keeps serving reads, then the catalog entry is swapped in
+/// atomically at a checkpoint. Downstream jobs keep running as long as all columns they
+/// reference still exist (matched by name) in the new query.
+pub async fn handle_alter_mv(
+    handler_args: HandlerArgs,
+    name: ObjectName,
+    new_query: Box<Query>,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+    let original_catalog = fetch_mv_catalog_for_alter(session.as_ref(), &name)?;
+
+    // Rewrite the original `CREATE MATERIALIZED VIEW` statement with the new query, so
+    // that the stored definition stays in creatable form.
+    let mut definition = original_catalog.create_sql_ast()?;
+    let Statement::CreateView {
+        materialized: true,
+        columns,
+        emit_mode,
+        query,
+        ..
+    } = &mut definition
+    else {
+        panic!("unexpected statement: {:?}", definition);
+    };
+    *query = new_query;
+    let (query, columns, emit_mode) = (*query.clone(), columns.clone(), emit_mode.clone());
+
+    // Plan the new query as if we're creating a new materialized view with the rewritten
+    // definition.
+    let handler_args = HandlerArgs::new(session.clone(), &definition, Arc::from(""))?;
+    let (mut table, graph) = {
+        let context = OptimizerContext::from_handler_args(handler_args);
+        let (plan, table) =
+            gen_create_mv_plan(&session, context.into(), query, name, columns, emit_mode)?;
+        let graph = build_graph(plan)?;
+        (table, graph)
+    };
+
+    // The new table catalog replaces the original one in place.
+    table.id = original_catalog.id().table_id;
+    table.owner = original_catalog.owner;
+    table.maybe_vnode_count = VnodeCount::set(original_catalog.vnode_count()).to_protobuf();
+
+    // Map the original columns to the new ones by name, so that downstream jobs can be
+    // rewritten to read from the new plan. Dropping a column that is still referenced
+    // downstream will be rejected by the meta service.
+    let col_index_mapping = ColIndexMapping::new(
+        original_catalog
+            .columns()
+            .iter()
+            .map(|old_c| {
+                table.columns.iter().position(|new_c| {
+                    new_c.get_column_desc().unwrap().name == old_c.name()
+                })
+            })
+            .collect(),
+        table.columns.len(),
+    );
+
+    let catalog_writer = session.catalog_writer()?;
+    catalog_writer
+        .replace_materialized_view(table, graph, col_index_mapping)
+        .await?;
+
+    Ok(PgResponse::empty_result(
+        StatementType::ALTER_MATERIALIZED_VIEW,
+    ))
+}
+
+pub fn fetch_mv_catalog_for_alter(
+    session: &SessionImpl,
+    mv_name: &ObjectName,
+) -> Result<Arc<TableCatalog>> {
+    let db_name = &session.database();
+    let (schema_name, real_mv_name) =
+        Binder::resolve_schema_qualified_name(db_name, mv_name.clone())?;
+    let search_path = session.config().search_path();
+    let user_name = &session.user_name();
+
+    let schema_path = SchemaPath::new(schema_name.as_deref(), &search_path, user_name);
+
+    let original_catalog = {
+        let reader = session.env().catalog_reader().read_guard();
+        let (table, schema_name) =
+            reader.get_created_table_by_name(db_name, schema_path, &real_mv_name)?;
+
+        if table.table_type() != TableType::MaterializedView {
+            Err(ErrorCode::InvalidInputSyntax(format!(
+                "\"{mv_name}\" is not a materialized view or cannot be altered"
+            )))?;
+        }
+
+        session.check_privilege_for_drop_alter(schema_name, &**table)?;
+
+        table.clone()
+    };
+
+    Ok(original_catalog)
+}
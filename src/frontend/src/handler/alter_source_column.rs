@@ -110,7 +110,13 @@ pub async fn handle_alter_source_column(
             catalog.definition =
                 alter_definition_add_column(&catalog.definition, column_def.clone())?;
             let mut bound_column = bind_sql_columns(&[column_def])?.remove(0);
-            bound_column.column_desc.column_id = max_column_id(columns).next();
+            // Allocate from the persisted allocator so that column IDs are never reused,
+            // falling back to the maximum existing ID for sources created before it existed.
+            let next_column_id = catalog
+                .next_column_id
+                .unwrap_or_else(|| max_column_id(columns).next());
+            bound_column.column_desc.column_id = next_column_id;
+            catalog.next_column_id = Some(next_column_id.next());
             columns.push(bound_column);
         }
         _ => unreachable!(),
@@ -19,7 +19,7 @@ use either::Either;
 use itertools::Itertools;
 use pgwire::pg_response::StatementType;
 use risingwave_common::bail_not_implemented;
-use risingwave_common::catalog::{max_column_id, ColumnCatalog};
+use risingwave_common::catalog::{ColumnCatalog, ColumnId};
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_connector::WithPropertiesExt;
 use risingwave_pb::catalog::StreamSourceInfo;
@@ -154,12 +154,18 @@ pub fn check_format_encode(
     Ok(())
 }
 
-/// Refresh the source registry and get the added/dropped columns.
+/// Refresh the source registry and get the added/dropped columns, as well as the next column
+/// ID to persist after the added columns are applied.
 pub async fn refresh_sr_and_get_columns_diff(
     original_source: &SourceCatalog,
     format_encode: &FormatEncodeOptions,
     session: &Arc<SessionImpl>,
-) -> Result<(StreamSourceInfo, Vec<ColumnCatalog>, Vec<ColumnCatalog>)> {
+) -> Result<(
+    StreamSourceInfo,
+    Vec<ColumnCatalog>,
+    Vec<ColumnCatalog>,
+    ColumnId,
+)> {
     let mut with_properties = original_source.with_properties.clone();
     validate_compatibility(format_encode, &mut with_properties)?;
 
@@ -181,7 +187,8 @@ pub async fn refresh_sr_and_get_columns_diff(
 
     let mut added_columns = columns_minus(&columns_from_resolve_source, &original_source.columns);
     // The newly resolved columns' column IDs also starts from 1. They cannot be used directly.
-    let mut next_col_id = max_column_id(&original_source.columns).next();
+    // Allocate from the persisted per-source allocator so that IDs are never reused.
+    let mut next_col_id = original_source.next_column_id_for_schema_change();
     for col in &mut added_columns {
         col.column_desc.column_id = next_col_id;
         next_col_id = next_col_id.next();
@@ -194,7 +201,7 @@ pub async fn refresh_sr_and_get_columns_diff(
         original_source = ?original_source.columns
     );
 
-    Ok((source_info, added_columns, dropped_columns))
+    Ok((source_info, added_columns, dropped_columns, next_col_id))
 }
 
 fn get_format_encode_from_source(source: &SourceCatalog) -> Result<FormatEncodeOptions> {
@@ -248,7 +255,7 @@ pub async fn handle_alter_source_with_sr(
         .into());
     }
 
-    let (source_info, added_columns, dropped_columns) =
+    let (source_info, added_columns, dropped_columns, next_column_id) =
         refresh_sr_and_get_columns_diff(&source, &format_encode, &session).await?;
 
     if !dropped_columns.is_empty() {
@@ -263,6 +270,7 @@ pub async fn handle_alter_source_with_sr(
 
     source.info = source_info;
     source.columns.extend(added_columns);
+    source.next_column_id = Some(next_column_id);
     source.definition =
         alter_definition_format_encode(&source.definition, format_encode.row_options.clone())?;
 
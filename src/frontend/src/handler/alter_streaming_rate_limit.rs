@@ -130,7 +130,9 @@ pub async fn handle_alter_streaming_rate_limit(
         Some(rate_limit as u32)
     };
 
-    meta_client.apply_throttle(kind, id, rate_limit).await?;
+    meta_client
+        .apply_throttle(kind, id, rate_limit, Some(session.user_name()))
+        .await?;
 
     Ok(PgResponse::empty_result(stmt_type))
 }
@@ -32,6 +32,7 @@ fn alter_prost_user_info(
     mut user_info: UserInfo,
     options: &UserOptions,
     session_user: &UserCatalog,
+    password_encryption: &str,
 ) -> Result<(UserInfo, Vec<UpdateField>)> {
     if !session_user.is_super {
         let require_super = user_info.is_super
@@ -96,7 +97,8 @@ fn alter_prost_user_info(
             UserOption::EncryptedPassword(p) => {
                 // TODO: Behaviour of PostgreSQL: Notice when password is empty string.
                 if !p.0.is_empty() {
-                    user_info.auth_info = encrypted_password(&user_info.name, &p.0);
+                    user_info.auth_info =
+                        encrypted_password(&user_info.name, &p.0, password_encryption);
                 } else {
                     user_info.auth_info = None;
                 };
@@ -107,7 +109,8 @@ fn alter_prost_user_info(
                 if let Some(password) = opt
                     && !password.0.is_empty()
                 {
-                    user_info.auth_info = encrypted_password(&user_info.name, &password.0);
+                    user_info.auth_info =
+                        encrypted_password(&user_info.name, &password.0, password_encryption);
                 } else {
                     user_info.auth_info = None;
                 }
@@ -176,7 +179,12 @@ pub async fn handle_alter_user(
 
         match stmt.mode {
             risingwave_sqlparser::ast::AlterUserMode::Options(options) => {
-                alter_prost_user_info(old_info, &options, session_user)?
+                alter_prost_user_info(
+                    old_info,
+                    &options,
+                    session_user,
+                    &session.config().password_encryption(),
+                )?
             }
             risingwave_sqlparser::ast::AlterUserMode::Rename(new_name) => {
                 alter_rename_prost_user_info(old_info, new_name, session_user)?
@@ -30,7 +30,10 @@ pub(super) async fn handle_cancel(
     let canceled_jobs = session
         .env()
         .meta_client()
-        .cancel_creating_jobs(PbJobs::Ids(CreatingJobIds { job_ids: jobs.0 }))
+        .cancel_creating_jobs(
+            PbJobs::Ids(CreatingJobIds { job_ids: jobs.0 }),
+            Some(session.user_name()),
+        )
         .await?;
     let rows = canceled_jobs
         .into_iter()
@@ -27,6 +27,7 @@ pub async fn handle_create_database(
     database_name: ObjectName,
     if_not_exist: bool,
     owner: Option<ObjectName>,
+    checkpoint_frequency: Option<u64>,
 ) -> Result<RwPgResponse> {
     let session = handler_args.session;
     let database_name = Binder::resolve_database_name(database_name)?;
@@ -71,9 +72,16 @@ pub async fn handle_create_database(
         session.user_id()
     };
 
+    if checkpoint_frequency == Some(0) {
+        return Err(crate::error::ErrorCode::InvalidInputSyntax(
+            "checkpoint_frequency must be positive".to_owned(),
+        )
+        .into());
+    }
+
     let catalog_writer = session.catalog_writer()?;
     catalog_writer
-        .create_database(&database_name, database_owner)
+        .create_database(&database_name, database_owner, checkpoint_frequency)
         .await?;
 
     Ok(PgResponse::empty_result(StatementType::CREATE_DATABASE))
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashSet;
+use std::time::Duration;
 
 use either::Either;
 use pgwire::pg_response::{PgResponse, StatementType};
@@ -255,9 +256,35 @@ It only indicates the physical clustering of the data, which may improve the per
 
     let session = session.clone();
     let catalog_writer = session.catalog_writer()?;
-    catalog_writer
+
+    // In foreground mode, periodically report the backfill progress to interactive clients via
+    // NOTICE messages, so that they see progress instead of a silent hang.
+    let progress_reporter = (!session.config().background_ddl()).then(|| {
+        let session = session.clone();
+        let definition = table.definition.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(5));
+            // The first tick completes immediately, skip it.
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let Ok(progresses) = session.env().meta_client().get_ddl_progress().await else {
+                    continue;
+                };
+                if let Some(progress) = progresses.iter().find(|p| p.statement == definition) {
+                    session.notice_to_user(format!("backfill progress: {}", progress.progress));
+                }
+            }
+        })
+    });
+
+    let result = catalog_writer
         .create_materialized_view(table, graph, dependencies)
-        .await?;
+        .await;
+    if let Some(reporter) = progress_reporter {
+        reporter.abort();
+    }
+    result?;
 
     Ok(PgResponse::empty_result(
         StatementType::CREATE_MATERIALIZED_VIEW,
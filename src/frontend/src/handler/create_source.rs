@@ -28,7 +28,8 @@ use rand::Rng;
 use risingwave_common::array::arrow::{arrow_schema_iceberg, IcebergArrowConvert};
 use risingwave_common::bail_not_implemented;
 use risingwave_common::catalog::{
-    debug_assert_column_ids_distinct, ColumnCatalog, ColumnDesc, ColumnId, Schema, TableId,
+    debug_assert_column_ids_distinct, max_column_id, ColumnCatalog, ColumnDesc, ColumnId, Schema,
+    TableId,
     ICEBERG_SEQUENCE_NUM_COLUMN_NAME, INITIAL_SOURCE_VERSION_ID, KAFKA_TIMESTAMP_COLUMN_NAME,
     ROWID_PREFIX,
 };
@@ -781,6 +782,7 @@ pub async fn bind_create_source_or_table_with_connector(
     } else {
         Some(TableId::placeholder())
     };
+    let next_column_id = max_column_id(&columns).next();
     let source = SourceCatalog {
         id: TableId::placeholder().table_id,
         name: source_name,
@@ -801,6 +803,7 @@ pub async fn bind_create_source_or_table_with_connector(
         created_at_cluster_version: None,
         initialized_at_cluster_version: None,
         rate_limit: source_rate_limit,
+        next_column_id: Some(next_column_id),
     };
     Ok((source, database_id, schema_id))
 }
@@ -181,6 +181,9 @@ fn ensure_column_options_supported(c: &ColumnDef) -> Result<()> {
             ColumnOption::GeneratedColumns(_) => {}
             ColumnOption::DefaultColumns(_) => {}
             ColumnOption::Unique { is_primary: true } => {}
+            // Foreign key constraints are informational only: they are kept in the table
+            // definition and exposed in `pg_constraint`, but not enforced.
+            ColumnOption::ForeignKey { .. } => {}
             _ => bail_not_implemented!("column constraints \"{}\"", option_def),
         }
     }
@@ -214,9 +217,9 @@ pub fn bind_sql_columns(column_defs: &[ColumnDef]) -> Result<Vec<ColumnCatalog>>
             //   > The built-in collatable data types are `text`, `varchar`, and `char`.
             //
             // But we don't support real collation, we simply ignore it here.
-            if !["C", "POSIX"].contains(&collation.real_value().as_str()) {
+            if !["C", "POSIX", "default", "ucs_basic"].contains(&collation.real_value().as_str()) {
                 bail_not_implemented!(
-                    "Collate collation other than `C` or `POSIX` is not implemented"
+                    "Collate collation other than the built-in byte-order collations (see `pg_collation`) is not implemented"
                 );
             }
 
@@ -396,7 +399,8 @@ pub fn bind_sql_column_constraints(
     Ok(())
 }
 
-/// Currently we only support Primary key table constraint, so just return pk names if it exists
+/// Currently we only enforce the primary key table constraint, so just return pk names if it
+/// exists. Foreign key constraints are accepted but informational only.
 pub fn bind_table_constraints(table_constraints: &[TableConstraint]) -> Result<Vec<String>> {
     let mut pk_column_names = vec![];
 
@@ -412,6 +416,9 @@ pub fn bind_table_constraints(table_constraints: &[TableConstraint]) -> Result<V
                 }
                 pk_column_names = columns.iter().map(|c| c.real_value()).collect_vec();
             }
+            // Foreign key constraints are informational only: they are kept in the table
+            // definition and exposed in `pg_constraint`, but not enforced.
+            TableConstraint::ForeignKey { .. } => {}
             _ => bail_not_implemented!("table constraint \"{}\"", constraint),
         }
     }
@@ -1124,7 +1131,12 @@ pub(super) async fn handle_create_table_plan(
             )?;
 
             let (columns, pk_names) = match wildcard_idx {
-                Some(_) => bind_cdc_table_schema_externally(cdc_with_options.clone()).await?,
+                Some(_) => {
+                    let (columns, pk_names) =
+                        bind_cdc_table_schema_externally(cdc_with_options.clone()).await?;
+                    let columns = apply_cdc_column_overrides(session, columns, &column_defs)?;
+                    (columns, pk_names)
+                }
                 None => {
                     for column_def in &column_defs {
                         for option_def in &column_def.options {
@@ -1210,11 +1222,18 @@ fn sanity_check_for_cdc_table(
     constraints: &Vec<TableConstraint>,
     source_watermarks: &Vec<SourceWatermark>,
 ) -> Result<()> {
-    // wildcard cannot be used with column definitions
-    if wildcard_idx.is_some() && !column_defs.is_empty() {
+    // column definitions alongside the wildcard are overrides on the derived schema,
+    // so they must not declare a primary key on their own
+    if wildcard_idx.is_some()
+        && column_defs.iter().any(|col| {
+            col.options
+                .iter()
+                .any(|opt| matches!(opt.option, ColumnOption::Unique { is_primary: true }))
+        })
+    {
         return Err(ErrorCode::NotSupported(
-            "wildcard(*) and column definitions cannot be used together".to_owned(),
-            "Remove the wildcard or column definitions".to_owned(),
+            "primary key column cannot be used together with wildcard(*)".to_owned(),
+            "The primary key is derived from the upstream table".to_owned(),
         )
         .into());
     }
@@ -1295,6 +1314,52 @@ async fn bind_cdc_table_schema_externally(
     ))
 }
 
+/// Applies explicit column definitions as overrides on top of the schema derived from
+/// the external table, e.g. `CREATE TABLE t (*, v jsonb) FROM src TABLE 'mydb.t1'`.
+///
+/// Each override must refer to an upstream column. If the overridden type differs from
+/// the inferred one, a notice is sent to the user since the conversion may be lossy,
+/// and the default value read from the external database is dropped as it is bound to
+/// the inferred type.
+fn apply_cdc_column_overrides(
+    session: &SessionImpl,
+    mut columns: Vec<ColumnCatalog>,
+    column_defs: &[ColumnDef],
+) -> Result<Vec<ColumnCatalog>> {
+    for column_def in column_defs {
+        for option_def in &column_def.options {
+            if let ColumnOption::DefaultColumns(_) = option_def.option {
+                return Err(ErrorCode::NotSupported(
+                    "Default value for columns defined on the table created from a CDC source"
+                        .into(),
+                    "Remove the default value expression in the column definitions".into(),
+                )
+                .into());
+            }
+        }
+    }
+    for override_col in bind_sql_columns(column_defs)? {
+        let Some(col) = columns.iter_mut().find(|c| c.name() == override_col.name()) else {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "column \"{}\" does not exist in the external table",
+                override_col.name()
+            ))
+            .into());
+        };
+        if col.column_desc.data_type != override_col.column_desc.data_type {
+            session.notice_to_user(format!(
+                "column \"{}\": overriding inferred type {} with {}, the conversion may be lossy",
+                col.name(),
+                col.column_desc.data_type,
+                override_col.column_desc.data_type,
+            ));
+            col.column_desc.generated_or_default_column = None;
+            col.column_desc.data_type = override_col.column_desc.data_type.clone();
+        }
+    }
+    Ok(columns)
+}
+
 /// Derive schema for cdc table when create a new Table or alter an existing Table
 fn bind_cdc_table_schema(
     column_defs: &Vec<ColumnDef>,
@@ -33,6 +33,7 @@ fn make_prost_user_info(
     options: &UserOptions,
     session_user: &UserCatalog,
     database_id: DatabaseId,
+    password_encryption: &str,
 ) -> Result<UserInfo> {
     if !session_user.is_super {
         let require_super = options
@@ -82,7 +83,8 @@ fn make_prost_user_info(
             UserOption::EncryptedPassword(password) => {
                 // TODO: Behaviour of PostgreSQL: Notice when password is empty string.
                 if !password.0.is_empty() {
-                    user_info.auth_info = encrypted_password(&user_info.name, &password.0);
+                    user_info.auth_info =
+                        encrypted_password(&user_info.name, &password.0, password_encryption);
                 }
             }
             UserOption::Password(opt) => {
@@ -90,7 +92,8 @@ fn make_prost_user_info(
                 if let Some(password) = opt
                     && !password.0.is_empty()
                 {
-                    user_info.auth_info = encrypted_password(&user_info.name, &password.0);
+                    user_info.auth_info =
+                        encrypted_password(&user_info.name, &password.0, password_encryption);
                 }
             }
             UserOption::OAuth(options) => {
@@ -131,7 +134,13 @@ pub async fn handle_create_user(
             .get_user_by_name(&session.user_name())
             .ok_or_else(|| CatalogError::NotFound("user", session.user_name()))?;
 
-        make_prost_user_info(user_name, &stmt.with_options, session_user, database_id)?
+        make_prost_user_info(
+            user_name,
+            &stmt.with_options,
+            session_user,
+            database_id,
+            &session.config().password_encryption(),
+        )?
     };
 
     let user_info_writer = session.user_info_writer()?;
@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::fmt::Display;
 
+use anyhow::Context;
 use itertools::Itertools;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{ColumnCatalog, ColumnDesc};
 use risingwave_common::types::Fields;
-use risingwave_sqlparser::ast::{display_comma_separated, ObjectName};
+use risingwave_connector::source::cdc::external::{ExternalTableConfig, ExternalTableImpl};
+use risingwave_sqlparser::ast::{display_comma_separated, ObjectName, SqlOption};
 
 use super::show::ShowColumnRow;
 use super::{fields_to_descriptors, RwPgResponse};
@@ -27,6 +30,8 @@ use crate::binder::{Binder, Relation};
 use crate::catalog::CatalogError;
 use crate::error::Result;
 use crate::handler::{HandlerArgs, RwPgResponseBuilderExt};
+use crate::utils::resolve_secret_ref_in_with_options;
+use crate::WithOptions;
 
 pub fn handle_describe(handler_args: HandlerArgs, object_name: ObjectName) -> Result<RwPgResponse> {
     let session = handler_args.session;
@@ -234,6 +239,47 @@ pub fn infer_describe() -> Vec<PgFieldDescriptor> {
     fields_to_descriptors(ShowColumnRow::fields())
 }
 
+#[derive(Fields)]
+#[fields(style = "Title Case")]
+struct DescribeExternalTableRow {
+    name: String,
+    r#type: String,
+    is_primary_key: bool,
+}
+
+/// Handles `DESCRIBE EXTERNAL TABLE`: connects to the upstream database with the
+/// given WITH options and returns the upstream column list, the inferred
+/// RisingWave types and the primary keys, without creating any catalog object.
+pub async fn handle_describe_external_table(
+    handler_args: HandlerArgs,
+    with_properties: Vec<SqlOption>,
+) -> Result<RwPgResponse> {
+    let options = WithOptions::try_from(with_properties.as_slice())?;
+    let (options, secret_refs) =
+        resolve_secret_ref_in_with_options(options, &handler_args.session)?.into_parts();
+    let config = ExternalTableConfig::try_from_btreemap(options, secret_refs)
+        .context("failed to extract external table config")?;
+
+    let table = ExternalTableImpl::connect(config)
+        .await
+        .context("failed to connect to the external table")?;
+
+    let pk_names: HashSet<_> = table.pk_names().iter().cloned().collect();
+    let rows = table
+        .column_descs()
+        .iter()
+        .map(|c| DescribeExternalTableRow {
+            name: c.name.clone(),
+            r#type: c.data_type.to_string(),
+            is_primary_key: pk_names.contains(&c.name),
+        })
+        .collect_vec();
+
+    Ok(PgResponse::builder(StatementType::DESCRIBE)
+        .rows(rows)
+        .into())
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
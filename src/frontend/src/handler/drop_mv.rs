@@ -84,9 +84,12 @@ pub async fn handle_drop_mv(
             let canceled_jobs = session
                 .env()
                 .meta_client()
-                .cancel_creating_jobs(PbJobs::Ids(CreatingJobIds {
-                    job_ids: vec![table_id.table_id],
-                }))
+                .cancel_creating_jobs(
+                    PbJobs::Ids(CreatingJobIds {
+                        job_ids: vec![table_id.table_id],
+                    }),
+                    Some(session.user_name()),
+                )
                 .await?;
             tracing::info!(?canceled_jobs, "cancelled creating jobs");
         }
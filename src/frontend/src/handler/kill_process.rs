@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use pgwire::pg_response::{PgResponse, StatementType};
+use thiserror_ext::AsReport;
 
 use crate::error::{ErrorCode, Result};
 use crate::handler::{HandlerArgs, RwPgResponse};
@@ -30,8 +31,17 @@ pub(super) async fn handle_kill(
     session_exists |= session.env().cancel_creating_jobs_in_session(session_id);
 
     if session_exists {
-        Ok(PgResponse::empty_result(StatementType::KILL))
-    } else {
-        Err(ErrorCode::SessionNotFound.into())
+        return Ok(PgResponse::empty_result(StatementType::KILL));
+    }
+
+    // The session does not live on this frontend node. Ask the meta node to kill it on
+    // whichever frontend node it lives.
+    tracing::trace!("forwarding kill of session {:?} to meta", session_id);
+    match session.env().meta_client().kill_process(process_id).await {
+        Ok(()) => Ok(PgResponse::empty_result(StatementType::KILL)),
+        Err(e) => {
+            tracing::warn!(error = %e.as_report(), process_id, "failed to kill session via meta");
+            Err(ErrorCode::SessionNotFound.into())
+        }
     }
 }
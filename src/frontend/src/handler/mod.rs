@@ -40,6 +40,7 @@ use crate::scheduler::{DistributedQueryStream, LocalQueryStream};
 use crate::session::SessionImpl;
 use crate::utils::WithOptions;
 
+mod alter_mv;
 mod alter_owner;
 mod alter_parallelism;
 mod alter_rename;
@@ -100,6 +101,7 @@ mod recover;
 pub mod show;
 mod transaction;
 mod use_db;
+mod validate_source;
 pub mod util;
 pub mod variable;
 mod wait;
@@ -260,6 +262,9 @@ pub async fn handle(
         Statement::CreateSource { stmt } => {
             create_source::handle_create_source(handler_args, stmt).await
         }
+        Statement::ValidateSource { stmt } => {
+            validate_source::handle_validate_source(handler_args, stmt).await
+        }
         Statement::CreateSink { stmt } => create_sink::handle_create_sink(handler_args, stmt).await,
         Statement::CreateSubscription { stmt } => {
             create_subscription::handle_create_subscription(handler_args, stmt).await
@@ -396,9 +401,16 @@ pub async fn handle(
             db_name,
             if_not_exists,
             owner,
+            checkpoint_frequency,
         } => {
-            create_database::handle_create_database(handler_args, db_name, if_not_exists, owner)
-                .await
+            create_database::handle_create_database(
+                handler_args,
+                db_name,
+                if_not_exists,
+                owner,
+                checkpoint_frequency,
+            )
+            .await
         }
         Statement::CreateSchema {
             schema_name,
@@ -426,6 +438,9 @@ pub async fn handle(
             handle_privilege::handle_revoke_privilege(handler_args, stmt).await
         }
         Statement::Describe { name } => describe::handle_describe(handler_args, name),
+        Statement::DescribeExternalTable { with_properties } => {
+            describe::handle_describe_external_table(handler_args, with_properties).await
+        }
         Statement::Discard(..) => discard::handle_discard(handler_args),
         Statement::ShowObjects {
             object: show_object,
@@ -888,6 +903,26 @@ pub async fn handle(
                 .await
             }
         }
+        Statement::AlterView {
+            materialized,
+            name,
+            operation: AlterViewOperation::ReplaceAs { query },
+        } if materialized => alter_mv::handle_alter_mv(handler_args, name, query).await,
+        Statement::AlterView {
+            materialized,
+            name,
+            operation: AlterViewOperation::Resume,
+        } if materialized => {
+            // Resume a (possibly quarantined) materialized view by resetting its backfill
+            // rate limit to the default.
+            alter_streaming_rate_limit::handle_alter_streaming_rate_limit(
+                handler_args,
+                PbThrottleTarget::Mv,
+                name,
+                -1,
+            )
+            .await
+        }
         Statement::AlterView {
             materialized,
             name,
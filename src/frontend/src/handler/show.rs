@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use itertools::Itertools;
@@ -30,6 +31,7 @@ use risingwave_pb::catalog::connection;
 use risingwave_sqlparser::ast::{
     display_comma_separated, Ident, ObjectName, ShowCreateType, ShowObject, ShowStatementFilter,
 };
+use risingwave_sqlparser::parser::Parser;
 
 use super::{fields_to_descriptors, RwPgResponse, RwPgResponseBuilderExt};
 use crate::binder::{Binder, Relation};
@@ -618,6 +620,28 @@ pub fn infer_show_create_object() -> Vec<PgFieldDescriptor> {
     fields_to_descriptors(ShowCreateObjectRow::fields())
 }
 
+/// Redact the values of sensitive `WITH` options (e.g. inline credentials) in the given
+/// `CREATE` SQL, based on the same keywords used for SQL redaction in the query log.
+/// Secrets referenced by name are kept as-is, so the result can be used to re-create the
+/// object in another environment.
+fn redact_definition(session: &SessionImpl, definition: String) -> String {
+    let keywords = Arc::new(
+        session
+            .env()
+            .batch_config()
+            .redact_sql_option_keywords
+            .iter()
+            .map(|s| s.to_lowercase())
+            .collect::<HashSet<_>>(),
+    );
+    match Parser::parse_sql(&definition) {
+        Ok(stmts) if stmts.len() == 1 => stmts[0].to_redacted_string(keywords),
+        // The definition is generated by ourselves and should always be parsable. Be defensive
+        // here anyway.
+        _ => definition,
+    }
+}
+
 pub fn handle_show_create_object(
     handle_args: HandlerArgs,
     show_create_type: ShowCreateType,
@@ -655,14 +679,14 @@ pub fn handle_show_create_object(
             let sink = schema
                 .get_sink_by_name(&object_name)
                 .ok_or_else(|| CatalogError::NotFound("sink", name.to_string()))?;
-            sink.create_sql()
+            redact_definition(&session, sink.create_sql())
         }
         ShowCreateType::Source => {
             let source = schema
                 .get_source_by_name(&object_name)
                 .filter(|s| s.associated_table_id.is_none())
                 .ok_or_else(|| CatalogError::NotFound("source", name.to_string()))?;
-            source.create_sql()
+            redact_definition(&session, source.create_sql())
         }
         ShowCreateType::Index => {
             let index = schema
@@ -678,7 +702,7 @@ pub fn handle_show_create_object(
             let subscription = schema
                 .get_subscription_by_name(&object_name)
                 .ok_or_else(|| CatalogError::NotFound("subscription", name.to_string()))?;
-            subscription.create_sql()
+            redact_definition(&session, subscription.create_sql())
         }
     };
     let name = format!("{}.{}", schema_name, object_name);
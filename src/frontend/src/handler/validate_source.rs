@@ -0,0 +1,191 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use either::Either;
+use futures::StreamExt;
+use itertools::Itertools;
+use pgwire::pg_response::StatementType;
+use pgwire::types::Row;
+use risingwave_common::config::default::developer::connector_message_buffer_size;
+use risingwave_common::types::ToText;
+use risingwave_connector::dispatch_source_prop;
+use risingwave_connector::source::monitor::SourceMetrics;
+use risingwave_connector::source::reader::desc::SourceDescBuilder;
+use risingwave_connector::source::{
+    SourceContext, SourceEnumeratorContext, SourceProperties, SplitEnumerator, SplitImpl,
+};
+use risingwave_sqlparser::ast::CreateSourceStatement;
+
+use super::create_source::{
+    bind_columns_from_source, bind_connector_props, bind_create_source_or_table_with_connector,
+    CreateSourceType,
+};
+use super::create_table::ColumnIdGenerator;
+use super::{fields_to_descriptors, HandlerArgs, RwPgResponse};
+use crate::catalog::source_catalog::SourceCatalog;
+use crate::error::ErrorCode::InvalidInputSyntax;
+use crate::error::{Result, RwError};
+use crate::utils::OverwriteOptions;
+
+/// Max number of decoded rows returned to the client.
+const SAMPLE_ROW_LIMIT: usize = 10;
+/// How long to wait for sample messages from the connector before returning
+/// the rows decoded so far.
+const SAMPLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Handles `VALIDATE SOURCE`: connects with the given WITH options, samples a few
+/// messages, runs them through the parser and returns the decoded rows to the
+/// client, without creating any catalog object.
+pub async fn handle_validate_source(
+    mut handler_args: HandlerArgs,
+    stmt: CreateSourceStatement,
+) -> Result<RwPgResponse> {
+    let session = handler_args.session.clone();
+    let overwrite_options = OverwriteOptions::new(&mut handler_args);
+
+    if handler_args.with_options.is_empty() {
+        return Err(RwError::from(InvalidInputSyntax(
+            "missing WITH clause".to_owned(),
+        )));
+    }
+
+    let format_encode = stmt.format_encode.into_v2_with_warning();
+    let with_properties = bind_connector_props(&handler_args, &format_encode, true)?;
+
+    let create_source_type = CreateSourceType::for_newly_created(&session, &*with_properties);
+    let (columns_from_resolve_source, source_info) = bind_columns_from_source(
+        &session,
+        &format_encode,
+        Either::Left(&with_properties),
+        create_source_type,
+    )
+    .await?;
+    let mut col_id_gen = ColumnIdGenerator::new_initial();
+
+    // Run the same binding as `CREATE SOURCE`, but the resulting catalog is only used
+    // to sample messages and is never persisted.
+    let (source_catalog, _database_id, _schema_id) = bind_create_source_or_table_with_connector(
+        handler_args.clone(),
+        stmt.source_name,
+        format_encode,
+        with_properties,
+        &stmt.columns,
+        stmt.constraints,
+        stmt.wildcard_idx,
+        stmt.source_watermarks,
+        columns_from_resolve_source,
+        source_info,
+        stmt.include_column_options,
+        &mut col_id_gen,
+        create_source_type,
+        overwrite_options.source_rate_limit,
+    )
+    .await?;
+
+    sample_source_rows(&source_catalog).await
+}
+
+/// Connects to the external source, reads a few messages from its splits and decodes
+/// them with the bound parser config.
+async fn sample_source_rows(source_catalog: &SourceCatalog) -> Result<RwPgResponse> {
+    let pk_indices = source_catalog
+        .pk_col_ids
+        .iter()
+        .filter_map(|id| {
+            source_catalog
+                .columns
+                .iter()
+                .position(|c| c.column_id() == *id)
+        })
+        .collect_vec();
+    let source_desc = SourceDescBuilder::new(
+        source_catalog
+            .columns
+            .iter()
+            .map(|c| c.to_protobuf())
+            .collect(),
+        Arc::new(SourceMetrics::default()),
+        source_catalog.row_id_index,
+        source_catalog.with_properties.clone(),
+        source_catalog.info.clone(),
+        connector_message_buffer_size(),
+        pk_indices,
+    )
+    .build()?;
+
+    // Establishing the enumerator connection and listing splits already validates the
+    // connectivity part of the WITH options.
+    let config = source_desc.source.config.clone();
+    let splits = dispatch_source_prop!(config, prop, list_splits(*prop).await?);
+
+    let column_ids = source_desc.columns.iter().map(|c| c.column_id).collect();
+    let (mut stream, _) = source_desc
+        .source
+        .build_stream(
+            Some(splits),
+            column_ids,
+            Arc::new(SourceContext::dummy()),
+            false,
+        )
+        .await?;
+
+    let fields = source_desc
+        .columns
+        .iter()
+        .filter(|c| c.is_visible())
+        .map(|c| (c.name.as_str(), c.data_type.clone()))
+        .collect_vec();
+
+    let mut rows = Vec::new();
+    let deadline = tokio::time::Instant::now() + SAMPLE_TIMEOUT;
+    'sample: while rows.len() < SAMPLE_ROW_LIMIT {
+        let chunk = match tokio::time::timeout_at(deadline, stream.next()).await {
+            Ok(Some(chunk)) => chunk?,
+            // The stream is exhausted, or the sample window has elapsed: return the rows
+            // decoded so far.
+            Ok(None) | Err(_) => break,
+        };
+        for (_, row_ref) in chunk.rows() {
+            let values = source_desc
+                .columns
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.is_visible())
+                .map(|(i, c)| {
+                    row_ref
+                        .datum_at(i)
+                        .map(|scalar| scalar.text_format(&c.data_type).into())
+                })
+                .collect();
+            rows.push(Row::new(values));
+            if rows.len() >= SAMPLE_ROW_LIMIT {
+                break 'sample;
+            }
+        }
+    }
+
+    Ok(RwPgResponse::builder(StatementType::VALIDATE_SOURCE)
+        .values(rows.into(), fields_to_descriptors(fields))
+        .into())
+}
+
+async fn list_splits<P: SourceProperties>(prop: P) -> Result<Vec<SplitImpl>> {
+    let mut enumerator =
+        P::SplitEnumerator::new(prop, SourceEnumeratorContext::dummy().into()).await?;
+    let splits = enumerator.list_splits().await?;
+    Ok(splits.into_iter().map(Into::into).collect())
+}
@@ -179,6 +179,13 @@ pub struct FrontendOpts {
     /// Usually the localhost + desired port.
     #[clap(long, env = "RW_WEBHOOK_LISTEN_ADDR", default_value = "0.0.0.0:4560")]
     pub webhook_listen_addr: String,
+
+    /// HBA-style rules restricting which hosts may connect, as a comma-separated list of
+    /// `allow <CIDR>` and `reject <CIDR>` entries evaluated in order, e.g.
+    /// `allow 10.0.0.0/8, reject 0.0.0.0/0`. Connections matching no rule are rejected.
+    /// If unset, connections from all hosts are allowed.
+    #[clap(long, env = "RW_AUTH_HBA_RULES")]
+    pub auth_hba_rules: Option<String>,
 }
 
 impl risingwave_common::opts::Opts for FrontendOpts {
@@ -200,6 +207,7 @@ impl Default for FrontendOpts {
 use std::future::Future;
 use std::pin::Pin;
 
+use pgwire::hba::HbaRules;
 use pgwire::pg_protocol::TlsConfig;
 
 use crate::session::SESSION_MANAGER;
@@ -216,6 +224,12 @@ pub fn start(
         let webhook_listen_addr = opts.webhook_listen_addr.parse().unwrap();
         let tcp_keepalive =
             TcpKeepalive::new().with_time(Duration::from_secs(opts.tcp_keepalive_idle_secs as _));
+        let hba_rules: HbaRules = opts
+            .auth_hba_rules
+            .as_deref()
+            .unwrap_or_default()
+            .parse()
+            .expect("failed to parse `--auth-hba-rules`");
 
         let session_mgr = Arc::new(SessionManagerImpl::new(opts).await.unwrap());
         SESSION_MANAGER.get_or_init(|| session_mgr.clone());
@@ -237,6 +251,7 @@ pub fn start(
             tcp_keepalive,
             session_mgr.clone(),
             TlsConfig::new_default(),
+            hba_rules,
             Some(redact_sql_option_keywords),
             shutdown,
         )
@@ -24,6 +24,7 @@ use risingwave_pb::backup_service::MetaSnapshotMetadata;
 use risingwave_pb::catalog::Table;
 use risingwave_pb::common::WorkerNode;
 use risingwave_pb::ddl_service::DdlProgress;
+use risingwave_pb::hummock::list_compaction_group_stats_response::CompactionGroupStats;
 use risingwave_pb::hummock::write_limits::WriteLimit;
 use risingwave_pb::hummock::{
     BranchedObject, CompactTaskAssignment, CompactTaskProgress, CompactionGroupInfo,
@@ -33,10 +34,13 @@ use risingwave_pb::meta::list_actor_splits_response::ActorSplit;
 use risingwave_pb::meta::list_actor_states_response::ActorState;
 use risingwave_pb::meta::list_fragment_distribution_response::FragmentDistribution;
 use risingwave_pb::meta::list_object_dependencies_response::PbObjectDependencies;
+use risingwave_pb::meta::list_actor_runtime_stats_response::ActorRuntimeStats;
+use risingwave_pb::meta::list_checkpoint_history_response::CheckpointHistoryEntry;
 use risingwave_pb::meta::list_rate_limits_response::RateLimitInfo;
+use risingwave_pb::meta::list_sink_commit_metrics_response::SinkCommitMetrics;
 use risingwave_pb::meta::list_table_fragment_states_response::TableFragmentState;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
-use risingwave_pb::meta::{EventLog, PbThrottleTarget, RecoveryStatus};
+use risingwave_pb::meta::{EventLog, PbThrottleTarget, ProcessInfo, RecoveryStatus};
 use risingwave_rpc_client::error::Result;
 use risingwave_rpc_client::{HummockMetaClient, MetaClient};
 
@@ -57,7 +61,11 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn recover(&self) -> Result<()>;
 
-    async fn cancel_creating_jobs(&self, jobs: PbJobs) -> Result<Vec<u32>>;
+    async fn cancel_creating_jobs(
+        &self,
+        jobs: PbJobs,
+        initiating_user: Option<String>,
+    ) -> Result<Vec<u32>>;
 
     async fn list_table_fragments(
         &self,
@@ -116,11 +124,16 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn list_compact_task_progress(&self) -> Result<Vec<CompactTaskProgress>>;
 
+    async fn list_compaction_group_stats(&self) -> Result<Vec<CompactionGroupStats>>;
+
+    async fn pin_snapshot_for_export(&self, table_ids: Vec<u32>, ttl_sec: u64) -> Result<u64>;
+
     async fn apply_throttle(
         &self,
         kind: PbThrottleTarget,
         id: u32,
         rate_limit: Option<u32>,
+        initiating_user: Option<String>,
     ) -> Result<()>;
 
     async fn get_cluster_recovery_status(&self) -> Result<RecoveryStatus>;
@@ -129,7 +142,19 @@ pub trait FrontendMetaClient: Send + Sync {
 
     async fn list_rate_limits(&self) -> Result<Vec<RateLimitInfo>>;
 
+    async fn list_sink_commit_metrics(&self) -> Result<Vec<SinkCommitMetrics>>;
+
+    async fn list_actor_runtime_stats(&self) -> Result<Vec<ActorRuntimeStats>>;
+
+    async fn list_checkpoint_history(&self) -> Result<Vec<CheckpointHistoryEntry>>;
+
     async fn get_meta_store_endpoint(&self) -> Result<String>;
+
+    /// List the active sessions of all frontend nodes in the cluster.
+    async fn list_process_list(&self) -> Result<Vec<ProcessInfo>>;
+
+    /// Kill the session with the given process id, on whichever frontend node it lives.
+    async fn kill_process(&self, process_id: i32) -> Result<()>;
 }
 
 pub struct FrontendMetaClientImpl(pub MetaClient);
@@ -152,8 +177,12 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.recover().await
     }
 
-    async fn cancel_creating_jobs(&self, infos: PbJobs) -> Result<Vec<u32>> {
-        self.0.cancel_creating_jobs(infos).await
+    async fn cancel_creating_jobs(
+        &self,
+        infos: PbJobs,
+        initiating_user: Option<String>,
+    ) -> Result<Vec<u32>> {
+        self.0.cancel_creating_jobs(infos, initiating_user).await
     }
 
     async fn list_table_fragments(
@@ -286,14 +315,24 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.list_compact_task_progress().await
     }
 
+    async fn list_compaction_group_stats(&self) -> Result<Vec<CompactionGroupStats>> {
+        self.0.list_compaction_group_stats().await
+    }
+
+    async fn pin_snapshot_for_export(&self, table_ids: Vec<u32>, ttl_sec: u64) -> Result<u64> {
+        let resp = self.0.pin_snapshot_for_export(table_ids, ttl_sec).await?;
+        Ok(resp.export_epoch)
+    }
+
     async fn apply_throttle(
         &self,
         kind: PbThrottleTarget,
         id: u32,
         rate_limit: Option<u32>,
+        initiating_user: Option<String>,
     ) -> Result<()> {
         self.0
-            .apply_throttle(kind, id, rate_limit)
+            .apply_throttle(kind, id, rate_limit, initiating_user)
             .await
             .map(|_| ())
     }
@@ -310,7 +349,27 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.list_rate_limits().await
     }
 
+    async fn list_sink_commit_metrics(&self) -> Result<Vec<SinkCommitMetrics>> {
+        self.0.list_sink_commit_metrics().await
+    }
+
+    async fn list_actor_runtime_stats(&self) -> Result<Vec<ActorRuntimeStats>> {
+        self.0.list_actor_runtime_stats().await
+    }
+
+    async fn list_checkpoint_history(&self) -> Result<Vec<CheckpointHistoryEntry>> {
+        self.0.list_checkpoint_history().await
+    }
+
     async fn get_meta_store_endpoint(&self) -> Result<String> {
         self.0.get_meta_store_endpoint().await
     }
+
+    async fn list_process_list(&self) -> Result<Vec<ProcessInfo>> {
+        self.0.list_process_list().await
+    }
+
+    async fn kill_process(&self, process_id: i32) -> Result<()> {
+        self.0.kill_process(process_id).await
+    }
 }
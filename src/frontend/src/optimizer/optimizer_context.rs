@@ -57,6 +57,10 @@ pub struct OptimizerContext {
     /// Store the mapping between `share_id` and the corresponding
     /// `PlanRef`, used by rcte's planning. (e.g., in `LogicalCteRef`)
     rcte_cache: RefCell<HashMap<ShareId, PlanRef>>,
+    /// The shared `now()` source of this plan, lazily created on first use. All temporal
+    /// filters in one plan reuse this node, so that the fragment graph contains a single
+    /// `now()` executor.
+    now_source: RefCell<Option<PlanRef>>,
 
     /// Last assigned plan node ID.
     last_plan_node_id: Cell<i32>,
@@ -101,6 +105,7 @@ impl OptimizerContext {
             total_rule_applied: RefCell::new(0),
             overwrite_options,
             rcte_cache: RefCell::new(HashMap::new()),
+            now_source: RefCell::new(None),
 
             last_plan_node_id: Cell::new(RESERVED_ID_NUM.into()),
             last_correlated_id: Cell::new(0),
@@ -126,6 +131,7 @@ impl OptimizerContext {
             total_rule_applied: RefCell::new(0),
             overwrite_options: OverwriteOptions::default(),
             rcte_cache: RefCell::new(HashMap::new()),
+            now_source: RefCell::new(None),
 
             last_plan_node_id: Cell::new(0),
             last_correlated_id: Cell::new(0),
@@ -262,6 +268,11 @@ impl OptimizerContext {
     pub fn insert_rcte_cache_plan(&self, id: ShareId, plan: PlanRef) {
         self.rcte_cache.borrow_mut().insert(id, plan);
     }
+
+    /// Return the shared `now()` source of this plan, creating it with `create` on first use.
+    pub fn shared_now_source(&self, create: impl FnOnce() -> PlanRef) -> PlanRef {
+        self.now_source.borrow_mut().get_or_insert_with(create).clone()
+    }
 }
 
 impl std::fmt::Debug for OptimizerContext {
@@ -329,7 +329,8 @@ impl Strong {
             | ExprType::HasSchemaPrivilege
             | ExprType::InetAton
             | ExprType::InetNtoa
-            | ExprType::RwEpochToTs => false,
+            | ExprType::RwEpochToTs
+            | ExprType::PauseAndSnapshot => false,
             ExprType::Unspecified => unreachable!(),
         }
     }
@@ -14,7 +14,7 @@
 
 use fixedbitset::FixedBitSet;
 use pretty_xmlish::XmlNode;
-use risingwave_common::types::Datum;
+use risingwave_common::types::{Datum, Interval};
 use risingwave_common::util::value_encoding::DatumToProtoExt;
 use risingwave_pb::stream_plan::now_node::PbMode as PbNowMode;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
@@ -84,7 +84,20 @@ impl StreamNode for StreamNow {
         NodeBody::Now(Box::new(PbNowNode {
             state_table: Some(table_catalog.to_internal_table_prost()),
             mode: Some(match &self.core.mode {
-                Mode::UpdateCurrent => PbNowMode::UpdateCurrent(PbNowModeUpdateCurrent {}),
+                Mode::UpdateCurrent => {
+                    let tick_interval_ms = self
+                        .base
+                        .ctx()
+                        .session_ctx()
+                        .config()
+                        .streaming_now_tick_interval_ms();
+                    let tick_interval = (tick_interval_ms > 0).then(|| {
+                        let interval =
+                            Interval::from_month_day_usec(0, 0, tick_interval_ms as i64 * 1000);
+                        Datum::Some(interval.into()).to_protobuf()
+                    });
+                    PbNowMode::UpdateCurrent(PbNowModeUpdateCurrent { tick_interval })
+                }
                 Mode::GenerateSeries {
                     start_timestamp,
                     interval,
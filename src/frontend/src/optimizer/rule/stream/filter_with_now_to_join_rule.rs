@@ -17,7 +17,7 @@ use risingwave_pb::plan_common::JoinType;
 
 use crate::expr::{ExprRewriter, FunctionCall, InputRef};
 use crate::optimizer::plan_node::generic::{self, GenericPlanRef};
-use crate::optimizer::plan_node::{LogicalFilter, LogicalJoin, LogicalNow};
+use crate::optimizer::plan_node::{LogicalFilter, LogicalJoin, LogicalNow, LogicalShare};
 use crate::optimizer::property::{analyze_monotonicity, monotonicity_variants};
 use crate::optimizer::rule::{BoxedRule, Rule};
 use crate::optimizer::PlanRef;
@@ -60,12 +60,18 @@ impl Rule for FilterWithNowToJoinRule {
         }
         let mut new_plan = plan.inputs()[0].clone();
 
+        // All temporal filters in one plan share a single `now()` source, so that the
+        // fragment graph contains only one `now()` executor ticking on barriers.
+        let now_source = plan.ctx().shared_now_source(|| {
+            LogicalShare::create(LogicalNow::new(generic::Now::update_current(plan.ctx())).into())
+        });
+
         let mut rewriter = NowAsInputRef::new(lhs_len);
         for now_filter in now_filters {
             let now_filter = rewriter.rewrite_expr(now_filter);
             new_plan = LogicalJoin::new(
                 new_plan,
-                LogicalNow::new(generic::Now::update_current(plan.ctx())).into(),
+                now_source.clone(),
                 JoinType::LeftSemi,
                 Condition {
                     conjunctions: vec![now_filter],
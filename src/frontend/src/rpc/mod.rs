@@ -13,10 +13,15 @@
 // limitations under the License.
 
 use itertools::Itertools;
-use pgwire::pg_server::{BoxedError, SessionManager};
+use pgwire::pg_protocol::truncated_fmt;
+use pgwire::pg_server::{BoxedError, Session, SessionManager};
 use risingwave_pb::ddl_service::{replace_job_plan, ReplaceJobPlan, TableSchemaChange};
 use risingwave_pb::frontend_service::frontend_service_server::FrontendService;
-use risingwave_pb::frontend_service::{GetTableReplacePlanRequest, GetTableReplacePlanResponse};
+use risingwave_pb::frontend_service::{
+    GetTableReplacePlanRequest, GetTableReplacePlanResponse, KillProcessRequest,
+    KillProcessResponse, ListProcessListRequest, ListProcessListResponse,
+};
+use risingwave_pb::meta::ProcessInfo;
 use risingwave_rpc_client::error::ToTonicStatus;
 use risingwave_sqlparser::ast::ObjectName;
 use tonic::{Request as RpcRequest, Response as RpcResponse, Status};
@@ -73,6 +78,62 @@ impl FrontendService for FrontendServiceImpl {
             replace_plan: Some(replace_plan),
         }))
     }
+
+    async fn list_process_list(
+        &self,
+        _request: RpcRequest<ListProcessListRequest>,
+    ) -> Result<RpcResponse<ListProcessListResponse>, Status> {
+        let session_mgr = SESSION_MANAGER
+            .get()
+            .expect("session manager has been initialized");
+        let frontend_addr = session_mgr.env().server_address().to_string();
+
+        let processes = session_mgr
+            .env()
+            .sessions_map()
+            .read()
+            .values()
+            .map(|session| ProcessInfo {
+                // Since process id and the secret key in the session id are the same in
+                // RisingWave, just expose the process id.
+                process_id: session.id().0,
+                frontend_addr: frontend_addr.clone(),
+                host: session.peer_addr().to_string(),
+                user_name: session.user_name(),
+                database: session.database(),
+                elapsed_millis: session.elapse_since_running_sql().map(|millis| millis as u64),
+                running_sql: session
+                    .running_sql()
+                    .map(|sql| format!("{}", truncated_fmt::TruncatedFmt(&sql, 1024))),
+            })
+            .collect();
+
+        Ok(RpcResponse::new(ListProcessListResponse { processes }))
+    }
+
+    async fn kill_process(
+        &self,
+        request: RpcRequest<KillProcessRequest>,
+    ) -> Result<RpcResponse<KillProcessResponse>, Status> {
+        let req = request.into_inner();
+        // Process id and secret key in session id are the same in RisingWave.
+        let session_id = (req.process_id, req.process_id);
+        let session_mgr = SESSION_MANAGER
+            .get()
+            .expect("session manager has been initialized");
+
+        let mut session_exists = session_mgr.env().cancel_queries_in_session(session_id);
+        session_exists |= session_mgr.env().cancel_creating_jobs_in_session(session_id);
+
+        if session_exists {
+            Ok(RpcResponse::new(KillProcessResponse {}))
+        } else {
+            Err(Status::not_found(format!(
+                "session {} does not exist on this frontend node",
+                req.process_id
+            )))
+        }
+    }
 }
 
 /// Get the new table plan for the given table schema change
@@ -17,6 +17,7 @@ use std::fmt::{Debug, Formatter};
 use std::pin::Pin;
 use std::sync::{Arc, RwLock};
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use futures::Stream;
 use pgwire::pg_server::{BoxedError, Session, SessionId};
@@ -142,6 +143,9 @@ pub struct QueryManager {
     distributed_query_semaphore: Option<Arc<tokio::sync::Semaphore>>,
     /// Total permitted distributed query number.
     pub total_distributed_query_limit: Option<u64>,
+    /// How long a query may wait in the admission queue for a free slot before it is rejected.
+    /// `None` means waiting indefinitely.
+    queue_timeout: Option<Duration>,
 }
 
 impl QueryManager {
@@ -152,6 +156,7 @@ impl QueryManager {
         query_metrics: Arc<DistributedQueryMetrics>,
         disrtibuted_query_limit: Option<u64>,
         total_distributed_query_limit: Option<u64>,
+        queue_timeout_ms: Option<u64>,
     ) -> Self {
         let distributed_query_semaphore = total_distributed_query_limit
             .map(|limit| Arc::new(tokio::sync::Semaphore::new(limit as usize)));
@@ -164,13 +169,25 @@ impl QueryManager {
             disrtibuted_query_limit,
             distributed_query_semaphore,
             total_distributed_query_limit,
+            queue_timeout: queue_timeout_ms.map(Duration::from_millis),
         }
     }
 
     async fn get_permit(&self) -> SchedulerResult<Option<OwnedSemaphorePermit>> {
         match self.distributed_query_semaphore {
             Some(ref semaphore) => {
-                let permit = semaphore.clone().acquire_owned().await;
+                let acquire = semaphore.clone().acquire_owned();
+                let permit = match self.queue_timeout {
+                    Some(timeout) => tokio::time::timeout(timeout, acquire)
+                        .await
+                        .map_err(|_elapsed| {
+                            self.query_metrics.rejected_query_counter.inc();
+                            crate::scheduler::SchedulerError::QueryQueueTimeout(
+                                timeout.as_millis() as u64
+                            )
+                        })?,
+                    None => acquire.await,
+                };
                 match permit {
                     Ok(permit) => Ok(Some(permit)),
                     Err(_) => {
@@ -193,7 +210,12 @@ impl QueryManager {
         query: Query,
         read_storage_tables: HashSet<TableId>,
     ) -> SchedulerResult<DistributedQueryStream> {
-        if let Some(query_limit) = self.disrtibuted_query_limit
+        // Superusers bypass admission control, so that administrative queries (e.g. inspecting
+        // `rw_processlist` or killing a runaway query) are not queued behind saturated compute.
+        let bypass_admission_control = context.session().is_super_user();
+
+        if !bypass_admission_control
+            && let Some(query_limit) = self.disrtibuted_query_limit
             && self.query_metrics.running_query_num.get() as u64 == query_limit
         {
             self.query_metrics.rejected_query_counter.inc();
@@ -203,7 +225,11 @@ impl QueryManager {
             ));
         }
         let query_id = query.query_id.clone();
-        let permit = self.get_permit().await?;
+        let permit = if bypass_admission_control {
+            None
+        } else {
+            self.get_permit().await?
+        };
         let query_execution = Arc::new(QueryExecution::new(query, context.session().id(), permit));
 
         // Add queries status when begin.
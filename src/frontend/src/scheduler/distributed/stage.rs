@@ -948,9 +948,16 @@ impl StageRunner {
             .map_err(|e| anyhow!(e))?;
 
         let t_id = task_id.task_id;
+        let mem_reservation_bytes = self.ctx.session().config().batch_mem_reservation_bytes();
 
         let stream_status: Fuse<Streaming<TaskInfoResponse>> = compute_client
-            .create_task(task_id, plan_fragment, self.epoch, expr_context)
+            .create_task(
+                task_id,
+                plan_fragment,
+                self.epoch,
+                expr_context,
+                mem_reservation_bytes,
+            )
             .await
             .inspect_err(|_| self.mask_failed_serving_worker(&worker))
             .map_err(|e| anyhow!(e))?
@@ -46,6 +46,9 @@ pub enum SchedulerError {
     #[error("Reject query: the {0} query number reaches the limit: {1}")]
     QueryReachLimit(QueryMode, u64),
 
+    #[error("Reject query: queued for more than {0} ms waiting for a free query slot")]
+    QueryQueueTimeout(u64),
+
     #[error(transparent)]
     BatchError(
         #[from]
@@ -128,9 +128,13 @@ impl StreamingJobTracker {
         let client = self.meta_client.clone();
         tokio::spawn(async move {
             client
-                .cancel_creating_jobs(PbJobs::Infos(CreatingJobInfos {
-                    infos: jobs.into_iter().map(|job| job.info).collect_vec(),
-                }))
+                .cancel_creating_jobs(
+                    PbJobs::Infos(CreatingJobInfos {
+                        infos: jobs.into_iter().map(|job| job.info).collect_vec(),
+                    }),
+                    // The initiating session is already gone at this point.
+                    None,
+                )
                 .await
         });
     }
@@ -62,6 +62,7 @@ impl BatchTaskContext for FrontendBatchTaskContext {
             self.session.auth_context(),
             self.session.shared_config(),
             self.session.env().system_params_manager().get_params(),
+            self.session.env().sessions_map().clone(),
         ))
     }
 
@@ -55,6 +55,7 @@ use risingwave_common::session_config::{ConfigReporter, SessionConfig, Visibilit
 use risingwave_common::system_param::local_manager::{
     LocalSystemParamsManager, LocalSystemParamsManagerRef,
 };
+use risingwave_common::system_param::reader::SystemParamsRead;
 use risingwave_common::telemetry::manager::TelemetryManager;
 use risingwave_common::telemetry::telemetry_env_enabled;
 use risingwave_common::types::DataType;
@@ -66,7 +67,7 @@ use risingwave_common::util::pretty_bytes::convert;
 use risingwave_common::util::runtime::BackgroundShutdownRuntime;
 use risingwave_common::{GIT_SHA, RW_VERSION};
 use risingwave_common_heap_profiling::HeapProfiler;
-use risingwave_common_service::{MetricsManager, ObserverManager};
+use risingwave_common_service::{apply_grpc_hardening, MetricsManager, ObserverManager};
 use risingwave_connector::source::monitor::{SourceMetrics, GLOBAL_SOURCE_METRICS};
 use risingwave_pb::common::worker_node::Property as AddWorkerNodeProperty;
 use risingwave_pb::common::WorkerType;
@@ -207,6 +208,7 @@ impl FrontendEnv {
             Arc::new(DistributedQueryMetrics::for_test()),
             None,
             None,
+            None,
         );
         let server_addr = HostAddr::try_from("127.0.0.1:4565").unwrap();
         let client_pool = Arc::new(ComputeClientPool::for_test());
@@ -326,6 +328,7 @@ impl FrontendEnv {
             Arc::new(GLOBAL_DISTRIBUTED_QUERY_METRICS.clone()),
             config.batch.distributed_query_limit,
             config.batch.max_batch_queries_per_frontend_node,
+            config.batch.distributed_query_queue_timeout_ms,
         );
 
         let user_info_manager = Arc::new(RwLock::new(UserInfoManager::default()));
@@ -396,8 +399,11 @@ impl FrontendEnv {
             tracing::info!("Telemetry didn't start due to config");
         }
 
+        let frontend_server_builder =
+            apply_grpc_hardening(tonic::transport::Server::builder(), &config.server)
+                .expect("failed to apply gRPC server hardening");
         tokio::spawn(async move {
-            tonic::transport::Server::builder()
+            frontend_server_builder
                 .add_service(HealthServer::new(health_srv))
                 .add_service(FrontendServiceServer::new(frontend_srv))
                 .serve(frontend_rpc_addr)
@@ -1363,6 +1369,27 @@ impl SessionManagerImpl {
         user_name: &str,
         peer_addr: AddressRef,
     ) -> std::result::Result<Arc<SessionImpl>, BoxedError> {
+        // Enforce the frontend-wide connection limit, if one is configured. Superusers are
+        // not exempted, like PostgreSQL's `superuser_reserved_connections = 0`.
+        let max_connections = self
+            .env
+            .system_params_manager()
+            .get_params()
+            .load()
+            .max_connections();
+        if max_connections != 0 && self.env.sessions_map().read().len() >= max_connections as usize
+        {
+            tracing::warn!(
+                user_name,
+                max_connections,
+                "rejecting connection: too many clients already",
+            );
+            return Err(Box::new(Error::new(
+                ErrorKind::ConnectionRefused,
+                "sorry, too many clients already",
+            )));
+        }
+
         let catalog_reader = self.env.catalog_reader();
         let reader = catalog_reader.read_guard();
         let database_name = reader
@@ -1410,6 +1437,11 @@ impl SessionManagerImpl {
                         }
                     } else if auth_info.encryption_type == EncryptionType::Oauth as i32 {
                         UserAuthenticator::OAuth(auth_info.metadata.clone())
+                    } else if auth_info.encryption_type == EncryptionType::ScramSha256 as i32 {
+                        let verifier = String::from_utf8_lossy(&auth_info.encrypted_value)
+                            .parse()
+                            .map_err(|e: String| Error::new(ErrorKind::InvalidInput, e))?;
+                        UserAuthenticator::ScramSha256(verifier)
                     } else {
                         return Err(Box::new(Error::new(
                             ErrorKind::Unsupported,
@@ -1604,6 +1636,36 @@ impl Session for SessionImpl {
         }
         Ok(())
     }
+
+    /// Check whether the session has been idle (outside of any transaction) for too long.
+    /// If yes, return an `IdleSessionTimeout` error, which closes the connection.
+    fn check_idle_session_timeout(&self) -> PsqlResult<()> {
+        // Unlike the idle-in-transaction timeout, this one only applies outside of a
+        // transaction, following PostgreSQL's `idle_session_timeout`.
+        if matches!(self.transaction_status(), TransactionStatus::Idle) {
+            let idle_session_timeout = self.config().idle_session_timeout() as u128;
+            // Idle session timeout has been enabled.
+            if idle_session_timeout != 0 {
+                let guard = self.exec_context.lock();
+                // No running sql i.e. idle
+                if guard.as_ref().and_then(|weak| weak.upgrade()).is_none() {
+                    if let Some(elapse_since_last_idle_instant) =
+                        self.elapse_since_last_idle_instant()
+                    {
+                        if elapse_since_last_idle_instant > idle_session_timeout {
+                            tracing::warn!(
+                                session_id = ?self.id(),
+                                user_name = self.user_name(),
+                                "killing session due to idle-session timeout",
+                            );
+                            return Err(PsqlError::IdleSessionTimeout);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Returns row description of the statement
@@ -48,6 +48,7 @@ use risingwave_pb::ddl_service::{
     alter_name_request, alter_set_schema_request, alter_swap_rename_request,
     create_connection_request, DdlProgress, PbTableJobType, ReplaceJobPlan, TableJobType,
 };
+use risingwave_pb::hummock::list_compaction_group_stats_response::CompactionGroupStats;
 use risingwave_pb::hummock::write_limits::WriteLimit;
 use risingwave_pb::hummock::{
     BranchedObject, CompactTaskAssignment, CompactTaskProgress, CompactionGroupInfo,
@@ -57,11 +58,14 @@ use risingwave_pb::meta::list_actor_splits_response::ActorSplit;
 use risingwave_pb::meta::list_actor_states_response::ActorState;
 use risingwave_pb::meta::list_fragment_distribution_response::FragmentDistribution;
 use risingwave_pb::meta::list_object_dependencies_response::PbObjectDependencies;
+use risingwave_pb::meta::list_actor_runtime_stats_response::ActorRuntimeStats;
+use risingwave_pb::meta::list_checkpoint_history_response::CheckpointHistoryEntry;
 use risingwave_pb::meta::list_rate_limits_response::RateLimitInfo;
+use risingwave_pb::meta::list_sink_commit_metrics_response::SinkCommitMetrics;
 use risingwave_pb::meta::list_table_fragment_states_response::TableFragmentState;
 use risingwave_pb::meta::list_table_fragments_response::TableFragmentInfo;
 use risingwave_pb::meta::{
-    EventLog, PbTableParallelism, PbThrottleTarget, RecoveryStatus, SystemParams,
+    EventLog, PbTableParallelism, PbThrottleTarget, ProcessInfo, RecoveryStatus, SystemParams,
 };
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_pb::user::update_user_request::UpdateField;
@@ -244,12 +248,18 @@ pub struct MockCatalogWriter {
 
 #[async_trait::async_trait]
 impl CatalogWriter for MockCatalogWriter {
-    async fn create_database(&self, db_name: &str, owner: UserId) -> Result<()> {
+    async fn create_database(
+        &self,
+        db_name: &str,
+        owner: UserId,
+        barrier_checkpoint_frequency: Option<u64>,
+    ) -> Result<()> {
         let database_id = self.gen_id();
         self.catalog.write().create_database(&PbDatabase {
             name: db_name.to_owned(),
             id: database_id,
             owner,
+            barrier_checkpoint_frequency,
         });
         self.create_schema(database_id, DEFAULT_SCHEMA_NAME, owner)
             .await?;
@@ -341,6 +351,17 @@ impl CatalogWriter for MockCatalogWriter {
         Ok(())
     }
 
+    async fn replace_materialized_view(
+        &self,
+        mut table: PbTable,
+        _graph: StreamFragmentGraph,
+        _mapping: ColIndexMapping,
+    ) -> Result<()> {
+        table.stream_job_status = PbStreamJobStatus::Created as _;
+        self.catalog.write().update_table(&table);
+        Ok(())
+    }
+
     async fn create_source(
         &self,
         source: PbSource,
@@ -687,6 +708,7 @@ impl MockCatalogWriter {
             id: 0,
             name: DEFAULT_DATABASE_NAME.to_owned(),
             owner: DEFAULT_SUPER_USER_ID,
+            barrier_checkpoint_frequency: None,
         });
         catalog.write().create_schema(&PbSchema {
             id: 1,
@@ -961,7 +983,11 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(())
     }
 
-    async fn cancel_creating_jobs(&self, _infos: PbJobs) -> RpcResult<Vec<u32>> {
+    async fn cancel_creating_jobs(
+        &self,
+        _infos: PbJobs,
+        _initiating_user: Option<String>,
+    ) -> RpcResult<Vec<u32>> {
         Ok(vec![])
     }
 
@@ -1072,6 +1098,14 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         unimplemented!()
     }
 
+    async fn list_compaction_group_stats(&self) -> RpcResult<Vec<CompactionGroupStats>> {
+        unimplemented!()
+    }
+
+    async fn pin_snapshot_for_export(&self, _table_ids: Vec<u32>, _ttl_sec: u64) -> RpcResult<u64> {
+        unimplemented!()
+    }
+
     async fn recover(&self) -> RpcResult<()> {
         unimplemented!()
     }
@@ -1081,6 +1115,7 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         _kind: PbThrottleTarget,
         _id: u32,
         _rate_limit: Option<u32>,
+        _initiating_user: Option<String>,
     ) -> RpcResult<()> {
         unimplemented!()
     }
@@ -1097,9 +1132,29 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(vec![])
     }
 
+    async fn list_sink_commit_metrics(&self) -> RpcResult<Vec<SinkCommitMetrics>> {
+        Ok(vec![])
+    }
+
+    async fn list_actor_runtime_stats(&self) -> RpcResult<Vec<ActorRuntimeStats>> {
+        Ok(vec![])
+    }
+
+    async fn list_checkpoint_history(&self) -> RpcResult<Vec<CheckpointHistoryEntry>> {
+        Ok(vec![])
+    }
+
     async fn get_meta_store_endpoint(&self) -> RpcResult<String> {
         unimplemented!()
     }
+
+    async fn list_process_list(&self) -> RpcResult<Vec<ProcessInfo>> {
+        Ok(vec![])
+    }
+
+    async fn kill_process(&self, _process_id: i32) -> RpcResult<()> {
+        unimplemented!()
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 
+use pgwire::scram::{is_scram_sha_256_verifier, ScramVerifier};
 use risingwave_pb::user::auth_info::EncryptionType;
 use risingwave_pb::user::AuthInfo;
 use risingwave_sqlparser::ast::SqlOption;
@@ -66,9 +67,18 @@ pub fn build_oauth_info(options: &Vec<SqlOption>) -> Option<AuthInfo> {
 /// password concatenated to their user name. The SHA-256 will be the default hash algorithm for
 /// Risingwave.
 ///
-/// A password that does not follow either of those formats is assumed to be unencrypted.
+/// A SCRAM-SHA-256 verifier in the PostgreSQL `SCRAM-SHA-256$<iterations>:<salt>$...`
+/// format is also stored as-is.
+///
+/// A password that does not follow any of those formats is assumed to be unencrypted and
+/// is encrypted with the algorithm given by the `password_encryption` session parameter,
+/// either `md5` or `scram-sha-256`.
 #[inline(always)]
-pub fn encrypted_password(name: &str, password: &str) -> Option<AuthInfo> {
+pub fn encrypted_password(
+    name: &str,
+    password: &str,
+    password_encryption: &str,
+) -> Option<AuthInfo> {
     // Specifying an empty string will also set the auth info to null.
     if password.is_empty() {
         return None;
@@ -86,6 +96,18 @@ pub fn encrypted_password(name: &str, password: &str) -> Option<AuthInfo> {
             encrypted_value: password.trim_start_matches(MD5_ENCRYPTED_PREFIX).into(),
             metadata: HashMap::new(),
         })
+    } else if is_scram_sha_256_verifier(password) {
+        Some(AuthInfo {
+            encryption_type: EncryptionType::ScramSha256 as i32,
+            encrypted_value: password.into(),
+            metadata: HashMap::new(),
+        })
+    } else if password_encryption == "scram-sha-256" {
+        Some(AuthInfo {
+            encryption_type: EncryptionType::ScramSha256 as i32,
+            encrypted_value: ScramVerifier::build(password).to_string().into_bytes(),
+            metadata: HashMap::new(),
+        })
     } else {
         Some(encrypt_default(name, password))
     }
@@ -110,6 +132,8 @@ pub fn encrypted_raw_password(info: &AuthInfo) -> String {
         EncryptionType::Sha256 => SHA256_ENCRYPTED_PREFIX,
         EncryptionType::Md5 => MD5_ENCRYPTED_PREFIX,
         EncryptionType::Oauth => "",
+        // The verifier is stored with its `SCRAM-SHA-256$` prefix included.
+        EncryptionType::ScramSha256 => "",
     };
     format!("{}{}", prefix, encrypted_pwd)
 }
@@ -201,8 +225,24 @@ mod tests {
         ];
         let output_passwords = input_passwords
             .iter()
-            .map(|&p| encrypted_password(user_name, p))
+            .map(|&p| encrypted_password(user_name, p, "md5"))
             .collect::<Vec<_>>();
         assert_eq!(output_passwords, expected_output_passwords);
     }
+
+    #[test]
+    fn test_encrypt_scram_sha_256_password() {
+        let (user_name, password) = ("foo", "bar");
+
+        // A verifier is generated under `password_encryption = 'scram-sha-256'`.
+        let info = encrypted_password(user_name, password, "scram-sha-256").unwrap();
+        assert_eq!(info.encryption_type, EncryptionType::ScramSha256 as i32);
+        let verifier = encrypted_raw_password(&info);
+        assert!(is_scram_sha_256_verifier(&verifier));
+
+        // A pre-computed verifier is stored as-is, regardless of `password_encryption`.
+        let info = encrypted_password(user_name, &verifier, "md5").unwrap();
+        assert_eq!(info.encryption_type, EncryptionType::ScramSha256 as i32);
+        assert_eq!(encrypted_raw_password(&info), verifier);
+    }
 }
@@ -40,6 +40,10 @@ pub type Service = Arc<WebhookService>;
 // We always use the `root` user to connect to the database to allow the webhook service to access all tables.
 const USER: &str = "root";
 
+/// Requests with this content type are treated as newline-delimited JSON and ingested
+/// as one row per line, so SaaS webhooks can batch events in a single POST.
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
 #[derive(Clone)]
 pub struct WebhookService {
     webhook_addr: SocketAddr,
@@ -136,14 +140,29 @@ pub(super) mod handlers {
             )
         })?;
 
+        let is_ndjson = headers
+            .get(axum::http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with(NDJSON_CONTENT_TYPE));
+        let rows: Vec<Vec<Expr>> = if is_ndjson {
+            payload
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| vec![Expr::Value(Value::SingleQuotedString(line.to_owned()))])
+                .collect()
+        } else {
+            vec![vec![Expr::Value(Value::SingleQuotedString(payload))]]
+        };
+        if rows.is_empty() {
+            return Ok(());
+        }
+
         let insert_stmt = Statement::Insert {
             table_name: ObjectName::from(vec![table.as_str().into()]),
             columns: vec![],
             source: Box::new(Query {
                 with: None,
-                body: SetExpr::Values(Values(vec![vec![Expr::Value(Value::SingleQuotedString(
-                    payload,
-                ))]])),
+                body: SetExpr::Values(Values(rows)),
                 order_by: vec![],
                 limit: None,
                 offset: None,
@@ -102,6 +102,8 @@ pub(crate) async fn new_hummock_java_binding_iter(
             path: read_plan.data_dir,
             prefetch_buffer_capacity: 1 << 10,
             max_prefetch_block_number: 16,
+            backfill_read_bytes_per_sec: 0,
+            compaction_read_bytes_per_sec: 0,
             recent_filter: None,
             state_store_metrics: Arc::new(global_hummock_state_store_metrics(
                 MetricLevel::Disabled,
@@ -31,6 +31,9 @@ mod m20241120_182555_hummock_add_time_travel_sst_index;
 mod m20241121_101830_table_engine;
 mod m20241125_043732_connection_params;
 mod m20241226_074013_clean_watermark_index_in_pk;
+mod m20250107_090000_event_log;
+mod m20250108_100000_source_next_column_id;
+mod m20250110_093000_database_checkpoint_frequency;
 mod utils;
 
 pub struct Migrator;
@@ -100,6 +103,9 @@ impl MigratorTrait for Migrator {
             Box::new(m20241121_101830_table_engine::Migration),
             Box::new(m20241125_043732_connection_params::Migration),
             Box::new(m20241226_074013_clean_watermark_index_in_pk::Migration),
+            Box::new(m20250107_090000_event_log::Migration),
+            Box::new(m20250108_100000_source_next_column_id::Migration),
+            Box::new(m20250110_093000_database_checkpoint_frequency::Migration),
         ]
     }
 }
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+use crate::utils::ColumnDefExt;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventLog::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(EventLog::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(EventLog::ChannelId).integer().not_null())
+                    .col(ColumnDef::new(EventLog::Timestamp).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(EventLog::Payload)
+                            .rw_binary(manager)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .table(EventLog::Table)
+                    .name("idx_event_log_channel_id")
+                    .col(EventLog::ChannelId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        crate::drop_tables!(manager, EventLog);
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum EventLog {
+    Table,
+    Id,
+    ChannelId,
+    Timestamp,
+    Payload,
+}
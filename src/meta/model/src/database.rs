@@ -26,6 +26,7 @@ pub struct Model {
     pub database_id: DatabaseId,
     #[sea_orm(unique)]
     pub name: String,
+    pub barrier_checkpoint_frequency: Option<i64>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -53,6 +54,7 @@ impl From<PbDatabase> for ActiveModel {
         Self {
             database_id: Set(db.id as _),
             name: Set(db.name),
+            barrier_checkpoint_frequency: Set(db.barrier_checkpoint_frequency.map(|v| v as _)),
         }
     }
 }
@@ -33,6 +33,7 @@ pub mod compaction_status;
 pub mod compaction_task;
 pub mod connection;
 pub mod database;
+pub mod event_log;
 pub mod fragment;
 pub mod function;
 pub mod hummock_epoch_to_version;
@@ -403,6 +404,7 @@ derive_from_blob!(
 );
 derive_from_blob!(ConnectionParams, risingwave_pb::catalog::ConnectionParams);
 derive_from_blob!(AuthInfo, risingwave_pb::user::PbAuthInfo);
+derive_from_blob!(EventLogPayload, risingwave_pb::meta::PbEventLog);
 
 derive_from_blob!(ConnectorSplits, risingwave_pb::source::ConnectorSplits);
 derive_from_blob!(VnodeBitmap, risingwave_pb::common::Buffer);
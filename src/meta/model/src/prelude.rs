@@ -23,6 +23,7 @@ pub use super::compaction_status::Entity as CompactionStatus;
 pub use super::compaction_task::Entity as CompactionTask;
 pub use super::connection::Entity as Connection;
 pub use super::database::Entity as Database;
+pub use super::event_log::Entity as EventLog;
 pub use super::fragment::Entity as Fragment;
 pub use super::function::Entity as Function;
 pub use super::hummock_pinned_snapshot::Entity as HummockPinnedSnapshot;
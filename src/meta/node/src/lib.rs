@@ -168,6 +168,12 @@ pub struct MetaNodeOpts {
     #[override_opts(path = meta.dangerous_max_idle_secs)]
     pub dangerous_max_idle_secs: Option<u64>,
 
+    /// Suspend all streaming jobs if idle for a certain period of time, and resume them on
+    /// the next activity.
+    #[clap(long, hide = true, env = "RW_AUTO_SUSPEND_IDLE_SECS")]
+    #[override_opts(path = meta.auto_suspend_idle_secs)]
+    pub auto_suspend_idle_secs: Option<u64>,
+
     /// Endpoint of the connector node.
     #[deprecated = "connector node has been deprecated."]
     #[clap(long, hide = true, env = "RW_CONNECTOR_RPC_ENDPOINT")]
@@ -290,6 +296,7 @@ pub fn start(
         let max_heartbeat_interval =
             Duration::from_secs(config.meta.max_heartbeat_interval_secs as u64);
         let max_idle_ms = config.meta.dangerous_max_idle_secs.unwrap_or(0) * 1000;
+        let auto_suspend_idle_ms = config.meta.auto_suspend_idle_secs.unwrap_or(0) * 1000;
         let in_flight_barrier_nums = config.streaming.in_flight_barrier_nums;
         let privatelink_endpoint_default_tags =
             opts.privatelink_endpoint_default_tags.map(|tags| {
@@ -351,7 +358,12 @@ pub fn start(
                     .parallelism_control_trigger_first_delay_sec,
                 in_flight_barrier_nums,
                 max_idle_ms,
+                auto_suspend_idle_ms,
                 compaction_deterministic_test: config.meta.enable_compaction_deterministic,
+                periodic_sst_scrub_interval_sec: config
+                    .meta
+                    .periodic_sst_scrub_interval_sec
+                    .unwrap_or(0),
                 default_parallelism: config.meta.default_parallelism,
                 vacuum_interval_sec: config.meta.vacuum_interval_sec,
                 vacuum_spin_interval_ms: config.meta.vacuum_spin_interval_ms,
@@ -469,6 +481,7 @@ pub fn start(
                     .actor_cnt_per_worker_parallelism_soft_limit,
                 license_key_path: opts.license_key_path,
             },
+            config.server.clone(),
             config.system.into_init_system_params(),
             Default::default(),
             shutdown,
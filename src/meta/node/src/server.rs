@@ -17,6 +17,7 @@ use std::time::Duration;
 
 use otlp_embedded::TraceServiceServer;
 use regex::Regex;
+use risingwave_common::config::ServerConfig;
 use risingwave_common::monitor::{RouterExt, TcpConfig};
 use risingwave_common::secret::LocalSecretManager;
 use risingwave_common::session_config::SessionConfig;
@@ -24,7 +25,7 @@ use risingwave_common::system_param::reader::SystemParamsRead;
 use risingwave_common::telemetry::manager::TelemetryManager;
 use risingwave_common::telemetry::{report_scarf_enabled, report_to_scarf, telemetry_env_enabled};
 use risingwave_common::util::tokio_util::sync::CancellationToken;
-use risingwave_common_service::{MetricsManager, TracingExtractLayer};
+use risingwave_common_service::{apply_grpc_hardening, MetricsManager, TracingExtractLayer};
 use risingwave_meta::barrier::GlobalBarrierManager;
 use risingwave_meta::controller::catalog::CatalogController;
 use risingwave_meta::controller::cluster::ClusterController;
@@ -123,6 +124,7 @@ pub async fn rpc_serve(
     max_cluster_heartbeat_interval: Duration,
     lease_interval_secs: u64,
     opts: MetaOpts,
+    server_config: ServerConfig,
     init_system_params: SystemParams,
     init_session_config: SessionConfig,
     shutdown: CancellationToken,
@@ -162,6 +164,7 @@ pub async fn rpc_serve(
         max_cluster_heartbeat_interval,
         lease_interval_secs,
         opts,
+        server_config,
         init_system_params,
         init_session_config,
         shutdown,
@@ -180,6 +183,7 @@ pub async fn rpc_serve_with_store(
     max_cluster_heartbeat_interval: Duration,
     lease_interval_secs: u64,
     opts: MetaOpts,
+    server_config: ServerConfig,
     init_system_params: SystemParams,
     init_session_config: SessionConfig,
     shutdown: CancellationToken,
@@ -214,6 +218,7 @@ pub async fn rpc_serve_with_store(
         let follower_handle = tokio::spawn(start_service_as_election_follower(
             follower_shutdown.clone(),
             address_info.clone(),
+            server_config.clone(),
             election_client.clone(),
         ));
 
@@ -244,6 +249,7 @@ pub async fn rpc_serve_with_store(
         address_info,
         max_cluster_heartbeat_interval,
         opts,
+        server_config,
         init_system_params,
         init_session_config,
         election_client,
@@ -264,6 +270,7 @@ pub async fn rpc_serve_with_store(
 pub async fn start_service_as_election_follower(
     shutdown: CancellationToken,
     address_info: AddressInfo,
+    server_config: ServerConfig,
     election_client: ElectionClientRef,
 ) {
     tracing::info!("starting follower services");
@@ -272,7 +279,9 @@ pub async fn start_service_as_election_follower(
 
     let health_srv = HealthServiceImpl::new();
 
-    let server = tonic::transport::Server::builder()
+    let server_builder = apply_grpc_hardening(tonic::transport::Server::builder(), &server_config)
+        .expect("failed to apply gRPC server hardening");
+    let server = server_builder
         .layer(MetricsMiddlewareLayer::new(Arc::new(
             GLOBAL_META_METRICS.clone(),
         )))
@@ -306,6 +315,7 @@ pub async fn start_service_as_election_leader(
     address_info: AddressInfo,
     max_cluster_heartbeat_interval: Duration,
     opts: MetaOpts,
+    server_config: ServerConfig,
     init_system_params: SystemParams,
     init_session_config: SessionConfig,
     election_client: ElectionClientRef,
@@ -516,7 +526,11 @@ pub async fn start_service_as_election_leader(
         scale_controller.clone(),
     );
 
-    let cluster_srv = ClusterServiceImpl::new(metadata_manager.clone(), barrier_manager.clone());
+    let cluster_srv = ClusterServiceImpl::new(
+        env.clone(),
+        metadata_manager.clone(),
+        barrier_manager.clone(),
+    );
     let stream_srv = StreamServiceImpl::new(
         env.clone(),
         barrier_scheduler.clone(),
@@ -603,6 +617,16 @@ pub async fn start_service_as_election_leader(
         shutdown.clone(),
     );
 
+    if env.opts.auto_suspend_idle_ms > 0 {
+        sub_tasks.push(IdleManager::start_auto_suspend_checker(
+            env.idle_manager_ref(),
+            barrier_scheduler.clone(),
+            metadata_manager.clone(),
+            Duration::from_millis(env.opts.auto_suspend_idle_ms),
+            Duration::from_secs(10),
+        ));
+    }
+
     let (abort_sender, abort_recv) = tokio::sync::oneshot::channel();
     let notification_mgr = env.notification_manager_ref();
     let stream_abort_handler = tokio::spawn(async move {
@@ -648,21 +672,28 @@ pub async fn start_service_as_election_leader(
         risingwave_pb::meta::event_log::Event::MetaNodeStart(event),
     ]);
 
-    let server_builder = tonic::transport::Server::builder()
+    // Unlimited by default, to allow services carrying arbitrarily large SQL definitions
+    // or graphs.
+    let max_message_size = server_config
+        .grpc_max_decoding_message_size
+        .map_or(usize::MAX, |s| s as usize);
+    let server_builder = apply_grpc_hardening(tonic::transport::Server::builder(), &server_config)?;
+    let server_builder = server_builder
         .layer(MetricsMiddlewareLayer::new(meta_metrics))
         .layer(TracingExtractLayer::new())
         .add_service(HeartbeatServiceServer::new(heartbeat_srv))
         .add_service(ClusterServiceServer::new(cluster_srv))
         .add_service(StreamManagerServiceServer::new(stream_srv))
         .add_service(
-            HummockManagerServiceServer::new(hummock_srv).max_decoding_message_size(usize::MAX),
+            HummockManagerServiceServer::new(hummock_srv)
+                .max_decoding_message_size(max_message_size),
         )
         .add_service(NotificationServiceServer::new(notification_srv))
         .add_service(MetaMemberServiceServer::new(meta_member_srv))
-        .add_service(DdlServiceServer::new(ddl_srv).max_decoding_message_size(usize::MAX))
+        .add_service(DdlServiceServer::new(ddl_srv).max_decoding_message_size(max_message_size))
         .add_service(UserServiceServer::new(user_srv))
         .add_service(CloudServiceServer::new(cloud_srv))
-        .add_service(ScaleServiceServer::new(scale_srv).max_decoding_message_size(usize::MAX))
+        .add_service(ScaleServiceServer::new(scale_srv).max_decoding_message_size(max_message_size))
         .add_service(HealthServer::new(health_srv))
         .add_service(BackupServiceServer::new(backup_srv))
         .add_service(SystemParamsServiceServer::new(system_params_srv))
@@ -13,16 +13,17 @@
 // limitations under the License.
 
 use risingwave_meta::barrier::BarrierManagerRef;
-use risingwave_meta::manager::MetadataManager;
+use risingwave_meta::manager::{MetaSrvEnv, MetadataManager};
 use risingwave_meta_model::WorkerId;
 use risingwave_pb::common::worker_node::State;
-use risingwave_pb::common::HostAddress;
+use risingwave_pb::common::{HostAddress, WorkerType};
 use risingwave_pb::meta::cluster_service_server::ClusterService;
 use risingwave_pb::meta::{
     ActivateWorkerNodeRequest, ActivateWorkerNodeResponse, AddWorkerNodeRequest,
     AddWorkerNodeResponse, DeleteWorkerNodeRequest, DeleteWorkerNodeResponse,
     GetClusterRecoveryStatusRequest, GetClusterRecoveryStatusResponse, GetMetaStoreInfoRequest,
-    GetMetaStoreInfoResponse, ListAllNodesRequest, ListAllNodesResponse,
+    GetMetaStoreInfoResponse, KillProcessRequest, KillProcessResponse, ListAllNodesRequest,
+    ListAllNodesResponse, ListProcessListRequest, ListProcessListResponse,
     UpdateWorkerNodeSchedulabilityRequest, UpdateWorkerNodeSchedulabilityResponse,
 };
 use tonic::{Request, Response, Status};
@@ -31,13 +32,19 @@ use crate::MetaError;
 
 #[derive(Clone)]
 pub struct ClusterServiceImpl {
+    env: MetaSrvEnv,
     metadata_manager: MetadataManager,
     barrier_manager: BarrierManagerRef,
 }
 
 impl ClusterServiceImpl {
-    pub fn new(metadata_manager: MetadataManager, barrier_manager: BarrierManagerRef) -> Self {
+    pub fn new(
+        env: MetaSrvEnv,
+        metadata_manager: MetadataManager,
+        barrier_manager: BarrierManagerRef,
+    ) -> Self {
         ClusterServiceImpl {
+            env,
             metadata_manager,
             barrier_manager,
         }
@@ -179,4 +186,71 @@ impl ClusterService for ClusterServiceImpl {
                 .meta_store_endpoint(),
         }))
     }
+
+    /// Aggregate the active sessions of all frontend nodes in the cluster.
+    async fn list_process_list(
+        &self,
+        _request: Request<ListProcessListRequest>,
+    ) -> Result<Response<ListProcessListResponse>, Status> {
+        let frontends = self
+            .metadata_manager
+            .list_worker_node(Some(WorkerType::Frontend), Some(State::Running))
+            .await?;
+
+        let mut processes = Vec::new();
+        for frontend in frontends {
+            let client = self
+                .env
+                .frontend_client_pool()
+                .get(&frontend)
+                .await
+                .map_err(MetaError::from)?;
+            let resp = client
+                .list_process_list(ListProcessListRequest {})
+                .await?
+                .into_inner();
+            processes.extend(resp.processes);
+        }
+
+        Ok(Response::new(ListProcessListResponse { processes }))
+    }
+
+    /// Kill the session with the given process id, on whichever frontend node it lives.
+    ///
+    /// Since process ids are only unique within one frontend node, the request is fanned out to
+    /// all frontend nodes and succeeds if any of them reports the session.
+    async fn kill_process(
+        &self,
+        request: Request<KillProcessRequest>,
+    ) -> Result<Response<KillProcessResponse>, Status> {
+        let req = request.into_inner();
+        let frontends = self
+            .metadata_manager
+            .list_worker_node(Some(WorkerType::Frontend), Some(State::Running))
+            .await?;
+
+        let mut killed = false;
+        for frontend in frontends {
+            let client = self
+                .env
+                .frontend_client_pool()
+                .get(&frontend)
+                .await
+                .map_err(MetaError::from)?;
+            match client.kill_process(req.clone()).await {
+                Ok(_) => killed = true,
+                Err(status) if status.code() == tonic::Code::NotFound => continue,
+                Err(status) => return Err(status),
+            }
+        }
+
+        if killed {
+            Ok(Response::new(KillProcessResponse {}))
+        } else {
+            Err(Status::not_found(format!(
+                "session {} does not exist in the cluster",
+                req.process_id
+            )))
+        }
+    }
 }
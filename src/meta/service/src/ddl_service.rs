@@ -18,7 +18,7 @@ use std::sync::Arc;
 use anyhow::anyhow;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use replace_job_plan::{ReplaceSource, ReplaceTable};
+use replace_job_plan::{ReplaceMaterializedView, ReplaceSource, ReplaceTable};
 use risingwave_common::catalog::ColumnCatalog;
 use risingwave_common::types::DataType;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
@@ -110,6 +110,9 @@ impl DdlServiceImpl {
                 replace_job_plan::ReplaceJob::ReplaceSource(ReplaceSource { source }) => {
                     StreamingJob::Source(source.unwrap())
                 }
+                replace_job_plan::ReplaceJob::ReplaceMaterializedView(
+                    ReplaceMaterializedView { table },
+                ) => StreamingJob::MaterializedView(table.unwrap()),
             },
             fragment_graph: fragment_graph.unwrap(),
             col_index_mapping,
@@ -40,7 +40,7 @@ impl HeartbeatService for HeartbeatServiceImpl {
         let result = self
             .metadata_manager
             .cluster_controller
-            .heartbeat(req.node_id as _)
+            .heartbeat(req.node_id as _, req.utilization)
             .await;
 
         match result {
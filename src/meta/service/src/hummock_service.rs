@@ -21,7 +21,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::{TableId, SYS_CATALOG_START_ID};
 use risingwave_hummock_sdk::key_range::KeyRange;
 use risingwave_hummock_sdk::version::HummockVersionDelta;
-use risingwave_hummock_sdk::HummockVersionId;
+use risingwave_hummock_sdk::{HummockVersionId, LocalSstableInfo};
 use risingwave_meta::backup_restore::BackupManagerRef;
 use risingwave_meta::manager::MetadataManager;
 use risingwave_pb::hummock::get_compaction_score_response::PickerInfo;
@@ -388,6 +388,16 @@ impl HummockManagerService for HummockServiceImpl {
         }))
     }
 
+    async fn rise_ctl_checkpoint_version(
+        &self,
+        _request: Request<RiseCtlCheckpointVersionRequest>,
+    ) -> Result<Response<RiseCtlCheckpointVersionResponse>, Status> {
+        let checkpointed_delta_log_num = self.hummock_manager.create_version_checkpoint(0).await?;
+        Ok(Response::new(RiseCtlCheckpointVersionResponse {
+            checkpointed_delta_log_num,
+        }))
+    }
+
     async fn rise_ctl_list_compaction_status(
         &self,
         _request: Request<RiseCtlListCompactionStatusRequest>,
@@ -612,6 +622,81 @@ impl HummockManagerService for HummockServiceImpl {
             .await?;
         Ok(Response::new(MergeCompactionGroupResponse {}))
     }
+
+    async fn rise_ctl_list_orphan_objects(
+        &self,
+        request: Request<RiseCtlListOrphanObjectsRequest>,
+    ) -> Result<Response<RiseCtlListOrphanObjectsResponse>, Status> {
+        let req = request.into_inner();
+        let orphan_objects = self
+            .hummock_manager
+            .list_orphan_objects(
+                Duration::from_secs(req.sst_retention_time_sec),
+                Some(self.backup_manager.clone()),
+            )
+            .await?;
+        Ok(Response::new(RiseCtlListOrphanObjectsResponse {
+            orphan_objects,
+        }))
+    }
+
+    async fn rise_ctl_update_object_holds(
+        &self,
+        request: Request<RiseCtlUpdateObjectHoldsRequest>,
+    ) -> Result<Response<RiseCtlUpdateObjectHoldsResponse>, Status> {
+        let req = request.into_inner();
+        self.hummock_manager.hold_objects(req.object_ids);
+        Ok(Response::new(RiseCtlUpdateObjectHoldsResponse {}))
+    }
+
+    async fn list_compaction_group_stats(
+        &self,
+        _request: Request<ListCompactionGroupStatsRequest>,
+    ) -> Result<Response<ListCompactionGroupStatsResponse>, Status> {
+        Ok(Response::new(ListCompactionGroupStatsResponse {
+            stats: self.hummock_manager.list_compaction_group_stats(),
+        }))
+    }
+
+    async fn pin_snapshot_for_export(
+        &self,
+        request: Request<PinSnapshotForExportRequest>,
+    ) -> Result<Response<PinSnapshotForExportResponse>, Status> {
+        let req = request.into_inner();
+        let (export_epoch, version_id) = self
+            .hummock_manager
+            .pin_snapshot_for_export(req.table_ids, Duration::from_secs(req.ttl_sec))
+            .await?;
+        Ok(Response::new(PinSnapshotForExportResponse {
+            export_epoch,
+            hummock_version_id: version_id.to_u64(),
+        }))
+    }
+
+    async fn ingest_external_sstables(
+        &self,
+        request: Request<IngestExternalSstablesRequest>,
+    ) -> Result<Response<IngestExternalSstablesResponse>, Status> {
+        let req = request.into_inner();
+        let ssts = req
+            .ssts
+            .into_iter()
+            .map(|sst| {
+                let sst_info = sst
+                    .sst
+                    .ok_or_else(|| Status::invalid_argument("sst info not provided"))?;
+                Ok(LocalSstableInfo::new(
+                    sst_info.into(),
+                    Default::default(),
+                    sst.created_at,
+                ))
+            })
+            .try_collect::<_, Vec<_>, Status>()?;
+        self.hummock_manager
+            .ingest_external_sstables(TableId::new(req.table_id), ssts)
+            .await?;
+        Ok(Response::new(IngestExternalSstablesResponse {}))
+    }
 }
 
 #[cfg(test)]
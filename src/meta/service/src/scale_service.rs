@@ -20,9 +20,10 @@ use risingwave_meta_model::FragmentId;
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::meta::scale_service_server::ScaleService;
 use risingwave_pb::meta::{
-    GetClusterInfoRequest, GetClusterInfoResponse, GetServerlessStreamingJobsStatusRequest,
-    GetServerlessStreamingJobsStatusResponse, PbWorkerReschedule, RescheduleRequest,
-    RescheduleResponse, UpdateStreamingJobNodeLabelsRequest, UpdateStreamingJobNodeLabelsResponse,
+    event_log, GetClusterInfoRequest, GetClusterInfoResponse,
+    GetServerlessStreamingJobsStatusRequest, GetServerlessStreamingJobsStatusResponse,
+    PbWorkerReschedule, RescheduleRequest, RescheduleResponse,
+    UpdateStreamingJobNodeLabelsRequest, UpdateStreamingJobNodeLabelsResponse,
 };
 use risingwave_pb::source::{ConnectorSplit, ConnectorSplits};
 use tonic::{Request, Response, Status};
@@ -117,6 +118,11 @@ impl ScaleService for ScaleServiceImpl {
             ..
         } = request.into_inner();
 
+        let audit_arguments = format!(
+            "worker_reschedules={:?}, resolve_no_shuffle_upstream={}",
+            worker_reschedules, resolve_no_shuffle_upstream
+        );
+
         let _reschedule_job_lock = self.stream_manager.reschedule_lock_write_guard().await;
         for (database_id, worker_reschedules) in self
             .metadata_manager
@@ -166,6 +172,16 @@ impl ScaleService for ScaleServiceImpl {
                 .await?;
         }
 
+        self.stream_manager
+            .env
+            .event_log_manager_ref()
+            .add_event_logs(vec![event_log::Event::AuditLog(event_log::EventAuditLog {
+                operation: "RESCHEDULE".into(),
+                arguments: audit_arguments,
+                // Reschedule is only issued by risectl, not on behalf of a user session.
+                user_name: String::new(),
+            })]);
+
         Ok(Response::new(RescheduleResponse {
             success: true,
             revision: 0,
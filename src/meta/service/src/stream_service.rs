@@ -165,6 +165,19 @@ impl StreamManagerService for StreamServiceImpl {
             .run_command(database_id, Command::Throttle(mutation))
             .await?;
 
+        self.env
+            .event_log_manager_ref()
+            .add_event_logs(vec![event_log::Event::AuditLog(event_log::EventAuditLog {
+                operation: "THROTTLE".into(),
+                arguments: format!(
+                    "kind={:?}, id={}, rate={:?}",
+                    request.kind(),
+                    request.id,
+                    request.rate
+                ),
+                user_name: request.initiating_user.unwrap_or_default(),
+            })]);
+
         Ok(Response::new(ApplyThrottleResponse { status: None }))
     }
 
@@ -192,6 +205,15 @@ impl StreamManagerService for StreamServiceImpl {
             .into_iter()
             .map(|id| id.table_id)
             .collect_vec();
+
+        self.env
+            .event_log_manager_ref()
+            .add_event_logs(vec![event_log::Event::AuditLog(event_log::EventAuditLog {
+                operation: "CANCEL_CREATING_JOBS".into(),
+                arguments: format!("canceled_jobs={:?}", canceled_jobs),
+                user_name: req.initiating_user.unwrap_or_default(),
+            })]);
+
         Ok(Response::new(CancelCreatingJobsResponse {
             status: None,
             canceled_jobs,
@@ -456,4 +478,31 @@ impl StreamManagerService for StreamServiceImpl {
             .await?;
         Ok(Response::new(ListRateLimitsResponse { rate_limits }))
     }
+
+    async fn list_sink_commit_metrics(
+        &self,
+        _request: Request<ListSinkCommitMetricsRequest>,
+    ) -> Result<Response<ListSinkCommitMetricsResponse>, Status> {
+        let metrics = risingwave_meta::manager::sink_coordination::list_sink_commit_metrics();
+        Ok(Response::new(ListSinkCommitMetricsResponse { metrics }))
+    }
+
+    async fn list_actor_runtime_stats(
+        &self,
+        _request: Request<ListActorRuntimeStatsRequest>,
+    ) -> Result<Response<ListActorRuntimeStatsResponse>, Status> {
+        let stats =
+            risingwave_meta::manager::actor_stats::list_actor_runtime_stats(&self.metadata_manager)
+                .await?;
+        Ok(Response::new(ListActorRuntimeStatsResponse { stats }))
+    }
+
+    async fn list_checkpoint_history(
+        &self,
+        _request: Request<ListCheckpointHistoryRequest>,
+    ) -> Result<Response<ListCheckpointHistoryResponse>, Status> {
+        let entries = risingwave_meta::barrier::list_checkpoint_history();
+        Ok(Response::new(ListCheckpointHistoryResponse { entries }))
+    }
+
 }
@@ -49,6 +49,7 @@ use crate::barrier::{
     InflightSubscriptionInfo, SnapshotBackfillInfo, TracedEpoch,
 };
 use crate::manager::ActiveStreamingWorkerNodes;
+use crate::model::ActorId;
 use crate::rpc::metrics::GLOBAL_META_METRICS;
 use crate::stream::fill_snapshot_backfill_epoch;
 use crate::{MetaError, MetaResult};
@@ -57,12 +58,16 @@ use crate::{MetaError, MetaResult};
 pub(crate) struct CheckpointControl {
     pub(super) databases: HashMap<DatabaseId, DatabaseCheckpointControlStatus>,
     pub(super) hummock_version_stats: HummockVersionStats,
+    /// Per-database `checkpoint_frequency` overrides from the database catalog. Databases
+    /// not in the map follow the system-wide checkpoint cadence.
+    database_checkpoint_frequencies: HashMap<DatabaseId, u64>,
 }
 
 impl CheckpointControl {
     pub(crate) fn new(
         databases: impl IntoIterator<Item = (DatabaseId, DatabaseCheckpointControl)>,
         hummock_version_stats: HummockVersionStats,
+        database_checkpoint_frequencies: HashMap<DatabaseId, u64>,
     ) -> Self {
         Self {
             databases: databases
@@ -75,6 +80,7 @@ impl CheckpointControl {
                 })
                 .collect(),
             hummock_version_stats,
+            database_checkpoint_frequencies,
         }
     }
 
@@ -153,6 +159,7 @@ impl CheckpointControl {
             command,
             span,
             checkpoint,
+            checkpoint_forced,
         } = new_barrier;
 
         if let Some((database_id, command, notifiers)) = command {
@@ -166,9 +173,13 @@ impl CheckpointControl {
                 ),
                 Entry::Vacant(entry) => match &command {
                     Command::CreateStreamingJob {
+                        info,
                         job_type: CreateStreamingJobType::Normal,
-                        ..
                     } => {
+                        if let Some(frequency) = info.database_checkpoint_frequency {
+                            self.database_checkpoint_frequencies
+                                .insert(database_id, frequency);
+                        }
                         let new_database = DatabaseCheckpointControl::new(database_id);
                         let max_prev_epoch = if let Some(max_prev_epoch) = max_prev_epoch {
                             if max_prev_epoch.value()
@@ -207,9 +218,14 @@ impl CheckpointControl {
 
             let curr_epoch = max_prev_epoch.next();
 
+            let database_checkpoint = database.next_checkpoint(
+                self.database_checkpoint_frequencies.get(&database_id).copied(),
+                checkpoint,
+                checkpoint_forced,
+            );
             database.handle_new_barrier(
                 Some((command, notifiers)),
-                checkpoint,
+                database_checkpoint,
                 span.clone(),
                 control_stream_manager,
                 active_streaming_nodes,
@@ -223,9 +239,16 @@ impl CheckpointControl {
                 if database.database_id == database_id {
                     continue;
                 }
+                let database_checkpoint = database.next_checkpoint(
+                    self.database_checkpoint_frequencies
+                        .get(&database.database_id)
+                        .copied(),
+                    checkpoint,
+                    checkpoint_forced,
+                );
                 database.handle_new_barrier(
                     None,
-                    checkpoint,
+                    database_checkpoint,
                     span.clone(),
                     control_stream_manager,
                     active_streaming_nodes,
@@ -242,9 +265,16 @@ impl CheckpointControl {
                 let Some(database) = database.running_state_mut() else {
                     continue;
                 };
+                let database_checkpoint = database.next_checkpoint(
+                    self.database_checkpoint_frequencies
+                        .get(&database.database_id)
+                        .copied(),
+                    checkpoint,
+                    checkpoint_forced,
+                );
                 database.handle_new_barrier(
                     None,
-                    checkpoint,
+                    database_checkpoint,
                     span.clone(),
                     control_stream_manager,
                     active_streaming_nodes,
@@ -342,6 +372,16 @@ impl CheckpointControl {
         });
     }
 
+    /// Find the streaming job that the given actor belongs to, if the database is running.
+    pub(crate) fn find_job_of_actor(
+        &self,
+        database_id: DatabaseId,
+        actor_id: ActorId,
+    ) -> Option<TableId> {
+        let database = self.databases.get(&database_id)?.checkpoint_control()?;
+        database.state.inflight_graph_info.job_of_actor(actor_id)
+    }
+
     pub(crate) fn inflight_infos(
         &self,
     ) -> impl Iterator<Item = (DatabaseId, &InflightSubscriptionInfo, &InflightDatabaseInfo)> + '_
@@ -470,6 +510,10 @@ pub(crate) struct DatabaseCheckpointControl {
 
     creating_streaming_job_controls: HashMap<TableId, CreatingStreamingJobControl>,
 
+    /// The number of barriers since the last checkpoint barrier of this database. Only
+    /// consulted when the database has a `checkpoint_frequency` override.
+    num_uncheckpointed_barriers: u64,
+
     create_mview_tracker: CreateMviewProgressTracker,
 }
 
@@ -482,6 +526,7 @@ impl DatabaseCheckpointControl {
             completing_barrier: None,
             creating_streaming_job_controls: Default::default(),
             create_mview_tracker: Default::default(),
+            num_uncheckpointed_barriers: 0,
         }
     }
 
@@ -497,7 +542,33 @@ impl DatabaseCheckpointControl {
             completing_barrier: None,
             creating_streaming_job_controls: Default::default(),
             create_mview_tracker,
+            num_uncheckpointed_barriers: 0,
+        }
+    }
+
+    /// Decide whether the next barrier of this database should be a checkpoint.
+    ///
+    /// When the database has a `checkpoint_frequency` override, a local barrier counter
+    /// is consulted instead of the global checkpoint cadence, while forced checkpoints
+    /// (e.g. from `FLUSH` or DDL commands) are always honored.
+    fn next_checkpoint(
+        &mut self,
+        override_frequency: Option<u64>,
+        default_checkpoint: bool,
+        checkpoint_forced: bool,
+    ) -> bool {
+        let checkpoint = match override_frequency {
+            Some(frequency) => {
+                checkpoint_forced || self.num_uncheckpointed_barriers + 1 >= frequency
+            }
+            None => default_checkpoint,
+        };
+        if checkpoint {
+            self.num_uncheckpointed_barriers = 0;
+        } else {
+            self.num_uncheckpointed_barriers += 1;
         }
+        checkpoint
     }
 
     fn total_command_num(&self) -> usize {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::mem::{replace, take};
 use std::task::{Context, Poll};
 
@@ -22,18 +22,21 @@ use risingwave_meta_model::WorkerId;
 use risingwave_pb::stream_service::streaming_control_stream_response::{
     ReportDatabaseFailureResponse, ResetDatabaseResponse,
 };
+use risingwave_pb::stream_plan::StreamActor;
 use risingwave_pb::stream_service::BarrierCompleteResponse;
 use thiserror_ext::AsReport;
 use tracing::{info, warn};
 
 use crate::barrier::checkpoint::control::DatabaseCheckpointControlStatus;
 use crate::barrier::checkpoint::{CheckpointControl, DatabaseCheckpointControl};
+use crate::barrier::info::InflightDatabaseInfo;
 use crate::barrier::complete_task::BarrierCompleteOutput;
 use crate::barrier::rpc::ControlStreamManager;
 use crate::barrier::worker::{
     get_retry_backoff_strategy, RetryBackoffFuture, RetryBackoffStrategy,
 };
 use crate::barrier::DatabaseRuntimeInfoSnapshot;
+use crate::model::{ActorId, FragmentId};
 use crate::MetaResult;
 
 /// We can treat each database as a state machine of 3 states: `Running`, `Resetting` and `Initializing`.
@@ -80,6 +83,9 @@ enum DatabaseRecoveringStage {
 
 pub(crate) struct DatabaseRecoveringState {
     stage: DatabaseRecoveringStage,
+    /// The actor whose failure triggered the recovery, if reported by the compute node.
+    /// Used to resolve the failed fragment subgraph when re-initializing the database.
+    failed_actor: Option<ActorId>,
     next_reset_request_id: u32,
     retry_backoff_strategy: RetryBackoffStrategy,
 }
@@ -230,7 +236,9 @@ impl CheckpointControl {
     }
 }
 
-pub(crate) struct EnterReset;
+pub(crate) struct EnterReset {
+    pub(crate) failed_actor: Option<ActorId>,
+}
 
 impl DatabaseStatusAction<'_, EnterReset> {
     pub(crate) fn enter(
@@ -259,6 +267,7 @@ impl DatabaseStatusAction<'_, EnterReset> {
                             reset_request_id,
                             backoff_future: None,
                         },
+                        failed_actor: self.action.failed_actor,
                         next_reset_request_id: reset_request_id + 1,
                         retry_backoff_strategy: get_retry_backoff_strategy(),
                     });
@@ -271,6 +280,9 @@ impl DatabaseStatusAction<'_, EnterReset> {
                     let (backoff_future, reset_request_id) = state.next_retry();
                     let remaining_workers =
                         control_stream_manager.reset_database(self.database_id, reset_request_id);
+                    if self.action.failed_actor.is_some() {
+                        state.failed_actor = self.action.failed_actor;
+                    }
                     state.stage = DatabaseRecoveringStage::Resetting {
                         remaining_workers,
                         reset_workers: Default::default(),
@@ -290,10 +302,11 @@ impl CheckpointControl {
         control_stream_manager: &mut ControlStreamManager,
     ) -> Option<DatabaseStatusAction<'_, EnterReset>> {
         let database_id = DatabaseId::new(resp.database_id);
+        let failed_actor = resp.failed_actor_id;
         let database_status = self.databases.get_mut(&database_id).expect("should exist");
         match database_status {
             DatabaseCheckpointControlStatus::Running(_) => {
-                Some(self.new_database_status_action(database_id, EnterReset))
+                Some(self.new_database_status_action(database_id, EnterReset { failed_actor }))
             }
             DatabaseCheckpointControlStatus::Recovering(state) => match state.stage {
                 DatabaseRecoveringStage::Resetting { .. } => {
@@ -305,6 +318,9 @@ impl CheckpointControl {
                     let (backoff_future, reset_request_id) = state.next_retry();
                     let remaining_workers =
                         control_stream_manager.reset_database(database_id, reset_request_id);
+                    if failed_actor.is_some() {
+                        state.failed_actor = failed_actor;
+                    }
                     state.stage = DatabaseRecoveringStage::Resetting {
                         remaining_workers,
                         reset_workers: Default::default(),
@@ -350,6 +366,22 @@ impl DatabaseStatusAction<'_, EnterInitializing> {
             mut source_splits,
             mut background_jobs,
         } = runtime_info;
+        // Resolve the minimal fragment subgraph affected by the reported failure: the failed
+        // fragment and everything transitively downstream of it. The reset is still performed
+        // database-wide for now, and the subgraph is recorded so that the rebuild can be scoped
+        // to it once compute nodes support resetting individual fragments.
+        if let Some(failed_actor) = status.failed_actor
+            && let Some(to_restore) =
+                fragment_subgraph_to_restore(failed_actor, &database_fragment_info, &stream_actors)
+        {
+            info!(
+                database_id = self.database_id.database_id,
+                failed_actor,
+                affected_fragments = to_restore.len(),
+                total_fragments = database_fragment_info.fragment_infos().count(),
+                "resolved failed fragment subgraph for recovery"
+            );
+        }
         let result: MetaResult<_> = try {
             control_stream_manager.add_partial_graph(self.database_id, None)?;
             control_stream_manager.inject_database_initial_barrier(
@@ -395,6 +427,46 @@ impl DatabaseStatusAction<'_, EnterInitializing> {
     }
 }
 
+/// Compute the fragments that must be restored for the given failed actor: the fragment
+/// containing the actor, plus all fragments transitively downstream of it via dispatchers.
+/// Returns `None` if the actor no longer exists in the database, e.g. when it has been
+/// dropped between the failure report and the reset.
+fn fragment_subgraph_to_restore(
+    failed_actor: ActorId,
+    database_info: &InflightDatabaseInfo,
+    stream_actors: &HashMap<ActorId, StreamActor>,
+) -> Option<HashSet<FragmentId>> {
+    let mut actor_location: HashMap<ActorId, FragmentId> = HashMap::new();
+    let mut fragment_actors: HashMap<FragmentId, Vec<ActorId>> = HashMap::new();
+    for (fragment_id, info) in database_info.fragment_infos_with_id() {
+        for actor_id in info.actors.keys() {
+            actor_location.insert(*actor_id, fragment_id);
+            fragment_actors.entry(fragment_id).or_default().push(*actor_id);
+        }
+    }
+
+    let failed_fragment = *actor_location.get(&failed_actor)?;
+    let mut to_restore = HashSet::from([failed_fragment]);
+    let mut queue = VecDeque::from([failed_fragment]);
+    while let Some(fragment_id) = queue.pop_front() {
+        for actor_id in fragment_actors.get(&fragment_id).into_iter().flatten() {
+            let Some(actor) = stream_actors.get(actor_id) else {
+                continue;
+            };
+            for dispatcher in &actor.dispatcher {
+                for downstream_actor in &dispatcher.downstream_actor_id {
+                    if let Some(downstream_fragment) = actor_location.get(downstream_actor)
+                        && to_restore.insert(*downstream_fragment)
+                    {
+                        queue.push_back(*downstream_fragment);
+                    }
+                }
+            }
+        }
+    }
+    Some(to_restore)
+}
+
 pub(crate) struct EnterRunning;
 
 impl DatabaseStatusAction<'_, EnterRunning> {
@@ -0,0 +1,49 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use risingwave_pb::meta::list_checkpoint_history_response::CheckpointHistoryEntry;
+
+/// Number of recent checkpoints kept for `rw_catalog.rw_checkpoint_history`.
+const CHECKPOINT_HISTORY_CAPACITY: usize = 1024;
+
+static CHECKPOINT_HISTORY: LazyLock<Mutex<VecDeque<CheckpointHistoryEntry>>> =
+    LazyLock::new(|| Mutex::new(VecDeque::with_capacity(CHECKPOINT_HISTORY_CAPACITY)));
+
+/// Records a committed checkpoint. Called from the barrier complete task, so entries are
+/// appended in commit order.
+pub(crate) fn record_checkpoint(epoch: u64, duration_sec: f64, command: String) {
+    let mut history = CHECKPOINT_HISTORY.lock().unwrap();
+    if history.len() == CHECKPOINT_HISTORY_CAPACITY {
+        history.pop_front();
+    }
+    let committed_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    history.push_back(CheckpointHistoryEntry {
+        epoch,
+        committed_at_ms,
+        duration_sec,
+        command,
+    });
+}
+
+/// Returns the recent checkpoint history, oldest first.
+pub fn list_checkpoint_history() -> Vec<CheckpointHistoryEntry> {
+    CHECKPOINT_HISTORY.lock().unwrap().iter().cloned().collect()
+}
@@ -190,6 +190,9 @@ pub struct CreateStreamingJobCommandInfo {
     pub create_type: CreateType,
     pub streaming_job: StreamingJob,
     pub internal_tables: Vec<Table>,
+    /// The `checkpoint_frequency` override of the job's database, if any, so that a newly
+    /// created database is scheduled with the right checkpoint cadence right away.
+    pub database_checkpoint_frequency: Option<u64>,
 }
 
 impl CreateStreamingJobCommandInfo {
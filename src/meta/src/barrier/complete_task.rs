@@ -138,6 +138,17 @@ impl CompleteBarrierTask {
             {
                 let duration_sec = enqueue_time.stop_and_record();
                 Self::report_complete_event(&env, duration_sec, &command_ctx);
+                if command_ctx.barrier_info.kind.is_checkpoint() {
+                    crate::barrier::checkpoint_stats::record_checkpoint(
+                        command_ctx.barrier_info.curr_epoch.value().0,
+                        duration_sec,
+                        command_ctx
+                            .command
+                            .as_ref()
+                            .map(|command| command.to_string())
+                            .unwrap_or_else(|| "barrier".to_owned()),
+                    );
+                }
                 GLOBAL_META_METRICS
                     .last_committed_barrier_time
                     .set(command_ctx.barrier_info.curr_epoch.value().as_unix_secs() as i64);
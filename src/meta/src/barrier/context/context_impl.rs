@@ -15,14 +15,16 @@
 use std::sync::Arc;
 
 use futures::future::try_join_all;
-use risingwave_common::catalog::DatabaseId;
+use risingwave_common::catalog::{DatabaseId, TableId};
 use risingwave_pb::common::WorkerNode;
 use risingwave_pb::hummock::HummockVersionStats;
-use risingwave_pb::meta::PausedReason;
+use risingwave_pb::meta::{event_log, PausedReason};
 use risingwave_pb::stream_plan::PbFragmentTypeFlag;
 use risingwave_pb::stream_service::streaming_control_stream_request::PbInitRequest;
 use risingwave_pb::stream_service::WaitEpochCommitRequest;
 use risingwave_rpc_client::StreamingControlHandle;
+use thiserror_ext::AsReport;
+use tracing::warn;
 
 use crate::barrier::command::CommandContext;
 use crate::barrier::context::{GlobalBarrierWorkerContext, GlobalBarrierWorkerContextImpl};
@@ -96,6 +98,43 @@ impl GlobalBarrierWorkerContext for GlobalBarrierWorkerContextImpl {
     ) -> MetaResult<Option<DatabaseRuntimeInfoSnapshot>> {
         self.reload_database_runtime_info_impl(database_id).await
     }
+
+    async fn quarantine_job(
+        &self,
+        database_id: DatabaseId,
+        job_id: TableId,
+        consecutive_failures: u32,
+    ) {
+        // Pause the backfill of the job. The zero rate limit is persisted in the fragment
+        // graph and will take effect when the recovery rebuilds the actors.
+        if let Err(e) = self
+            .metadata_manager
+            .update_backfill_rate_limit_by_table_id(job_id, Some(0))
+            .await
+        {
+            warn!(
+                job_id = job_id.table_id,
+                error = %e.as_report(),
+                "failed to quarantine streaming job"
+            );
+            return;
+        }
+        warn!(
+            job_id = job_id.table_id,
+            database_id = database_id.database_id,
+            consecutive_failures,
+            "streaming job quarantined after consecutive failures, backfill paused; \
+             retry with `ALTER MATERIALIZED VIEW ... RESUME`"
+        );
+        let event = event_log::EventStreamJobQuarantine {
+            job_id: job_id.table_id,
+            database_id: database_id.database_id,
+            consecutive_failures,
+        };
+        self.env
+            .event_log_manager_ref()
+            .add_event_logs(vec![event_log::Event::StreamJobQuarantine(event)]);
+    }
 }
 
 impl GlobalBarrierWorkerContextImpl {
@@ -19,7 +19,7 @@ use std::future::Future;
 use std::sync::Arc;
 
 use arc_swap::ArcSwap;
-use risingwave_common::catalog::DatabaseId;
+use risingwave_common::catalog::{DatabaseId, TableId};
 use risingwave_pb::common::WorkerNode;
 use risingwave_pb::hummock::HummockVersionStats;
 use risingwave_pb::stream_service::streaming_control_stream_request::PbInitRequest;
@@ -75,6 +75,18 @@ pub(super) trait GlobalBarrierWorkerContext: Send + Sync + 'static {
         &self,
         database_id: DatabaseId,
     ) -> MetaResult<Option<DatabaseRuntimeInfoSnapshot>>;
+
+    /// Quarantine a poison-pill streaming job that keeps triggering recovery: pause its
+    /// backfill by setting the backfill rate limit to 0 and emit an event log entry. The
+    /// zero rate limit is picked up when the recovery rebuilds the actors. The job can be
+    /// retried with `ALTER MATERIALIZED VIEW ... RESUME` (or by setting the backfill rate
+    /// limit back explicitly).
+    fn quarantine_job(
+        &self,
+        database_id: DatabaseId,
+        job_id: TableId,
+        consecutive_failures: u32,
+    ) -> impl Future<Output = ()> + Send + '_;
 }
 
 pub(super) struct GlobalBarrierWorkerContextImpl {
@@ -260,6 +260,19 @@ impl GlobalBarrierWorkerContextImpl {
 
                     // get split assignments for all actors
                     let source_splits = self.source_manager.list_assignments().await;
+
+                    let database_checkpoint_frequencies = self
+                        .metadata_manager
+                        .catalog_controller
+                        .list_databases()
+                        .await?
+                        .into_iter()
+                        .filter_map(|db| {
+                            db.barrier_checkpoint_frequency
+                                .map(|frequency| (DatabaseId::new(db.id), frequency))
+                        })
+                        .collect();
+
                     Ok(BarrierWorkerRuntimeInfoSnapshot {
                         active_streaming_nodes,
                         database_fragment_infos: info,
@@ -269,6 +282,7 @@ impl GlobalBarrierWorkerContextImpl {
                         source_splits,
                         background_jobs,
                         hummock_version_stats: self.hummock_manager.get_version_stats().await,
+                        database_checkpoint_frequencies,
                     })
                 }
             }
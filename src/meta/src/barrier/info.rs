@@ -75,6 +75,16 @@ impl InflightDatabaseInfo {
         self.jobs.values().flat_map(|job| job.fragment_infos())
     }
 
+    pub fn fragment_infos_with_id(
+        &self,
+    ) -> impl Iterator<Item = (FragmentId, &InflightFragmentInfo)> + '_ {
+        self.jobs.values().flat_map(|job| {
+            job.fragment_infos
+                .iter()
+                .map(|(fragment_id, info)| (*fragment_id, info))
+        })
+    }
+
     pub fn job_ids(&self) -> impl Iterator<Item = TableId> + '_ {
         self.jobs.keys().cloned()
     }
@@ -82,6 +92,15 @@ impl InflightDatabaseInfo {
     pub fn contains_job(&self, job_id: TableId) -> bool {
         self.jobs.contains_key(&job_id)
     }
+
+    /// Find the streaming job that contains the given actor, if any.
+    pub fn job_of_actor(&self, actor_id: ActorId) -> Option<TableId> {
+        self.jobs.values().find_map(|job| {
+            job.fragment_infos()
+                .any(|fragment| fragment.actors.contains_key(&actor_id))
+                .then_some(job.job_id)
+        })
+    }
 }
 
 impl InflightDatabaseInfo {
@@ -0,0 +1,47 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use risingwave_common::catalog::TableId;
+
+/// A streaming job is quarantined after this many consecutive recovery-triggering failures.
+pub(super) const QUARANTINE_CONSECUTIVE_FAILURES: u32 = 5;
+
+/// Failures further apart than this interval are not considered consecutive: a job that has
+/// been running stably for this long gets its failure accounting reset.
+const CONSECUTIVE_FAILURE_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Per-job accounting of recovery-triggering failures, used to quarantine poison-pill jobs
+/// that would otherwise keep the database in a recovery loop.
+#[derive(Default)]
+pub(super) struct JobFailureTracker {
+    failures: HashMap<TableId, (u32, Instant)>,
+}
+
+impl JobFailureTracker {
+    /// Record a recovery-triggering failure attributed to the given job and return the
+    /// updated number of consecutive failures.
+    pub(super) fn record_failure(&mut self, job_id: TableId) -> u32 {
+        let now = Instant::now();
+        let (count, last_failure) = self.failures.entry(job_id).or_insert((0, now));
+        if now.duration_since(*last_failure) > CONSECUTIVE_FAILURE_INTERVAL {
+            *count = 0;
+        }
+        *count += 1;
+        *last_failure = now;
+        *count
+    }
+}
@@ -30,10 +30,12 @@ use crate::model::{ActorId, StreamJobFragments};
 use crate::{MetaError, MetaResult};
 
 mod checkpoint;
+mod checkpoint_stats;
 mod command;
 mod complete_task;
 mod context;
 mod info;
+mod job_failure;
 mod manager;
 mod notifier;
 mod progress;
@@ -43,6 +45,7 @@ mod trace;
 mod utils;
 mod worker;
 
+pub use self::checkpoint_stats::list_checkpoint_history;
 pub use self::command::{
     BarrierKind, Command, CreateStreamingJobCommandInfo, CreateStreamingJobType,
     ReplaceStreamJobPlan, Reschedule, SnapshotBackfillInfo,
@@ -108,6 +111,7 @@ struct BarrierWorkerRuntimeInfoSnapshot {
     source_splits: HashMap<ActorId, Vec<SplitImpl>>,
     background_jobs: HashMap<TableId, (String, StreamJobFragments)>,
     hummock_version_stats: HummockVersionStats,
+    database_checkpoint_frequencies: HashMap<DatabaseId, u64>,
 }
 
 impl BarrierWorkerRuntimeInfoSnapshot {
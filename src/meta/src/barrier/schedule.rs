@@ -39,7 +39,13 @@ use crate::{MetaError, MetaResult};
 pub(super) struct NewBarrier {
     pub command: Option<(DatabaseId, Command, Vec<Notifier>)>,
     pub span: tracing::Span,
+    /// Whether the barrier should be a checkpoint following the system-wide checkpoint
+    /// cadence. Databases with a `checkpoint_frequency` override follow their own cadence
+    /// instead, unless the checkpoint is forced.
     pub checkpoint: bool,
+    /// Whether the checkpoint is forced, e.g. by a command that must be checkpointed.
+    /// A forced checkpoint applies to all databases regardless of overrides.
+    pub checkpoint_forced: bool,
 }
 
 /// A queue for scheduling barriers.
@@ -365,15 +371,17 @@ impl PeriodicBarriers {
         context: &impl GlobalBarrierWorkerContext,
     ) -> NewBarrier {
         let checkpoint = self.try_get_checkpoint();
+        let force_checkpoint = self.force_checkpoint;
         let scheduled = select! {
             biased;
             scheduled = context.next_scheduled() => {
                 self.min_interval.reset();
-                let checkpoint = scheduled.command.need_checkpoint() || checkpoint;
+                let checkpoint_forced = scheduled.command.need_checkpoint() || force_checkpoint;
                 NewBarrier {
                     command: Some((scheduled.database_id, scheduled.command, scheduled.notifiers)),
                     span: scheduled.span,
-                    checkpoint,
+                    checkpoint: checkpoint || checkpoint_forced,
+                    checkpoint_forced,
                 }
             },
             _ = self.min_interval.tick() => {
@@ -381,6 +389,7 @@ impl PeriodicBarriers {
                     command: None,
                     span: tracing_span(),
                     checkpoint,
+                    checkpoint_forced: force_checkpoint,
                 }
             }
         };
@@ -37,6 +37,7 @@ use tracing::{debug, error, info, warn, Instrument};
 use crate::barrier::checkpoint::{CheckpointControl, CheckpointControlEvent};
 use crate::barrier::complete_task::{BarrierCompleteOutput, CompletingTask};
 use crate::barrier::context::{GlobalBarrierWorkerContext, GlobalBarrierWorkerContextImpl};
+use crate::barrier::job_failure::{JobFailureTracker, QUARANTINE_CONSECUTIVE_FAILURES};
 use crate::barrier::rpc::{merge_node_rpc_errors, ControlStreamManager};
 use crate::barrier::schedule::PeriodicBarriers;
 use crate::barrier::{
@@ -90,6 +91,9 @@ pub(super) struct GlobalBarrierWorker<C> {
     sink_manager: SinkCoordinatorManager,
 
     control_stream_manager: ControlStreamManager,
+
+    /// Per-job failure accounting for poison-pill quarantine.
+    job_failure_tracker: JobFailureTracker,
 }
 
 impl GlobalBarrierWorker<GlobalBarrierWorkerContextImpl> {
@@ -145,6 +149,7 @@ impl GlobalBarrierWorker<GlobalBarrierWorkerContextImpl> {
             active_streaming_nodes,
             sink_manager,
             control_stream_manager,
+            job_failure_tracker: JobFailureTracker::default(),
         }
     }
 
@@ -371,6 +376,21 @@ impl<C: GlobalBarrierWorkerContext> GlobalBarrierWorker<C> {
                                 if !self.enable_recovery {
                                     panic!("database failure reported but recovery not enabled: {:?}", resp)
                                 }
+                                // Per-job failure accounting: quarantine the job if it keeps
+                                // triggering recovery, so that a poison-pill job does not keep
+                                // the database in a recovery loop.
+                                if let Some(actor_id) = resp.failed_actor_id
+                                    && let Some(job_id) = self.checkpoint_control.find_job_of_actor(DatabaseId::new(resp.database_id), actor_id)
+                                {
+                                    let consecutive_failures = self.job_failure_tracker.record_failure(job_id);
+                                    if consecutive_failures == QUARANTINE_CONSECUTIVE_FAILURES {
+                                        let context = self.context.clone();
+                                        let database_id = DatabaseId::new(resp.database_id);
+                                        tokio::spawn(async move {
+                                            context.quarantine_job(database_id, job_id, consecutive_failures).await;
+                                        });
+                                    }
+                                }
                                 if let Some(entering_recovery) = self.checkpoint_control.on_report_failure(resp, &mut self.control_stream_manager) {
                                     let database_id = entering_recovery.database_id();
                                     warn!(database_id = database_id.database_id, "database entering recovery");
@@ -638,6 +658,7 @@ impl<C: GlobalBarrierWorkerContext> GlobalBarrierWorker<C> {
                 mut source_splits,
                 mut background_jobs,
                 hummock_version_stats,
+                database_checkpoint_frequencies,
             } = runtime_info_snapshot;
 
             self.sink_manager.reset().await;
@@ -721,6 +742,7 @@ impl<C: GlobalBarrierWorkerContext> GlobalBarrierWorker<C> {
                     CheckpointControl::new(
                         collected_databases,
                         hummock_version_stats,
+                        database_checkpoint_frequencies,
                     ),
                 )
             };
@@ -37,6 +37,21 @@ impl CatalogController {
         Ok(database_id.ok_or_else(|| anyhow!("object has no database id: {object_id}"))?)
     }
 
+    pub async fn get_database_checkpoint_frequency(
+        &self,
+        database_id: DatabaseId,
+    ) -> MetaResult<Option<u64>> {
+        let inner = self.inner.read().await;
+        let (frequency,): (Option<i64>,) = Database::find_by_id(database_id)
+            .select_only()
+            .select_column(database::Column::BarrierCheckpointFrequency)
+            .into_tuple()
+            .one(&inner.db)
+            .await?
+            .ok_or_else(|| MetaError::catalog_id_not_found("database", database_id))?;
+        Ok(frequency.map(|v| v as _))
+    }
+
     pub async fn get_connection_by_id(
         &self,
         connection_id: ConnectionId,
@@ -34,6 +34,7 @@ use risingwave_pb::common::worker_node::{
     PbProperty, PbProperty as AddNodeProperty, PbResource, PbState,
 };
 use risingwave_pb::common::{HostAddress, PbHostAddress, PbWorkerNode, PbWorkerType, WorkerNode};
+use risingwave_pb::meta::heartbeat_request::PbResourceUtilization;
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use risingwave_pb::meta::update_worker_node_schedulability_request::Schedulability;
 use sea_orm::prelude::Expr;
@@ -196,12 +197,16 @@ impl ClusterController {
     }
 
     /// Invoked when it receives a heartbeat from a worker node.
-    pub async fn heartbeat(&self, worker_id: WorkerId) -> MetaResult<()> {
+    pub async fn heartbeat(
+        &self,
+        worker_id: WorkerId,
+        utilization: Option<PbResourceUtilization>,
+    ) -> MetaResult<()> {
         tracing::trace!(target: "events::meta::server_heartbeat", worker_id = worker_id, "receive heartbeat");
         self.inner
             .write()
             .await
-            .heartbeat(worker_id, self.max_heartbeat_interval)
+            .heartbeat(worker_id, utilization, self.max_heartbeat_interval)
     }
 
     pub fn start_heartbeat_checker(
@@ -405,6 +410,9 @@ impl StreamingClusterInfo {
     }
 }
 
+/// Number of the most recent heartbeat utilization reports kept for each worker.
+const UTILIZATION_HISTORY_SIZE: usize = 16;
+
 #[derive(Default, Clone)]
 pub struct WorkerExtraInfo {
     // Volatile values updated by meta node as follows.
@@ -413,9 +421,39 @@ pub struct WorkerExtraInfo {
     expire_at: Option<u64>,
     started_at: Option<u64>,
     resource: Option<PbResource>,
+    /// A rolling window of the most recent resource utilization reports carried by the
+    /// worker's heartbeats, latest at the back.
+    utilization_history: VecDeque<PbResourceUtilization>,
 }
 
 impl WorkerExtraInfo {
+    /// Returns the average CPU usage (in percent, aggregated over all cores) over the
+    /// kept utilization history, or `None` if the worker has not reported any. Schedulers
+    /// may use this to prefer underutilized workers.
+    pub fn avg_cpu_usage_percent(&self) -> Option<f64> {
+        if self.utilization_history.is_empty() {
+            return None;
+        }
+        let sum: f64 = self
+            .utilization_history
+            .iter()
+            .map(|u| u.cpu_usage_percent)
+            .sum();
+        Some(sum / self.utilization_history.len() as f64)
+    }
+
+    /// Returns the most recent resource utilization report of the worker, if any.
+    pub fn latest_utilization(&self) -> Option<&PbResourceUtilization> {
+        self.utilization_history.back()
+    }
+
+    fn record_utilization(&mut self, utilization: PbResourceUtilization) {
+        if self.utilization_history.len() == UTILIZATION_HISTORY_SIZE {
+            self.utilization_history.pop_front();
+        }
+        self.utilization_history.push_back(utilization);
+    }
+
     fn update_ttl(&mut self, ttl: Duration) {
         let expire = cmp::max(
             self.expire_at.unwrap_or_default(),
@@ -816,9 +854,17 @@ impl ClusterControllerInner {
         Ok(WorkerInfo(worker, property, extra_info).into())
     }
 
-    pub fn heartbeat(&mut self, worker_id: WorkerId, ttl: Duration) -> MetaResult<()> {
+    pub fn heartbeat(
+        &mut self,
+        worker_id: WorkerId,
+        utilization: Option<PbResourceUtilization>,
+        ttl: Duration,
+    ) -> MetaResult<()> {
         if let Some(worker_info) = self.worker_extra_info.get_mut(&worker_id) {
             worker_info.update_ttl(ttl);
+            if let Some(utilization) = utilization {
+                worker_info.record_utilization(utilization);
+            }
             Ok(())
         } else {
             Err(MetaError::invalid_worker(worker_id, "worker not found"))
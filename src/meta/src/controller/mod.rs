@@ -159,6 +159,7 @@ impl From<ObjectModel<database::Model>> for PbDatabase {
             id: value.0.database_id as _,
             name: value.0.name,
             owner: value.1.owner_id as _,
+            barrier_checkpoint_frequency: value.0.barrier_checkpoint_frequency.map(|v| v as _),
         }
     }
 }
@@ -284,6 +285,7 @@ impl From<ObjectModel<source::Model>> for PbSource {
             created_at_cluster_version: value.1.created_at_cluster_version,
             secret_refs: secret_ref_map,
             rate_limit: value.0.rate_limit.map(|v| v as _),
+            next_column_id: value.0.next_column_id,
         }
     }
 }
@@ -1068,6 +1068,12 @@ impl CatalogController {
                 let source = source::ActiveModel::from(source);
                 source.update(txn).await?;
             }
+            StreamingJob::MaterializedView(table) => {
+                // Update the mview catalog with the new one, which carries the new definition
+                // and column catalog.
+                let table = table::ActiveModel::from(table);
+                table.update(txn).await?;
+            }
             _ => unreachable!(
                 "invalid streaming job type: {:?}",
                 streaming_job.job_type_str()
@@ -72,6 +72,8 @@ impl CompactionConfigBuilder {
                     compaction_config::disable_auto_group_scheduling(),
                 ),
                 max_overlapping_level_size: Some(compaction_config::max_overlapping_level_size()),
+                max_concurrent_task_count: Some(compaction_config::max_concurrent_task_count()),
+                scheduling_weight: Some(compaction_config::scheduling_weight()),
             },
         }
     }
@@ -0,0 +1,117 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Live estimation of per-compaction-group write/read amplification.
+//!
+//! Amplification is estimated from cumulative compaction stats collected since the meta node
+//! started: bytes flushed into L0 at `commit_epoch` and bytes read/written by successfully
+//! reported compaction tasks. The estimators are exposed both as metrics and through
+//! `rw_catalog.rw_hummock_compaction_stats`, to guide tuning of level sizes and the
+//! compaction config.
+
+use itertools::Itertools;
+use risingwave_hummock_sdk::CompactionGroupId;
+use risingwave_pb::hummock::list_compaction_group_stats_response::CompactionGroupStats;
+
+use crate::hummock::HummockManager;
+
+/// Cumulative compaction stats of one compaction group.
+#[derive(Default, Clone)]
+pub(super) struct CompactionGroupLocalStats {
+    /// Bytes flushed into L0 of the group.
+    pub ingest_bytes: u64,
+    /// Bytes read by compaction in the group.
+    pub compact_read_bytes: u64,
+    /// Bytes written by compaction in the group.
+    pub compact_write_bytes: u64,
+}
+
+impl CompactionGroupLocalStats {
+    /// Total bytes written per byte ingested, including the initial flush.
+    pub fn write_amplification(&self) -> f64 {
+        if self.ingest_bytes == 0 {
+            return 0.0;
+        }
+        (self.ingest_bytes + self.compact_write_bytes) as f64 / self.ingest_bytes as f64
+    }
+
+    /// Bytes read by compaction per byte ingested.
+    pub fn read_amplification(&self) -> f64 {
+        if self.ingest_bytes == 0 {
+            return 0.0;
+        }
+        self.compact_read_bytes as f64 / self.ingest_bytes as f64
+    }
+}
+
+impl HummockManager {
+    /// Records bytes flushed into L0 of the given groups at `commit_epoch`.
+    pub(super) fn record_flush_bytes(
+        &self,
+        bytes_by_group: impl IntoIterator<Item = (CompactionGroupId, u64)>,
+    ) {
+        let mut guard = self.compaction_group_stats.write();
+        for (compaction_group_id, bytes) in bytes_by_group {
+            let stats = guard.entry(compaction_group_id).or_default();
+            stats.ingest_bytes += bytes;
+            self.trigger_amplification_stat(compaction_group_id, stats);
+        }
+    }
+
+    /// Records bytes read and written by a successfully reported compaction task.
+    pub(super) fn record_compaction_io_bytes(
+        &self,
+        compaction_group_id: CompactionGroupId,
+        read_bytes: u64,
+        write_bytes: u64,
+    ) {
+        let mut guard = self.compaction_group_stats.write();
+        let stats = guard.entry(compaction_group_id).or_default();
+        stats.compact_read_bytes += read_bytes;
+        stats.compact_write_bytes += write_bytes;
+        self.trigger_amplification_stat(compaction_group_id, stats);
+    }
+
+    fn trigger_amplification_stat(
+        &self,
+        compaction_group_id: CompactionGroupId,
+        stats: &CompactionGroupLocalStats,
+    ) {
+        let group_label = compaction_group_id.to_string();
+        self.metrics
+            .compaction_group_write_amplification
+            .with_label_values(&[&group_label])
+            .set(stats.write_amplification());
+        self.metrics
+            .compaction_group_read_amplification
+            .with_label_values(&[&group_label])
+            .set(stats.read_amplification());
+    }
+
+    pub fn list_compaction_group_stats(&self) -> Vec<CompactionGroupStats> {
+        self.compaction_group_stats
+            .read()
+            .iter()
+            .map(|(compaction_group_id, stats)| CompactionGroupStats {
+                compaction_group_id: *compaction_group_id,
+                ingest_bytes: stats.ingest_bytes,
+                compact_read_bytes: stats.compact_read_bytes,
+                compact_write_bytes: stats.compact_write_bytes,
+                write_amplification: stats.write_amplification(),
+                read_amplification: stats.read_amplification(),
+            })
+            .sorted_by_key(|s| s.compaction_group_id)
+            .collect()
+    }
+}
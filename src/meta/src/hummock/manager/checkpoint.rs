@@ -97,6 +97,7 @@ impl HummockManager {
     ) -> Result<()> {
         use prost::Message;
         let buf = checkpoint.to_protobuf().encode_to_vec();
+        self.metrics.version_checkpoint_size.set(buf.len() as _);
         self.object_store
             .upload(&self.version_checkpoint_path, buf.into())
             .await?;
@@ -120,9 +121,10 @@ impl HummockManager {
 
     /// Creates a hummock version checkpoint.
     /// Returns the diff between new and old checkpoint id.
-    /// Note that this method must not be called concurrently, because internally it doesn't hold
-    /// lock throughout the method.
+    /// Concurrent calls are serialized internally, because the method doesn't hold the
+    /// versioning lock throughout.
     pub async fn create_version_checkpoint(&self, min_delta_log_num: u64) -> Result<u64> {
+        let _taken = self.version_checkpoint_taken.lock().await;
         let timer = self.metrics.version_checkpoint_latency.start_timer();
         // 1. hold read lock and create new checkpoint
         let versioning_guard = self.versioning.read().await;
@@ -188,6 +188,16 @@ impl HummockManager {
             }
         }
 
+        let flush_bytes_by_group = commit_sstables
+            .iter()
+            .map(|(group_id, ssts)| {
+                (
+                    *group_id,
+                    ssts.iter().map(|sst| sst.file_size).sum::<u64>(),
+                )
+            })
+            .collect_vec();
+
         let group_id_to_sub_levels =
             rewrite_commit_sstables_to_sub_level(commit_sstables, &group_id_to_config);
 
@@ -294,6 +304,7 @@ impl HummockManager {
             );
         }
         trigger_epoch_stat(&self.metrics, &versioning.current_version);
+        self.record_flush_bytes(flush_bytes_by_group);
 
         drop(versioning_guard);
 
@@ -319,6 +330,49 @@ impl HummockManager {
         Ok(())
     }
 
+    /// Atomically adds externally built sstables to the compaction group of `table_id`.
+    ///
+    /// This is the final step of the bulk-load flow: the caller has already built the
+    /// sorted-run ssts and uploaded them to the object store. They are committed via the
+    /// regular commit-epoch flow at `committed_epoch + 1` of the table, i.e. within the
+    /// gap before the next barrier epoch, so that they become visible atomically without
+    /// interfering with barrier-driven commits.
+    pub async fn ingest_external_sstables(
+        &self,
+        table_id: TableId,
+        ssts: Vec<LocalSstableInfo>,
+    ) -> Result<()> {
+        for sst in &ssts {
+            if sst.sst_info.table_ids != vec![table_id.table_id] {
+                return Err(anyhow::anyhow!(
+                    "external sst {} should contain data of table {} only, but get table ids {:?}",
+                    sst.sst_info.object_id,
+                    table_id,
+                    sst.sst_info.table_ids
+                )
+                .into());
+            }
+        }
+        let committed_epoch = self
+            .on_current_version(|version| {
+                version
+                    .state_table_info
+                    .info()
+                    .get(&table_id)
+                    .map(|info| info.committed_epoch)
+            })
+            .await
+            .ok_or_else(|| {
+                anyhow::anyhow!("table {} not found in the current version", table_id)
+            })?;
+        self.commit_epoch(CommitEpochInfo {
+            sstables: ssts,
+            tables_to_commit: HashMap::from_iter([(table_id, committed_epoch + 1)]),
+            ..Default::default()
+        })
+        .await
+    }
+
     async fn collect_table_write_throughput(&self, table_stats: PbTableStatsMap) {
         let params = self.env.system_params_reader().await;
         let barrier_interval_ms = params.barrier_interval_ms() as u64;
@@ -605,6 +605,12 @@ fn update_compaction_config(target: &mut CompactionConfig, items: &[MutableConfi
             MutableConfig::MaxOverlappingLevelSize(c) => {
                 target.max_overlapping_level_size = Some(*c);
             }
+            MutableConfig::MaxConcurrentTaskCount(c) => {
+                target.max_concurrent_task_count = Some(*c);
+            }
+            MutableConfig::SchedulingWeight(c) => {
+                target.scheduling_weight = Some(*c);
+            }
         }
     }
 }
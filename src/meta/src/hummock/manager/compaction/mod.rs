@@ -38,7 +38,7 @@ use futures::{FutureExt, StreamExt};
 use itertools::Itertools;
 use parking_lot::Mutex;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
 use risingwave_common::util::epoch::Epoch;
 use risingwave_hummock_sdk::compact_task::{CompactTask, ReportTask};
 use risingwave_hummock_sdk::compaction_group::StateTableId;
@@ -513,11 +513,34 @@ impl HummockManager {
             .unwrap();
     }
 
+    /// Returns all compaction group ids in a weighted random order: a group with
+    /// `scheduling_weight = w` is roughly `w` times more likely to be ordered before a group
+    /// with weight 1, so heavier tenants are scheduled first without starving the others.
+    async fn weighted_shuffled_compaction_group_ids(&self) -> Vec<CompactionGroupId> {
+        let compaction_group_ids = self.compaction_group_ids().await;
+        let group_map = self.get_compaction_group_map().await;
+        let mut rng = thread_rng();
+        let mut keyed_group_ids: Vec<_> = compaction_group_ids
+            .into_iter()
+            .map(|cg_id| {
+                let weight = group_map
+                    .get(&cg_id)
+                    .and_then(|group| group.compaction_config.scheduling_weight)
+                    .unwrap_or(1)
+                    .max(1);
+                // Weighted random shuffle (Efraimidis-Spirakis): order by `rand ^ (1 / weight)`
+                // descending.
+                (rng.gen::<f64>().powf(1.0 / weight as f64), cg_id)
+            })
+            .collect();
+        keyed_group_ids.sort_by(|(key_a, _), (key_b, _)| key_b.total_cmp(key_a));
+        keyed_group_ids.into_iter().map(|(_, cg_id)| cg_id).collect()
+    }
+
     pub async fn auto_pick_compaction_group_and_type(
         &self,
     ) -> Option<(CompactionGroupId, compact_task::TaskType)> {
-        let mut compaction_group_ids = self.compaction_group_ids().await;
-        compaction_group_ids.shuffle(&mut thread_rng());
+        let compaction_group_ids = self.weighted_shuffled_compaction_group_ids().await;
 
         for cg_id in compaction_group_ids {
             if let Some(pick_type) = self.compaction_state.auto_pick_type(cg_id) {
@@ -533,8 +556,7 @@ impl HummockManager {
     async fn auto_pick_compaction_groups_and_type(
         &self,
     ) -> (Vec<CompactionGroupId>, compact_task::TaskType) {
-        let mut compaction_group_ids = self.compaction_group_ids().await;
-        compaction_group_ids.shuffle(&mut thread_rng());
+        let compaction_group_ids = self.weighted_shuffled_compaction_group_ids().await;
 
         let mut normal_groups = vec![];
         for cg_id in compaction_group_ids {
@@ -693,6 +715,28 @@ impl HummockManager {
                 }
             };
 
+            // Enforce the per-group concurrent task quota, so that one group cannot consume
+            // all compactor slots.
+            let max_concurrent_task_count = group_config
+                .compaction_config
+                .max_concurrent_task_count
+                .unwrap_or(0);
+            if max_concurrent_task_count > 0 {
+                let assigned_task_count = compact_task_assignment
+                    .tree_ref()
+                    .values()
+                    .filter(|assignment| {
+                        assignment
+                            .compact_task
+                            .as_ref()
+                            .is_some_and(|task| task.compaction_group_id == compaction_group_id)
+                    })
+                    .count();
+                if assigned_task_count >= max_concurrent_task_count as usize {
+                    continue;
+                }
+            }
+
             // StoredIdGenerator already implements ids pre-allocation by ID_PREALLOCATE_INTERVAL.
             let task_id = next_compaction_task_id(&self.env).await?;
 
@@ -1233,6 +1277,22 @@ impl HummockManager {
             };
             if is_success {
                 success_count += 1;
+                let read_bytes = compact_task
+                    .input_ssts
+                    .iter()
+                    .flat_map(|level| level.table_infos.iter())
+                    .map(|sst| sst.file_size)
+                    .sum::<u64>();
+                let write_bytes = compact_task
+                    .sorted_output_ssts
+                    .iter()
+                    .map(|sst| sst.file_size)
+                    .sum::<u64>();
+                self.record_compaction_io_bytes(
+                    compact_task.compaction_group_id,
+                    read_bytes,
+                    write_bytes,
+                );
                 version.apply_compact_task(&compact_task);
                 if purge_prost_table_stats(&mut version_stats.table_stats, version.latest_version())
                 {
@@ -449,6 +449,43 @@ impl HummockManager {
         }
         trigger_safepoint_stat(&self.metrics, &wl.version_safe_points);
     }
+
+    /// Pins a consistent snapshot of the given state tables for external export. Returns the
+    /// committed epoch at which all of them can be read, along with the version safe point
+    /// backing the snapshot, which is released automatically after `ttl`.
+    pub async fn pin_snapshot_for_export(
+        &self,
+        table_ids: Vec<u32>,
+        ttl: std::time::Duration,
+    ) -> Result<(u64, HummockVersionId)> {
+        let safe_point = self.register_safe_point().await;
+        let version_id = safe_point.id;
+        let export_epoch = self
+            .on_current_version(|version| {
+                let mut export_epoch = u64::MAX;
+                for table_id in &table_ids {
+                    match version.state_table_info.info().get(&TableId::new(*table_id)) {
+                        Some(info) => {
+                            export_epoch = std::cmp::min(export_epoch, info.committed_epoch);
+                        }
+                        None => {
+                            return Err(Error::Internal(anyhow::anyhow!(
+                                "state table {} not found in the current version",
+                                table_id
+                            )));
+                        }
+                    }
+                }
+                Ok(export_epoch)
+            })
+            .await?;
+        // Auto-release the safe point after the TTL, in case the exporter never comes back.
+        tokio::spawn(async move {
+            tokio::time::sleep(ttl).await;
+            drop(safe_point);
+        });
+        Ok((export_epoch, version_id))
+    }
 }
 
 fn trigger_safepoint_stat(metrics: &MetaMetrics, safepoints: &[HummockVersionId]) {
@@ -13,12 +13,13 @@
 // limitations under the License.
 
 use std::cmp;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ops::Bound::{Excluded, Included};
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, SystemTime};
 
+use bytes::Buf;
 use chrono::DateTime;
 use futures::future::try_join_all;
 use futures::{future, StreamExt, TryStreamExt};
@@ -32,24 +33,37 @@ use risingwave_meta_model::hummock_sequence::HUMMOCK_NOW;
 use risingwave_meta_model::{hummock_gc_history, hummock_sequence};
 use risingwave_meta_model_migration::OnConflict;
 use risingwave_object_store::object::{ObjectMetadataIter, ObjectStoreRef};
+use risingwave_pb::hummock::rise_ctl_list_orphan_objects_response;
 use risingwave_pb::stream_service::GetMinUncommittedSstIdRequest;
 use risingwave_rpc_client::StreamClientPool;
 use sea_orm::{ActiveValue, ColumnTrait, EntityTrait, QueryFilter, Set};
+use thiserror_ext::AsReport;
 
 use crate::backup_restore::BackupManagerRef;
 use crate::hummock::error::{Error, Result};
 use crate::hummock::manager::commit_multi_var;
+use crate::hummock::manager::context::ContextInfo;
+use crate::hummock::manager::versioning::Versioning;
 use crate::hummock::HummockManager;
 use crate::manager::MetadataManager;
 use crate::model::BTreeMapTransaction;
 use crate::MetaResult;
 
+/// Size of the fixed-size footer at the end of an SST object:
+/// checksum (u64) + format version (u32) + magic (u32).
+const SST_FOOTER_SIZE: usize = 16;
+/// Must match `MAGIC` of the sstable encoding in the storage crate.
+const SST_MAGIC: u32 = 0x5785ab73;
+
 pub(crate) struct GcManager {
     store: ObjectStoreRef,
     path_prefix: String,
     use_new_object_prefix_strategy: bool,
     /// These objects may still be used by backup or time travel.
     may_delete_object_ids: parking_lot::Mutex<HashSet<HummockSstableObjectId>>,
+    /// Objects found corrupt by the SST scrubber. They are held back from deletion so that
+    /// they can be inspected manually.
+    quarantined_object_ids: parking_lot::Mutex<HashSet<HummockSstableObjectId>>,
 }
 
 impl GcManager {
@@ -63,7 +77,94 @@ impl GcManager {
             path_prefix: path_prefix.to_owned(),
             use_new_object_prefix_strategy,
             may_delete_object_ids: Default::default(),
+            quarantined_object_ids: Default::default(),
+        }
+    }
+
+    /// Holds the given objects back from deletion.
+    pub fn quarantine_objects(
+        &self,
+        object_ids: impl IntoIterator<Item = HummockSstableObjectId>,
+    ) {
+        self.quarantined_object_ids.lock().extend(object_ids);
+    }
+
+    pub fn quarantined_object_ids(&self) -> HashSet<HummockSstableObjectId> {
+        self.quarantined_object_ids.lock().clone()
+    }
+
+    /// Verifies the integrity of one SST object against the version manifest. Any failed
+    /// check, including failure to read the object at all, is returned as a description of
+    /// the finding.
+    pub async fn verify_object(
+        &self,
+        object_id: HummockSstableObjectId,
+        expected_file_size: u64,
+    ) -> std::result::Result<(), String> {
+        let obj_prefix = self
+            .store
+            .get_object_prefix(object_id, self.use_new_object_prefix_strategy);
+        let path = get_sst_data_path(&obj_prefix, &self.path_prefix, object_id);
+        let metadata = self
+            .store
+            .metadata(&path)
+            .await
+            .map_err(|e| format!("failed to read object metadata: {}", e.as_report()))?;
+        if metadata.total_size as u64 != expected_file_size {
+            return Err(format!(
+                "object size {} does not match file size {} in version manifest",
+                metadata.total_size, expected_file_size
+            ));
+        }
+        if metadata.total_size < SST_FOOTER_SIZE + 8 {
+            return Err(format!("object is too small: {} bytes", metadata.total_size));
+        }
+
+        // Footer layout: [.. meta .. | meta_offset u64 | checksum u64 | version u32 | magic u32].
+        let footer_start = metadata.total_size - SST_FOOTER_SIZE;
+        let mut footer = self
+            .store
+            .read(&path, footer_start..metadata.total_size)
+            .await
+            .map_err(|e| format!("failed to read object footer: {}", e.as_report()))?;
+        let checksum = footer.get_u64_le();
+        let _version = footer.get_u32_le();
+        let magic = footer.get_u32_le();
+        if magic != SST_MAGIC {
+            return Err(format!(
+                "magic mismatch: expected {}, got {}",
+                SST_MAGIC, magic
+            ));
+        }
+
+        // The metadata region covers everything from `meta_offset` up to the checksum, and
+        // itself ends with the `meta_offset` field.
+        let meta_offset_start = metadata.total_size - SST_FOOTER_SIZE - 8;
+        let meta_offset = self
+            .store
+            .read(&path, meta_offset_start..meta_offset_start + 8)
+            .await
+            .map_err(|e| format!("failed to read meta offset: {}", e.as_report()))?
+            .get_u64_le();
+        if meta_offset >= metadata.total_size as u64 {
+            return Err(format!(
+                "meta offset {} is out of bounds of object size {}",
+                meta_offset, metadata.total_size
+            ));
+        }
+        let meta_region = self
+            .store
+            .read(&path, meta_offset as usize..footer_start)
+            .await
+            .map_err(|e| format!("failed to read metadata region: {}", e.as_report()))?;
+        let actual_checksum = xxhash_rust::xxh64::xxh64(&meta_region, 0);
+        if actual_checksum != checksum {
+            return Err(format!(
+                "metadata checksum mismatch: expected {}, got {}",
+                checksum, actual_checksum
+            ));
         }
+        Ok(())
     }
 
     /// Deletes all SSTs specified in the given list of IDs from storage.
@@ -72,7 +173,12 @@ impl GcManager {
         object_id_list: impl Iterator<Item = HummockSstableObjectId>,
     ) -> Result<()> {
         let mut paths = Vec::with_capacity(1000);
+        let quarantined_object_ids = self.quarantined_object_ids.lock().clone();
         for object_id in object_id_list {
+            if quarantined_object_ids.contains(&object_id) {
+                tracing::warn!(object_id, "skip deletion of quarantined object");
+                continue;
+            }
             let obj_prefix = self
                 .store
                 .get_object_prefix(object_id, self.use_new_object_prefix_strategy);
@@ -152,6 +258,51 @@ impl GcManager {
         ))
     }
 
+    /// Like `list_objects`, but returns size and last modified time of the filtered objects,
+    /// for reporting purposes.
+    pub async fn list_objects_with_metadata(
+        &self,
+        sst_retention_watermark: u64,
+        prefix: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u64>,
+    ) -> Result<(
+        HashMap<HummockSstableObjectId, (u64, u64)>,
+        Option<String>,
+    )> {
+        let mut total_object_count = 0;
+        let mut next_start_after: Option<String> = None;
+        let metadata_iter = self
+            .list_object_metadata_from_object_store(prefix, start_after, limit.map(|i| i as usize))
+            .await?;
+        let filtered = metadata_iter
+            .filter_map(|r| {
+                let result = match r {
+                    Ok(o) => {
+                        total_object_count += 1;
+                        if let Some(limit) = limit
+                            && limit == total_object_count
+                        {
+                            next_start_after = Some(o.key.clone());
+                        }
+                        if o.last_modified < sst_retention_watermark as f64 {
+                            Some(Ok((
+                                get_object_id_from_path(&o.key),
+                                (o.total_size as u64, o.last_modified as u64),
+                            )))
+                        } else {
+                            None
+                        }
+                    }
+                    Err(e) => Some(Err(Error::ObjectStore(e))),
+                };
+                async move { result }
+            })
+            .try_collect::<HashMap<HummockSstableObjectId, (u64, u64)>>()
+            .await?;
+        Ok((filtered, next_start_after))
+    }
+
     pub fn add_may_delete_object_ids(
         &self,
         may_delete_object_ids: impl Iterator<Item = HummockSstableObjectId>,
@@ -225,27 +376,7 @@ impl HummockManager {
         let versioning = self.versioning.read().await;
         let tracked_object_ids: HashSet<HummockSstableObjectId> = {
             let context_info = self.context_info.read().await;
-            // object ids in checkpoint version
-            let mut tracked_object_ids = versioning.checkpoint.version.get_object_ids();
-            // add object ids added between checkpoint version and current version
-            for (_, delta) in versioning.hummock_version_deltas.range((
-                Excluded(versioning.checkpoint.version.id),
-                Included(versioning.current_version.id),
-            )) {
-                tracked_object_ids.extend(delta.newly_added_object_ids());
-            }
-            // add stale object ids before the checkpoint version
-            let min_pinned_version_id = context_info.min_pinned_version_id();
-            tracked_object_ids.extend(
-                versioning
-                    .checkpoint
-                    .stale_objects
-                    .iter()
-                    .filter(|(version_id, _)| **version_id >= min_pinned_version_id)
-                    .flat_map(|(_, objects)| objects.id.iter())
-                    .cloned(),
-            );
-            tracked_object_ids
+            compute_tracked_object_ids(&versioning, &context_info)
         };
         let to_delete = object_ids.filter(|object_id| !tracked_object_ids.contains(object_id));
         self.write_gc_history(to_delete.clone()).await?;
@@ -545,6 +676,93 @@ impl HummockManager {
         Ok(total)
     }
 
+    /// Dry run of full GC: LIST object store and report stale objects, without deleting any of
+    /// them or writing GC history. Applies the same filters as full GC, i.e. SST retention time,
+    /// metadata backup, time travel, uncommitted SSTs and retained versions.
+    pub async fn list_orphan_objects(
+        &self,
+        sst_retention_time: Duration,
+        backup_manager: Option<BackupManagerRef>,
+    ) -> Result<Vec<rise_ctl_list_orphan_objects_response::OrphanObject>> {
+        let sst_retention_time = cmp::max(
+            sst_retention_time,
+            Duration::from_secs(self.env.opts.min_sst_retention_time_sec),
+        );
+        let sst_retention_watermark = self
+            .now()
+            .await?
+            .saturating_sub(sst_retention_time.as_secs());
+        let limit = self.env.opts.full_gc_object_limit;
+        let mut start_after = None;
+        let mut candidates: HashMap<HummockSstableObjectId, (u64, u64)> = HashMap::new();
+        tracing::info!(
+            retention_sec = sst_retention_time.as_secs(),
+            limit,
+            "Start orphan object report."
+        );
+        loop {
+            let (batch, next_start_after) = self
+                .gc_manager
+                .list_objects_with_metadata(
+                    sst_retention_watermark,
+                    None,
+                    start_after.clone(),
+                    Some(limit),
+                )
+                .await?;
+            candidates.extend(batch);
+            if next_start_after.is_none() {
+                break;
+            }
+            start_after = next_start_after;
+        }
+        // It's crucial to get pinned_by_metadata_backup only after object_ids.
+        let pinned_by_metadata_backup = backup_manager
+            .as_ref()
+            .map(|b| b.list_pinned_ssts())
+            .unwrap_or_default();
+        // It's crucial to collect_min_uncommitted_sst_id only after LIST object store.
+        let min_sst_id =
+            collect_min_uncommitted_sst_id(&self.metadata_manager, self.env.stream_client_pool())
+                .await?;
+        candidates.retain(|id, _| !pinned_by_metadata_backup.contains(id) && *id < min_sst_id);
+        let after_time_travel = self
+            .filter_out_objects_by_time_travel(candidates.keys().cloned())
+            .await?;
+        candidates.retain(|id, _| after_time_travel.contains(id));
+        let tracked_object_ids = {
+            let versioning = self.versioning.read().await;
+            let context_info = self.context_info.read().await;
+            compute_tracked_object_ids(&versioning, &context_info)
+        };
+        candidates.retain(|id, _| !tracked_object_ids.contains(id));
+        let held_object_ids = self.gc_manager.quarantined_object_ids();
+        let mut orphan_objects = candidates
+            .into_iter()
+            .map(
+                |(object_id, (total_size, last_modified_sec))| {
+                    rise_ctl_list_orphan_objects_response::OrphanObject {
+                        object_id,
+                        total_size,
+                        last_modified_sec,
+                        held: held_object_ids.contains(&object_id),
+                    }
+                },
+            )
+            .collect_vec();
+        orphan_objects.sort_unstable_by_key(|o| o.object_id);
+        tracing::info!(
+            orphan_object_count = orphan_objects.len(),
+            "Finish orphan object report."
+        );
+        Ok(orphan_objects)
+    }
+
+    /// Holds the given objects back from deletion, e.g. for manual inspection.
+    pub fn hold_objects(&self, object_ids: Vec<HummockSstableObjectId>) {
+        self.gc_manager.quarantine_objects(object_ids);
+    }
+
     /// Minor GC attempts to delete objects that were part of Hummock version but are no longer in use.
     pub async fn try_start_minor_gc(&self, backup_manager: BackupManagerRef) -> Result<()> {
         const MIN_MINOR_GC_OBJECT_COUNT: usize = 1000;
@@ -591,6 +809,35 @@ async fn collect_min_uncommitted_sst_id(
     Ok(min_watermark)
 }
 
+/// Collects object ids that are still referenced by any retained version, i.e. the checkpoint
+/// version, any later version, or any pinned version.
+fn compute_tracked_object_ids(
+    versioning: &Versioning,
+    context_info: &ContextInfo,
+) -> HashSet<HummockSstableObjectId> {
+    // object ids in checkpoint version
+    let mut tracked_object_ids = versioning.checkpoint.version.get_object_ids();
+    // add object ids added between checkpoint version and current version
+    for (_, delta) in versioning.hummock_version_deltas.range((
+        Excluded(versioning.checkpoint.version.id),
+        Included(versioning.current_version.id),
+    )) {
+        tracked_object_ids.extend(delta.newly_added_object_ids());
+    }
+    // add stale object ids before the checkpoint version
+    let min_pinned_version_id = context_info.min_pinned_version_id();
+    tracked_object_ids.extend(
+        versioning
+            .checkpoint
+            .stale_objects
+            .iter()
+            .filter(|(version_id, _)| **version_id >= min_pinned_version_id)
+            .flat_map(|(_, objects)| objects.id.iter())
+            .cloned(),
+    );
+    tracked_object_ids
+}
+
 pub struct FullGcState {
     is_started: AtomicBool,
 }
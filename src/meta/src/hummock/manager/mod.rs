@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::{Deref, DerefMut};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -49,10 +49,13 @@ use crate::manager::{MetaSrvEnv, MetadataManager};
 use crate::model::{ClusterId, MetadataModelError};
 use crate::rpc::metrics::MetaMetrics;
 
+mod amplification;
 mod context;
 mod gc;
+mod scrub;
 mod tests;
 mod versioning;
+use amplification::CompactionGroupLocalStats;
 pub use context::HummockVersionSafePoint;
 use versioning::*;
 pub(crate) mod checkpoint;
@@ -97,6 +100,9 @@ pub struct HummockManager {
     version_checkpoint_path: String,
     version_archive_dir: String,
     pause_version_checkpoint: AtomicBool,
+    /// Serializes calls to `create_version_checkpoint`, which is not concurrency-safe
+    /// since it does not hold the versioning lock throughout.
+    version_checkpoint_taken: Mutex<()>,
     table_write_throughput_statistic_manager:
         parking_lot::RwLock<TableWriteThroughputStatisticManager>,
 
@@ -112,6 +118,10 @@ pub struct HummockManager {
     now: Mutex<u64>,
     inflight_time_travel_query: Semaphore,
     gc_manager: GcManager,
+    /// Per-group cumulative compaction stats since the meta node started, used to estimate
+    /// write/read amplification.
+    compaction_group_stats:
+        parking_lot::RwLock<HashMap<CompactionGroupId, CompactionGroupLocalStats>>,
 }
 
 pub type HummockManagerRef = Arc<HummockManager>;
@@ -285,6 +295,7 @@ impl HummockManager {
             version_checkpoint_path,
             version_archive_dir,
             pause_version_checkpoint: AtomicBool::new(false),
+            version_checkpoint_taken: Mutex::new(()),
             table_write_throughput_statistic_manager: parking_lot::RwLock::new(
                 TableWriteThroughputStatisticManager::new(max_table_statistic_expired_time),
             ),
@@ -294,6 +305,7 @@ impl HummockManager {
             now: Mutex::new(0),
             inflight_time_travel_query: Semaphore::new(inflight_time_travel_query as usize),
             gc_manager,
+            compaction_group_stats: Default::default(),
         };
         let instance = Arc::new(instance);
         instance.init_time_travel_state().await?;
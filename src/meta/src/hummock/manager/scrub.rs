@@ -0,0 +1,91 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Background SST integrity scrubbing.
+//!
+//! The scrubber samples SST objects referenced by the current version and verifies them
+//! against the manifest: object existence, object size, footer magic and format version,
+//! meta offset bound, and the checksum of the metadata region. It complements
+//! `enable_committed_sst_sanity_check`, which only covers SSTs at commit time, by also
+//! detecting corruption that happens after commit, e.g. in the object store itself.
+//!
+//! Corrupt objects are quarantined from GC so that they can be inspected manually, and a
+//! `SstCorruption` event is raised through the event log.
+
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use risingwave_hummock_sdk::HummockSstableObjectId;
+use risingwave_pb::meta::event_log;
+
+use crate::hummock::error::Result;
+use crate::hummock::HummockManager;
+
+/// Number of SST objects sampled per scrub run.
+const SCRUB_SAMPLE_COUNT: usize = 64;
+
+impl HummockManager {
+    /// Samples up to [`SCRUB_SAMPLE_COUNT`] SST objects from the current version and verifies
+    /// their integrity. Returns the object ids found corrupt, which have been quarantined
+    /// from GC and reported through the event log.
+    pub async fn scrub_sst_objects(&self) -> Result<Vec<HummockSstableObjectId>> {
+        let version = self.get_current_version().await;
+        let mut candidates: Vec<(u64, HummockSstableObjectId, u64)> = vec![];
+        let mut seen_object_ids = std::collections::HashSet::new();
+        for (compaction_group_id, levels) in &version.levels {
+            for level in levels.l0.sub_levels.iter().chain(levels.levels.iter()) {
+                for sstable_info in &level.table_infos {
+                    if seen_object_ids.insert(sstable_info.object_id) {
+                        candidates.push((
+                            *compaction_group_id,
+                            sstable_info.object_id,
+                            sstable_info.file_size,
+                        ));
+                    }
+                }
+            }
+        }
+        let samples = candidates
+            .choose_multiple(&mut thread_rng(), SCRUB_SAMPLE_COUNT)
+            .cloned()
+            .collect_vec();
+        tracing::debug!(
+            "SST scrubber sampled {} of {} objects",
+            samples.len(),
+            candidates.len()
+        );
+
+        let mut corrupt_object_ids = vec![];
+        for (compaction_group_id, object_id, file_size) in samples {
+            if let Err(error) = self.gc_manager.verify_object(object_id, file_size).await {
+                tracing::error!(
+                    object_id,
+                    compaction_group_id,
+                    error,
+                    "SST scrubber found corrupt object, quarantining it from GC"
+                );
+                self.gc_manager.quarantine_objects([object_id]);
+                self.env.event_log_manager_ref().add_event_logs(vec![
+                    event_log::Event::SstCorruption(event_log::EventSstCorruption {
+                        object_id,
+                        compaction_group_id,
+                        error,
+                    }),
+                ]);
+                corrupt_object_ids.push(object_id);
+            }
+        }
+        Ok(corrupt_object_ids)
+    }
+}
@@ -58,6 +58,8 @@ impl HummockManager {
 
                 FullGc,
 
+                SstScrub,
+
                 GroupScheduleMerge,
             }
             let mut check_compact_trigger_interval =
@@ -150,6 +152,20 @@ impl HummockManager {
                 Box::pin(tombstone_reclaim_trigger),
             ];
 
+            let periodic_sst_scrub_interval_sec =
+                hummock_manager.env.opts.periodic_sst_scrub_interval_sec;
+
+            if periodic_sst_scrub_interval_sec > 0 {
+                let mut sst_scrub_interval =
+                    tokio::time::interval(Duration::from_secs(periodic_sst_scrub_interval_sec));
+                sst_scrub_interval
+                    .set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                sst_scrub_interval.reset();
+                let sst_scrub_trigger =
+                    IntervalStream::new(sst_scrub_interval).map(|_| HummockTimerEvent::SstScrub);
+                triggers.push(Box::pin(sst_scrub_trigger));
+            }
+
             let periodic_scheduling_compaction_group_split_interval_sec = hummock_manager
                 .env
                 .opts
@@ -465,6 +481,19 @@ impl HummockManager {
                                             });
                                     });
                                 }
+
+                                HummockTimerEvent::SstScrub => {
+                                    let hummock_manager_2 = hummock_manager.clone();
+                                    tokio::task::spawn(async move {
+                                        use thiserror_ext::AsReport;
+                                        let _ = hummock_manager_2
+                                            .scrub_sst_objects()
+                                            .await
+                                            .inspect_err(|e| {
+                                                warn!(error = %e.as_report(), "Failed to scrub SST objects.")
+                                            });
+                                    });
+                                }
                             }
                         }
                     }
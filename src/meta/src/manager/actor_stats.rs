@@ -0,0 +1,48 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::common::WorkerType;
+use risingwave_pb::meta::list_actor_runtime_stats_response::ActorRuntimeStats;
+use risingwave_rpc_client::ComputeClientPool;
+
+use crate::manager::MetadataManager;
+use crate::MetaResult;
+
+/// Collects per-actor runtime stats (await-tree depth, executor cache entry counts and
+/// memory estimates) by fanning out to the monitor service of every compute node.
+///
+/// This is called on demand when `rw_catalog.rw_actor_runtime_stats` is queried, so no
+/// state is kept on the meta node.
+pub async fn list_actor_runtime_stats(
+    metadata_manager: &MetadataManager,
+) -> MetaResult<Vec<ActorRuntimeStats>> {
+    let worker_nodes = metadata_manager
+        .list_worker_node(Some(WorkerType::ComputeNode), None)
+        .await?;
+
+    let compute_clients = ComputeClientPool::adhoc();
+    let mut stats = Vec::new();
+    for worker_node in &worker_nodes {
+        let client = compute_clients.get(worker_node).await?;
+        let resp = client.get_actor_runtime_stats().await?;
+        stats.extend(resp.stats.into_iter().map(|s| ActorRuntimeStats {
+            actor_id: s.actor_id,
+            worker_id: worker_node.id,
+            await_tree_depth: s.await_tree_depth,
+            cache_entry_count: s.cache_entry_count,
+            memory_usage_bytes: s.memory_usage_bytes,
+        }));
+    }
+    Ok(stats)
+}
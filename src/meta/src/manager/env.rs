@@ -98,8 +98,13 @@ pub struct MetaOpts {
     /// After specified seconds of idle (no mview or flush), the process will be exited.
     /// 0 for infinite, process will never be exited due to long idle time.
     pub max_idle_ms: u64,
+    /// After specified milliseconds of idle, all streaming jobs will be paused and
+    /// automatically resumed on the next activity. 0 to disable auto-suspend.
+    pub auto_suspend_idle_ms: u64,
     /// Whether run in compaction detection test mode
     pub compaction_deterministic_test: bool,
+    /// Interval of the background SST integrity scrubber. 0 to disable scrubbing.
+    pub periodic_sst_scrub_interval_sec: u64,
     /// Default parallelism of units for all streaming jobs.
     pub default_parallelism: DefaultParallelism,
 
@@ -265,6 +270,8 @@ impl MetaOpts {
             parallelism_control_trigger_first_delay_sec: 30,
             in_flight_barrier_nums: 40,
             max_idle_ms: 0,
+            auto_suspend_idle_ms: 0,
+            periodic_sst_scrub_interval_sec: 0,
             compaction_deterministic_test: false,
             default_parallelism: DefaultParallelism::Full,
             vacuum_interval_sec: 30,
@@ -341,11 +348,6 @@ impl MetaSrvEnv {
         let idle_manager = Arc::new(IdleManager::new(opts.max_idle_ms));
         let stream_client_pool = Arc::new(StreamClientPool::new(1)); // typically no need for plural clients
         let frontend_client_pool = Arc::new(FrontendClientPool::new(1));
-        let event_log_manager = Arc::new(start_event_log_manager(
-            opts.event_log_enabled,
-            opts.event_log_channel_max_size,
-        ));
-
         // When license key path is specified, license key from system parameters can be easily
         // overwritten. So we simply reject this case.
         if opts.license_key_path.is_some()
@@ -382,6 +384,14 @@ impl MetaSrvEnv {
         // - the prefix is ​​not divided for the sake of compatibility.
         init_system_params.use_new_object_prefix_strategy = Some(cluster_first_launch);
 
+        // Start the event log manager after the meta store is migrated, so that persisted
+        // event logs can be restored.
+        let event_log_manager = Arc::new(start_event_log_manager(
+            opts.event_log_enabled,
+            opts.event_log_channel_max_size,
+            Some(meta_store_impl.clone()),
+        ));
+
         let system_param_controller = Arc::new(
             SystemParamsController::new(
                 meta_store_impl.clone(),
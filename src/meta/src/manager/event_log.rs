@@ -17,10 +17,18 @@ use std::sync::Arc;
 use std::time::SystemTime;
 
 use parking_lot::RwLock;
+use risingwave_meta_model::event_log;
+use risingwave_meta_model::prelude::EventLog as EventLogModel;
 use risingwave_pb::meta::event_log::{Event as PbEvent, Event};
 use risingwave_pb::meta::EventLog as PbEventLog;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use thiserror_ext::AsReport;
 use tokio::task::JoinHandle;
 
+use crate::controller::SqlMetaStore;
+
 pub type EventLogManagerRef = Arc<EventLogManger>;
 type EventLogSender = tokio::sync::mpsc::Sender<EventLog>;
 type ShutdownSender = tokio::sync::oneshot::Sender<()>;
@@ -35,7 +43,14 @@ type Channel = VecDeque<EventLog>;
 type EventStoreRef = Arc<RwLock<HashMap<ChannelId, Channel>>>;
 
 /// Spawns a task that's responsible for event log insertion and expiration.
-pub fn start_event_log_manager(enabled: bool, event_log_channel_max_size: u32) -> EventLogManger {
+///
+/// If `meta_store` is given, event logs are also persisted to it on insertion, bounded by the
+/// same per-channel limit, and restored on startup, so that they survive meta restarts.
+pub fn start_event_log_manager(
+    enabled: bool,
+    event_log_channel_max_size: u32,
+    meta_store: Option<SqlMetaStore>,
+) -> EventLogManger {
     use futures::FutureExt;
     const BUFFER_SIZE: usize = 1024;
     let (event_tx, mut event_rx) = tokio::sync::mpsc::channel::<EventLog>(BUFFER_SIZE);
@@ -47,6 +62,10 @@ pub fn start_event_log_manager(enabled: bool, event_log_channel_max_size: u32) -
         if !enabled {
             return;
         }
+        if let Some(meta_store) = &meta_store {
+            restore_event_logs(meta_store, &event_logs_shared, event_log_channel_max_size as _)
+                .await;
+        }
         loop {
             futures::select_biased! {
                 _ = shutdown_rx_shared.clone().fuse() => {
@@ -58,8 +77,12 @@ pub fn start_event_log_manager(enabled: bool, event_log_channel_max_size: u32) -
                         tracing::info!("event log worker is stopped");
                         return;
                     };
-                    let mut write = event_logs_shared.write();
                     let channel_id: ChannelId = (&event_log).into();
+                    if let Some(meta_store) = &meta_store {
+                        persist_event_log(meta_store, channel_id, &event_log, event_log_channel_max_size as _)
+                            .await;
+                    }
+                    let mut write = event_logs_shared.write();
                     let channel = write.entry(channel_id).or_default();
                     channel.push_back(event_log);
                     // Apply expiration strategies.
@@ -160,6 +183,80 @@ fn keep_latest_n(channel: &mut Channel, max_n: usize) {
     }
 }
 
+/// Restores persisted event logs from the meta store into the in-memory store.
+async fn restore_event_logs(meta_store: &SqlMetaStore, event_logs: &EventStoreRef, max_n: usize) {
+    let models = match EventLogModel::find()
+        .order_by_asc(event_log::Column::Id)
+        .all(&meta_store.conn)
+        .await
+    {
+        Ok(models) => models,
+        Err(e) => {
+            tracing::warn!(error = %e.as_report(), "failed to restore persisted event logs");
+            return;
+        }
+    };
+    let mut write = event_logs.write();
+    for model in models {
+        let event_log = EventLog {
+            payload: model.payload.to_protobuf(),
+        };
+        let channel = write.entry(model.channel_id as _).or_default();
+        channel.push_back(event_log);
+        keep_latest_n(channel, max_n);
+    }
+}
+
+/// Persists one event log to the meta store, then expires the oldest persisted ones of the
+/// same channel exceeding `max_n`. Both are best-effort: errors are logged and swallowed, as
+/// the in-memory store remains the source of truth for serving.
+async fn persist_event_log(
+    meta_store: &SqlMetaStore,
+    channel_id: ChannelId,
+    event_log: &EventLog,
+    max_n: usize,
+) {
+    let model = event_log::ActiveModel {
+        id: Default::default(),
+        channel_id: Set(channel_id as _),
+        timestamp: Set(event_log.payload.timestamp.unwrap_or_default() as _),
+        payload: Set((&event_log.payload).into()),
+    };
+    if let Err(e) = EventLogModel::insert(model).exec(&meta_store.conn).await {
+        tracing::warn!(error = %e.as_report(), "failed to persist event log");
+        return;
+    }
+    // Find the id of the newest event log beyond the per-channel limit, if any, and expire
+    // all older ones of the channel.
+    let threshold: Option<i64> = match EventLogModel::find()
+        .select_only()
+        .column(event_log::Column::Id)
+        .filter(event_log::Column::ChannelId.eq(channel_id as i32))
+        .order_by_desc(event_log::Column::Id)
+        .offset(max_n as u64)
+        .limit(1)
+        .into_tuple()
+        .one(&meta_store.conn)
+        .await
+    {
+        Ok(threshold) => threshold,
+        Err(e) => {
+            tracing::warn!(error = %e.as_report(), "failed to expire persisted event logs");
+            return;
+        }
+    };
+    if let Some(threshold) = threshold {
+        if let Err(e) = EventLogModel::delete_many()
+            .filter(event_log::Column::ChannelId.eq(channel_id as i32))
+            .filter(event_log::Column::Id.lte(threshold))
+            .exec(&meta_store.conn)
+            .await
+        {
+            tracing::warn!(error = %e.as_report(), "failed to expire persisted event logs");
+        }
+    }
+}
+
 // TODO: avoid manual implementation
 impl From<&EventLog> for ChannelId {
     fn from(value: &EventLog) -> Self {
@@ -173,6 +270,10 @@ impl From<&EventLog> for ChannelId {
             Event::WorkerNodePanic(_) => 7,
             Event::AutoSchemaChangeFail(_) => 8,
             Event::SinkFail(_) => 9,
+            Event::SstCorruption(_) => 10,
+            Event::CdcSourceLag(_) => 11,
+            Event::StreamJobQuarantine(_) => 12,
+            Event::AuditLog(_) => 13,
         }
     }
 }
@@ -17,8 +17,14 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use risingwave_common::util::tokio_util::sync::CancellationToken;
+use risingwave_pb::meta::PausedReason;
+use thiserror_ext::AsReport;
+use tokio::sync::oneshot::Sender;
 use tokio::task::JoinHandle;
 
+use crate::barrier::{BarrierScheduler, Command};
+use crate::manager::MetadataManager;
+
 /// `IdleManager` keeps track of latest activity and report whether the meta service has been
 /// idle for long time.
 pub struct IdleManager {
@@ -54,6 +60,13 @@ impl IdleManager {
         ((now - self.instant_base).as_secs_f64() * 1000.0) as u64
     }
 
+    /// Returns the duration since the last recorded activity.
+    pub fn idle_duration(&self) -> Duration {
+        let new_offset_ms = self.offset_ms_now();
+        let last_offset_ms = self.last_active_offset_ms.load(Ordering::Acquire);
+        Duration::from_millis(new_offset_ms.saturating_sub(last_offset_ms))
+    }
+
     pub fn record_activity(&self) {
         self.last_active_offset_ms
             .store(self.offset_ms_now(), Ordering::Release);
@@ -101,6 +114,76 @@ impl IdleManager {
             shutdown.cancel();
         })
     }
+
+    /// Auto-suspend checker pauses all streaming jobs when the meta service has been idle for
+    /// longer than `auto_suspend_idle`, releasing compute resources. The jobs are automatically
+    /// resumed once any activity is recorded again, e.g. a request from the frontend.
+    pub fn start_auto_suspend_checker(
+        idle_manager: IdleManagerRef,
+        barrier_scheduler: BarrierScheduler,
+        metadata_manager: MetadataManager,
+        auto_suspend_idle: Duration,
+        check_interval: Duration,
+    ) -> (JoinHandle<()>, Sender<()>) {
+        tracing::info!(
+            "auto-suspend is enabled, streaming jobs will be paused after idle for {:?}",
+            auto_suspend_idle
+        );
+
+        let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
+        let join_handle = tokio::spawn(async move {
+            let mut min_interval = tokio::time::interval(check_interval);
+            min_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut suspended = false;
+            loop {
+                tokio::select! {
+                    _ = min_interval.tick() => {},
+                    _ = &mut shutdown_rx => {
+                        tracing::info!("Auto-suspend checker is stopped");
+                        return;
+                    }
+                }
+
+                let idle = idle_manager.idle_duration();
+                let pausing = if !suspended && idle >= auto_suspend_idle {
+                    tracing::info!(
+                        "auto-suspend checker found the server idle for {:?}, pausing all streaming jobs",
+                        idle
+                    );
+                    true
+                } else if suspended && idle < auto_suspend_idle {
+                    tracing::info!("activity detected, resuming all streaming jobs");
+                    false
+                } else {
+                    continue;
+                };
+
+                let database_ids = match metadata_manager.list_active_database_ids().await {
+                    Ok(database_ids) => database_ids,
+                    Err(e) => {
+                        tracing::warn!(error = %e.as_report(), "auto-suspend checker failed to list databases");
+                        continue;
+                    }
+                };
+                let mut success = true;
+                for database_id in database_ids {
+                    let command = if pausing {
+                        Command::pause(PausedReason::AutoSuspend)
+                    } else {
+                        Command::resume(PausedReason::AutoSuspend)
+                    };
+                    if let Err(e) = barrier_scheduler.run_command(database_id, command).await {
+                        tracing::warn!(error = %e.as_report(), "auto-suspend checker failed to run command");
+                        success = false;
+                    }
+                }
+                if success {
+                    suspended = pausing;
+                }
+            }
+        });
+        (join_handle, shutdown_tx)
+    }
 }
 
 #[cfg(test)]
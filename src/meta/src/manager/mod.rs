@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod actor_stats;
 pub mod diagnose;
 mod env;
 pub mod event_log;
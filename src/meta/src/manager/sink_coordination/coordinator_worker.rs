@@ -236,6 +236,7 @@ impl CoordinatorWorker {
             pending_epochs: Default::default(),
         };
 
+        let sink_id = worker.handle_manager.param.sink_id.sink_id;
         if let Err(e) = worker.run_coordination(coordinator).await {
             for handle in worker.handle_manager.writer_handles.into_values() {
                 handle.abort(Status::internal(format!(
@@ -244,6 +245,7 @@ impl CoordinatorWorker {
                 )))
             }
         }
+        super::remove_sink_commit_stats(sink_id);
     }
 
     async fn run_coordination(
@@ -266,7 +268,6 @@ impl CoordinatorWorker {
                 .can_commit()
             {
                 let (epoch, requests) = self.pending_epochs.pop_first().expect("non-empty");
-                // TODO: measure commit time
                 let start_time = Instant::now();
                 run_future_with_periodic_fn(
                     coordinator.commit(epoch, requests.metadatas),
@@ -281,6 +282,10 @@ impl CoordinatorWorker {
                 )
                 .await
                 .map_err(|e| anyhow!(e))?;
+                super::record_sink_commit(
+                    self.handle_manager.param.sink_id.sink_id,
+                    start_time.elapsed(),
+                );
                 self.handle_manager.ack_commit(epoch, requests.handle_ids)?;
             }
         }
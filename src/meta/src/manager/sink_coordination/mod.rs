@@ -16,11 +16,75 @@ mod coordinator_worker;
 mod handle;
 mod manager;
 
+use std::collections::{HashMap, VecDeque};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
 use futures::stream::BoxStream;
 pub use manager::SinkCoordinatorManager;
 use risingwave_pb::connector_service::{CoordinateRequest, CoordinateResponse};
+use risingwave_pb::meta::list_sink_commit_metrics_response::SinkCommitMetrics;
 use tokio::sync::mpsc::UnboundedSender;
 use tonic::Status;
 
 pub type SinkWriterRequestStream = BoxStream<'static, Result<CoordinateRequest, Status>>;
 pub type SinkCoordinatorResponseSender = UnboundedSender<Result<CoordinateResponse, Status>>;
+
+/// Number of recent commits kept per sink for percentile computation.
+const COMMIT_LATENCY_WINDOW: usize = 128;
+
+#[derive(Default)]
+struct SinkCommitStats {
+    commit_count: u64,
+    sum_latency_ms: u64,
+    max_latency_ms: u64,
+    /// Latencies of the most recent [`COMMIT_LATENCY_WINDOW`] commits.
+    recent_latency_ms: VecDeque<u64>,
+}
+
+/// Per-sink commit stats of all coordinator workers in this meta node, exposed to the frontend
+/// via `rw_catalog.rw_sink_metrics`. Only coordinated sinks report here; entries are removed
+/// when the sink's coordinator stops.
+static SINK_COMMIT_STATS: LazyLock<Mutex<HashMap<u32, SinkCommitStats>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+pub(crate) fn record_sink_commit(sink_id: u32, latency: Duration) {
+    let latency_ms = latency.as_millis() as u64;
+    let mut stats = SINK_COMMIT_STATS.lock().unwrap();
+    let entry = stats.entry(sink_id).or_default();
+    entry.commit_count += 1;
+    entry.sum_latency_ms += latency_ms;
+    entry.max_latency_ms = entry.max_latency_ms.max(latency_ms);
+    if entry.recent_latency_ms.len() == COMMIT_LATENCY_WINDOW {
+        entry.recent_latency_ms.pop_front();
+    }
+    entry.recent_latency_ms.push_back(latency_ms);
+}
+
+pub(crate) fn remove_sink_commit_stats(sink_id: u32) {
+    SINK_COMMIT_STATS.lock().unwrap().remove(&sink_id);
+}
+
+pub fn list_sink_commit_metrics() -> Vec<SinkCommitMetrics> {
+    let stats = SINK_COMMIT_STATS.lock().unwrap();
+    stats
+        .iter()
+        .map(|(sink_id, stat)| {
+            let mut recent: Vec<_> = stat.recent_latency_ms.iter().copied().collect();
+            recent.sort_unstable();
+            let percentile = |p: f64| {
+                let idx = ((recent.len() as f64 - 1.0) * p) as usize;
+                recent[idx]
+            };
+            SinkCommitMetrics {
+                sink_id: *sink_id,
+                commit_count: stat.commit_count,
+                avg_latency_ms: stat.sum_latency_ms / stat.commit_count.max(1),
+                max_latency_ms: stat.max_latency_ms,
+                p50_latency_ms: percentile(0.5),
+                p90_latency_ms: percentile(0.9),
+                p99_latency_ms: percentile(0.99),
+            }
+        })
+        .collect()
+}
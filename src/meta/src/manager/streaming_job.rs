@@ -395,9 +395,11 @@ impl StreamingJob {
                     return Err(MetaError::permission_denied("source version is stale"));
                 }
             }
-            StreamingJob::MaterializedView(_)
-            | StreamingJob::Sink(_, _)
-            | StreamingJob::Index(_, _) => {
+            StreamingJob::MaterializedView(_) => {
+                // Materialized views don't carry a version field. Concurrent replacement is
+                // already rejected by the dependency check in `create_job_catalog_for_replace`.
+            }
+            StreamingJob::Sink(_, _) | StreamingJob::Index(_, _) => {
                 bail_not_implemented!("schema change for {}", self.job_type_str())
             }
         }
@@ -52,7 +52,7 @@ use risingwave_pb::ddl_service::{
 };
 use risingwave_pb::meta::table_fragments::fragment::FragmentDistributionType;
 use risingwave_pb::meta::table_fragments::PbFragment;
-use risingwave_pb::meta::PbTableParallelism;
+use risingwave_pb::meta::{event_log, PbTableParallelism};
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::update_mutation::PbMergeUpdate;
 use risingwave_pb::stream_plan::{
@@ -81,7 +81,7 @@ use crate::stream::{
 };
 use crate::{MetaError, MetaResult};
 
-#[derive(PartialEq)]
+#[derive(Debug, PartialEq)]
 pub enum DropMode {
     Restrict,
     Cascade,
@@ -97,6 +97,7 @@ impl DropMode {
     }
 }
 
+#[derive(Debug)]
 pub enum StreamingJobId {
     MaterializedView(TableId),
     Sink(SinkId),
@@ -190,6 +191,39 @@ impl DdlCommand {
             | DdlCommand::CreateSubscription(_) => false,
         }
     }
+
+    /// Returns the audit event to be recorded in the event log if the command is a dangerous
+    /// operation, or `None` otherwise.
+    fn audit_event(&self) -> Option<event_log::EventAuditLog> {
+        let (operation, arguments) = match self {
+            DdlCommand::DropDatabase(id) => ("DROP_DATABASE", format!("database_id={id}")),
+            DdlCommand::DropSchema(id) => ("DROP_SCHEMA", format!("schema_id={id}")),
+            DdlCommand::DropSource(id, drop_mode) => {
+                ("DROP_SOURCE", format!("source_id={id}, drop_mode={drop_mode:?}"))
+            }
+            DdlCommand::DropFunction(id) => ("DROP_FUNCTION", format!("function_id={id}")),
+            DdlCommand::DropView(id, drop_mode) => {
+                ("DROP_VIEW", format!("view_id={id}, drop_mode={drop_mode:?}"))
+            }
+            DdlCommand::DropStreamingJob(job_id, drop_mode, _) => (
+                "DROP_STREAMING_JOB",
+                format!("job_id={job_id:?}, drop_mode={drop_mode:?}"),
+            ),
+            DdlCommand::DropConnection(id) => ("DROP_CONNECTION", format!("connection_id={id}")),
+            DdlCommand::DropSecret(id) => ("DROP_SECRET", format!("secret_id={id}")),
+            DdlCommand::DropSubscription(id, drop_mode) => (
+                "DROP_SUBSCRIPTION",
+                format!("subscription_id={id}, drop_mode={drop_mode:?}"),
+            ),
+            _ => return None,
+        };
+        Some(event_log::EventAuditLog {
+            operation: operation.into(),
+            arguments,
+            // DDL requests do not carry the initiating user yet.
+            user_name: String::new(),
+        })
+    }
 }
 
 #[derive(Clone)]
@@ -292,6 +326,7 @@ impl DdlController {
         if !command.allow_in_recovery() {
             self.barrier_manager.check_status_running()?;
         }
+        let audit_event = command.audit_event();
         let ctrl = self.clone();
         let fut = async move {
             match command {
@@ -369,6 +404,12 @@ impl DdlController {
         }
         .in_current_span();
         let notification_version = tokio::spawn(fut).await.map_err(|e| anyhow!(e))??;
+        // Record dangerous operations in the audit event log, only after they have succeeded.
+        if let Some(audit_event) = audit_event {
+            self.env
+                .event_log_manager_ref()
+                .add_event_logs(vec![event_log::Event::AuditLog(audit_event)]);
+        }
         Ok(Some(WaitVersion {
             catalog_version: notification_version,
             hummock_version_id: self.barrier_manager.get_hummock_version_id().await.to_u64(),
@@ -1296,7 +1337,10 @@ impl DdlController {
         Ok(version)
     }
 
-    /// This is used for `ALTER TABLE ADD/DROP COLUMN` / `ALTER SOURCE ADD COLUMN`.
+    /// This is used for `ALTER TABLE ADD/DROP COLUMN` / `ALTER SOURCE ADD COLUMN` /
+    /// `ALTER MATERIALIZED VIEW REPLACE AS`. The new job is built side-by-side under a
+    /// temporary id while the old one keeps serving, then swapped in atomically at a
+    /// checkpoint by the `ReplaceStreamJob` barrier command.
     pub async fn replace_job(
         &self,
         mut streaming_job: StreamingJob,
@@ -1304,10 +1348,10 @@ impl DdlController {
         col_index_mapping: Option<ColIndexMapping>,
     ) -> MetaResult<NotificationVersion> {
         match &mut streaming_job {
-            StreamingJob::Table(..) | StreamingJob::Source(..) => {}
-            StreamingJob::MaterializedView(..)
-            | StreamingJob::Sink(..)
-            | StreamingJob::Index(..) => {
+            StreamingJob::Table(..)
+            | StreamingJob::Source(..)
+            | StreamingJob::MaterializedView(..) => {}
+            StreamingJob::Sink(..) | StreamingJob::Index(..) => {
                 bail_not_implemented!("schema change for {}", streaming_job.job_type_str())
             }
         }
@@ -1696,10 +1740,10 @@ impl DdlController {
         tmp_job_id: TableId,
     ) -> MetaResult<(ReplaceStreamJobContext, StreamJobFragments)> {
         match &stream_job {
-            StreamingJob::Table(..) | StreamingJob::Source(..) => {}
-            StreamingJob::MaterializedView(..)
-            | StreamingJob::Sink(..)
-            | StreamingJob::Index(..) => {
+            StreamingJob::Table(..)
+            | StreamingJob::Source(..)
+            | StreamingJob::MaterializedView(..) => {}
+            StreamingJob::Sink(..) | StreamingJob::Index(..) => {
                 bail_not_implemented!("schema change for {}", stream_job.job_type_str())
             }
         }
@@ -1752,8 +1796,10 @@ impl DdlController {
                     job_type,
                 )?
             }
-            StreamingJobType::Table(TableJobType::SharedCdcSource) => {
-                // get the upstream fragment which should be the cdc source
+            StreamingJobType::Table(TableJobType::SharedCdcSource)
+            | StreamingJobType::MaterializedView => {
+                // get the upstream fragments, which should be the cdc source or the scanned
+                // upstream jobs of the new mview plan
                 let (upstream_root_fragments, upstream_actor_location) = self
                     .metadata_manager
                     .get_upstream_root_fragments(fragment_graph.dependent_table_ids())
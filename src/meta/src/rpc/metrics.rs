@@ -149,6 +149,8 @@ pub struct MetaMetrics {
     pub delta_log_count: IntGauge,
     /// latency of version checkpoint
     pub version_checkpoint_latency: Histogram,
+    /// Size in bytes of the latest persisted hummock version checkpoint (the manifest).
+    pub version_checkpoint_size: IntGauge,
     /// Latency for hummock manager to acquire lock
     pub hummock_manager_lock_time: HistogramVec,
     /// Latency for hummock manager to really process a request after acquire the lock
@@ -159,6 +161,10 @@ pub struct MetaMetrics {
     pub compact_pending_bytes: IntGaugeVec,
     /// Per level compression ratio
     pub compact_level_compression_ratio: GenericGaugeVec<AtomicF64>,
+    /// Per group write amplification, estimated from compaction stats
+    pub compaction_group_write_amplification: GenericGaugeVec<AtomicF64>,
+    /// Per group read amplification of compaction, estimated from compaction stats
+    pub compaction_group_read_amplification: GenericGaugeVec<AtomicF64>,
     /// Per level number of running compaction task
     pub level_compact_task_cnt: IntGaugeVec,
     pub time_after_last_observation: Arc<AtomicU64>,
@@ -506,6 +512,12 @@ impl MetaMetrics {
             exponential_buckets(0.1, 1.5, 20).unwrap()
         );
         let version_checkpoint_latency = register_histogram_with_registry!(opts, registry).unwrap();
+        let version_checkpoint_size = register_int_gauge_with_registry!(
+            "storage_version_checkpoint_size",
+            "size in bytes of the latest persisted hummock version checkpoint",
+            registry
+        )
+        .unwrap();
 
         let hummock_manager_lock_time = register_histogram_vec_with_registry!(
             "hummock_manager_lock_time",
@@ -555,6 +567,22 @@ impl MetaMetrics {
         )
         .unwrap();
 
+        let compaction_group_write_amplification = register_gauge_vec_with_registry!(
+            "storage_compaction_group_write_amplification",
+            "estimated write amplification of each compaction group",
+            &["group"],
+            registry
+        )
+        .unwrap();
+
+        let compaction_group_read_amplification = register_gauge_vec_with_registry!(
+            "storage_compaction_group_read_amplification",
+            "estimated read amplification of compaction of each compaction group",
+            &["group"],
+            registry
+        )
+        .unwrap();
+
         let level_compact_task_cnt = register_int_gauge_vec_with_registry!(
             "storage_level_compact_task_cnt",
             "num of compact_task organized by group and level",
@@ -808,6 +836,7 @@ impl MetaMetrics {
             total_object_size,
             delta_log_count,
             version_checkpoint_latency,
+            version_checkpoint_size,
             current_version_id,
             checkpoint_version_id,
             min_pinned_version_id,
@@ -823,6 +852,8 @@ impl MetaMetrics {
             meta_type,
             compact_pending_bytes,
             compact_level_compression_ratio,
+            compaction_group_write_amplification,
+            compaction_group_read_amplification,
             level_compact_task_cnt,
             object_store_metric,
             source_is_up,
@@ -388,6 +388,12 @@ impl GlobalStreamManager {
                 .await?,
         );
 
+        let database_checkpoint_frequency = self
+            .metadata_manager
+            .catalog_controller
+            .get_database_checkpoint_frequency(streaming_job.database_id() as _)
+            .await?;
+
         let info = CreateStreamingJobCommandInfo {
             stream_job_fragments,
             upstream_root_actors,
@@ -398,6 +404,7 @@ impl GlobalStreamManager {
             internal_tables: internal_tables.into_values().collect_vec(),
             job_type,
             create_type,
+            database_checkpoint_frequency,
         };
 
         let command = if let Some(snapshot_backfill_info) = snapshot_backfill_info {
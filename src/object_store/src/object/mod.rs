@@ -861,6 +861,90 @@ impl<OS: ObjectStore> MonitoredObjectStore<OS> {
     }
 }
 
+/// Verifies that the object store is accessible by performing a write-read-delete roundtrip
+/// on a probe object under `data_directory`, and reports the measured latencies.
+///
+/// This is intended to be run on startup (see `ObjectStoreConfig::startup_probe`) to fail
+/// fast with actionable errors on misconfigured permissions or connectivity, instead of
+/// cryptic failures at runtime when the first object is uploaded.
+pub async fn verify_object_store_access(
+    store: &ObjectStoreImpl,
+    data_directory: &str,
+) -> ObjectResult<()> {
+    let path = format!(
+        "{}/probe-{}-{}",
+        data_directory,
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis(),
+    );
+    let payload = Bytes::from_static(b"risingwave object store probe");
+
+    let start = std::time::Instant::now();
+    store.upload(&path, payload.clone()).await.map_err(|e| {
+        ObjectError::internal(format!(
+            "failed to write probe object `{}`: {}. Please check that the credentials \
+             allow writing to the configured bucket and data directory",
+            path,
+            e.as_report()
+        ))
+    })?;
+    let upload_latency = start.elapsed();
+
+    let start = std::time::Instant::now();
+    let read = store.read(&path, ..).await.map_err(|e| {
+        ObjectError::internal(format!(
+            "failed to read probe object `{}` back: {}. Please check that the credentials \
+             allow reading from the configured bucket and data directory",
+            path,
+            e.as_report()
+        ))
+    })?;
+    let read_latency = start.elapsed();
+    if read != payload {
+        return Err(ObjectError::internal(format!(
+            "probe object `{}` read back with corrupted content",
+            path
+        )));
+    }
+
+    let start = std::time::Instant::now();
+    store.delete(&path).await.map_err(|e| {
+        ObjectError::internal(format!(
+            "failed to delete probe object `{}`: {}. Please check that the credentials \
+             allow deleting from the configured bucket and data directory",
+            path,
+            e.as_report()
+        ))
+    })?;
+    let delete_latency = start.elapsed();
+
+    // The probe object must be gone after deletion. If it is still readable, deletion is
+    // silently ineffective, e.g. due to a lifecycle or versioning configuration keeping
+    // deleted objects readable, which would break vacuuming of stale objects.
+    match store.read(&path, ..).await {
+        Err(e) if e.is_object_not_found_error() => {}
+        Ok(_) => {
+            return Err(ObjectError::internal(format!(
+                "probe object `{}` is still readable after deletion. Please check the \
+                 lifecycle and versioning configuration of the bucket",
+                path
+            )));
+        }
+        Err(e) => return Err(e),
+    }
+
+    tracing::info!(
+        ?upload_latency,
+        ?read_latency,
+        ?delete_latency,
+        "object store startup probe succeeded"
+    );
+    Ok(())
+}
+
 /// Creates a new [`ObjectStore`] from the given `url`. Credentials are configured via environment
 /// variables.
 ///
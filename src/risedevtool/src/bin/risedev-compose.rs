@@ -219,7 +219,10 @@ fn main() -> Result<()> {
             | ServiceConfig::MySql(_)
             | ServiceConfig::Postgres(_)
             | ServiceConfig::SqlServer(_)
-            | ServiceConfig::SchemaRegistry(_) => return Err(anyhow!("not supported")),
+            | ServiceConfig::SchemaRegistry(_)
+            | ServiceConfig::Pulsar(_)
+            | ServiceConfig::MongoDb(_)
+            | ServiceConfig::Chaos(_) => return Err(anyhow!("not supported")),
         };
         compose.container_name = service.id().to_owned();
         if opts.deploy {
@@ -24,10 +24,11 @@ use fs_err::OpenOptions;
 use indicatif::ProgressBar;
 use risedev::util::{complete_spin, fail_spin};
 use risedev::{
-    generate_risedev_env, preflight_check, CompactorService, ComputeNodeService, ConfigExpander,
-    ConfigureTmuxTask, DummyService, EnsureStopService, ExecuteContext, FrontendService,
-    GrafanaService, KafkaService, MetaNodeService, MinioService, MySqlService, PostgresService,
-    PrometheusService, PubsubService, RedisService, SchemaRegistryService, ServiceConfig,
+    generate_risedev_env, preflight_check, ChaosService, CompactorService, ComputeNodeService,
+    ConfigExpander, ConfigureTmuxTask, DummyService, EnsureStopService, ExecuteContext,
+    FrontendService, GrafanaService, KafkaService, MetaNodeService, MinioService,
+    MongoDbReplicaSetInitTask, MongoDbService, MySqlService, PostgresService, PrometheusService,
+    PubsubService, PulsarService, RedisService, SchemaRegistryService, ServiceConfig,
     SqlServerService, SqliteConfig, Task, TempoService, RISEDEV_NAME,
 };
 use tempfile::tempdir;
@@ -173,8 +174,12 @@ fn task_main(
                 let mut service = ComputeNodeService::new(c.clone())?;
                 service.execute(&mut ctx)?;
 
-                let mut task =
-                    risedev::TcpReadyCheckTask::new(c.address.clone(), c.port, c.user_managed)?;
+                let mut task = risedev::HealthCheckTask::new(
+                    c.address.clone(),
+                    c.port,
+                    c.user_managed,
+                    c.health_check.clone(),
+                )?;
                 task.execute(&mut ctx)?;
                 ctx.pb
                     .set_message(format!("api grpc://{}:{}/", c.address, c.port));
@@ -184,8 +189,12 @@ fn task_main(
                     ExecuteContext::new(&mut logger, manager.new_progress(), status_dir.clone());
                 let mut service = MetaNodeService::new(c.clone())?;
                 service.execute(&mut ctx)?;
-                let mut task =
-                    risedev::TcpReadyCheckTask::new(c.address.clone(), c.port, c.user_managed)?;
+                let mut task = risedev::HealthCheckTask::new(
+                    c.address.clone(),
+                    c.port,
+                    c.user_managed,
+                    c.health_check.clone(),
+                )?;
                 task.execute(&mut ctx)?;
                 ctx.pb.set_message(format!(
                     "api grpc://{}:{}/, dashboard http://{}:{}/",
@@ -197,8 +206,12 @@ fn task_main(
                     ExecuteContext::new(&mut logger, manager.new_progress(), status_dir.clone());
                 let mut service = FrontendService::new(c.clone())?;
                 service.execute(&mut ctx)?;
-                let mut task =
-                    risedev::TcpReadyCheckTask::new(c.address.clone(), c.port, c.user_managed)?;
+                let mut task = risedev::HealthCheckTask::new(
+                    c.address.clone(),
+                    c.port,
+                    c.user_managed,
+                    c.health_check.clone(),
+                )?;
                 task.execute(&mut ctx)?;
                 ctx.pb
                     .set_message(format!("api postgres://{}:{}/", c.address, c.port));
@@ -219,8 +232,12 @@ fn task_main(
                     ExecuteContext::new(&mut logger, manager.new_progress(), status_dir.clone());
                 let mut service = CompactorService::new(c.clone())?;
                 service.execute(&mut ctx)?;
-                let mut task =
-                    risedev::TcpReadyCheckTask::new(c.address.clone(), c.port, c.user_managed)?;
+                let mut task = risedev::HealthCheckTask::new(
+                    c.address.clone(),
+                    c.port,
+                    c.user_managed,
+                    c.health_check.clone(),
+                )?;
                 task.execute(&mut ctx)?;
                 ctx.pb
                     .set_message(format!("compactor {}:{}", c.address, c.port));
@@ -287,6 +304,35 @@ fn task_main(
                 ctx.pb
                     .set_message(format!("schema registry http://{}:{}", c.address, c.port));
             }
+            ServiceConfig::Pulsar(c) => {
+                let mut ctx =
+                    ExecuteContext::new(&mut logger, manager.new_progress(), status_dir.clone());
+                let mut service = PulsarService::new(c.clone());
+                service.execute(&mut ctx)?;
+                let mut task = risedev::TcpReadyCheckTask::new(
+                    c.address.clone(),
+                    c.broker_port,
+                    c.user_managed,
+                )?;
+                task.execute(&mut ctx)?;
+                ctx.pb.set_message(format!(
+                    "pulsar {}:{}, admin http://{}:{}",
+                    c.address, c.broker_port, c.address, c.http_port
+                ));
+            }
+            ServiceConfig::MongoDb(c) => {
+                let mut ctx =
+                    ExecuteContext::new(&mut logger, manager.new_progress(), status_dir.clone());
+                let mut service = MongoDbService::new(c.clone());
+                service.execute(&mut ctx)?;
+                let mut task =
+                    risedev::TcpReadyCheckTask::new(c.address.clone(), c.port, c.user_managed)?;
+                task.execute(&mut ctx)?;
+                let mut task = MongoDbReplicaSetInitTask::new(c.clone())?;
+                task.execute(&mut ctx)?;
+                ctx.pb
+                    .set_message(format!("mongodb {}:{}", c.address, c.port));
+            }
 
             ServiceConfig::Pubsub(c) => {
                 let mut ctx =
@@ -365,6 +411,13 @@ fn task_main(
                 ctx.pb
                     .set_message(format!("sqlserver {}:{}", c.address, c.port));
             }
+            ServiceConfig::Chaos(c) => {
+                let mut ctx =
+                    ExecuteContext::new(&mut logger, manager.new_progress(), status_dir.clone());
+                let mut service = ChaosService::new(c.clone())?;
+                service.execute(&mut ctx)?;
+                ctx.pb.set_message(format!("chaos on {}", c.target));
+            }
         }
 
         let service_id = service.id().to_owned();
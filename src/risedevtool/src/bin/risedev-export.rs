@@ -0,0 +1,156 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use clap::{Parser, ValueEnum};
+use console::style;
+use risedev::{
+    generate_k8s_manifests, Compose, ComposeConfig, ComposeFile, ComposeService, ComposeVolume,
+    ConfigExpander, DockerImageConfig, ServiceConfig, RISEDEV_CONFIG_FILE,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum ExportFormat {
+    DockerCompose,
+    K8s,
+}
+
+/// Export a risedev profile as deployable manifests with matching config files.
+#[derive(Parser)]
+#[clap(author, version, about, long_about = None)]
+#[clap(propagate_version = true)]
+pub struct RiseDevExportOpts {
+    /// The directory to output the manifests and config files to.
+    #[clap(short, long)]
+    directory: String,
+
+    /// The output format.
+    #[clap(long, value_enum, default_value = "docker-compose")]
+    format: ExportFormat,
+
+    /// The Kubernetes namespace to deploy into. Only used with `--format k8s`.
+    #[clap(long, default_value = "risedev")]
+    namespace: String,
+
+    #[clap(default_value = "compose")]
+    profile: String,
+}
+
+fn load_docker_image_config(risedev_config: &str) -> Result<DockerImageConfig> {
+    #[derive(Deserialize)]
+    struct ConfigInRiseDev {
+        compose: DockerImageConfig,
+    }
+    let config: ConfigInRiseDev = serde_yaml::from_str(risedev_config)?;
+    Ok(config.compose)
+}
+
+fn main() -> Result<()> {
+    let opts = RiseDevExportOpts::parse();
+
+    let (rw_config_path, _env, expanded_config) = ConfigExpander::expand(".", &opts.profile)?;
+
+    let compose_config = ComposeConfig {
+        image: load_docker_image_config(&fs_err::read_to_string(RISEDEV_CONFIG_FILE)?)?,
+        config_directory: opts.directory.clone(),
+        rw_config_path,
+    };
+
+    let services = ConfigExpander::deserialize(&expanded_config)?;
+
+    let mut compose_services: BTreeMap<String, ComposeService> = BTreeMap::new();
+    let mut volumes: BTreeMap<String, ComposeVolume> = BTreeMap::new();
+
+    for service in &services {
+        let compose = match service {
+            ServiceConfig::Minio(c) => c.compose(&compose_config)?,
+            ServiceConfig::Prometheus(c) => c.compose(&compose_config)?,
+            ServiceConfig::ComputeNode(c) => c.compose(&compose_config)?,
+            ServiceConfig::MetaNode(c) => c.compose(&compose_config)?,
+            ServiceConfig::Frontend(c) => c.compose(&compose_config)?,
+            ServiceConfig::Compactor(c) => c.compose(&compose_config)?,
+            ServiceConfig::Grafana(c) => c.compose(&compose_config)?,
+            ServiceConfig::Tempo(c) => c.compose(&compose_config)?,
+            ServiceConfig::Sqlite(_) | ServiceConfig::Opendal(_) | ServiceConfig::AwsS3(_) => {
+                continue
+            }
+            other => {
+                return Err(anyhow!(
+                    "service `{}` is not supported in export",
+                    other.id()
+                ))
+            }
+        };
+
+        // Collect named data volumes for the compose file.
+        for volume in &compose.volumes {
+            if let Some((source, _)) = volume.split_once(':')
+                && !source.starts_with('.')
+            {
+                volumes.insert(source.to_owned(), ComposeVolume::default());
+            }
+        }
+
+        let mut compose = compose;
+        compose.container_name = service.id().to_owned();
+        compose_services.insert(service.id().to_owned(), compose);
+    }
+
+    match opts.format {
+        ExportFormat::DockerCompose => {
+            let compose_file = ComposeFile {
+                services: compose_services,
+                volumes,
+                name: format!("risingwave-{}", opts.profile),
+            };
+            let yaml = serde_yaml::to_string(&compose_file)?;
+            fs_err::write(
+                Path::new(&opts.directory).join("docker-compose.yml"),
+                yaml,
+            )?;
+            println!(
+                "docker-compose file written to {}",
+                style(format!("{}/docker-compose.yml", opts.directory)).green()
+            );
+        }
+        ExportFormat::K8s => {
+            // The `Compose` impls above have written all config files (e.g. `risingwave.toml`,
+            // `prometheus.yaml`) into the output directory; bundle them into the `ConfigMap`.
+            let mut config_files = BTreeMap::new();
+            for entry in fs_err::read_dir(&opts.directory)? {
+                let entry = entry?;
+                if entry.file_type()?.is_file() {
+                    config_files.insert(
+                        entry.file_name().to_string_lossy().into_owned(),
+                        fs_err::read_to_string(entry.path())?,
+                    );
+                }
+            }
+
+            let manifests =
+                generate_k8s_manifests(&compose_services, &config_files, &opts.namespace)?;
+            fs_err::write(Path::new(&opts.directory).join("risingwave.k8s.yml"), manifests)?;
+            println!(
+                "Kubernetes manifests written to {}",
+                style(format!("{}/risingwave.k8s.yml", opts.directory)).green()
+            );
+        }
+    }
+
+    Ok(())
+}
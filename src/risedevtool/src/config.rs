@@ -23,10 +23,14 @@ use crate::ServiceConfig;
 
 mod dollar_expander;
 mod id_expander;
+mod inherit_expander;
+mod port_expander;
 mod provide_expander;
 mod use_expander;
 use dollar_expander::DollarExpander;
 use id_expander::IdExpander;
+use inherit_expander::InheritExpander;
+use port_expander::PortExpander;
 use provide_expander::ProvideExpander;
 use use_expander::UseExpander;
 
@@ -131,11 +135,8 @@ impl ConfigExpander {
             .get(&Yaml::String("template".to_owned()))
             .ok_or_else(|| anyhow!("expect `profile` section"))?;
 
-        let profile_section = all_profile_section
-            .get(&Yaml::String(profile.to_owned()))
-            .ok_or_else(|| anyhow!("profile '{}' not found", profile))?
-            .as_hash()
-            .ok_or_else(|| anyhow!("expect `profile` section to be a hashmap"))?;
+        // resolve `inherit` and `override` before reading the profile's own sections
+        let profile_section = &InheritExpander::new(&all_profile_section).visit(profile)?;
 
         let config_path = profile_section
             .get(&Yaml::String("config-path".to_owned()))
@@ -163,7 +164,13 @@ impl ConfigExpander {
             .ok_or_else(|| anyhow!("expect `steps` section"))?
             .clone();
 
+        let auto_ports = profile_section
+            .get(&Yaml::String("auto-ports".to_owned()))
+            .and_then(|s| s.as_bool())
+            .unwrap_or(false);
+
         let steps = UseExpander::new(template_section)?.visit(steps)?;
+        let steps = PortExpander::new(auto_ports).visit(steps)?;
         let steps = DollarExpander::new(extra_info).visit(steps)?;
         let steps = IdExpander::new(&steps)?.visit(steps)?;
         let steps = ProvideExpander::new(&steps)?.visit(steps)?;
@@ -206,6 +213,8 @@ impl ConfigExpander {
                     "opendal" => ServiceConfig::Opendal(serde_yaml::from_str(&out_str)?),
                     "aws-s3" => ServiceConfig::AwsS3(serde_yaml::from_str(&out_str)?),
                     "kafka" => ServiceConfig::Kafka(serde_yaml::from_str(&out_str)?),
+                    "pulsar" => ServiceConfig::Pulsar(serde_yaml::from_str(&out_str)?),
+                    "mongodb" => ServiceConfig::MongoDb(serde_yaml::from_str(&out_str)?),
                     "pubsub" => ServiceConfig::Pubsub(serde_yaml::from_str(&out_str)?),
                     "redis" => ServiceConfig::Redis(serde_yaml::from_str(&out_str)?),
                     "redpanda" => ServiceConfig::RedPanda(serde_yaml::from_str(&out_str)?),
@@ -215,6 +224,7 @@ impl ConfigExpander {
                     "schema-registry" => {
                         ServiceConfig::SchemaRegistry(serde_yaml::from_str(&out_str)?)
                     }
+                    "chaos" => ServiceConfig::Chaos(serde_yaml::from_str(&out_str)?),
                     other => return Err(anyhow!("unsupported use type: {}", other)),
                 };
                 Ok(result)
@@ -0,0 +1,279 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{anyhow, bail, Result};
+use yaml_rust::{yaml, Yaml};
+
+/// Expands `inherit: <profile>` in the `profile` section.
+///
+/// A profile may inherit from another profile (possibly itself inheriting from a third one):
+///
+/// ```yaml
+/// my-profile:
+///   inherit: base-profile
+///   override:
+///     meta-node:
+///       meta-backend: postgres
+///   steps:
+///     - use: postgres
+/// ```
+///
+/// * keys other than `steps` (e.g. `config-path`, `env`) are taken from the parent unless the
+///   child sets its own, in which case the child's value wins;
+/// * the child's `steps` are appended after the inherited ones;
+/// * `override` patches every inherited step whose `use` type matches the given key, with the
+///   child's values winning on key conflicts.
+pub struct InheritExpander {
+    profiles: yaml::Hash,
+}
+
+impl InheritExpander {
+    pub fn new(profiles: &yaml::Hash) -> Self {
+        Self {
+            profiles: profiles.clone(),
+        }
+    }
+
+    /// Returns the fully resolved section of `profile`, with `inherit` and `override` removed.
+    pub fn visit(&self, profile: &str) -> Result<yaml::Hash> {
+        self.resolve(profile, &mut vec![])
+    }
+
+    fn resolve(&self, profile: &str, visiting: &mut Vec<String>) -> Result<yaml::Hash> {
+        if visiting.iter().any(|p| p == profile) {
+            bail!(
+                "found cycle in profile inheritance: {} -> {}",
+                visiting.join(" -> "),
+                profile
+            );
+        }
+
+        let section = self
+            .profiles
+            .get(&Yaml::String(profile.to_owned()))
+            .ok_or_else(|| anyhow!("profile '{}' not found", profile))?
+            .as_hash()
+            .ok_or_else(|| anyhow!("expect profile '{}' to be a hashmap", profile))?;
+
+        let parent = section.get(&Yaml::String("inherit".into()));
+        let override_section = section.get(&Yaml::String("override".into()));
+
+        let Some(parent) = parent else {
+            if override_section.is_some() {
+                bail!(
+                    "profile '{}' has an `override` section but does not `inherit` any profile",
+                    profile
+                );
+            }
+            return Ok(section.clone());
+        };
+        let parent = parent
+            .as_str()
+            .ok_or_else(|| anyhow!("expect `inherit` of profile '{}' to be a string", profile))?;
+
+        visiting.push(profile.to_owned());
+        let mut result = self.resolve(parent, visiting)?;
+        visiting.pop();
+
+        if let Some(override_section) = override_section {
+            let override_section = override_section.as_hash().ok_or_else(|| {
+                anyhow!("expect `override` of profile '{}' to be a hashmap", profile)
+            })?;
+            for (use_id, patch) in override_section {
+                Self::apply_override(&mut result, use_id, patch)?;
+            }
+        }
+
+        for (k, v) in section {
+            match k.as_str() {
+                Some("inherit") | Some("override") => continue,
+                Some("steps") => {
+                    // append the child's steps after the inherited ones
+                    let steps = v
+                        .as_vec()
+                        .ok_or_else(|| anyhow!("expect `steps` to be an array"))?;
+                    match result.get_mut(k) {
+                        Some(Yaml::Array(inherited)) => inherited.extend(steps.clone()),
+                        _ => {
+                            result.insert(k.clone(), v.clone());
+                        }
+                    }
+                }
+                _ => {
+                    // the child's value wins on key conflicts, but keep the parent's order
+                    match result.get_mut(k) {
+                        Some(old_v) => *old_v = v.clone(),
+                        None => {
+                            result.insert(k.clone(), v.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Merges `patch` into every inherited step whose `use` type is `use_id`.
+    fn apply_override(result: &mut yaml::Hash, use_id: &Yaml, patch: &Yaml) -> Result<()> {
+        let patch = patch
+            .as_hash()
+            .ok_or_else(|| anyhow!("expect override for {:?} to be a hashmap", use_id))?;
+        let steps = match result.get_mut(&Yaml::String("steps".into())) {
+            Some(Yaml::Array(steps)) => steps,
+            _ => bail!("cannot apply `override`: no `steps` inherited"),
+        };
+
+        let mut matched = false;
+        for step in steps {
+            let Yaml::Hash(step) = step else {
+                bail!("expect a hashmap for step");
+            };
+            if step.get(&Yaml::String("use".into())) != Some(use_id) {
+                continue;
+            }
+            matched = true;
+            for (k, new_v) in patch {
+                match step.get_mut(k) {
+                    Some(v) => *v = new_v.clone(),
+                    None => {
+                        step.insert(k.clone(), new_v.clone());
+                    }
+                }
+            }
+        }
+        if !matched {
+            bail!("`override` target {:?} matches no inherited step", use_id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust::YamlLoader;
+
+    use super::*;
+
+    fn load(source: &str) -> yaml::Hash {
+        YamlLoader::load_from_str(source).unwrap()[0]
+            .as_hash()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_inherit_nested() {
+        let profiles = load(
+            "
+base:
+  config-path: a.toml
+  steps:
+    - use: minio
+    - use: meta-node
+mid:
+  inherit: base
+  config-path: b.toml
+  steps:
+    - use: compute-node
+child:
+  inherit: mid
+  steps:
+    - use: frontend
+      ",
+        );
+        let expected = load(
+            "
+result:
+  config-path: b.toml
+  steps:
+    - use: minio
+    - use: meta-node
+    - use: compute-node
+    - use: frontend
+      ",
+        );
+        let result = InheritExpander::new(&profiles).visit("child").unwrap();
+        assert_eq!(
+            Yaml::Hash(result),
+            expected.get(&Yaml::String("result".into())).unwrap().clone()
+        );
+    }
+
+    #[test]
+    fn test_inherit_override() {
+        let profiles = load(
+            "
+base:
+  steps:
+    - use: meta-node
+      meta-backend: sqlite
+    - use: compute-node
+      parallelism: 1
+child:
+  inherit: base
+  override:
+    meta-node:
+      meta-backend: postgres
+      port: 5690
+      ",
+        );
+        let expected = load(
+            "
+result:
+  steps:
+    - use: meta-node
+      meta-backend: postgres
+      port: 5690
+    - use: compute-node
+      parallelism: 1
+      ",
+        );
+        let result = InheritExpander::new(&profiles).visit("child").unwrap();
+        assert_eq!(
+            Yaml::Hash(result),
+            expected.get(&Yaml::String("result".into())).unwrap().clone()
+        );
+
+        // overriding a `use` type that no inherited step has is rejected
+        let profiles = load(
+            "
+base:
+  steps:
+    - use: meta-node
+child:
+  inherit: base
+  override:
+    frontend:
+      port: 4566
+      ",
+        );
+        assert!(InheritExpander::new(&profiles).visit("child").is_err());
+    }
+
+    #[test]
+    fn test_inherit_cycle() {
+        let profiles = load(
+            "
+a:
+  inherit: b
+  steps: []
+b:
+  inherit: a
+  steps: []
+      ",
+        );
+        assert!(InheritExpander::new(&profiles).visit("a").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use yaml_rust::Yaml;
+
+/// Detects port collisions between the steps of a profile, before any service is started.
+///
+/// Two steps collide if they listen on the same port under the same address. With
+/// `auto-ports: true` set on the profile, the colliding port is rewritten to the next free
+/// one instead of failing; this runs before [`super::DollarExpander`] and
+/// [`super::ProvideExpander`], so derived ids (e.g. `meta-node-${port}`) and the endpoints
+/// of dependent services pick up the new port automatically.
+pub struct PortExpander {
+    auto: bool,
+}
+
+impl PortExpander {
+    pub fn new(auto: bool) -> Self {
+        Self { auto }
+    }
+
+    fn is_port_key(key: &str) -> bool {
+        key == "port" || key.ends_with("-port")
+    }
+
+    fn address_of(map: &yaml_rust::yaml::Hash) -> String {
+        map.get(&Yaml::String("address".into()))
+            .and_then(|a| a.as_str())
+            .unwrap_or_default()
+            .to_owned()
+    }
+
+    fn port_of(value: &Yaml) -> Option<i64> {
+        match value {
+            Yaml::Integer(port) => Some(*port),
+            Yaml::String(port) => port.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn visit(&mut self, yaml: Yaml) -> Result<Yaml> {
+        let yaml = yaml
+            .into_vec()
+            .ok_or_else(|| anyhow!("expect an array for port check"))?;
+
+        // (address, port) -> the step that first claimed it, for error reporting.
+        let mut used: HashMap<(String, i64), String> = HashMap::new();
+        // All ports in the profile, regardless of address, to avoid reallocating onto
+        // another service's port.
+        let mut all_ports: HashSet<i64> = HashSet::new();
+        for step in &yaml {
+            if let Some(map) = step.as_hash() {
+                for (k, v) in map {
+                    if k.as_str().is_some_and(Self::is_port_key)
+                        && let Some(port) = Self::port_of(v)
+                    {
+                        all_ports.insert(port);
+                    }
+                }
+            }
+        }
+
+        let yaml = yaml.into_iter().map(|step| {
+            let mut map = step
+                .into_hash()
+                .ok_or_else(|| anyhow!("expect a hashmap for port check"))?;
+            let address = Self::address_of(&map);
+            let step_name = map
+                .get(&Yaml::String("use".into()))
+                .and_then(|u| u.as_str())
+                .unwrap_or("<unknown>")
+                .to_owned();
+
+            for (k, v) in &mut map {
+                let Some(key) = k.as_str().filter(|k| Self::is_port_key(k)) else {
+                    continue;
+                };
+                let Some(port) = Self::port_of(v) else {
+                    continue;
+                };
+
+                match used.get(&(address.clone(), port)) {
+                    None => {
+                        used.insert((address.clone(), port), step_name.clone());
+                    }
+                    Some(claimed_by) => {
+                        if !self.auto {
+                            bail!(
+                                "port {} (`{}` of `{}`) is already used by `{}` in this profile. \
+                                Change the port, or set `auto-ports: true` on the profile to \
+                                assign a free one automatically.",
+                                port,
+                                key,
+                                step_name,
+                                claimed_by,
+                            );
+                        }
+                        let mut new_port = port + 1;
+                        while all_ports.contains(&new_port)
+                            || used.contains_key(&(address.clone(), new_port))
+                        {
+                            new_port += 1;
+                        }
+                        used.insert((address.clone(), new_port), step_name.clone());
+                        all_ports.insert(new_port);
+                        *v = Yaml::Integer(new_port);
+                    }
+                }
+            }
+
+            Ok::<_, anyhow::Error>(Yaml::Hash(map))
+        });
+        Ok(Yaml::Array(yaml.try_collect()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust::YamlLoader;
+
+    use super::*;
+
+    fn load(source: &str) -> Yaml {
+        YamlLoader::load_from_str(source).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_port_collision() {
+        let steps = load(
+            "
+- use: meta-node
+  address: \"127.0.0.1\"
+  port: 5690
+- use: compute-node
+  address: \"127.0.0.1\"
+  port: 5690
+      ",
+        );
+        assert!(PortExpander::new(false).visit(steps).is_err());
+
+        // no collision when the addresses differ
+        let steps = load(
+            "
+- use: meta-node
+  address: node-1
+  port: 5690
+- use: meta-node
+  address: node-2
+  port: 5690
+      ",
+        );
+        assert!(PortExpander::new(false).visit(steps).is_ok());
+    }
+
+    #[test]
+    fn test_port_auto_allocation() {
+        let steps = load(
+            "
+- use: meta-node
+  address: \"127.0.0.1\"
+  port: 5690
+  exporter-port: 5691
+- use: meta-node
+  address: \"127.0.0.1\"
+  port: 5690
+      ",
+        );
+        let expected = load(
+            "
+- use: meta-node
+  address: \"127.0.0.1\"
+  port: 5690
+  exporter-port: 5691
+- use: meta-node
+  address: \"127.0.0.1\"
+  port: 5692
+      ",
+        );
+        let result = PortExpander::new(true).visit(steps).unwrap();
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,357 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generate Kubernetes manifests for risedev components.
+//!
+//! Each service from the compose representation is converted into a `Deployment` and a
+//! `Service` of the same name. Config files (e.g. `risingwave.toml`) are collected into a
+//! single `ConfigMap`, and data volumes become `emptyDir` volumes — for anything beyond a
+//! small test deployment, replace them with persistent volume claims.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use crate::ComposeService;
+
+/// Name of the `ConfigMap` holding all generated config files.
+const CONFIG_MAP_NAME: &str = "risedev-config";
+
+#[derive(Debug, Clone, Serialize, Default)]
+struct Metadata {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    namespace: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    labels: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Namespace {
+    api_version: String,
+    kind: String,
+    metadata: Metadata,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigMap {
+    api_version: String,
+    kind: String,
+    metadata: Metadata,
+    data: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EnvVar {
+    name: String,
+    value: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContainerPort {
+    container_port: u16,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VolumeMount {
+    name: String,
+    mount_path: String,
+    sub_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TcpSocketAction {
+    port: u16,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Probe {
+    tcp_socket: TcpSocketAction,
+    period_seconds: u32,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Container {
+    name: String,
+    image: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    command: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    env: Vec<EnvVar>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    ports: Vec<ContainerPort>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    volume_mounts: Vec<VolumeMount>,
+    readiness_probe: Option<Probe>,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct EmptyDir {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigMapVolumeSource {
+    name: String,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PodVolume {
+    name: String,
+    empty_dir: Option<EmptyDir>,
+    config_map: Option<ConfigMapVolumeSource>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PodSpec {
+    containers: Vec<Container>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    volumes: Vec<PodVolume>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PodTemplateSpec {
+    metadata: Metadata,
+    spec: PodSpec,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LabelSelector {
+    match_labels: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeploymentSpec {
+    replicas: u32,
+    selector: LabelSelector,
+    template: PodTemplateSpec,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Deployment {
+    api_version: String,
+    kind: String,
+    metadata: Metadata,
+    spec: DeploymentSpec,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ServicePort {
+    port: u16,
+    target_port: u16,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ServiceSpec {
+    selector: BTreeMap<String, String>,
+    ports: Vec<ServicePort>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KubeService {
+    api_version: String,
+    kind: String,
+    metadata: Metadata,
+    spec: ServiceSpec,
+}
+
+fn app_labels(name: &str) -> BTreeMap<String, String> {
+    [("app".to_owned(), name.to_owned())].into_iter().collect()
+}
+
+fn metadata(name: &str, namespace: &str) -> Metadata {
+    Metadata {
+        name: name.to_owned(),
+        namespace: Some(namespace.to_owned()),
+        labels: app_labels(name),
+    }
+}
+
+/// Converts a single service into a `Deployment` and a `Service`.
+fn convert_service(
+    name: &str,
+    service: &ComposeService,
+    namespace: &str,
+) -> Result<(Deployment, KubeService)> {
+    let ports = service
+        .expose
+        .iter()
+        .map(|p| {
+            p.parse::<u16>()
+                .map_err(|_| anyhow!("invalid port {} of service {}", p, name))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut volumes = vec![];
+    let mut volume_mounts = vec![];
+    for volume in &service.volumes {
+        let (source, target) = volume
+            .split_once(':')
+            .ok_or_else(|| anyhow!("invalid volume {} of service {}", volume, name))?;
+        if let Some(file) = source.strip_prefix("./") {
+            // A generated config file: mount it from the shared `ConfigMap`.
+            volume_mounts.push(VolumeMount {
+                name: CONFIG_MAP_NAME.to_owned(),
+                mount_path: target.to_owned(),
+                sub_path: Some(file.to_owned()),
+            });
+        } else {
+            // A data volume: use an `emptyDir`.
+            volumes.push(PodVolume {
+                name: source.to_owned(),
+                empty_dir: Some(EmptyDir::default()),
+                config_map: None,
+            });
+            volume_mounts.push(VolumeMount {
+                name: source.to_owned(),
+                mount_path: target.to_owned(),
+                sub_path: None,
+            });
+        }
+    }
+    if volume_mounts.iter().any(|m| m.sub_path.is_some()) {
+        volumes.push(PodVolume {
+            name: CONFIG_MAP_NAME.to_owned(),
+            empty_dir: None,
+            config_map: Some(ConfigMapVolumeSource {
+                name: CONFIG_MAP_NAME.to_owned(),
+            }),
+        });
+    }
+
+    let container = Container {
+        name: name.to_owned(),
+        image: service.image.clone(),
+        command: service.command.clone(),
+        env: service
+            .environment
+            .iter()
+            .map(|(k, v)| EnvVar {
+                name: k.clone(),
+                value: v.clone(),
+            })
+            .collect(),
+        ports: ports
+            .iter()
+            .map(|&p| ContainerPort { container_port: p })
+            .collect(),
+        volume_mounts,
+        // Replaces the compose health check: dependents wait for this probe instead of
+        // `depends_on`, which has no equivalent in Kubernetes.
+        readiness_probe: ports.first().map(|&p| Probe {
+            tcp_socket: TcpSocketAction { port: p },
+            period_seconds: 1,
+        }),
+    };
+
+    let deployment = Deployment {
+        api_version: "apps/v1".to_owned(),
+        kind: "Deployment".to_owned(),
+        metadata: metadata(name, namespace),
+        spec: DeploymentSpec {
+            replicas: 1,
+            selector: LabelSelector {
+                match_labels: app_labels(name),
+            },
+            template: PodTemplateSpec {
+                metadata: Metadata {
+                    name: name.to_owned(),
+                    namespace: None,
+                    labels: app_labels(name),
+                },
+                spec: PodSpec {
+                    containers: vec![container],
+                    volumes,
+                },
+            },
+        },
+    };
+
+    let kube_service = KubeService {
+        api_version: "v1".to_owned(),
+        kind: "Service".to_owned(),
+        metadata: metadata(name, namespace),
+        spec: ServiceSpec {
+            selector: app_labels(name),
+            ports: ports
+                .iter()
+                .map(|&p| ServicePort {
+                    port: p,
+                    target_port: p,
+                })
+                .collect(),
+        },
+    };
+
+    Ok((deployment, kube_service))
+}
+
+/// Generate a multi-document manifest for the given services.
+///
+/// `config_files` maps file names to their contents and is emitted as a single `ConfigMap`.
+pub fn generate_k8s_manifests(
+    services: &BTreeMap<String, ComposeService>,
+    config_files: &BTreeMap<String, String>,
+    namespace: &str,
+) -> Result<String> {
+    let mut docs = vec![];
+
+    docs.push(serde_yaml::to_string(&Namespace {
+        api_version: "v1".to_owned(),
+        kind: "Namespace".to_owned(),
+        metadata: Metadata {
+            name: namespace.to_owned(),
+            namespace: None,
+            labels: BTreeMap::new(),
+        },
+    })?);
+
+    if !config_files.is_empty() {
+        docs.push(serde_yaml::to_string(&ConfigMap {
+            api_version: "v1".to_owned(),
+            kind: "ConfigMap".to_owned(),
+            metadata: metadata(CONFIG_MAP_NAME, namespace),
+            data: config_files.clone(),
+        })?);
+    }
+
+    for (name, service) in services {
+        let (deployment, kube_service) = convert_service(name, service, namespace)?;
+        docs.push(serde_yaml::to_string(&deployment)?);
+        docs.push(serde_yaml::to_string(&kube_service)?);
+    }
+
+    Ok(docs.join("---\n"))
+}
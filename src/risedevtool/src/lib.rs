@@ -28,6 +28,8 @@ mod compose;
 pub use compose::*;
 mod compose_deploy;
 pub use compose_deploy::*;
+mod k8s_gen;
+pub use k8s_gen::*;
 mod risedev_env;
 pub use risedev_env::*;
 
@@ -14,6 +14,33 @@
 
 use serde::{Deserialize, Serialize};
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[serde(deny_unknown_fields)]
+pub enum HealthCheckProbe {
+    /// The service is ready once its port accepts TCP connections.
+    Tcp,
+    /// The service is ready once `http://{address}:{port}/` returns a success status.
+    Http,
+    /// The service is ready once `SELECT 1` succeeds over the Postgres protocol.
+    Sql,
+}
+
+/// Readiness probe of a service. The next step only starts after the probe reports healthy,
+/// or fails with the probe's error once `timeout-secs` has elapsed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[serde(deny_unknown_fields)]
+pub struct HealthCheckConfig {
+    pub probe: HealthCheckProbe,
+    #[serde(default = "default_health_check_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_health_check_timeout_secs() -> u64 {
+    30
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[serde(deny_unknown_fields)]
@@ -41,6 +68,9 @@ pub struct ComputeNodeConfig {
     pub total_memory_bytes: usize,
     pub parallelism: usize,
     pub role: String,
+
+    #[serde(default)]
+    pub health_check: Option<HealthCheckConfig>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -86,6 +116,9 @@ pub struct MetaNodeConfig {
     pub provide_minio: Option<Vec<MinioConfig>>,
     pub provide_opendal: Option<Vec<OpendalConfig>>,
     pub enable_in_memory_kv_state_backend: bool,
+
+    #[serde(default)]
+    pub health_check: Option<HealthCheckConfig>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -107,6 +140,9 @@ pub struct FrontendConfig {
     pub provide_tempo: Option<Vec<TempoConfig>>,
 
     pub user_managed: bool,
+
+    #[serde(default)]
+    pub health_check: Option<HealthCheckConfig>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -130,6 +166,9 @@ pub struct CompactorConfig {
 
     pub user_managed: bool,
     pub compaction_worker_threads_number: Option<usize>,
+
+    #[serde(default)]
+    pub health_check: Option<HealthCheckConfig>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -298,6 +337,41 @@ pub struct SchemaRegistryConfig {
     pub user_managed: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[serde(deny_unknown_fields)]
+pub struct PulsarConfig {
+    #[serde(rename = "use")]
+    phantom_use: Option<String>,
+    pub id: String,
+
+    pub address: String,
+    #[serde(with = "string")]
+    pub broker_port: u16,
+    #[serde(with = "string")]
+    pub http_port: u16,
+
+    pub image: String,
+    pub persist_data: bool,
+    pub user_managed: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[serde(deny_unknown_fields)]
+pub struct MongoDbConfig {
+    #[serde(rename = "use")]
+    phantom_use: Option<String>,
+    pub id: String,
+
+    pub port: u16,
+    pub address: String,
+
+    pub image: String,
+    pub persist_data: bool,
+    pub user_managed: bool,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[serde(deny_unknown_fields)]
@@ -408,6 +482,37 @@ pub struct SqlServerConfig {
     pub persist_data: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[serde(deny_unknown_fields)]
+pub struct ChaosConfig {
+    #[serde(rename = "use")]
+    phantom_use: Option<String>,
+    pub id: String,
+
+    /// Id of the service in the same profile to inject faults into.
+    pub target: String,
+
+    /// SIGKILL the target's process tree at this interval. `0` disables the kill loop.
+    pub kill_interval_secs: u64,
+
+    /// Latency to add to the target's network interface (docker-backed targets only).
+    pub netem_latency_ms: Option<u64>,
+    /// Packet loss percentage of the target's network interface (docker-backed targets only).
+    pub netem_loss_percent: Option<u64>,
+
+    /// One-shot clock skew applied to the target's container (docker-backed targets only).
+    pub clock_skew_secs: Option<i64>,
+
+    /// Fill the disk with a file of this size at `disk-fill-path`.
+    pub disk_fill_mb: Option<u64>,
+    /// Where to create the disk-filling file. Required if `disk-fill-mb` is set.
+    pub disk_fill_path: Option<String>,
+
+    /// Saturate the CPU with this many busy-loop threads for the lifetime of the chaos step.
+    pub cpu_burn_threads: Option<u64>,
+}
+
 /// All service configuration
 #[derive(Clone, Debug, PartialEq)]
 pub enum ServiceConfig {
@@ -424,12 +529,15 @@ pub enum ServiceConfig {
     AwsS3(AwsS3Config),
     Kafka(KafkaConfig),
     SchemaRegistry(SchemaRegistryConfig),
+    Pulsar(PulsarConfig),
+    MongoDb(MongoDbConfig),
     Pubsub(PubsubConfig),
     Redis(RedisConfig),
     RedPanda(RedPandaConfig),
     MySql(MySqlConfig),
     Postgres(PostgresConfig),
     SqlServer(SqlServerConfig),
+    Chaos(ChaosConfig),
 }
 
 impl ServiceConfig {
@@ -446,6 +554,8 @@ impl ServiceConfig {
             Self::Tempo(c) => &c.id,
             Self::AwsS3(c) => &c.id,
             Self::Kafka(c) => &c.id,
+            Self::Pulsar(c) => &c.id,
+            Self::MongoDb(c) => &c.id,
             Self::Pubsub(c) => &c.id,
             Self::Redis(c) => &c.id,
             Self::RedPanda(c) => &c.id,
@@ -454,6 +564,7 @@ impl ServiceConfig {
             Self::Postgres(c) => &c.id,
             Self::SqlServer(c) => &c.id,
             Self::SchemaRegistry(c) => &c.id,
+            Self::Chaos(c) => &c.id,
         }
     }
 
@@ -471,6 +582,8 @@ impl ServiceConfig {
             Self::Tempo(c) => Some(c.port),
             Self::AwsS3(_) => None,
             Self::Kafka(c) => Some(c.port),
+            Self::Pulsar(c) => Some(c.broker_port),
+            Self::MongoDb(c) => Some(c.port),
             Self::Pubsub(c) => Some(c.port),
             Self::Redis(c) => Some(c.port),
             Self::RedPanda(_c) => None,
@@ -479,6 +592,7 @@ impl ServiceConfig {
             Self::Postgres(c) => Some(c.port),
             Self::SqlServer(c) => Some(c.port),
             Self::SchemaRegistry(c) => Some(c.port),
+            Self::Chaos(_) => None,
         }
     }
 
@@ -495,6 +609,8 @@ impl ServiceConfig {
             Self::Tempo(_c) => false,
             Self::AwsS3(_c) => false,
             Self::Kafka(c) => c.user_managed,
+            Self::Pulsar(c) => c.user_managed,
+            Self::MongoDb(c) => c.user_managed,
             Self::Pubsub(_c) => false,
             Self::Redis(_c) => false,
             Self::RedPanda(_c) => false,
@@ -503,6 +619,7 @@ impl ServiceConfig {
             Self::Postgres(c) => c.user_managed,
             Self::SqlServer(c) => c.user_managed,
             Self::SchemaRegistry(c) => c.user_managed,
+            Self::Chaos(_c) => false,
         }
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod chaos_service;
 mod compactor_service;
 mod compute_node_service;
 mod configure_tmux_service;
@@ -23,14 +24,17 @@ mod grafana_service;
 mod kafka_service;
 mod meta_node_service;
 mod minio_service;
+mod mongodb_service;
 mod mysql_service;
 mod postgres_service;
 mod prometheus_service;
 mod pubsub_service;
+mod pulsar_service;
 mod redis_service;
 mod schema_registry_service;
 mod sql_server_service;
 mod task_configure_minio;
+mod task_health_check;
 mod task_kafka_ready_check;
 mod task_log_ready_check;
 mod task_pubsub_emu_ready_check;
@@ -52,6 +56,7 @@ use reqwest::blocking::{Client, Response};
 use tempfile::TempDir;
 pub use utils::*;
 
+pub use self::chaos_service::*;
 pub use self::compactor_service::*;
 pub use self::compute_node_service::*;
 pub use self::configure_tmux_service::*;
@@ -62,14 +67,17 @@ pub use self::grafana_service::*;
 pub use self::kafka_service::*;
 pub use self::meta_node_service::*;
 pub use self::minio_service::*;
+pub use self::mongodb_service::*;
 pub use self::mysql_service::*;
 pub use self::postgres_service::*;
 pub use self::prometheus_service::*;
 pub use self::pubsub_service::*;
+pub use self::pulsar_service::*;
 pub use self::redis_service::*;
 pub use self::schema_registry_service::SchemaRegistryService;
 pub use self::sql_server_service::*;
 pub use self::task_configure_minio::*;
+pub use self::task_health_check::*;
 pub use self::task_kafka_ready_check::*;
 pub use self::task_log_ready_check::*;
 pub use self::task_pubsub_emu_ready_check::*;
@@ -185,6 +193,14 @@ where
     }
 
     pub fn wait_tcp(&mut self, server: impl AsRef<str>) -> anyhow::Result<()> {
+        self.wait_tcp_with_timeout(server, Duration::from_secs(30))
+    }
+
+    pub fn wait_tcp_with_timeout(
+        &mut self,
+        server: impl AsRef<str>,
+        timeout: Duration,
+    ) -> anyhow::Result<()> {
         let addr = server
             .as_ref()
             .to_socket_addrs()?
@@ -200,7 +216,7 @@ where
             &mut self.log,
             self.status_file.as_ref().unwrap(),
             self.id.as_ref().unwrap(),
-            Some(Duration::from_secs(30)),
+            Some(timeout),
             true,
         )?;
         Ok(())
@@ -209,6 +225,7 @@ where
     fn wait_http_with_response_cb(
         &mut self,
         server: impl AsRef<str>,
+        timeout: Duration,
         cb: impl Fn(Response) -> anyhow::Result<()>,
     ) -> anyhow::Result<()> {
         let server = server.as_ref();
@@ -229,13 +246,21 @@ where
             &mut self.log,
             self.status_file.as_ref().unwrap(),
             self.id.as_ref().unwrap(),
-            Some(Duration::from_secs(30)),
+            Some(timeout),
             true,
         )
     }
 
     pub fn wait_http(&mut self, server: impl AsRef<str>) -> anyhow::Result<()> {
-        self.wait_http_with_response_cb(server, |_| Ok(()))
+        self.wait_http_with_response_cb(server, Duration::from_secs(30), |_| Ok(()))
+    }
+
+    pub fn wait_http_with_timeout(
+        &mut self,
+        server: impl AsRef<str>,
+        timeout: Duration,
+    ) -> anyhow::Result<()> {
+        self.wait_http_with_response_cb(server, timeout, |_| Ok(()))
     }
 
     pub fn wait_http_with_text_cb(
@@ -243,7 +268,7 @@ where
         server: impl AsRef<str>,
         cb: impl Fn(&str) -> bool,
     ) -> anyhow::Result<()> {
-        self.wait_http_with_response_cb(server, |resp| {
+        self.wait_http_with_response_cb(server, Duration::from_secs(30), |resp| {
             let data = resp.text()?;
             if cb(&data) {
                 Ok(())
@@ -257,12 +282,20 @@ where
     }
 
     pub fn wait(&mut self, wait_func: impl FnMut() -> Result<()>) -> anyhow::Result<()> {
+        self.wait_with_timeout(wait_func, Duration::from_secs(30))
+    }
+
+    pub fn wait_with_timeout(
+        &mut self,
+        wait_func: impl FnMut() -> Result<()>,
+        timeout: Duration,
+    ) -> anyhow::Result<()> {
         wait(
             wait_func,
             &mut self.log,
             self.status_file.as_ref().unwrap(),
             self.id.as_ref().unwrap(),
-            Some(Duration::from_secs(30)),
+            Some(timeout),
             true,
         )
     }
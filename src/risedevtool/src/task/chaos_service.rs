@@ -0,0 +1,129 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::process::Command;
+
+use anyhow::{anyhow, Result};
+
+use super::{ExecuteContext, Task, RISEDEV_NAME};
+use crate::ChaosConfig;
+
+/// Injects chaos into another service of the same profile for local resilience testing.
+///
+/// The disturbances are all best-effort:
+/// * periodically SIGKILL-ing the target's process tree (tmux-managed services);
+/// * latency/packet loss via `tc netem` inside the target's container (docker-backed
+///   services, requires `NET_ADMIN`);
+/// * a one-shot clock skew via `date -s` inside the target's container;
+/// * filling the disk with a fixed-size file;
+/// * saturating the CPU with busy loops for the lifetime of the step.
+pub struct ChaosService {
+    config: ChaosConfig,
+}
+
+impl ChaosService {
+    pub fn new(config: ChaosConfig) -> Result<Self> {
+        if config.target.is_empty() {
+            return Err(anyhow!("`target` is required for the chaos service"));
+        }
+        if config.disk_fill_mb.is_some() && config.disk_fill_path.is_none() {
+            return Err(anyhow!(
+                "`disk-fill-path` is required when `disk-fill-mb` is set"
+            ));
+        }
+        Ok(Self { config })
+    }
+
+    fn generate_script(&self) -> String {
+        let c = &self.config;
+        let container = format!("risedev-{}", c.target);
+        let mut script = String::new();
+
+        let mut netem_args = String::new();
+        if let Some(latency) = c.netem_latency_ms {
+            netem_args += &format!(" delay {latency}ms");
+        }
+        if let Some(loss) = c.netem_loss_percent {
+            netem_args += &format!(" loss {loss}%");
+        }
+        if !netem_args.is_empty() {
+            script += &format!(
+                "docker exec {container} tc qdisc replace dev eth0 root netem{netem_args} \\\n  || echo \"failed to apply netem to {container}, is the target docker-backed with NET_ADMIN?\"\n",
+            );
+        }
+
+        if let Some(skew) = c.clock_skew_secs {
+            script += &format!(
+                "docker exec {container} date -s \"@$(( $(date +%s) + {skew} ))\" \\\n  || echo \"failed to skew clock of {container}\"\n",
+            );
+        }
+
+        if let Some(mb) = c.disk_fill_mb {
+            let path = c.disk_fill_path.as_ref().expect("checked in `new`");
+            script += &format!(
+                "fallocate -l {mb}M {path}/risedev-chaos.fill 2>/dev/null \\\n  || dd if=/dev/zero of={path}/risedev-chaos.fill bs=1M count={mb} \\\n  || echo \"failed to fill disk at {path}\"\n",
+            );
+        }
+
+        if let Some(threads) = c.cpu_burn_threads {
+            // the busy loops are children of this window's shell and die with it
+            script += &format!(
+                "for _ in $(seq 1 {threads}); do (while true; do :; done) & done\n",
+            );
+        }
+
+        if c.kill_interval_secs > 0 {
+            script += &format!(
+                r#"while true; do
+  sleep {interval}
+  pid=$(tmux -L {tmux} list-panes -s -t {tmux} -F '#{{window_name}} #{{pane_pid}}' 2>/dev/null | awk -v w={target} '$1 == w {{ print $2; exit }}')
+  if [ -n "$pid" ]; then
+    echo "killing {target} (pane pid $pid)"
+    pkill -KILL -P "$pid" || true
+  else
+    echo "target {target} not running"
+  fi
+done
+"#,
+                interval = c.kill_interval_secs,
+                tmux = RISEDEV_NAME,
+                target = c.target,
+            );
+        } else {
+            // keep the window alive so the one-shot disturbances remain visible
+            script += "sleep infinity\n";
+        }
+        script
+    }
+}
+
+impl Task for ChaosService {
+    fn execute(&mut self, ctx: &mut ExecuteContext<impl std::io::Write>) -> anyhow::Result<()> {
+        ctx.service(self);
+        ctx.pb.set_message("starting...");
+
+        let mut cmd = Command::new("bash");
+        cmd.arg("-c").arg(self.generate_script());
+        ctx.run_command(ctx.tmux_run(cmd)?)?;
+
+        ctx.pb
+            .set_message(format!("chaos on {}", self.config.target));
+
+        Ok(())
+    }
+
+    fn id(&self) -> String {
+        self.config.id.clone()
+    }
+}
@@ -57,6 +57,12 @@ pub trait DockerServiceConfig: Send + 'static {
     fn data_path(&self) -> Option<String> {
         None
     }
+
+    /// The command and arguments to pass to the docker container, overriding the
+    /// image's default command, e.g. `["--replSet", "rs0"]`.
+    fn args(&self) -> Vec<String> {
+        vec![]
+    }
 }
 
 /// A service that runs a docker container with the given configuration.
@@ -120,6 +126,10 @@ where
 
         cmd.arg(self.config.image());
 
+        for arg in self.config.args() {
+            cmd.arg(arg);
+        }
+
         Ok(cmd)
     }
 }
@@ -0,0 +1,116 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::process::{Command, Stdio};
+
+use anyhow::{anyhow, Result};
+
+use super::docker_service::{DockerService, DockerServiceConfig};
+use super::{ExecuteContext, Task};
+use crate::MongoDbConfig;
+
+/// MongoDB listener port in the container.
+const MONGODB_PORT: &str = "27017";
+
+/// Name of the single-node replica set. CDC requires a replica set, as change streams
+/// are only available with an oplog.
+const MONGODB_REPLICA_SET: &str = "rs0";
+
+impl DockerServiceConfig for MongoDbConfig {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn is_user_managed(&self) -> bool {
+        self.user_managed
+    }
+
+    fn image(&self) -> String {
+        self.image.clone()
+    }
+
+    fn args(&self) -> Vec<String> {
+        vec![
+            "--replSet".to_owned(),
+            MONGODB_REPLICA_SET.to_owned(),
+            "--bind_ip_all".to_owned(),
+        ]
+    }
+
+    fn ports(&self) -> Vec<(String, String)> {
+        vec![(self.port.to_string(), MONGODB_PORT.to_owned())]
+    }
+
+    fn data_path(&self) -> Option<String> {
+        self.persist_data.then(|| "/data/db".to_owned())
+    }
+}
+
+/// Docker-backed MongoDB service, running as a single-node replica set so that CDC change
+/// streams are available.
+pub type MongoDbService = DockerService<MongoDbConfig>;
+
+/// Initiate the replica set once the server is up, which makes the node primary and
+/// enables change streams. A no-op if the replica set is already initiated.
+pub struct MongoDbReplicaSetInitTask {
+    config: MongoDbConfig,
+}
+
+impl MongoDbReplicaSetInitTask {
+    pub fn new(config: MongoDbConfig) -> Result<Self> {
+        Ok(Self { config })
+    }
+}
+
+impl Task for MongoDbReplicaSetInitTask {
+    fn execute(&mut self, ctx: &mut ExecuteContext<impl std::io::Write>) -> anyhow::Result<()> {
+        if self.config.user_managed {
+            return Ok(());
+        }
+
+        ctx.pb.set_message("initiating replica set...");
+
+        let container = format!("risedev-{}", self.config.id);
+        let eval = format!(
+            "try {{ rs.status().ok }} catch (e) {{ rs.initiate({{ _id: '{}', members: [{{ _id: 0, host: '{}:{}' }}] }}).ok }}",
+            MONGODB_REPLICA_SET, self.config.address, self.config.port,
+        );
+
+        ctx.wait(|| {
+            let status = Command::new("docker")
+                .arg("exec")
+                .arg(&container)
+                .arg("mongosh")
+                .arg("--quiet")
+                .arg("--eval")
+                .arg(&eval)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()?;
+            if status.success() {
+                Ok(())
+            } else {
+                Err(anyhow!("failed to initiate replica set in {}", container))
+            }
+        })?;
+
+        ctx.complete_spin();
+
+        Ok(())
+    }
+
+    fn id(&self) -> String {
+        self.config.id.clone()
+    }
+}
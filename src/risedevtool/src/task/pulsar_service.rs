@@ -0,0 +1,54 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::docker_service::{DockerService, DockerServiceConfig};
+use crate::PulsarConfig;
+
+/// Broker and web service ports in the container.
+const PULSAR_BROKER_PORT: &str = "6650";
+const PULSAR_HTTP_PORT: &str = "8080";
+
+impl DockerServiceConfig for PulsarConfig {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn is_user_managed(&self) -> bool {
+        self.user_managed
+    }
+
+    fn image(&self) -> String {
+        self.image.clone()
+    }
+
+    fn args(&self) -> Vec<String> {
+        // Standalone mode runs the broker, bookie, and ZooKeeper in a single process,
+        // which is sufficient for connector e2e tests.
+        vec!["bin/pulsar".to_owned(), "standalone".to_owned()]
+    }
+
+    fn ports(&self) -> Vec<(String, String)> {
+        vec![
+            (self.broker_port.to_string(), PULSAR_BROKER_PORT.to_owned()),
+            (self.http_port.to_string(), PULSAR_HTTP_PORT.to_owned()),
+        ]
+    }
+
+    fn data_path(&self) -> Option<String> {
+        self.persist_data.then(|| "/pulsar/data".to_owned())
+    }
+}
+
+/// Docker-backed Pulsar service.
+pub type PulsarService = DockerService<PulsarConfig>;
@@ -0,0 +1,115 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use super::{ExecuteContext, Task};
+use crate::{HealthCheckConfig, HealthCheckProbe};
+
+/// Run the readiness probe declared in the service's `health-check` section, so that the next
+/// step only starts after this service reports healthy.
+///
+/// Falls back to a TCP probe with the default timeout when no `health-check` is declared,
+/// which is the behavior of [`super::TcpReadyCheckTask`].
+pub struct HealthCheckTask {
+    advertise_address: String,
+    port: u16,
+    user_managed: bool,
+    config: Option<HealthCheckConfig>,
+}
+
+impl HealthCheckTask {
+    pub fn new(
+        advertise_address: String,
+        port: u16,
+        user_managed: bool,
+        config: Option<HealthCheckConfig>,
+    ) -> Result<Self> {
+        Ok(Self {
+            advertise_address,
+            port,
+            user_managed,
+            config,
+        })
+    }
+
+    fn sql_ping(address: &str, port: u16) -> Result<()> {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        rt.block_on(async move {
+            let options = sqlx::postgres::PgConnectOptions::new()
+                .host(address)
+                .port(port)
+                .username("root")
+                .database("dev");
+            let mut conn = sqlx::ConnectOptions::connect(&options)
+                .await
+                .context("failed to connect for SQL health check")?;
+            sqlx::raw_sql("SELECT 1;")
+                .execute(&mut conn)
+                .await
+                .context("SQL health check query failed")?;
+            Ok::<_, anyhow::Error>(())
+        })
+    }
+}
+
+impl Task for HealthCheckTask {
+    fn execute(&mut self, ctx: &mut ExecuteContext<impl std::io::Write>) -> anyhow::Result<()> {
+        let Some(id) = ctx.id.clone() else {
+            panic!("Service should be set before executing HealthCheckTask");
+        };
+        let address = format!("{}:{}", self.advertise_address, self.port);
+
+        if self.user_managed {
+            ctx.pb.set_message(
+                "waiting for user-managed service online... (see `risedev.log` for cli args)",
+            );
+            ctx.wait_tcp_user(&address).with_context(|| {
+                format!("failed to wait for user-managed service `{id}` to be online")
+            })?;
+            ctx.complete_spin();
+            return Ok(());
+        }
+
+        let (probe, timeout) = match &self.config {
+            Some(c) => (c.probe.clone(), Duration::from_secs(c.timeout_secs)),
+            None => (HealthCheckProbe::Tcp, Duration::from_secs(30)),
+        };
+
+        ctx.pb.set_message("waiting for online...");
+        match probe {
+            HealthCheckProbe::Tcp => ctx
+                .wait_tcp_with_timeout(&address, timeout)
+                .with_context(|| format!("failed to wait for service `{id}` to be online"))?,
+            HealthCheckProbe::Http => ctx
+                .wait_http_with_timeout(format!("http://{address}/"), timeout)
+                .with_context(|| format!("failed to wait for service `{id}` to be healthy"))?,
+            HealthCheckProbe::Sql => {
+                let (advertise_address, port) = (self.advertise_address.clone(), self.port);
+                ctx.wait_with_timeout(|| Self::sql_ping(&advertise_address, port), timeout)
+                    .with_context(|| {
+                        format!("failed to wait for service `{id}` to answer SQL queries")
+                    })?
+            }
+        }
+
+        ctx.complete_spin();
+
+        Ok(())
+    }
+}
@@ -30,7 +30,8 @@ use risingwave_pb::compute::{
 };
 use risingwave_pb::monitor_service::monitor_service_client::MonitorServiceClient;
 use risingwave_pb::monitor_service::{
-    AnalyzeHeapRequest, AnalyzeHeapResponse, GetBackPressureRequest, GetBackPressureResponse,
+    AnalyzeHeapRequest, AnalyzeHeapResponse, GetActorRuntimeStatsRequest,
+    GetActorRuntimeStatsResponse, GetBackPressureRequest, GetBackPressureResponse,
     HeapProfilingRequest, HeapProfilingResponse, ListHeapProfilingRequest,
     ListHeapProfilingResponse, ProfilingRequest, ProfilingResponse, StackTraceRequest,
     StackTraceResponse,
@@ -174,6 +175,7 @@ impl ComputeClient {
         plan: PlanFragment,
         epoch: BatchQueryEpoch,
         expr_context: ExprContext,
+        mem_reservation_bytes: u64,
     ) -> Result<Streaming<TaskInfoResponse>> {
         Ok(self
             .task_client
@@ -184,6 +186,7 @@ impl ComputeClient {
                 epoch: Some(epoch),
                 tracing_context: TracingContext::from_current_span().to_protobuf(),
                 expr_context: Some(expr_context),
+                mem_reservation_bytes,
             })
             .await
             .map_err(RpcError::from_compute_status)?
@@ -220,6 +223,16 @@ impl ComputeClient {
             .into_inner())
     }
 
+    pub async fn get_actor_runtime_stats(&self) -> Result<GetActorRuntimeStatsResponse> {
+        Ok(self
+            .monitor_client
+            .to_owned()
+            .get_actor_runtime_stats(GetActorRuntimeStatsRequest::default())
+            .await
+            .map_err(RpcError::from_compute_status)?
+            .into_inner())
+    }
+
     pub async fn get_back_pressure(&self) -> Result<GetBackPressureResponse> {
         Ok(self
             .monitor_client
@@ -20,7 +20,10 @@ use risingwave_common::config::MAX_CONNECTION_WINDOW_SIZE;
 use risingwave_common::monitor::{EndpointExt, TcpConfig};
 use risingwave_common::util::addr::HostAddr;
 use risingwave_pb::frontend_service::frontend_service_client::FrontendServiceClient;
-use risingwave_pb::frontend_service::{GetTableReplacePlanRequest, GetTableReplacePlanResponse};
+use risingwave_pb::frontend_service::{
+    GetTableReplacePlanRequest, GetTableReplacePlanResponse, KillProcessRequest,
+    KillProcessResponse, ListProcessListRequest, ListProcessListResponse,
+};
 use tokio_retry::strategy::{jitter, ExponentialBackoff};
 use tonic::transport::Endpoint;
 use tonic::Response;
@@ -115,4 +118,30 @@ impl FrontendRetryClient {
         )
         .await
     }
+
+    pub async fn list_process_list(
+        &self,
+        request: ListProcessListRequest,
+    ) -> std::result::Result<Response<ListProcessListResponse>, tonic::Status> {
+        tokio_retry::RetryIf::spawn(
+            Self::get_retry_strategy(),
+            || async { self.client.to_owned().0.list_process_list(request.clone()).await },
+            Self::should_retry,
+        )
+        .await
+    }
+
+    pub async fn kill_process(
+        &self,
+        request: KillProcessRequest,
+    ) -> std::result::Result<Response<KillProcessResponse>, tonic::Status> {
+        // Killing a process is not idempotent from the user's perspective, but retrying on
+        // transport errors is fine since cancelling an already-cancelled session is a no-op.
+        tokio_retry::RetryIf::spawn(
+            Self::get_retry_strategy(),
+            || async { self.client.to_owned().0.kill_process(request.clone()).await },
+            Self::should_retry,
+        )
+        .await
+    }
 }
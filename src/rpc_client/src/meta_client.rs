@@ -25,7 +25,10 @@ use async_trait::async_trait;
 use cluster_limit_service_client::ClusterLimitServiceClient;
 use either::Either;
 use futures::stream::BoxStream;
+use list_actor_runtime_stats_response::ActorRuntimeStats;
+use list_checkpoint_history_response::CheckpointHistoryEntry;
 use list_rate_limits_response::RateLimitInfo;
+use list_sink_commit_metrics_response::SinkCommitMetrics;
 use lru::LruCache;
 use replace_job_plan::ReplaceJob;
 use risingwave_common::catalog::{FunctionId, IndexId, ObjectId, SecretId, TableId};
@@ -38,7 +41,9 @@ use risingwave_common::util::addr::HostAddr;
 use risingwave_common::util::column_index_mapping::ColIndexMapping;
 use risingwave_common::util::meta_addr::MetaAddressStrategy;
 use risingwave_common::util::resource_util::cpu::total_cpu_available;
-use risingwave_common::util::resource_util::memory::system_memory_available_bytes;
+use risingwave_common::util::resource_util::memory::{
+    system_memory_available_bytes, total_memory_used_bytes,
+};
 use risingwave_common::RW_VERSION;
 use risingwave_error::bail;
 use risingwave_error::tonic::ErrorIsFromTonicServerImpl;
@@ -352,9 +357,17 @@ impl MetaClient {
         Ok(())
     }
 
-    /// Send heartbeat signal to meta service.
-    pub async fn send_heartbeat(&self, node_id: u32) -> Result<()> {
-        let request = HeartbeatRequest { node_id };
+    /// Send heartbeat signal to meta service, along with the current resource utilization
+    /// of the node if available.
+    pub async fn send_heartbeat(
+        &self,
+        node_id: u32,
+        utilization: Option<heartbeat_request::ResourceUtilization>,
+    ) -> Result<()> {
+        let request = HeartbeatRequest {
+            node_id,
+            utilization,
+        };
         let resp = self.inner.heartbeat(request).await?;
         if let Some(status) = resp.status {
             if status.code() == risingwave_pb::common::status::Code::UnknownWorker {
@@ -896,6 +909,20 @@ impl MetaClient {
         Ok(resp.nodes)
     }
 
+    /// List the active sessions of all frontend nodes in the cluster.
+    pub async fn list_process_list(&self) -> Result<Vec<ProcessInfo>> {
+        let request = ListProcessListRequest {};
+        let resp = self.inner.list_process_list(request).await?;
+        Ok(resp.processes)
+    }
+
+    /// Kill the session with the given process id, on whichever frontend node it lives.
+    pub async fn kill_process(&self, process_id: i32) -> Result<()> {
+        let request = KillProcessRequest { process_id };
+        self.inner.kill_process(request).await?;
+        Ok(())
+    }
+
     /// Starts a heartbeat worker.
     pub fn start_heartbeat_loop(
         meta_client: MetaClient,
@@ -904,6 +931,9 @@ impl MetaClient {
         let (shutdown_tx, mut shutdown_rx) = tokio::sync::oneshot::channel();
         let join_handle = tokio::spawn(async move {
             let mut min_interval_ticker = tokio::time::interval(min_interval);
+            // Keep the `System` across ticks so that each refresh measures the CPU usage
+            // since the last heartbeat. The very first heartbeat thus reports 0.
+            let mut system = sysinfo::System::new();
             loop {
                 tokio::select! {
                     biased;
@@ -916,10 +946,18 @@ impl MetaClient {
                     _ = min_interval_ticker.tick() => {},
                 }
                 tracing::debug!(target: "events::meta::client_heartbeat", "heartbeat");
+                let utilization = {
+                    system.refresh_cpu_usage();
+                    heartbeat_request::ResourceUtilization {
+                        cpu_usage_percent: system.global_cpu_usage() as f64,
+                        used_memory_bytes: total_memory_used_bytes() as u64,
+                        total_memory_bytes: system_memory_available_bytes() as u64,
+                    }
+                };
                 match tokio::time::timeout(
                     // TODO: decide better min_interval for timeout
                     min_interval * 3,
-                    meta_client.send_heartbeat(meta_client.worker_id()),
+                    meta_client.send_heartbeat(meta_client.worker_id(), Some(utilization)),
                 )
                 .await
                 {
@@ -960,8 +998,15 @@ impl MetaClient {
         Ok(())
     }
 
-    pub async fn cancel_creating_jobs(&self, jobs: PbJobs) -> Result<Vec<u32>> {
-        let request = CancelCreatingJobsRequest { jobs: Some(jobs) };
+    pub async fn cancel_creating_jobs(
+        &self,
+        jobs: PbJobs,
+        initiating_user: Option<String>,
+    ) -> Result<Vec<u32>> {
+        let request = CancelCreatingJobsRequest {
+            jobs: Some(jobs),
+            initiating_user,
+        };
         let resp = self.inner.cancel_creating_jobs(request).await?;
         Ok(resp.canceled_jobs)
     }
@@ -1035,11 +1080,13 @@ impl MetaClient {
         kind: PbThrottleTarget,
         id: u32,
         rate: Option<u32>,
+        initiating_user: Option<String>,
     ) -> Result<ApplyThrottleResponse> {
         let request = ApplyThrottleRequest {
             kind: kind as i32,
             id,
             rate,
+            initiating_user,
         };
         let resp = self.inner.apply_throttle(request).await?;
         Ok(resp)
@@ -1090,6 +1137,13 @@ impl MetaClient {
         self.inner.rise_ctl_get_checkpoint_version(request).await
     }
 
+    pub async fn risectl_checkpoint_hummock_version(
+        &self,
+    ) -> Result<RiseCtlCheckpointVersionResponse> {
+        let request = RiseCtlCheckpointVersionRequest {};
+        self.inner.rise_ctl_checkpoint_version(request).await
+    }
+
     pub async fn risectl_pause_hummock_version_checkpoint(
         &self,
     ) -> Result<RiseCtlPauseVersionCheckpointResponse> {
@@ -1486,6 +1540,26 @@ impl MetaClient {
         Ok(())
     }
 
+    pub async fn add_cdc_source_lag_event(
+        &self,
+        source_id: u32,
+        table_name: String,
+        lag_ms: u64,
+        threshold_ms: u64,
+    ) -> Result<()> {
+        let event = event_log::EventCdcSourceLag {
+            source_id,
+            table_name,
+            lag_ms,
+            threshold_ms,
+        };
+        let req = AddEventLogRequest {
+            event: Some(add_event_log_request::Event::CdcSourceLag(event)),
+        };
+        self.inner.add_event_log(req).await?;
+        Ok(())
+    }
+
     pub async fn cancel_compact_task(&self, task_id: u64, task_status: TaskStatus) -> Result<bool> {
         let req = CancelCompactTaskRequest {
             task_id,
@@ -1526,12 +1600,69 @@ impl MetaClient {
         Ok(())
     }
 
+    pub async fn list_orphan_objects(
+        &self,
+        sst_retention_time_sec: u64,
+    ) -> Result<Vec<rise_ctl_list_orphan_objects_response::OrphanObject>> {
+        let req = RiseCtlListOrphanObjectsRequest {
+            sst_retention_time_sec,
+        };
+        let resp = self.inner.rise_ctl_list_orphan_objects(req).await?;
+        Ok(resp.orphan_objects)
+    }
+
+    pub async fn update_object_holds(&self, object_ids: Vec<u64>) -> Result<()> {
+        let req = RiseCtlUpdateObjectHoldsRequest { object_ids };
+        self.inner.rise_ctl_update_object_holds(req).await?;
+        Ok(())
+    }
+
+    pub async fn list_compaction_group_stats(
+        &self,
+    ) -> Result<Vec<list_compaction_group_stats_response::CompactionGroupStats>> {
+        let req = ListCompactionGroupStatsRequest {};
+        let resp = self.inner.list_compaction_group_stats(req).await?;
+        Ok(resp.stats)
+    }
+
+    /// Pins a consistent snapshot of the given state tables for external export. Returns the
+    /// export epoch and the pinned hummock version id. The pin is released automatically by the
+    /// meta node after `ttl_sec`.
+    pub async fn pin_snapshot_for_export(
+        &self,
+        table_ids: Vec<u32>,
+        ttl_sec: u64,
+    ) -> Result<PinSnapshotForExportResponse> {
+        let req = PinSnapshotForExportRequest { table_ids, ttl_sec };
+        let resp = self.inner.pin_snapshot_for_export(req).await?;
+        Ok(resp)
+    }
+
     /// List all rate limits for sources and backfills
     pub async fn list_rate_limits(&self) -> Result<Vec<RateLimitInfo>> {
         let request = ListRateLimitsRequest {};
         let resp = self.inner.list_rate_limits(request).await?;
         Ok(resp.rate_limits)
     }
+
+    pub async fn list_sink_commit_metrics(&self) -> Result<Vec<SinkCommitMetrics>> {
+        let request = ListSinkCommitMetricsRequest {};
+        let resp = self.inner.list_sink_commit_metrics(request).await?;
+        Ok(resp.metrics)
+    }
+
+    pub async fn list_actor_runtime_stats(&self) -> Result<Vec<ActorRuntimeStats>> {
+        let request = ListActorRuntimeStatsRequest {};
+        let resp = self.inner.list_actor_runtime_stats(request).await?;
+        Ok(resp.stats)
+    }
+
+    pub async fn list_checkpoint_history(&self) -> Result<Vec<CheckpointHistoryEntry>> {
+        let request = ListCheckpointHistoryRequest {};
+        let resp = self.inner.list_checkpoint_history(request).await?;
+        Ok(resp.entries)
+    }
+
 }
 
 #[async_trait]
@@ -1953,12 +2084,12 @@ impl GrpcMetaClient {
 
     /// Connect to the meta server from `addrs`.
     pub async fn new(strategy: &MetaAddressStrategy, config: MetaConfig) -> Result<Self> {
-        let (channel, addr) = match strategy {
-            MetaAddressStrategy::LoadBalance(addr) => {
-                Self::try_build_rpc_channel(vec![addr.clone()]).await
-            }
-            MetaAddressStrategy::List(addrs) => Self::try_build_rpc_channel(addrs.clone()).await,
-        }?;
+        let known_addrs = match strategy {
+            MetaAddressStrategy::LoadBalance(addr) => vec![addr.clone()],
+            MetaAddressStrategy::List(addrs) => addrs.clone(),
+            MetaAddressStrategy::DnsSrv(name) => Self::resolve_srv(name).await?,
+        };
+        let (channel, addr) = Self::try_build_rpc_channel(known_addrs.clone()).await?;
         let (force_refresh_sender, force_refresh_receiver) = mpsc::channel(1);
         let client = GrpcMetaClient {
             member_monitor_event_sender: force_refresh_sender,
@@ -1968,9 +2099,9 @@ impl GrpcMetaClient {
         let meta_member_client = client.core.read().await.meta_member_client.clone();
         let members = match strategy {
             MetaAddressStrategy::LoadBalance(_) => Either::Left(meta_member_client),
-            MetaAddressStrategy::List(addrs) => {
+            MetaAddressStrategy::List(_) | MetaAddressStrategy::DnsSrv(_) => {
                 let mut members = LruCache::new(20);
-                for addr in addrs {
+                for addr in known_addrs {
                     members.put(addr.clone(), None);
                 }
                 members.put(addr.clone(), Some(meta_member_client));
@@ -1990,6 +2121,35 @@ impl GrpcMetaClient {
         Endpoint::from(addr).initial_connection_window_size(MAX_CONNECTION_WINDOW_SIZE)
     }
 
+    /// Resolves the meta endpoints from a DNS SRV record, ordered by priority.
+    ///
+    /// The leader is still discovered through the membership service afterwards, so the
+    /// record only needs to contain a quorum of healthy endpoints, not the exact leader.
+    async fn resolve_srv(name: &str) -> Result<Vec<http::Uri>> {
+        let resolver = trust_dns_resolver::TokioAsyncResolver::tokio_from_system_conf()
+            .map_err(|e| anyhow!(e).context("failed to create DNS resolver"))?;
+        let lookup = resolver
+            .srv_lookup(name)
+            .await
+            .map_err(|e| anyhow!(e).context(format!("failed to resolve SRV record `{name}`")))?;
+        let mut records: Vec<_> = lookup.iter().collect();
+        records.sort_by_key(|srv| srv.priority());
+        let mut addrs = Vec::with_capacity(records.len());
+        for srv in records {
+            let host = srv.target().to_string();
+            let addr = format!("http://{}:{}", host.trim_end_matches('.'), srv.port());
+            addrs.push(
+                addr.parse()
+                    .map_err(|e| anyhow!("invalid address `{}` from SRV record: {}", addr, e))?,
+            );
+        }
+        if addrs.is_empty() {
+            bail!("SRV record `{}` resolved to no endpoints", name);
+        }
+        tracing::info!(?addrs, "resolved meta endpoints from SRV record {}", name);
+        Ok(addrs)
+    }
+
     pub(crate) async fn try_build_rpc_channel(
         addrs: impl IntoIterator<Item = http::Uri>,
     ) -> Result<(Channel, http::Uri)> {
@@ -2070,6 +2230,8 @@ macro_rules! for_all_meta_rpc {
             ,{ cluster_client, list_all_nodes, ListAllNodesRequest, ListAllNodesResponse }
             ,{ cluster_client, get_cluster_recovery_status, GetClusterRecoveryStatusRequest, GetClusterRecoveryStatusResponse }
             ,{ cluster_client, get_meta_store_info, GetMetaStoreInfoRequest, GetMetaStoreInfoResponse }
+            ,{ cluster_client, list_process_list, ListProcessListRequest, ListProcessListResponse }
+            ,{ cluster_client, kill_process, KillProcessRequest, KillProcessResponse }
             ,{ heartbeat_client, heartbeat, HeartbeatRequest, HeartbeatResponse }
             ,{ stream_client, flush, FlushRequest, FlushResponse }
             ,{ stream_client, pause, PauseRequest, PauseResponse }
@@ -2084,6 +2246,9 @@ macro_rules! for_all_meta_rpc {
             ,{ stream_client, list_object_dependencies, ListObjectDependenciesRequest, ListObjectDependenciesResponse }
             ,{ stream_client, recover, RecoverRequest, RecoverResponse }
             ,{ stream_client, list_rate_limits, ListRateLimitsRequest, ListRateLimitsResponse }
+            ,{ stream_client, list_sink_commit_metrics, ListSinkCommitMetricsRequest, ListSinkCommitMetricsResponse }
+            ,{ stream_client, list_actor_runtime_stats, ListActorRuntimeStatsRequest, ListActorRuntimeStatsResponse }
+            ,{ stream_client, list_checkpoint_history, ListCheckpointHistoryRequest, ListCheckpointHistoryResponse }
             ,{ ddl_client, create_table, CreateTableRequest, CreateTableResponse }
             ,{ ddl_client, alter_name, AlterNameRequest, AlterNameResponse }
             ,{ ddl_client, alter_owner, AlterOwnerRequest, AlterOwnerResponse }
@@ -2137,6 +2302,7 @@ macro_rules! for_all_meta_rpc {
             ,{ hummock_client, rise_ctl_list_compaction_group, RiseCtlListCompactionGroupRequest, RiseCtlListCompactionGroupResponse }
             ,{ hummock_client, rise_ctl_update_compaction_config, RiseCtlUpdateCompactionConfigRequest, RiseCtlUpdateCompactionConfigResponse }
             ,{ hummock_client, rise_ctl_get_checkpoint_version, RiseCtlGetCheckpointVersionRequest, RiseCtlGetCheckpointVersionResponse }
+            ,{ hummock_client, rise_ctl_checkpoint_version, RiseCtlCheckpointVersionRequest, RiseCtlCheckpointVersionResponse }
             ,{ hummock_client, rise_ctl_pause_version_checkpoint, RiseCtlPauseVersionCheckpointRequest, RiseCtlPauseVersionCheckpointResponse }
             ,{ hummock_client, rise_ctl_resume_version_checkpoint, RiseCtlResumeVersionCheckpointRequest, RiseCtlResumeVersionCheckpointResponse }
             ,{ hummock_client, init_metadata_for_replay, InitMetadataForReplayRequest, InitMetadataForReplayResponse }
@@ -2153,6 +2319,10 @@ macro_rules! for_all_meta_rpc {
             ,{ hummock_client, cancel_compact_task, CancelCompactTaskRequest, CancelCompactTaskResponse}
             ,{ hummock_client, get_version_by_epoch, GetVersionByEpochRequest, GetVersionByEpochResponse }
             ,{ hummock_client, merge_compaction_group, MergeCompactionGroupRequest, MergeCompactionGroupResponse }
+            ,{ hummock_client, rise_ctl_list_orphan_objects, RiseCtlListOrphanObjectsRequest, RiseCtlListOrphanObjectsResponse }
+            ,{ hummock_client, rise_ctl_update_object_holds, RiseCtlUpdateObjectHoldsRequest, RiseCtlUpdateObjectHoldsResponse }
+            ,{ hummock_client, list_compaction_group_stats, ListCompactionGroupStatsRequest, ListCompactionGroupStatsResponse }
+            ,{ hummock_client, pin_snapshot_for_export, PinSnapshotForExportRequest, PinSnapshotForExportResponse }
             ,{ user_client, create_user, CreateUserRequest, CreateUserResponse }
             ,{ user_client, update_user, UpdateUserRequest, UpdateUserResponse }
             ,{ user_client, drop_user, DropUserRequest, DropUserResponse }
@@ -22,8 +22,8 @@ use serde::{Deserialize, Serialize};
 
 use super::FormatEncodeOptions;
 use crate::ast::{
-    display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName, SecretRefValue,
-    SetVariableValue, Value,
+    display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName, Query,
+    SecretRefValue, SetVariableValue, Value,
 };
 use crate::parser::{SOURCE_RATE_LIMIT_PAUSED, SOURCE_RATE_LIMIT_RESUMED};
 use crate::tokenizer::Token;
@@ -161,6 +161,13 @@ pub enum AlterViewOperation {
     SwapRenameView {
         target_view: ObjectName,
     },
+    /// `REPLACE AS <query>` (materialized views only)
+    ReplaceAs {
+        query: Box<Query>,
+    },
+    /// `RESUME` (materialized views only): reset the backfill rate limit to the default,
+    /// e.g. to retry a job that has been quarantined after repeated failures.
+    Resume,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -393,6 +400,12 @@ impl fmt::Display for AlterViewOperation {
             AlterViewOperation::SwapRenameView { target_view } => {
                 write!(f, "SWAP WITH {}", target_view)
             }
+            AlterViewOperation::ReplaceAs { query } => {
+                write!(f, "REPLACE AS {}", query)
+            }
+            AlterViewOperation::Resume => {
+                write!(f, "RESUME")
+            }
         }
     }
 }
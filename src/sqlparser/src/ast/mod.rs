@@ -1328,6 +1328,13 @@ pub enum Statement {
     CreateSource {
         stmt: CreateSourceStatement,
     },
+    /// VALIDATE SOURCE
+    ///
+    /// Connects with the given WITH options, samples a few messages and runs them
+    /// through the parser, without creating any catalog object.
+    ValidateSource {
+        stmt: CreateSourceStatement,
+    },
     /// CREATE SINK
     CreateSink {
         stmt: CreateSinkStatement,
@@ -1454,6 +1461,14 @@ pub enum Statement {
         /// Table or Source name
         name: ObjectName,
     },
+    /// DESCRIBE EXTERNAL TABLE
+    ///
+    /// Connects to the upstream database with the given WITH options and returns the
+    /// upstream column list, the inferred RisingWave types and the primary keys,
+    /// without creating any catalog object.
+    DescribeExternalTable {
+        with_properties: Vec<SqlOption>,
+    },
     /// SHOW OBJECT COMMAND
     ShowObjects {
         object: ShowObject,
@@ -1554,6 +1569,8 @@ pub enum Statement {
         db_name: ObjectName,
         if_not_exists: bool,
         owner: Option<ObjectName>,
+        /// Overrides the system-wide `checkpoint_frequency` for this database.
+        checkpoint_frequency: Option<u64>,
     },
     /// GRANT privileges ON objects TO grantees
     Grant {
@@ -1662,6 +1679,14 @@ impl fmt::Display for Statement {
                 write!(f, "DESCRIBE {}", name)?;
                 Ok(())
             }
+            Statement::DescribeExternalTable { with_properties } => {
+                write!(
+                    f,
+                    "DESCRIBE EXTERNAL TABLE WITH ({})",
+                    display_comma_separated(with_properties)
+                )?;
+                Ok(())
+            }
             Statement::ShowObjects { object: show_object, filter } => {
                 write!(f, "SHOW {}", show_object)?;
                 if let Some(filter) = filter {
@@ -1754,14 +1779,21 @@ impl fmt::Display for Statement {
                 db_name,
                 if_not_exists,
                 owner,
+                checkpoint_frequency,
             } => {
                 write!(f, "CREATE DATABASE")?;
                 if *if_not_exists {
                     write!(f, " IF NOT EXISTS")?;
                 }
                 write!(f, " {}", db_name)?;
+                if owner.is_some() || checkpoint_frequency.is_some() {
+                    write!(f, " WITH")?;
+                }
                 if let Some(owner) = owner {
-                    write!(f, " WITH OWNER = {}", owner)?;
+                    write!(f, " OWNER = {}", owner)?;
+                }
+                if let Some(checkpoint_frequency) = checkpoint_frequency {
+                    write!(f, " CHECKPOINT_FREQUENCY = {}", checkpoint_frequency)?;
                 }
                 Ok(())
             }
@@ -1955,6 +1987,7 @@ impl fmt::Display for Statement {
                 "CREATE SOURCE {}",
                 stmt,
             ),
+            Statement::ValidateSource { stmt } => write!(f, "VALIDATE SOURCE {}", stmt,),
             Statement::CreateSink { stmt } => write!(f, "CREATE SINK {}", stmt,),
             Statement::CreateSubscription { stmt } => write!(f, "CREATE SUBSCRIPTION {}", stmt,),
             Statement::CreateConnection { stmt } => write!(f, "CREATE CONNECTION {}", stmt,),
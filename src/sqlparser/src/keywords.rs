@@ -127,6 +127,7 @@ define_keywords!(
     CHARACTER_LENGTH,
     CHAR_LENGTH,
     CHECK,
+    CHECKPOINT_FREQUENCY,
     CLOB,
     CLOSE,
     CLUSTER,
@@ -308,10 +308,19 @@ impl Parser<'_> {
                     }
                 }
                 Keyword::CANCEL => Ok(self.parse_cancel_job()?),
+                Keyword::VALIDATE => Ok(self.parse_validate()?),
                 Keyword::KILL => Ok(self.parse_kill_process()?),
-                Keyword::DESCRIBE => Ok(Statement::Describe {
-                    name: self.parse_object_name()?,
-                }),
+                Keyword::DESCRIBE => {
+                    if self.parse_keywords(&[Keyword::EXTERNAL, Keyword::TABLE]) {
+                        Ok(Statement::DescribeExternalTable {
+                            with_properties: self.parse_with_properties()?,
+                        })
+                    } else {
+                        Ok(Statement::Describe {
+                            name: self.parse_object_name()?,
+                        })
+                    }
+                }
                 Keyword::GRANT => Ok(self.parse_grant()?),
                 Keyword::REVOKE => Ok(self.parse_revoke()?),
                 Keyword::START => Ok(self.parse_start_transaction()?),
@@ -2078,11 +2087,18 @@ impl Parser<'_> {
         } else {
             None
         };
+        let checkpoint_frequency = if self.parse_keyword(Keyword::CHECKPOINT_FREQUENCY) {
+            let _ = self.consume_token(&Token::Eq);
+            Some(self.parse_literal_uint()?)
+        } else {
+            None
+        };
 
         Ok(Statement::CreateDatabase {
             db_name,
             if_not_exists,
             owner,
+            checkpoint_frequency,
         })
     }
 
@@ -2168,6 +2184,16 @@ impl Parser<'_> {
         Ok(Statement::CreateSource { stmt })
     }
 
+    /// `VALIDATE SOURCE` accepts the same body as `CREATE SOURCE`, but only samples and
+    /// decodes a few messages from the connector instead of creating a catalog object.
+    pub fn parse_validate(&mut self) -> PResult<Statement> {
+        self.expect_keyword(Keyword::SOURCE)?;
+        let Statement::CreateSource { stmt } = self.parse_create_source(false, false)? else {
+            unreachable!()
+        };
+        Ok(Statement::ValidateSource { stmt })
+    }
+
     // CREATE [OR REPLACE]?
     // SINK
     // [IF NOT EXISTS]?
@@ -3389,6 +3415,12 @@ impl Parser<'_> {
         } else if self.parse_keywords(&[Keyword::SWAP, Keyword::WITH]) {
             let target_view = self.parse_object_name()?;
             AlterViewOperation::SwapRenameView { target_view }
+        } else if materialized && self.parse_keyword(Keyword::REPLACE) {
+            self.expect_keyword(Keyword::AS)?;
+            let query = Box::new(self.parse_query()?);
+            AlterViewOperation::ReplaceAs { query }
+        } else if materialized && self.parse_keyword(Keyword::RESUME) {
+            AlterViewOperation::Resume
         } else if self.parse_keyword(Keyword::SET) {
             if self.parse_keyword(Keyword::SCHEMA) {
                 let schema_name = self.parse_object_name()?;
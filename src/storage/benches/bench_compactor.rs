@@ -48,7 +48,7 @@ use risingwave_storage::hummock::sstable::SstableIteratorReadOptions;
 use risingwave_storage::hummock::sstable_store::SstableStoreRef;
 use risingwave_storage::hummock::value::HummockValue;
 use risingwave_storage::hummock::{
-    CachePolicy, SstableBuilder, SstableBuilderOptions, SstableIterator, SstableStore,
+    CachePolicy, IoPriority, SstableBuilder, SstableBuilderOptions, SstableIterator, SstableStore,
     SstableStoreConfig, SstableWriterOptions, Xor16FilterBuilder,
 };
 use risingwave_storage::monitor::{
@@ -82,6 +82,8 @@ pub async fn mock_sstable_store() -> SstableStoreRef {
 
         prefetch_buffer_capacity: 64 << 20,
         max_prefetch_block_number: 16,
+        backfill_read_bytes_per_sec: 0,
+        compaction_read_bytes_per_sec: 0,
         recent_filter: None,
         state_store_metrics: Arc::new(global_hummock_state_store_metrics(MetricLevel::Disabled)),
         use_new_object_prefix_strategy: true,
@@ -349,6 +351,7 @@ fn bench_merge_iterator_compactor(c: &mut Criterion) {
         prefetch_for_large_query: false,
         must_iterated_end_user_key: None,
         max_preload_retry_times: 0,
+        io_priority: IoPriority::Serving,
     });
     c.bench_function("bench_union_merge_iterator", |b| {
         b.to_async(FuturesExecutor).iter(|| {
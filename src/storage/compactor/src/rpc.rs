@@ -18,7 +18,8 @@ use risingwave_pb::compactor::{
 };
 use risingwave_pb::monitor_service::monitor_service_server::MonitorService;
 use risingwave_pb::monitor_service::{
-    AnalyzeHeapRequest, AnalyzeHeapResponse, GetBackPressureRequest, GetBackPressureResponse,
+    AnalyzeHeapRequest, AnalyzeHeapResponse, GetActorRuntimeStatsRequest,
+    GetActorRuntimeStatsResponse, GetBackPressureRequest, GetBackPressureResponse,
     HeapProfilingRequest, HeapProfilingResponse, ListHeapProfilingRequest,
     ListHeapProfilingResponse, ProfilingRequest, ProfilingResponse, StackTraceRequest,
     StackTraceResponse, TieredCacheTracingRequest, TieredCacheTracingResponse,
@@ -150,4 +151,13 @@ impl MonitorService for MonitorServiceImpl {
             "Tiered Cache Tracing unimplemented in compactor",
         ))
     }
+
+    async fn get_actor_runtime_stats(
+        &self,
+        _: Request<GetActorRuntimeStatsRequest>,
+    ) -> Result<Response<GetActorRuntimeStatsResponse>, Status> {
+        Err(Status::unimplemented(
+            "Get Actor Runtime Stats unimplemented in compactor",
+        ))
+    }
 }
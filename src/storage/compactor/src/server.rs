@@ -29,9 +29,9 @@ use risingwave_common::util::resource_util::memory::system_memory_available_byte
 use risingwave_common::util::tokio_util::sync::CancellationToken;
 use risingwave_common::{GIT_SHA, RW_VERSION};
 use risingwave_common_heap_profiling::HeapProfiler;
-use risingwave_common_service::{MetricsManager, ObserverManager};
-use risingwave_object_store::object::build_remote_object_store;
+use risingwave_common_service::{apply_grpc_hardening, MetricsManager, ObserverManager};
 use risingwave_object_store::object::object_metrics::GLOBAL_OBJECT_STORE_METRICS;
+use risingwave_object_store::object::{build_remote_object_store, verify_object_store_access};
 use risingwave_pb::common::WorkerType;
 use risingwave_pb::compactor::compactor_service_server::CompactorServiceServer;
 use risingwave_pb::monitor_service::monitor_service_server::MonitorServiceServer;
@@ -50,6 +50,7 @@ use risingwave_storage::monitor::{
     monitor_cache, CompactorMetrics, GLOBAL_COMPACTOR_METRICS, GLOBAL_HUMMOCK_METRICS,
 };
 use risingwave_storage::opts::StorageOpts;
+use thiserror_ext::AsReport;
 use tokio::sync::mpsc;
 use tracing::info;
 
@@ -119,6 +120,17 @@ pub async fn prepare_start_parameters(
     )
     .await;
 
+    if config.storage.object_store.startup_probe {
+        verify_object_store_access(&object_store, &storage_opts.data_directory)
+            .await
+            .unwrap_or_else(|e| {
+                panic!(
+                    "object store startup self-check failed: {}",
+                    e.as_report()
+                )
+            });
+    }
+
     let object_store = Arc::new(object_store);
     let sstable_store = Arc::new(
         SstableStore::for_compactor(
@@ -280,7 +292,9 @@ pub async fn compactor_serve(
 
     let compactor_srv = CompactorServiceImpl::default();
     let monitor_srv = MonitorServiceImpl::new(await_tree_reg);
-    let server = tonic::transport::Server::builder()
+    let server_builder = apply_grpc_hardening(tonic::transport::Server::builder(), &config.server)
+        .expect("failed to apply gRPC server hardening");
+    let server = server_builder
         .add_service(CompactorServiceServer::new(compactor_srv))
         .add_service(MonitorServiceServer::new(monitor_srv))
         .monitored_serve_with_shutdown(
@@ -370,7 +384,9 @@ pub async fn shared_compactor_serve(
         compactor_context,
     );
 
-    let server = tonic::transport::Server::builder()
+    let server_builder = apply_grpc_hardening(tonic::transport::Server::builder(), &config.server)
+        .expect("failed to apply gRPC server hardening");
+    let server = server_builder
         .add_service(CompactorServiceServer::new(compactor_srv))
         .add_service(MonitorServiceServer::new(monitor_srv))
         .monitored_serve_with_shutdown(
@@ -43,9 +43,28 @@ pub enum FilterKeyExtractorImpl {
     FixedLength(FixedLengthFilterKeyExtractor),
 }
 
+/// Resolves the number of leading pk columns used as the filter key of the table: the
+/// explicit `filter_key_prefix_len` wins, then `read_prefix_len_hint`, then the pk prefix
+/// covered by the distribution key. Returns 0 if none applies, i.e. the full key is used.
+fn resolve_filter_key_prefix_len(table_catalog: &Table) -> usize {
+    if let Some(prefix_len) = table_catalog.filter_key_prefix_len {
+        return prefix_len as usize;
+    }
+    let read_prefix_len = table_catalog.get_read_prefix_len_hint() as usize;
+    if read_prefix_len != 0 {
+        return read_prefix_len;
+    }
+    table_catalog
+        .dist_key_in_pk
+        .iter()
+        .map(|idx| *idx as usize + 1)
+        .max()
+        .unwrap_or(0)
+}
+
 impl FilterKeyExtractorImpl {
     pub fn from_table(table_catalog: &Table) -> Self {
-        let read_prefix_len = table_catalog.get_read_prefix_len_hint() as usize;
+        let read_prefix_len = resolve_filter_key_prefix_len(table_catalog);
 
         if read_prefix_len == 0 || read_prefix_len > table_catalog.get_pk().len() {
             // for now frontend had not infer the table_id_to_filter_key_extractor, so we
@@ -162,7 +181,7 @@ impl SchemaFilterKeyExtractor {
             .map(|col_order| col_order.column_index as usize)
             .collect();
 
-        let read_prefix_len = table_catalog.get_read_prefix_len_hint() as usize;
+        let read_prefix_len = resolve_filter_key_prefix_len(table_catalog);
 
         let data_types = pk_indices
             .iter()
@@ -314,6 +333,7 @@ impl CompactionCatalogManager {
 
         let mut multi_filter_key_extractor = MultiFilterKeyExtractor::default();
         let mut table_id_to_vnode = HashMap::new();
+        let mut use_ribbon_filter = true;
         {
             let guard = self.table_id_to_catalog.read();
             table_ids.retain(|table_id| match guard.get(table_id) {
@@ -321,6 +341,7 @@ impl CompactionCatalogManager {
                     multi_filter_key_extractor
                         .register(*table_id, FilterKeyExtractorImpl::from_table(table_catalog));
                     table_id_to_vnode.insert(*table_id, table_catalog.vnode_count());
+                    use_ribbon_filter &= table_catalog.use_ribbon_filter();
                     false
                 }
 
@@ -346,6 +367,7 @@ impl CompactionCatalogManager {
                     let table_id = table.id;
                     let key_extractor = FilterKeyExtractorImpl::from_table(&table);
                     let vnode = table.vnode_count();
+                    use_ribbon_filter &= table.use_ribbon_filter();
                     guard.insert(table_id, table);
                     multi_filter_key_extractor.register(table_id, key_extractor);
                     table_id_to_vnode.insert(table_id, vnode);
@@ -353,10 +375,12 @@ impl CompactionCatalogManager {
             }
         }
 
-        Ok(Arc::new(CompactionCatalogAgent::new(
+        let mut agent = CompactionCatalogAgent::new(
             FilterKeyExtractorImpl::Multi(multi_filter_key_extractor),
             table_id_to_vnode,
-        )))
+        );
+        agent.set_use_ribbon_filter(use_ribbon_filter);
+        Ok(Arc::new(agent))
     }
 
     /// `build_compaction_catalog_agent` is used to build `CompactionCatalogAgent` by `table_catalogs`
@@ -365,16 +389,20 @@ impl CompactionCatalogManager {
     ) -> CompactionCatalogAgentRef {
         let mut multi_filter_key_extractor = MultiFilterKeyExtractor::default();
         let mut table_id_to_vnode = HashMap::new();
+        let mut use_ribbon_filter = !table_catalogs.is_empty();
         for (table_id, table_catalog) in table_catalogs {
             multi_filter_key_extractor
                 .register(table_id, FilterKeyExtractorImpl::from_table(&table_catalog));
             table_id_to_vnode.insert(table_id, table_catalog.vnode_count());
+            use_ribbon_filter &= table_catalog.use_ribbon_filter();
         }
 
-        Arc::new(CompactionCatalogAgent::new(
+        let mut agent = CompactionCatalogAgent::new(
             FilterKeyExtractorImpl::Multi(multi_filter_key_extractor),
             table_id_to_vnode,
-        ))
+        );
+        agent.set_use_ribbon_filter(use_ribbon_filter);
+        Arc::new(agent)
     }
 }
 
@@ -384,6 +412,8 @@ impl CompactionCatalogManager {
 pub struct CompactionCatalogAgent {
     filter_key_extractor_manager: FilterKeyExtractorImpl,
     table_id_to_vnode: HashMap<StateTableId, usize>,
+    /// Whether all tables of the task opted in to a ribbon filter for their SSTs.
+    use_ribbon_filter: bool,
 }
 
 impl CompactionCatalogAgent {
@@ -394,6 +424,7 @@ impl CompactionCatalogAgent {
         Self {
             filter_key_extractor_manager,
             table_id_to_vnode,
+            use_ribbon_filter: false,
         }
     }
 
@@ -401,6 +432,7 @@ impl CompactionCatalogAgent {
         Self {
             filter_key_extractor_manager: FilterKeyExtractorImpl::Dummy(DummyFilterKeyExtractor),
             table_id_to_vnode: Default::default(),
+            use_ribbon_filter: false,
         }
     }
 
@@ -442,6 +474,14 @@ impl CompactionCatalogAgent {
     pub fn table_ids(&self) -> impl Iterator<Item = StateTableId> + '_ {
         self.table_id_to_vnode.keys().cloned()
     }
+
+    pub fn use_ribbon_filter(&self) -> bool {
+        self.use_ribbon_filter
+    }
+
+    pub fn set_use_ribbon_filter(&mut self, use_ribbon_filter: bool) {
+        self.use_ribbon_filter = use_ribbon_filter;
+    }
 }
 
 pub type CompactionCatalogManagerRef = Arc<CompactionCatalogManager>;
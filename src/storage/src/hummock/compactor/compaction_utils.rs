@@ -31,6 +31,7 @@ use risingwave_hummock_sdk::{can_concat, EpochWithGap, KeyComparator};
 use risingwave_pb::hummock::compact_task::PbTaskType;
 use risingwave_pb::hummock::{BloomFilterType, PbLevelType, PbTableSchema};
 use tokio::time::Instant;
+use xxhash_rust::xxh64::xxh64;
 
 pub use super::context::CompactorContext;
 use crate::compaction_catalog_manager::CompactionCatalogAgentRef;
@@ -402,7 +403,11 @@ pub async fn check_compaction_result(
         None,
     );
 
-    check_result(left_iter, right_iter).await
+    if context.storage_opts.check_compaction_result_checksum {
+        check_result_checksum(left_iter, right_iter).await
+    } else {
+        check_result(left_iter, right_iter).await
+    }
 }
 
 pub async fn check_flush_result<I: HummockIterator<Direction = Forward>>(
@@ -426,7 +431,11 @@ pub async fn check_flush_result<I: HummockIterator<Direction = Forward>>(
         0,
         None,
     );
-    check_result(left_iter, right_iter).await
+    if context.storage_opts.check_compaction_result_checksum {
+        check_result_checksum(left_iter, right_iter).await
+    } else {
+        check_result(left_iter, right_iter).await
+    }
 }
 
 async fn check_result<
@@ -482,6 +491,64 @@ async fn check_result<
     Ok(true)
 }
 
+/// Per-table digest over one side of a compaction task, used by the checksum
+/// verification mode of the result check.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+struct TableCheckDigest {
+    key_count: u64,
+    key_checksum: u64,
+    value_checksum: u64,
+}
+
+async fn collect_table_digests<I: HummockIterator<Direction = Forward>>(
+    mut iter: UserIterator<I>,
+) -> HummockResult<HashMap<StateTableId, TableCheckDigest>> {
+    let mut digests: HashMap<StateTableId, TableCheckDigest> = HashMap::new();
+    iter.rewind().await?;
+    while iter.is_valid() {
+        let key = iter.key();
+        let digest = digests.entry(key.user_key.table_id.table_id).or_default();
+        digest.key_count += 1;
+        digest.key_checksum ^= xxh64(&key.encode(), 0);
+        digest.value_checksum ^= xxh64(iter.value(), 0);
+        iter.next().await?;
+    }
+    Ok(digests)
+}
+
+/// Compares per-table key-counts and xor-checksums of the input and output of a
+/// compaction task, so that a mismatch pinpoints the corrupted table.
+async fn check_result_checksum<
+    I1: HummockIterator<Direction = Forward>,
+    I2: HummockIterator<Direction = Forward>,
+>(
+    left_iter: UserIterator<I1>,
+    right_iter: UserIterator<I2>,
+) -> HummockResult<bool> {
+    let left_digests = collect_table_digests(left_iter).await?;
+    let right_digests = collect_table_digests(right_iter).await?;
+    let mut matched = true;
+    for table_id in left_digests
+        .keys()
+        .chain(right_digests.keys())
+        .sorted()
+        .dedup()
+    {
+        let left = left_digests.get(table_id).copied().unwrap_or_default();
+        let right = right_digests.get(table_id).copied().unwrap_or_default();
+        if left != right {
+            tracing::error!(
+                table_id,
+                "The digest of input and output not equal: {:?} vs {:?}",
+                left,
+                right
+            );
+            matched = false;
+        }
+    }
+    Ok(matched)
+}
+
 pub fn optimize_by_copy_block(compact_task: &CompactTask, context: &CompactorContext) -> bool {
     let sstable_infos = compact_task
         .input_ssts
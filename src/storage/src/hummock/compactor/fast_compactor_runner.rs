@@ -42,8 +42,8 @@ use crate::hummock::sstable_store::SstableStoreRef;
 use crate::hummock::value::HummockValue;
 use crate::hummock::{
     Block, BlockBuilder, BlockHolder, BlockIterator, BlockMeta, BlockedXor16FilterBuilder,
-    CachePolicy, CompressionAlgorithm, GetObjectId, HummockResult, SstableBuilderOptions,
-    TableHolder, UnifiedSstableWriterFactory,
+    CachePolicy, CompressionAlgorithm, GetObjectId, HummockResult, IoPriority,
+    SstableBuilderOptions, TableHolder, UnifiedSstableWriterFactory,
 };
 use crate::monitor::{CompactorMetrics, StoreLocalStatistic};
 
@@ -112,6 +112,7 @@ impl BlockStreamIterator {
             .get_stream_for_blocks(
                 self.sstable_info.object_id,
                 &self.sstable.meta.block_metas[self.next_block_index..],
+                IoPriority::Compaction,
             )
             .verbose_instrument_await("stream_iter_get_stream")
             .await?;
@@ -31,7 +31,7 @@ use crate::hummock::compactor::task_progress::TaskProgress;
 use crate::hummock::iterator::{Forward, HummockIterator, ValueMeta};
 use crate::hummock::sstable_store::SstableStoreRef;
 use crate::hummock::value::HummockValue;
-use crate::hummock::{BlockHolder, BlockIterator, BlockMeta, HummockResult};
+use crate::hummock::{BlockHolder, BlockIterator, BlockMeta, HummockResult, IoPriority};
 use crate::monitor::StoreLocalStatistic;
 
 const PROGRESS_KEY_INTERVAL: usize = 100;
@@ -130,6 +130,7 @@ impl SstableStreamIterator {
             .get_stream_for_blocks(
                 self.sstable_info.object_id,
                 &self.block_metas[self.block_idx..],
+                IoPriority::Compaction,
             )
             .verbose_instrument_await("stream_iter_get_stream")
             .await?;
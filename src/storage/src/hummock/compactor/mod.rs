@@ -78,7 +78,8 @@ pub use self::compaction_utils::{
 pub use self::task_progress::TaskProgress;
 use super::multi_builder::CapacitySplitTableBuilder;
 use super::{
-    GetObjectId, HummockResult, SstableBuilderOptions, SstableObjectIdManager, Xor16FilterBuilder,
+    GetObjectId, HummockResult, RibbonFilterBuilder, SstableBuilderOptions,
+    SstableObjectIdManager, Xor16FilterBuilder,
 };
 use crate::compaction_catalog_manager::{
     CompactionCatalogAgentRef, CompactionCatalogManager, CompactionCatalogManagerRef,
@@ -160,6 +161,17 @@ impl Compactor {
                 )
                 .verbose_instrument_await("compact")
                 .await?
+            } else if compaction_catalog_agent_ref.use_ribbon_filter() {
+                self.compact_key_range_impl::<_, RibbonFilterBuilder>(
+                    factory,
+                    iter,
+                    compaction_filter,
+                    compaction_catalog_agent_ref,
+                    task_progress.clone(),
+                    self.object_id_getter.clone(),
+                )
+                .verbose_instrument_await("compact")
+                .await?
             } else {
                 self.compact_key_range_impl::<_, Xor16FilterBuilder>(
                     factory,
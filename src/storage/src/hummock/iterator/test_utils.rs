@@ -87,6 +87,8 @@ pub async fn mock_sstable_store_with_object_store(store: ObjectStoreRef) -> Ssta
 
         prefetch_buffer_capacity: 64 << 20,
         max_prefetch_block_number: 16,
+        backfill_read_bytes_per_sec: 0,
+        compaction_read_bytes_per_sec: 0,
 
         recent_filter: None,
         state_store_metrics: Arc::new(global_hummock_state_store_metrics(MetricLevel::Disabled)),
@@ -48,6 +48,9 @@ mod validator;
 pub mod value;
 pub mod write_limiter;
 
+pub mod io_scheduler;
+pub use io_scheduler::IoPriority;
+
 pub mod recent_filter;
 pub use recent_filter::*;
 
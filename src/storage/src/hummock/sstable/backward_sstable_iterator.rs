@@ -69,6 +69,7 @@ impl BackwardSstableIterator {
                     &self.sst,
                     idx as usize,
                     crate::hummock::CachePolicy::Fill(CacheHint::Normal),
+                    crate::hummock::IoPriority::Serving,
                     &mut self.stats,
                 )
                 .await?;
@@ -24,7 +24,7 @@ use super::super::{HummockResult, HummockValue};
 use crate::hummock::block_stream::BlockStream;
 use crate::hummock::iterator::{Forward, HummockIterator, ValueMeta};
 use crate::hummock::sstable::SstableIteratorReadOptions;
-use crate::hummock::{BlockIterator, SstableStoreRef, TableHolder};
+use crate::hummock::{BlockIterator, IoPriority, SstableStoreRef, TableHolder};
 use crate::monitor::StoreLocalStatistic;
 
 pub trait SstableIteratorType: HummockIterator + 'static {
@@ -235,6 +235,7 @@ impl SstableIterator {
                     idx,
                     self.preload_end_block_idx,
                     self.options.cache_policy,
+                    self.options.io_priority,
                     &mut self.stats,
                 )
                 .verbose_instrument_await("prefetch_blocks")
@@ -296,6 +297,7 @@ impl SstableIterator {
                             idx,
                             self.preload_end_block_idx,
                             self.options.cache_policy,
+                            self.options.io_priority,
                             &mut self.stats,
                         )
                         .verbose_instrument_await("prefetch_blocks")
@@ -315,7 +317,13 @@ impl SstableIterator {
         if !hit_cache {
             let block = self
                 .sstable_store
-                .get(&self.sst, idx, self.options.cache_policy, &mut self.stats)
+                .get(
+                    &self.sst,
+                    idx,
+                    self.options.cache_policy,
+                    self.options.io_priority,
+                    &mut self.stats,
+                )
                 .await?;
             self.block_iter = Some(BlockIterator::new(block));
         };
@@ -597,6 +605,7 @@ mod tests {
             must_iterated_end_user_key: Some(Bound::Included(uk.clone())),
             max_preload_retry_times: 0,
             prefetch_for_large_query: false,
+            io_priority: IoPriority::Serving,
         });
         let mut stats = StoreLocalStatistic::default();
         let mut sstable_iter = SstableIterator::create(
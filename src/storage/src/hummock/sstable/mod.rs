@@ -25,8 +25,10 @@ pub use block::*;
 mod block_iterator;
 pub use block_iterator::*;
 mod bloom;
+mod ribbon_filter;
 mod xor_filter;
 pub use bloom::BloomFilterBuilder;
+pub use ribbon_filter::RibbonFilterBuilder;
 use serde::{Deserialize, Serialize};
 pub use xor_filter::{
     BlockedXor16FilterBuilder, Xor16FilterBuilder, Xor8FilterBuilder, XorFilterReader,
@@ -58,6 +60,7 @@ use xxhash_rust::{xxh32, xxh64};
 
 use self::utils::{xxhash64_checksum, xxhash64_verify};
 use super::{HummockError, HummockResult};
+use crate::hummock::io_scheduler::IoPriority;
 use crate::hummock::CachePolicy;
 use crate::store::ReadOptions;
 
@@ -468,6 +471,7 @@ pub struct SstableIteratorReadOptions {
     pub must_iterated_end_user_key: Option<Bound<UserKey<KeyPayloadType>>>,
     pub max_preload_retry_times: usize,
     pub prefetch_for_large_query: bool,
+    pub io_priority: IoPriority,
 }
 
 impl SstableIteratorReadOptions {
@@ -477,6 +481,13 @@ impl SstableIteratorReadOptions {
             must_iterated_end_user_key: None,
             max_preload_retry_times: 0,
             prefetch_for_large_query: read_options.prefetch_options.for_large_query,
+            // Large range scans are issued by backfill and batch queries, which shall
+            // not starve the low-latency serving reads.
+            io_priority: if read_options.prefetch_options.for_large_query {
+                IoPriority::Backfill
+            } else {
+                IoPriority::Serving
+            },
         }
     }
 }
@@ -22,6 +22,7 @@ use risingwave_common::must_match;
 use risingwave_hummock_sdk::key::{FullKey, UserKeyRangeRef};
 use xorf::{Filter, Xor16, Xor8};
 
+use super::ribbon_filter::{RibbonFilterReader, FOOTER_RIBBON};
 use super::{FilterBuilder, Sstable};
 use crate::hummock::{BlockMeta, MemoryLimiter};
 
@@ -278,6 +279,7 @@ pub enum XorFilter {
     Xor8(Xor8),
     Xor16(Xor16),
     BlockXor16(BlockBasedXor16Filter),
+    Ribbon(RibbonFilterReader),
 }
 
 pub struct XorFilterReader {
@@ -301,6 +303,8 @@ impl XorFilterReader {
         let filter = if kind == FOOTER_BLOCKED_XOR16 {
             let block_filter = Self::to_block_xor16(data, metas);
             XorFilter::BlockXor16(block_filter)
+        } else if kind == FOOTER_RIBBON {
+            XorFilter::Ribbon(RibbonFilterReader::new(data))
         } else if kind == FOOTER_XOR16 {
             let xor16 = Self::to_xor16(data);
             XorFilter::Xor16(xor16)
@@ -376,6 +380,7 @@ impl XorFilterReader {
                 .iter()
                 .map(|filter| filter.1.fingerprints.len() * std::mem::size_of::<u16>())
                 .sum(),
+            XorFilter::Ribbon(reader) => reader.estimate_size(),
         }
     }
 
@@ -384,6 +389,7 @@ impl XorFilterReader {
             XorFilter::Xor8(filter) => filter.block_length == 0,
             XorFilter::Xor16(filter) => filter.block_length == 0,
             XorFilter::BlockXor16(reader) => reader.filters.is_empty(),
+            XorFilter::Ribbon(reader) => reader.is_empty(),
         }
     }
 
@@ -402,6 +408,7 @@ impl XorFilterReader {
                 XorFilter::Xor8(filter) => filter.contains(&h),
                 XorFilter::Xor16(filter) => filter.contains(&h),
                 XorFilter::BlockXor16(reader) => reader.may_exist(user_key_range, h),
+                XorFilter::Ribbon(reader) => reader.contains(h),
             }
         }
     }
@@ -436,6 +443,9 @@ impl Clone for XorFilterReader {
             XorFilter::BlockXor16(reader) => Self {
                 filter: XorFilter::BlockXor16(reader.clone()),
             },
+            XorFilter::Ribbon(reader) => Self {
+                filter: XorFilter::Ribbon(reader.clone()),
+            },
         }
     }
 }
@@ -458,7 +468,7 @@ mod tests {
     use crate::hummock::sstable::{SstableBuilder, SstableBuilderOptions};
     use crate::hummock::test_utils::{test_user_key_of, test_value_of, TEST_KEYS_COUNT};
     use crate::hummock::value::HummockValue;
-    use crate::hummock::{BlockIterator, CachePolicy, SstableWriterOptions};
+    use crate::hummock::{BlockIterator, CachePolicy, IoPriority, SstableWriterOptions};
     use crate::monitor::StoreLocalStatistic;
 
     #[tokio::test]
@@ -523,6 +533,7 @@ mod tests {
                         &sstable,
                         idx,
                         CachePolicy::Fill(CacheHint::Normal),
+                        IoPriority::Serving,
                         &mut stat,
                     )
                     .await
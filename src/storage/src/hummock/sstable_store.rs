@@ -41,6 +41,7 @@ use super::{
 use crate::hummock::block_stream::{
     BlockDataStream, BlockStream, MemoryUsageTracker, PrefetchBlockStream,
 };
+use crate::hummock::io_scheduler::{IoPriority, ReadIoScheduler};
 use crate::hummock::{BlockHolder, HummockError, HummockResult};
 use crate::monitor::{HummockStateStoreMetrics, StoreLocalStatistic};
 
@@ -120,6 +121,9 @@ pub struct SstableStoreConfig {
 
     pub prefetch_buffer_capacity: usize,
     pub max_prefetch_block_number: usize,
+    /// Rate limits in bytes per second for backfill and compaction reads. 0 means unthrottled.
+    pub backfill_read_bytes_per_sec: u64,
+    pub compaction_read_bytes_per_sec: u64,
     pub recent_filter: Option<Arc<RecentFilter<(HummockSstableObjectId, usize)>>>,
     pub state_store_metrics: Arc<HummockStateStoreMetrics>,
     pub use_new_object_prefix_strategy: bool,
@@ -142,6 +146,9 @@ pub struct SstableStore {
     prefetch_buffer_usage: Arc<AtomicUsize>,
     prefetch_buffer_capacity: usize,
     max_prefetch_block_number: usize,
+    /// Scheduler that throttles backfill and compaction reads so that they cannot
+    /// starve low-latency serving reads on the same node.
+    read_io_scheduler: Arc<ReadIoScheduler>,
     /// Whether the object store is divided into prefixes depends on two factors:
     ///   1. The specific object store type.
     ///   2. Whether the existing cluster is a new cluster.
@@ -169,6 +176,10 @@ impl SstableStore {
             prefetch_buffer_usage: Arc::new(AtomicUsize::new(0)),
             prefetch_buffer_capacity: config.prefetch_buffer_capacity,
             max_prefetch_block_number: config.max_prefetch_block_number,
+            read_io_scheduler: Arc::new(ReadIoScheduler::new(
+                config.backfill_read_bytes_per_sec,
+                config.compaction_read_bytes_per_sec,
+            )),
             use_new_object_prefix_strategy: config.use_new_object_prefix_strategy,
         }
     }
@@ -213,6 +224,9 @@ impl SstableStore {
             prefetch_buffer_usage: Arc::new(AtomicUsize::new(0)),
             prefetch_buffer_capacity: block_cache_capacity,
             max_prefetch_block_number: 16, /* compactor won't use this parameter, so just assign a default value. */
+            // The compactor node does not serve latency-sensitive reads, so its reads
+            // are not rate-limited here.
+            read_io_scheduler: Arc::new(ReadIoScheduler::unthrottled()),
             recent_filter: None,
             use_new_object_prefix_strategy,
 
@@ -253,11 +267,12 @@ impl SstableStore {
         block_index: usize,
         end_index: usize,
         policy: CachePolicy,
+        priority: IoPriority,
         stats: &mut StoreLocalStatistic,
     ) -> HummockResult<Box<dyn BlockStream>> {
         let object_id = sst.id;
         if self.prefetch_buffer_usage.load(Ordering::Acquire) > self.prefetch_buffer_capacity {
-            let block = self.get(sst, block_index, policy, stats).await?;
+            let block = self.get(sst, block_index, policy, priority, stats).await?;
             return Ok(Box::new(PrefetchBlockStream::new(
                 VecDeque::from([block]),
                 block_index,
@@ -314,7 +329,11 @@ impl SstableStore {
         let tracker = MemoryUsageTracker::new(self.prefetch_buffer_usage.clone(), memory_usage);
         let span: await_tree::Span = format!("Prefetch SST-{}", object_id).into();
         let store = self.store.clone();
+        let io_scheduler = self.read_io_scheduler.clone();
         let join_handle = tokio::spawn(async move {
+            io_scheduler
+                .acquire(priority, end_offset - start_offset)
+                .await;
             store
                 .read(&data_path, start_offset..end_offset)
                 .verbose_instrument_await(span)
@@ -383,11 +402,13 @@ impl SstableStore {
         sst: &Sstable,
         block_index: usize,
         policy: CachePolicy,
+        priority: IoPriority,
         stats: &mut StoreLocalStatistic,
     ) -> HummockResult<BlockResponse> {
         let object_id = sst.id;
         let (range, uncompressed_capacity) = sst.calculate_block_info(block_index);
         let store = self.store.clone();
+        let io_scheduler = self.read_io_scheduler.clone();
 
         stats.cache_data_block_total += 1;
         let file_size = sst.meta.estimated_size;
@@ -409,6 +430,7 @@ impl SstableStore {
             let range = range.clone();
 
             async move {
+                io_scheduler.acquire(priority, range.len()).await;
                 let block_data = match store
                     .read(&data_path, range.clone())
                     .verbose_instrument_await("get_block_response")
@@ -482,10 +504,11 @@ impl SstableStore {
         sst: &Sstable,
         block_index: usize,
         policy: CachePolicy,
+        priority: IoPriority,
         stats: &mut StoreLocalStatistic,
     ) -> HummockResult<BlockHolder> {
         match self
-            .get_block_response(sst, block_index, policy, stats)
+            .get_block_response(sst, block_index, policy, priority, stats)
             .await
         {
             Ok(block_response) => block_response.wait().await,
@@ -619,16 +642,22 @@ impl SstableStore {
         &self,
         object_id: HummockSstableObjectId,
         metas: &[BlockMeta],
+        priority: IoPriority,
     ) -> HummockResult<BlockDataStream> {
         fail_point!("get_stream_err");
         let data_path = self.get_sst_data_path(object_id);
         let store = self.store().clone();
+        let io_scheduler = self.read_io_scheduler.clone();
         let block_meta = &metas[0];
         let start_pos = block_meta.offset as usize;
         let end_pos = metas.iter().map(|meta| meta.len as usize).sum::<usize>() + start_pos;
         let range = start_pos..end_pos;
         // spawn to tokio pool because the object-storage sdk may not be safe to cancel.
-        let ret = tokio::spawn(async move { store.streaming_read(&data_path, range).await }).await;
+        let ret = tokio::spawn(async move {
+            io_scheduler.acquire(priority, range.len()).await;
+            store.streaming_read(&data_path, range).await
+        })
+        .await;
 
         let reader = match ret {
             Ok(Ok(reader)) => reader,
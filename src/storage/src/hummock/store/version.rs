@@ -56,8 +56,8 @@ use crate::hummock::utils::{
 use crate::hummock::{
     get_from_batch, get_from_sstable_info, hit_sstable_bloom_filter, BackwardIteratorFactory,
     ForwardIteratorFactory, HummockError, HummockResult, HummockStorageIterator,
-    HummockStorageIteratorInner, HummockStorageRevIteratorInner, ReadVersionTuple, Sstable,
-    SstableIterator,
+    HummockStorageIteratorInner, HummockStorageRevIteratorInner, IoPriority, ReadVersionTuple,
+    Sstable, SstableIterator,
 };
 use crate::mem_table::{
     ImmId, ImmutableMemtable, MemTableHummockIterator, MemTableHummockRevIterator,
@@ -1013,6 +1013,7 @@ impl HummockVersionReader {
             must_iterated_end_user_key: None,
             max_preload_retry_times: 0,
             prefetch_for_large_query: false,
+            io_priority: IoPriority::Serving,
         });
 
         async fn make_iter(
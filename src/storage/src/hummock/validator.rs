@@ -23,7 +23,7 @@ use risingwave_hummock_sdk::key::FullKey;
 use crate::hummock::iterator::HummockIterator;
 use crate::hummock::sstable::SstableIteratorReadOptions;
 use crate::hummock::sstable_store::SstableStoreRef;
-use crate::hummock::{CachePolicy, SstableIterator};
+use crate::hummock::{CachePolicy, IoPriority, SstableIterator};
 use crate::monitor::StoreLocalStatistic;
 
 /// Validate SSTs in terms of Ordered, Locally unique and Globally unique.
@@ -70,6 +70,7 @@ pub async fn validate_ssts(task: ValidationTask, sstable_store: SstableStoreRef)
                 must_iterated_end_user_key: None,
                 max_preload_retry_times: 0,
                 prefetch_for_large_query: false,
+                io_priority: IoPriority::Serving,
             }),
             &sstable_info,
         );
@@ -67,6 +67,11 @@ pub struct StorageOpts {
 
     pub max_prefetch_block_number: usize,
 
+    /// Rate limit in bytes per second for backfill reads. 0 means unthrottled.
+    pub backfill_read_bytes_per_sec: u64,
+    /// Rate limit in bytes per second for compaction reads. 0 means unthrottled.
+    pub compaction_read_bytes_per_sec: u64,
+
     pub disable_remote_compactor: bool,
     /// Number of tasks shared buffer can upload in parallel.
     pub share_buffer_upload_concurrency: usize,
@@ -130,6 +135,8 @@ pub struct StorageOpts {
     /// enable `FastCompactorRunner`.
     pub enable_fast_compaction: bool,
     pub check_compaction_result: bool,
+    /// Verify compaction results with per-table checksums instead of pairwise comparison.
+    pub check_compaction_result_checksum: bool,
     pub max_preload_io_retry_times: usize,
     pub compactor_fast_max_compact_delete_ratio: u32,
     pub compactor_fast_max_compact_task_size: u64,
@@ -182,6 +189,8 @@ impl From<(&RwConfig, &SystemParamsReader, &StorageMemoryConfig)> for StorageOpt
             prefetch_buffer_capacity_mb: s.prefetch_buffer_capacity_mb,
             max_cached_recent_versions_number: c.storage.max_cached_recent_versions_number,
             max_prefetch_block_number: c.storage.max_prefetch_block_number,
+            backfill_read_bytes_per_sec: c.storage.backfill_read_bytes_per_sec,
+            compaction_read_bytes_per_sec: c.storage.compaction_read_bytes_per_sec,
             disable_remote_compactor: c.storage.disable_remote_compactor,
             share_buffer_upload_concurrency: c.storage.share_buffer_upload_concurrency,
             compactor_memory_limit_mb: s.compactor_memory_limit_mb,
@@ -234,6 +243,7 @@ impl From<(&RwConfig, &SystemParamsReader, &StorageMemoryConfig)> for StorageOpt
             compactor_max_sst_size: c.storage.compactor_max_sst_size,
             enable_fast_compaction: c.storage.enable_fast_compaction,
             check_compaction_result: c.storage.check_compaction_result,
+            check_compaction_result_checksum: c.storage.check_compaction_result_checksum,
             mem_table_spill_threshold: c.storage.mem_table_spill_threshold,
             object_store_config: c.storage.object_store.clone(),
             compactor_fast_max_compact_delete_ratio: c
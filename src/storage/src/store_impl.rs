@@ -24,7 +24,7 @@ use foyer::{
 use risingwave_common::monitor::GLOBAL_METRICS_REGISTRY;
 use risingwave_common_service::RpcNotificationClient;
 use risingwave_hummock_sdk::HummockSstableObjectId;
-use risingwave_object_store::object::build_remote_object_store;
+use risingwave_object_store::object::{build_remote_object_store, verify_object_store_access};
 
 use crate::compaction_catalog_manager::{CompactionCatalogManager, RemoteTableAccessor};
 use crate::error::StorageResult;
@@ -731,11 +731,19 @@ impl StateStoreImpl {
                 )
                 .await;
 
+                if opts.object_store_config.startup_probe {
+                    verify_object_store_access(&object_store, &opts.data_directory)
+                        .await
+                        .map_err(HummockError::from)?;
+                }
+
                 let sstable_store = Arc::new(SstableStore::new(SstableStoreConfig {
                     store: Arc::new(object_store),
                     path: opts.data_directory.clone(),
                     prefetch_buffer_capacity: opts.prefetch_buffer_capacity_mb * (1 << 20),
                     max_prefetch_block_number: opts.max_prefetch_block_number,
+                    backfill_read_bytes_per_sec: opts.backfill_read_bytes_per_sec,
+                    compaction_read_bytes_per_sec: opts.compaction_read_bytes_per_sec,
                     recent_filter,
                     state_store_metrics: state_store_metrics.clone(),
                     use_new_object_prefix_strategy,
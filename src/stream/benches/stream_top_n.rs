@@ -0,0 +1,171 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use futures::executor::block_on;
+use futures::StreamExt;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use risingwave_common::array::{Array, I64Array, Op, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+use risingwave_common::util::epoch::test_epoch;
+use risingwave_common::util::sort_util::{ColumnOrder, OrderType};
+use risingwave_stream::executor::test_utils::top_n_executor::create_in_memory_state_table;
+use risingwave_stream::executor::test_utils::*;
+use risingwave_stream::executor::{
+    ActorContext, AppendOnlyTopNExecutor, Execute, Executor, ExecutorInfo, PkIndices, TopNExecutor,
+};
+use tokio::runtime::Runtime;
+
+risingwave_expr_impl::enable!();
+
+const NUM_OF_CHUNKS: usize = 1000;
+const CHUNK_SIZE: usize = 1024;
+const LIMIT: usize = 100;
+
+/// The max value of the sort key. A small key space yields a highly skewed workload
+/// where most rows contend for the top; a large one yields high cardinality.
+#[derive(Clone, Copy)]
+enum KeySpace {
+    Skewed,
+    HighCardinality,
+}
+
+impl KeySpace {
+    fn max_key(self) -> i64 {
+        match self {
+            KeySpace::Skewed => 1000,
+            KeySpace::HighCardinality => i64::MAX,
+        }
+    }
+}
+
+fn bench_top_n(c: &mut Criterion) {
+    let mut group = c.benchmark_group("TopN");
+    group.sample_size(10);
+
+    let rt = Runtime::new().unwrap();
+    for (name, append_only, key_space) in [
+        ("top_n_high_cardinality", false, KeySpace::HighCardinality),
+        ("top_n_skewed", false, KeySpace::Skewed),
+        ("append_only_top_n", true, KeySpace::HighCardinality),
+    ] {
+        group.bench_function(name, |b| {
+            b.to_async(&rt).iter_batched(
+                || setup_bench_top_n(append_only, key_space),
+                execute_executor,
+                BatchSize::SmallInput,
+            )
+        });
+    }
+}
+
+/// Generates insert-only chunks with a random sort key and a unique row id, so that
+/// the same data can feed both the plain and the append-only executor.
+fn gen_chunks(key_space: KeySpace) -> Vec<StreamChunk> {
+    let mut rng = SmallRng::seed_from_u64(114514);
+    let mut row_id = 0i64;
+    (0..NUM_OF_CHUNKS)
+        .map(|_| {
+            let keys: I64Array = (0..CHUNK_SIZE)
+                .map(|_| Some(rng.gen_range(0..=key_space.max_key())))
+                .collect();
+            let ids: I64Array = (0..CHUNK_SIZE)
+                .map(|_| {
+                    row_id += 1;
+                    Some(row_id)
+                })
+                .collect();
+            StreamChunk::new(
+                vec![Op::Insert; CHUNK_SIZE],
+                vec![keys.into_ref(), ids.into_ref()],
+            )
+        })
+        .collect()
+}
+
+fn setup_bench_top_n(append_only: bool, key_space: KeySpace) -> Executor {
+    let schema = Schema {
+        fields: vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ],
+    };
+    let order_by = vec![ColumnOrder::new(0, OrderType::ascending())];
+    let storage_key = vec![
+        ColumnOrder::new(0, OrderType::ascending()),
+        ColumnOrder::new(1, OrderType::ascending()),
+    ];
+
+    // ---- Create MockSourceExecutor ----
+    let (mut tx, source) = MockSource::channel();
+    let source = source.into_executor(schema.clone(), PkIndices::new());
+    tx.push_barrier(test_epoch(1), false);
+    for chunk in gen_chunks(key_space) {
+        tx.push_chunk(chunk);
+    }
+    tx.push_barrier_with_prev_epoch_for_test(test_epoch(2), test_epoch(1), false);
+
+    // ---- Create TopNExecutor to be benchmarked ----
+    let state_table = block_on(create_in_memory_state_table(
+        &[DataType::Int64, DataType::Int64],
+        &[OrderType::ascending(), OrderType::ascending()],
+        &[0, 1],
+    ));
+
+    let info = ExecutorInfo {
+        schema: schema.clone(),
+        pk_indices: vec![0, 1],
+        identity: "TopNExecutor".to_owned(),
+    };
+    let exec: Box<dyn Execute> = if append_only {
+        Box::new(
+            AppendOnlyTopNExecutor::<_, false>::new(
+                source,
+                ActorContext::for_test(0),
+                schema,
+                storage_key,
+                (0, LIMIT),
+                order_by,
+                state_table,
+            )
+            .unwrap(),
+        )
+    } else {
+        Box::new(
+            TopNExecutor::<_, false>::new(
+                source,
+                ActorContext::for_test(0),
+                schema,
+                storage_key,
+                (0, LIMIT),
+                order_by,
+                state_table,
+            )
+            .unwrap(),
+        )
+    };
+    Executor::new(info, exec)
+}
+
+pub async fn execute_executor(executor: Executor) {
+    let mut stream = executor.execute();
+    while let Some(ret) = stream.next().await {
+        _ = black_box(ret.unwrap());
+    }
+}
+
+criterion_group!(benches, bench_top_n);
+criterion_main!(benches);
@@ -152,6 +152,21 @@ where
     pub fn clear(&mut self) {
         self.inner.clear();
     }
+
+    /// Retain only the entries for which the predicate returns `true`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &V) -> bool,
+    {
+        let reporter = &mut self.reporter;
+        self.inner.retain(|k, v| {
+            let retain = f(k, v);
+            if !retain {
+                reporter.dec(k.estimated_size() + v.estimated_size());
+            }
+            retain
+        });
+    }
 }
 
 impl<K, V> ManagedLruCache<K, V>
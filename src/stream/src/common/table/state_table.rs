@@ -1259,6 +1259,11 @@ where
     /// This function scans rows from the relational table with specific `prefix` and `sub_range` under the same
     /// `vnode`. If `sub_range` is (Unbounded, Unbounded), it scans rows from the relational table with specific `pk_prefix`.
     /// `pk_prefix` is used to identify the exact vnode the scan should perform on.
+    ///
+    /// If the table is built with a `read_prefix_len_hint` and `pk_prefix` covers at least that
+    /// many columns, the encoded prefix is also passed to the storage read path as the prefix
+    /// hint for bloom filter checks, so that SSTs not containing the prefix (e.g. the group key
+    /// of an agg state table) can be skipped without any I/O.
     pub async fn iter_with_prefix(
         &self,
         pk_prefix: impl Row,
@@ -1420,6 +1425,30 @@ where
     }
 }
 
+/// Commit all given state tables with the same `new_epoch` concurrently.
+///
+/// Each table still seals its own write batch into the shared buffer; the commits are
+/// only awaited together instead of one table after another, which hides the per-table
+/// seal latency for actors with multiple state tables.
+pub async fn commit_state_tables<
+    'a,
+    S,
+    SD,
+    const IS_REPLICATED: bool,
+    const USE_WATERMARK_CACHE: bool,
+>(
+    tables: impl IntoIterator<Item = &'a mut StateTableInner<S, SD, IS_REPLICATED, USE_WATERMARK_CACHE>>,
+    new_epoch: EpochPair,
+) -> StreamExecutorResult<()>
+where
+    S: StateStore,
+    SD: ValueRowSerde,
+{
+    futures::future::try_join_all(tables.into_iter().map(|table| table.commit(new_epoch)))
+        .await?;
+    Ok(())
+}
+
 fn deserialize_keyed_row_stream<'a, K: CopyFromSlice>(
     iter: impl StateStoreIter + 'a,
     deserializer: &'a impl ValueRowSerde,
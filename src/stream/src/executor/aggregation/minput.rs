@@ -193,6 +193,8 @@ impl MaterializedInputState {
             let mut cache_filler = self.cache.begin_syncing();
             let sub_range: &(Bound<OwnedRow>, Bound<OwnedRow>) =
                 &(Bound::Unbounded, Bound::Unbounded);
+            // Iterate with the group key as the prefix, which is also passed to the storage
+            // layer as the prefix hint for bloom filter checks.
             let all_data_iter = state_table
                 .iter_with_prefix(
                     group_key.map(GroupKey::table_pk),
@@ -23,6 +23,7 @@ use risingwave_common::util::epoch::EpochPair;
 use risingwave_storage::store::PrefetchOptions;
 use risingwave_storage::StateStore;
 
+use crate::common::table::state_table::commit_state_tables;
 use crate::executor::prelude::*;
 use crate::executor::StreamExecutorResult;
 
@@ -236,8 +237,11 @@ impl<S: StateStore> GlobalApproxPercentileState<S> {
                 .count_state_table
                 .update(last_row_count_state, row_count_row),
         }
-        self.count_state_table.commit(epoch).await?;
-        self.bucket_state_table.commit(epoch).await?;
+        commit_state_tables(
+            [&mut self.count_state_table, &mut self.bucket_state_table],
+            epoch,
+        )
+        .await?;
         Ok(())
     }
 }
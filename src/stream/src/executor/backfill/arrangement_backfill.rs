@@ -72,6 +72,11 @@ where
     S: StateStore,
     SD: ValueRowSerde,
 {
+    /// Max number of per-vnode snapshot range reads opened concurrently. Each opened
+    /// range read prefetches at most a small range scan worth of blocks, so this also
+    /// bounds the total bytes buffered by the prefetch pipeline.
+    const SNAPSHOT_READ_OPEN_CONCURRENCY: usize = 8;
+
     #[allow(clippy::too_many_arguments)]
     #[allow(dead_code)]
     pub fn new(
@@ -689,7 +694,7 @@ where
         upstream_table: &ReplicatedStateTable<S, SD>,
         backfill_state: BackfillState,
     ) {
-        let mut iterators = vec![];
+        let mut iter_futures = vec![];
         for vnode in upstream_table.vnodes().iter_vnodes() {
             let backfill_progress = backfill_state.get_progress(&vnode)?;
             let current_pos = match backfill_progress {
@@ -712,21 +717,29 @@ where
                 range_bounds = ?range_bounds,
                 "iter_with_vnode_and_output_indices"
             );
-            let vnode_row_iter = upstream_table
-                .iter_with_vnode_and_output_indices(
-                    vnode,
-                    &range_bounds,
-                    PrefetchOptions::prefetch_for_small_range_scan(),
-                )
-                .await?;
-
-            let vnode_row_iter = vnode_row_iter.map_ok(move |row| (vnode, row));
+            iter_futures.push(async move {
+                let vnode_row_iter = upstream_table
+                    .iter_with_vnode_and_output_indices(
+                        vnode,
+                        &range_bounds,
+                        PrefetchOptions::prefetch_for_small_range_scan(),
+                    )
+                    .await?;
 
-            let vnode_row_iter = Box::pin(vnode_row_iter);
+                let vnode_row_iter = vnode_row_iter.map_ok(move |row| (vnode, row));
 
-            iterators.push(vnode_row_iter);
+                Ok::<_, StreamExecutorError>(Box::pin(vnode_row_iter))
+            });
         }
 
+        // Open the per-vnode range reads with bounded concurrency, so that the storage
+        // layer can prefetch the first blocks of the next ranges while earlier ones are
+        // being opened. The bound also caps how many ranges prefetch buffers at once.
+        let iterators: Vec<_> = stream::iter(iter_futures)
+            .buffered(Self::SNAPSHOT_READ_OPEN_CONCURRENCY)
+            .try_collect()
+            .await?;
+
         // TODO(kwannoel): We can provide an option between snapshot read in parallel vs serial.
         let vnode_row_iter = select_all(iterators);
 
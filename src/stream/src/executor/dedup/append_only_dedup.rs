@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
+
 use futures::stream;
 use itertools::Itertools;
 use risingwave_common::array::Op;
 use risingwave_common::bitmap::BitmapBuilder;
 use risingwave_common::row::RowExt;
+use risingwave_common::util::sort_util::{cmp_datum, OrderType};
 
 use super::cache::DedupCache;
 use crate::common::metrics::MetricsInfo;
@@ -133,6 +136,17 @@ impl<S: StateStore> AppendOnlyDedupExecutor<S> {
                 }
 
                 Message::Watermark(watermark) => {
+                    if watermark.col_idx == self.dedup_cols[0] {
+                        // A watermark on the first dedup column bounds the dedup window: keys
+                        // below it can never show up again, so they are removed from the cache
+                        // here and cleaned from the state table on the next commit.
+                        let watermark_val = watermark.val.clone();
+                        self.cache.retain(|key| {
+                            cmp_datum(&key[0], Some(&watermark_val), OrderType::ascending())
+                                != Ordering::Less
+                        });
+                        self.state_table.update_watermark(watermark_val);
+                    }
                     yield Message::Watermark(watermark);
                 }
             }
@@ -53,6 +53,11 @@ impl<K: Hash + Eq + EstimateSize> DedupCache<K> {
         self.inner.evict()
     }
 
+    /// Retain only the keys for which the predicate returns `true`.
+    pub fn retain(&mut self, mut f: impl FnMut(&K) -> bool) {
+        self.inner.retain(|k, _| f(k))
+    }
+
     /// Clear everything in the cache.
     pub fn clear(&mut self) {
         self.inner.clear()
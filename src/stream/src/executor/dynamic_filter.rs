@@ -488,8 +488,12 @@ impl<S: StateStore, const USE_WATERMARK_CACHE: bool> DynamicFilterExecutor<S, US
                         }
                     }
 
-                    self.left_table.commit(barrier.epoch).await?;
-                    self.right_table.commit(barrier.epoch).await?;
+                    // Commit the two state tables concurrently instead of one after the
+                    // other.
+                    futures::try_join!(
+                        self.left_table.commit(barrier.epoch),
+                        self.right_table.commit(barrier.epoch),
+                    )?;
 
                     // Update the last committed RHS row and value.
                     committed_rhs_row.clone_from(&staging_rhs_row);
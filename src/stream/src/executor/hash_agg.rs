@@ -35,6 +35,7 @@ use super::monitor::HashAggMetrics;
 use super::sort_buffer::SortBuffer;
 use crate::cache::{cache_may_stale, ManagedLruCache};
 use crate::common::metrics::MetricsInfo;
+use crate::common::table::state_table::commit_state_tables;
 use crate::executor::aggregation::AggGroup as GenericAggGroup;
 use crate::executor::prelude::*;
 
@@ -531,12 +532,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         this: &mut ExecutorInner<K, S>,
         epoch: EpochPair,
     ) -> StreamExecutorResult<()> {
-        futures::future::try_join_all(
-            this.all_state_tables_mut()
-                .map(|table| async { table.commit(epoch).await }),
-        )
-        .await?;
-        Ok(())
+        commit_state_tables(this.all_state_tables_mut(), epoch).await
     }
 
     async fn try_flush_data(this: &mut ExecutorInner<K, S>) -> StreamExecutorResult<()> {
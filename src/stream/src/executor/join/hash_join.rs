@@ -31,7 +31,7 @@ use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_common::util::epoch::EpochPair;
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_common::util::row_serde::OrderedRowSerde;
-use risingwave_common::util::sort_util::OrderType;
+use risingwave_common::util::sort_util::{cmp_datum, OrderType};
 use risingwave_common_estimate_size::EstimateSize;
 use risingwave_storage::store::PrefetchOptions;
 use risingwave_storage::StateStore;
@@ -40,7 +40,7 @@ use thiserror_ext::AsReport;
 use super::row::{DegreeType, EncodedJoinRow};
 use crate::cache::ManagedLruCache;
 use crate::common::metrics::MetricsInfo;
-use crate::common::table::state_table::StateTable;
+use crate::common::table::state_table::{commit_state_tables, StateTable};
 use crate::consistency::{consistency_error, consistency_panic, enable_strict_consistency};
 use crate::executor::error::StreamExecutorResult;
 use crate::executor::join::row::JoinRow;
@@ -106,11 +106,14 @@ pub struct JoinHashMapMetrics {
     total_lookup_count: usize,
     /// How many times have we miss the cache when insert row
     insert_cache_miss_count: usize,
+    /// How many cached rows have been cleaned by watermark
+    watermark_cleaned_rows: usize,
 
     // Metrics
     join_lookup_total_count_metric: LabelGuardedIntCounter<4>,
     join_lookup_miss_count_metric: LabelGuardedIntCounter<4>,
     join_insert_cache_miss_count_metrics: LabelGuardedIntCounter<4>,
+    join_watermark_cleaned_rows_metric: LabelGuardedIntCounter<4>,
 }
 
 impl JoinHashMapMetrics {
@@ -133,14 +136,19 @@ impl JoinHashMapMetrics {
         let join_insert_cache_miss_count_metrics = metrics
             .join_insert_cache_miss_count
             .with_guarded_label_values(&[(side), &join_table_id, &actor_id, &fragment_id]);
+        let join_watermark_cleaned_rows_metric = metrics
+            .join_watermark_cleaned_rows
+            .with_guarded_label_values(&[(side), &join_table_id, &actor_id, &fragment_id]);
 
         Self {
             lookup_miss_count: 0,
             total_lookup_count: 0,
             insert_cache_miss_count: 0,
+            watermark_cleaned_rows: 0,
             join_lookup_total_count_metric,
             join_lookup_miss_count_metric,
             join_insert_cache_miss_count_metrics,
+            join_watermark_cleaned_rows_metric,
         }
     }
 
@@ -151,9 +159,12 @@ impl JoinHashMapMetrics {
             .inc_by(self.lookup_miss_count as u64);
         self.join_insert_cache_miss_count_metrics
             .inc_by(self.insert_cache_miss_count as u64);
+        self.join_watermark_cleaned_rows_metric
+            .inc_by(self.watermark_cleaned_rows as u64);
         self.total_lookup_count = 0;
         self.lookup_miss_count = 0;
         self.insert_cache_miss_count = 0;
+        self.watermark_cleaned_rows = 0;
     }
 }
 
@@ -359,7 +370,22 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
     }
 
     pub fn update_watermark(&mut self, watermark: ScalarImpl) {
-        // TODO: remove data in cache.
+        // Rows whose first join key column is below the watermark are provably unjoinable.
+        // Remove them from the cache eagerly here, and from the state tables lazily on the
+        // next commit, i.e. at barrier.
+        let mut cleaned_rows = 0;
+        let join_key_data_types = &self.join_key_data_types;
+        self.inner.retain(|key, state| {
+            let unjoinable = key.deserialize(join_key_data_types).is_ok_and(|row| {
+                cmp_datum(&row[0], Some(&watermark), OrderType::ascending()) == Ordering::Less
+            });
+            if unjoinable {
+                cleaned_rows += state.len();
+            }
+            !unjoinable
+        });
+        self.metrics.watermark_cleaned_rows += cleaned_rows;
+
         self.state.table.update_watermark(watermark.clone());
         if let Some(degree_state) = &mut self.degree_state {
             degree_state.table.update_watermark(watermark);
@@ -565,11 +591,9 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
 
     pub async fn flush(&mut self, epoch: EpochPair) -> StreamExecutorResult<()> {
         self.metrics.flush();
-        self.state.table.commit(epoch).await?;
-        if let Some(degree_state) = &mut self.degree_state {
-            degree_state.table.commit(epoch).await?;
-        }
-        Ok(())
+        let state_table = std::iter::once(&mut self.state.table);
+        let degree_table = self.degree_state.as_mut().map(|d| &mut d.table);
+        commit_state_tables(state_table.chain(degree_table), epoch).await
     }
 
     pub async fn try_flush(&mut self) -> StreamExecutorResult<()> {
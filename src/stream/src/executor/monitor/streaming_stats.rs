@@ -90,6 +90,7 @@ pub struct StreamingMetrics {
     pub join_lookup_miss_count: LabelGuardedIntCounterVec<4>,
     pub join_lookup_total_count: LabelGuardedIntCounterVec<4>,
     pub join_insert_cache_miss_count: LabelGuardedIntCounterVec<4>,
+    pub join_watermark_cleaned_rows: LabelGuardedIntCounterVec<4>,
     pub join_actor_input_waiting_duration_ns: LabelGuardedIntCounterVec<2>,
     pub join_match_duration_ns: LabelGuardedIntCounterVec<3>,
     pub join_cached_entry_count: LabelGuardedIntGaugeVec<3>,
@@ -467,6 +468,14 @@ impl StreamingMetrics {
         )
         .unwrap();
 
+        let join_watermark_cleaned_rows = register_guarded_int_counter_vec_with_registry!(
+            "stream_join_watermark_cleaned_rows",
+            "Join executor state rows cleaned by watermark",
+            &["side", "join_table_id", "actor_id", "fragment_id"],
+            registry
+        )
+        .unwrap();
+
         let join_actor_input_waiting_duration_ns = register_guarded_int_counter_vec_with_registry!(
             "stream_join_actor_input_waiting_duration_ns",
             "Total waiting duration (ns) of input buffer of join actor",
@@ -1083,6 +1092,7 @@ impl StreamingMetrics {
             join_lookup_miss_count,
             join_lookup_total_count,
             join_insert_cache_miss_count,
+            join_watermark_cleaned_rows,
             join_actor_input_waiting_duration_ns,
             join_match_duration_ns,
             join_cached_entry_count,
@@ -44,8 +44,10 @@ pub struct NowExecutor<S: StateStore> {
 }
 
 pub enum NowMode {
-    /// Emit current timestamp on startup, update it on barrier.
-    UpdateCurrent,
+    /// Emit current timestamp on startup, update it on barrier. If `tick_interval` is set,
+    /// only update after at least that interval has passed since the last emitted value,
+    /// coalescing per-barrier updates.
+    UpdateCurrent { tick_interval: Option<Interval> },
     /// Generate a series of timestamps starting from `start_timestamp` with `interval`.
     /// Keep generating new timestamps on barrier.
     GenerateSeries {
@@ -55,7 +57,9 @@ pub enum NowMode {
 }
 
 enum ModeVars {
-    UpdateCurrent,
+    UpdateCurrent {
+        add_tick_interval_expr: Option<NonStrictExpression>,
+    },
     GenerateSeries {
         chunk_builder: StreamChunkBuilder,
         add_interval_expr: NonStrictExpression,
@@ -100,7 +104,16 @@ impl<S: StateStore> NowExecutor<S> {
         let mut initialized = false;
 
         let mut mode_vars = match &mode {
-            NowMode::UpdateCurrent => ModeVars::UpdateCurrent,
+            NowMode::UpdateCurrent { tick_interval } => {
+                let add_tick_interval_expr = tick_interval
+                    .map(|interval| {
+                        build_add_interval_expr_captured(interval, eval_error_report.clone())
+                    })
+                    .transpose()?;
+                ModeVars::UpdateCurrent {
+                    add_tick_interval_expr,
+                }
+            }
             NowMode::GenerateSeries { interval, .. } => {
                 // in most cases there won't be more than one row except for the first time
                 let chunk_builder = StreamChunkBuilder::unlimited(data_types.clone(), Some(1));
@@ -180,7 +193,27 @@ impl<S: StateStore> NowExecutor<S> {
             }
 
             match (&mode, &mut mode_vars) {
-                (NowMode::UpdateCurrent, ModeVars::UpdateCurrent) => {
+                (
+                    NowMode::UpdateCurrent { .. },
+                    ModeVars::UpdateCurrent {
+                        add_tick_interval_expr,
+                    },
+                ) => {
+                    if let Some(add_tick_interval_expr) = add_tick_interval_expr
+                        && last_timestamp.is_some()
+                    {
+                        // Coalesce ticks: do not update `now` (nor emit a watermark) until
+                        // at least `tick_interval` has passed since the last emitted value.
+                        let last_row = OwnedRow::new(vec![last_timestamp.clone()]);
+                        let next_tick =
+                            add_tick_interval_expr.eval_row_infallible(&last_row).await;
+                        if DefaultOrdered(curr_timestamp.to_datum_ref())
+                            < DefaultOrdered(next_tick.to_datum_ref())
+                        {
+                            continue;
+                        }
+                    }
+
                     let chunk = if last_timestamp.is_some() {
                         let last_row = row::once(&last_timestamp);
                         let row = row::once(&curr_timestamp);
@@ -316,7 +349,7 @@ mod tests {
     #[tokio::test]
     async fn test_now() -> StreamExecutorResult<()> {
         let state_store = create_state_store();
-        let (tx, mut now) = create_executor(NowMode::UpdateCurrent, &state_store).await;
+        let (tx, mut now) = create_executor(NowMode::UpdateCurrent { tick_interval: None }, &state_store).await;
 
         // Init barrier
         tx.send(Barrier::new_test_barrier(test_epoch(1))).unwrap();
@@ -385,7 +418,7 @@ mod tests {
 
         // Recovery
         drop((tx, now));
-        let (tx, mut now) = create_executor(NowMode::UpdateCurrent, &state_store).await;
+        let (tx, mut now) = create_executor(NowMode::UpdateCurrent { tick_interval: None }, &state_store).await;
         tx.send(Barrier::with_prev_epoch_for_test(
             test_epoch(3),
             test_epoch(2),
@@ -421,7 +454,7 @@ mod tests {
 
         // Recovery with paused
         drop((tx, now));
-        let (tx, mut now) = create_executor(NowMode::UpdateCurrent, &state_store).await;
+        let (tx, mut now) = create_executor(NowMode::UpdateCurrent { tick_interval: None }, &state_store).await;
         tx.send(
             Barrier::with_prev_epoch_for_test(test_epoch(4), test_epoch(3))
                 .with_mutation(Mutation::Pause),
@@ -473,7 +506,7 @@ mod tests {
     #[tokio::test]
     async fn test_now_start_with_paused() -> StreamExecutorResult<()> {
         let state_store = create_state_store();
-        let (tx, mut now) = create_executor(NowMode::UpdateCurrent, &state_store).await;
+        let (tx, mut now) = create_executor(NowMode::UpdateCurrent { tick_interval: None }, &state_store).await;
 
         // Init barrier
         tx.send(Barrier::new_test_barrier(test_epoch(1)).with_mutation(Mutation::Pause))
@@ -24,6 +24,7 @@ use super::agg_common::{AggExecutorArgs, SimpleAggExecutorExtraArgs};
 use super::aggregation::{
     agg_call_filter_res, iter_table_storage, AggStateStorage, AlwaysOutput, DistinctDeduplicater,
 };
+use crate::common::table::state_table::commit_state_tables;
 use crate::executor::aggregation::AggGroup;
 use crate::executor::prelude::*;
 
@@ -219,8 +220,7 @@ impl<S: StateStore> SimpleAggExecutor<S> {
         });
 
         // Commit all state tables.
-        futures::future::try_join_all(this.all_state_tables_mut().map(|table| table.commit(epoch)))
-            .await?;
+        commit_state_tables(this.all_state_tables_mut(), epoch).await?;
 
         vars.state_changed = false;
         Ok(chunk)
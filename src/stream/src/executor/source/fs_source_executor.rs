@@ -109,6 +109,7 @@ impl<S: StateStore> FsSourceExecutor<S> {
             },
             source_desc.source.config.clone(),
             None,
+            None,
         );
         let stream = source_desc
             .source
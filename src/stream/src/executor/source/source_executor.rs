@@ -32,8 +32,8 @@ use risingwave_connector::parser::schema_change::SchemaChangeEnvelope;
 use risingwave_connector::source::reader::desc::{SourceDesc, SourceDescBuilder};
 use risingwave_connector::source::reader::reader::SourceReader;
 use risingwave_connector::source::{
-    BoxSourceChunkStream, ConnectorState, SourceContext, SourceCtrlOpts, SplitId, SplitImpl,
-    SplitMetaData, WaitCheckpointTask,
+    BoxSourceChunkStream, CdcLagAlert, CdcLagAlertSender, ConnectorState, SourceContext,
+    SourceCtrlOpts, SplitId, SplitImpl, SplitMetaData, WaitCheckpointTask,
 };
 use risingwave_hummock_sdk::HummockReadEpoch;
 use risingwave_storage::store::TryWaitEpochOptions;
@@ -191,6 +191,43 @@ impl<S: StateStore> SourceExecutor<S> {
             None
         };
 
+        let cdc_lag_alert = {
+            let threshold_ms = self
+                .actor_ctx
+                .streaming_config
+                .developer
+                .cdc_source_lag_alert_threshold_ms;
+            if threshold_ms > 0 {
+                let (tx, mut rx) = mpsc::channel::<CdcLagAlert>(16);
+                let meta_client = self.actor_ctx.meta_client.clone();
+                let source_id = self.stream_source_core.as_ref().unwrap().source_id;
+                // spawn a task to forward lag alerts from the source parser to meta
+                let _join_handle = tokio::task::spawn(async move {
+                    while let Some(alert) = rx.recv().await {
+                        if let Some(ref meta_client) = meta_client
+                            && let Err(e) = meta_client
+                                .add_cdc_source_lag_event(
+                                    source_id.table_id(),
+                                    alert.table_name.clone(),
+                                    alert.lag_ms,
+                                    alert.threshold_ms,
+                                )
+                                .await
+                        {
+                            tracing::warn!(
+                                error = ?e.as_report(),
+                                table_name = alert.table_name,
+                                "failed to report cdc source lag event",
+                            );
+                        }
+                    }
+                });
+                Some(CdcLagAlertSender { threshold_ms, tx })
+            } else {
+                None
+            }
+        };
+
         let source_ctx = SourceContext::new(
             self.actor_ctx.id,
             self.stream_source_core.as_ref().unwrap().source_id,
@@ -207,6 +244,7 @@ impl<S: StateStore> SourceExecutor<S> {
             },
             source_desc.source.config.clone(),
             schema_change_tx,
+            cdc_lag_alert,
         );
 
         (column_ids, source_ctx)
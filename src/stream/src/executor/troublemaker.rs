@@ -63,7 +63,7 @@ impl TroublemakerExecutor {
                 | DataType::Int64
                 | DataType::Float32
                 | DataType::Float64) => {
-                    FieldGeneratorImpl::with_number_random(t, None, None, rand::random()).ok()
+                    FieldGeneratorImpl::with_number_random(t, None, None, None, rand::random()).ok()
                 }
                 DataType::Varchar => Some(FieldGeneratorImpl::with_varchar(
                     &VarcharProperty::RandomVariableLength,
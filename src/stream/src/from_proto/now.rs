@@ -16,7 +16,7 @@ use anyhow::Context;
 use risingwave_common::types::{DataType, Datum};
 use risingwave_common::util::value_encoding::DatumFromProtoExt;
 use risingwave_pb::stream_plan::now_node::PbMode as PbNowMode;
-use risingwave_pb::stream_plan::{NowNode, PbNowModeGenerateSeries};
+use risingwave_pb::stream_plan::{NowNode, PbNowModeGenerateSeries, PbNowModeUpdateCurrent};
 use risingwave_storage::StateStore;
 
 use super::ExecutorBuilder;
@@ -41,7 +41,18 @@ impl ExecutorBuilder for NowExecutorBuilder {
 
         let mode = if let Ok(pb_mode) = node.get_mode() {
             match pb_mode {
-                PbNowMode::UpdateCurrent(_) => NowMode::UpdateCurrent,
+                PbNowMode::UpdateCurrent(PbNowModeUpdateCurrent { tick_interval }) => {
+                    let tick_interval = tick_interval
+                        .as_ref()
+                        .map(|interval| {
+                            Datum::from_protobuf(interval, &DataType::Interval)
+                                .context("`tick_interval` field is not decodable")?
+                                .context("`tick_interval` field should not be NULL")
+                                .map(|d| d.into_interval())
+                        })
+                        .transpose()?;
+                    NowMode::UpdateCurrent { tick_interval }
+                }
                 PbNowMode::GenerateSeries(PbNowModeGenerateSeries {
                     start_timestamp,
                     interval,
@@ -66,7 +77,9 @@ impl ExecutorBuilder for NowExecutorBuilder {
             }
         } else {
             // default to `UpdateCurrent` for backward-compatibility
-            NowMode::UpdateCurrent
+            NowMode::UpdateCurrent {
+                tick_interval: None,
+            }
         };
 
         let state_table =
@@ -17,7 +17,7 @@ use std::fmt::Display;
 use std::future::{pending, poll_fn};
 use std::sync::Arc;
 use std::task::Poll;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::anyhow;
 use await_tree::InstrumentAwait;
@@ -302,8 +302,18 @@ pub(super) struct LocalBarrierWorker {
     control_stream_handle: ControlStreamHandle,
 
     pub(super) actor_manager: Arc<StreamActorManager>,
+
+    /// Set when a graceful shutdown is requested while there are still in-flight barriers.
+    /// Holds the notifier of the `Shutdown` operation and the deadline after which we give
+    /// up draining and shut down anyway.
+    shutdown_drain: Option<(oneshot::Sender<()>, Instant)>,
 }
 
+/// Grace period for in-flight barriers to be collected and flushed when a graceful shutdown
+/// is requested. If exceeded, we shut down anyway and let recovery handle the unfinished
+/// epochs.
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl LocalBarrierWorker {
     pub(super) fn new(
         actor_manager: Arc<StreamActorManager>,
@@ -315,6 +325,7 @@ impl LocalBarrierWorker {
             await_epoch_completed_futures: Default::default(),
             control_stream_handle: ControlStreamHandle::empty(),
             actor_manager,
+            shutdown_drain: None,
         }
     }
 
@@ -377,9 +388,13 @@ impl LocalBarrierWorker {
     }
 
     async fn run(mut self, mut actor_op_rx: UnboundedReceiver<LocalActorOperation>) {
+        // Only used to periodically check the drain progress (and its deadline) during a
+        // graceful shutdown.
+        let mut drain_check_interval = tokio::time::interval(Duration::from_millis(100));
         loop {
             select! {
                 biased;
+                _ = drain_check_interval.tick(), if self.shutdown_drain.is_some() => {}
                 (database_id, event) = self.state.next_event() => {
                     match event {
                         ManagedBarrierStateEvent::BarrierCollected{
@@ -422,25 +437,22 @@ impl LocalBarrierWorker {
                                 self.control_stream_handle.send_response(streaming_control_stream_response::Response::Init(InitResponse {}));
                             }
                             LocalActorOperation::Shutdown { result_sender } => {
-                                if self.state.databases.values().any(|database| {
-                                    match database {
-                                        DatabaseStatus::Running(database) => {
-                                            !database.actor_states.is_empty()
-                                        }
-                                        DatabaseStatus::Suspended(_) | DatabaseStatus::Resetting(_) => {
-                                            false
-                                        }
-                                        DatabaseStatus::Unspecified => {
-                                            unreachable!()
-                                        }
-                                    }
-                                }) {
+                                if self.has_running_actors() {
                                     tracing::warn!(
                                         "shutdown with running actors, scaling or migration will be triggered"
                                     );
                                 }
-                                self.control_stream_handle.shutdown_stream().await;
-                                let _ = result_sender.send(());
+                                if self.has_inflight_barriers() {
+                                    // Drain the in-flight barriers first, so that their epochs
+                                    // are collected and flushed to the state store and a rolling
+                                    // restart does not have to rely on recovery for them.
+                                    tracing::info!("shutdown requested, draining in-flight barriers");
+                                    self.shutdown_drain =
+                                        Some((result_sender, Instant::now() + SHUTDOWN_DRAIN_TIMEOUT));
+                                } else {
+                                    self.control_stream_handle.shutdown_stream().await;
+                                    let _ = result_sender.send(());
+                                }
                             }
                             actor_op => {
                                 self.handle_actor_op(actor_op);
@@ -452,12 +464,67 @@ impl LocalBarrierWorker {
                     }
                 },
                 request = self.control_stream_handle.next_request() => {
-                    let result = self.handle_streaming_control_request(request.request.expect("non empty"));
+                    let request = request.request.expect("non empty");
+                    if self.shutdown_drain.is_some() && matches!(request, Request::InjectBarrier(_)) {
+                        // We're draining the in-flight barriers for a graceful shutdown and
+                        // will not process new barriers anymore. Meta will learn about the
+                        // shutdown from the `Shutdown` response sent after the drain.
+                        continue;
+                    }
+                    let result = self.handle_streaming_control_request(request);
                     if let Err((database_id, err)) = result {
                         self.on_database_failure(database_id, None, err, "failed to inject barrier");
                     }
                 },
             }
+            self.may_finish_shutdown_drain().await;
+        }
+    }
+
+    fn has_running_actors(&self) -> bool {
+        self.state.databases.values().any(|database| match database {
+            DatabaseStatus::Running(database) => !database.actor_states.is_empty(),
+            DatabaseStatus::Suspended(_) | DatabaseStatus::Resetting(_) => false,
+            DatabaseStatus::Unspecified => {
+                unreachable!()
+            }
+        })
+    }
+
+    /// Returns whether there are barriers that have been injected but not yet fully
+    /// collected and flushed to the state store.
+    fn has_inflight_barriers(&self) -> bool {
+        self.state.databases.values().any(|database| match database {
+            DatabaseStatus::Running(database) => {
+                database.graph_states.values().any(|state| !state.is_empty())
+            }
+            DatabaseStatus::Suspended(_) | DatabaseStatus::Resetting(_) => false,
+            DatabaseStatus::Unspecified => {
+                unreachable!()
+            }
+        }) || self
+            .await_epoch_completed_futures
+            .values()
+            .any(|futures| !futures.is_empty())
+    }
+
+    /// Finishes a pending graceful shutdown if all in-flight barriers have been drained,
+    /// or the grace period has passed.
+    async fn may_finish_shutdown_drain(&mut self) {
+        let Some((_, deadline)) = &self.shutdown_drain else {
+            return;
+        };
+        let deadline = *deadline;
+        let drained = !self.has_inflight_barriers();
+        if drained || Instant::now() >= deadline {
+            if !drained {
+                tracing::warn!(
+                    "in-flight barriers not drained within the grace period, shutting down anyway"
+                );
+            }
+            let (result_sender, _) = self.shutdown_drain.take().unwrap();
+            self.control_stream_handle.shutdown_stream().await;
+            let _ = result_sender.send(());
         }
     }
 
@@ -926,6 +993,7 @@ impl LocalBarrierWorker {
             .send_response(Response::ReportDatabaseFailure(
                 ReportDatabaseFailureResponse {
                     database_id: database_id.database_id,
+                    failed_actor_id: failed_actor,
                 },
             ));
     }
@@ -14,6 +14,7 @@
 
 use std::assert_matches::assert_matches;
 use std::fmt::{Display, Formatter};
+use std::time::Instant;
 
 use risingwave_common::util::epoch::EpochPair;
 use risingwave_pb::stream_service::barrier_complete_response::PbCreateMviewProgress;
@@ -25,10 +26,11 @@ use crate::task::ActorId;
 
 type ConsumedEpoch = u64;
 type ConsumedRows = u64;
+type ReadRowsPerSecond = f64;
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum BackfillState {
-    ConsumingUpstreamTableOrSource(ConsumedEpoch, ConsumedRows),
+    ConsumingUpstreamTableOrSource(ConsumedEpoch, ConsumedRows, ReadRowsPerSecond),
     DoneConsumingUpstreamTableOrSource(ConsumedRows),
     ConsumingLogStore { pending_barrier_num: usize },
     DoneConsumingLogStore,
@@ -36,24 +38,28 @@ pub(crate) enum BackfillState {
 
 impl BackfillState {
     pub fn to_pb(self, actor_id: ActorId) -> PbCreateMviewProgress {
-        let (done, consumed_epoch, consumed_rows, pending_barrier_num) = match self {
-            BackfillState::ConsumingUpstreamTableOrSource(consumed_epoch, consumed_rows) => {
-                (false, consumed_epoch, consumed_rows, 0)
-            }
-            BackfillState::DoneConsumingUpstreamTableOrSource(consumed_rows) => {
-                (true, 0, consumed_rows, 0)
-            } // unused field for done
-            BackfillState::ConsumingLogStore {
-                pending_barrier_num,
-            } => (false, 0, 0, pending_barrier_num as _),
-            BackfillState::DoneConsumingLogStore => (true, 0, 0, 0),
-        };
+        let (done, consumed_epoch, consumed_rows, pending_barrier_num, read_rows_per_second) =
+            match self {
+                BackfillState::ConsumingUpstreamTableOrSource(
+                    consumed_epoch,
+                    consumed_rows,
+                    read_rows_per_second,
+                ) => (false, consumed_epoch, consumed_rows, 0, read_rows_per_second),
+                BackfillState::DoneConsumingUpstreamTableOrSource(consumed_rows) => {
+                    (true, 0, consumed_rows, 0, 0.0)
+                } // unused field for done
+                BackfillState::ConsumingLogStore {
+                    pending_barrier_num,
+                } => (false, 0, 0, pending_barrier_num as _, 0.0),
+                BackfillState::DoneConsumingLogStore => (true, 0, 0, 0, 0.0),
+            };
         PbCreateMviewProgress {
             backfill_actor_id: actor_id,
             done,
             consumed_epoch,
             consumed_rows,
             pending_barrier_num,
+            read_rows_per_second,
         }
     }
 }
@@ -61,7 +67,7 @@ impl BackfillState {
 impl Display for BackfillState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            BackfillState::ConsumingUpstreamTableOrSource(epoch, rows) => {
+            BackfillState::ConsumingUpstreamTableOrSource(epoch, rows, _) => {
                 write!(
                     f,
                     "ConsumingUpstreamTable(epoch: {}, rows: {})",
@@ -155,6 +161,10 @@ pub struct CreateMviewProgressReporter {
     backfill_actor_id: ActorId,
 
     state: Option<BackfillState>,
+
+    /// The time and consumed rows of the last report, used to derive the achieved
+    /// snapshot read throughput.
+    throughput_probe: Option<(Instant, ConsumedRows)>,
 }
 
 impl CreateMviewProgressReporter {
@@ -163,6 +173,23 @@ impl CreateMviewProgressReporter {
             barrier_manager,
             backfill_actor_id,
             state: None,
+            throughput_probe: None,
+        }
+    }
+
+    /// Derive the snapshot read throughput (rows per second) since the last report.
+    fn probe_throughput(&mut self, current_consumed_rows: ConsumedRows) -> ReadRowsPerSecond {
+        let now = Instant::now();
+        match self.throughput_probe.replace((now, current_consumed_rows)) {
+            Some((last_time, last_consumed_rows)) => {
+                let elapsed = now.duration_since(last_time).as_secs_f64();
+                if elapsed > 0.0 {
+                    current_consumed_rows.saturating_sub(last_consumed_rows) as f64 / elapsed
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
         }
     }
 
@@ -192,7 +219,7 @@ impl CreateMviewProgressReporter {
         current_consumed_rows: ConsumedRows,
     ) {
         match self.state {
-            Some(BackfillState::ConsumingUpstreamTableOrSource(last, last_consumed_rows)) => {
+            Some(BackfillState::ConsumingUpstreamTableOrSource(last, last_consumed_rows, _)) => {
                 assert!(
                     last < consumed_epoch,
                     "last_epoch: {:#?} must be greater than consumed epoch: {:#?}",
@@ -209,9 +236,14 @@ impl CreateMviewProgressReporter {
             }
             None => {}
         };
+        let read_rows_per_second = self.probe_throughput(current_consumed_rows);
         self.update_inner(
             epoch,
-            BackfillState::ConsumingUpstreamTableOrSource(consumed_epoch, current_consumed_rows),
+            BackfillState::ConsumingUpstreamTableOrSource(
+                consumed_epoch,
+                current_consumed_rows,
+                read_rows_per_second,
+            ),
         );
     }
 
@@ -226,6 +258,7 @@ impl CreateMviewProgressReporter {
             Some(BackfillState::ConsumingUpstreamTableOrSource(
                 dummy_last_epoch,
                 _last_consumed_rows,
+                _,
             )) => {
                 debug_assert_eq!(dummy_last_epoch, 0);
             }
@@ -237,10 +270,15 @@ impl CreateMviewProgressReporter {
             }
             None => {}
         };
+        let read_rows_per_second = self.probe_throughput(current_consumed_rows);
         self.update_inner(
             epoch,
             // fill a dummy ConsumedEpoch
-            BackfillState::ConsumingUpstreamTableOrSource(0, current_consumed_rows),
+            BackfillState::ConsumingUpstreamTableOrSource(
+                0,
+                current_consumed_rows,
+                read_rows_per_second,
+            ),
         );
     }
 
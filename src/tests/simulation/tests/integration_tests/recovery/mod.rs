@@ -17,3 +17,4 @@ mod background_ddl;
 mod event_log;
 mod nexmark_recovery;
 mod pause_on_bootstrap;
+mod state_equivalence;
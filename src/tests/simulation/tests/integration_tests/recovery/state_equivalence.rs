@@ -0,0 +1,155 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use itertools::Itertools;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use risingwave_common::error::AsReport;
+use risingwave_simulation::cluster::{Cluster, Configuration, KillOpts};
+use risingwave_simulation::utils::AssertResult;
+use tokio::time::sleep;
+
+/// Runs `create` and the deterministic `insert_batches` twice: once uninterrupted to
+/// capture the expected result, and once with crash-recovery cycles injected at random
+/// points between batches, then asserts the final materialized state is identical.
+///
+/// A batch is retried after a crash even though it may have already committed, so the
+/// streaming queries under test must be insensitive to duplicated input rows, e.g.
+/// `max`/`min` or `count(distinct ..)`.
+async fn assert_state_equivalence_under_recovery(
+    create: &[&str],
+    insert_batches: &[String],
+    select: &str,
+    drop: &[&str],
+) -> Result<()> {
+    let mut cluster = Cluster::start(Configuration::for_scale()).await?;
+
+    // get the output without failures as the standard result
+    for sql in create {
+        cluster.run(*sql).await?;
+    }
+    for batch in insert_batches {
+        cluster.run(batch.as_str()).await?;
+    }
+    cluster.run("flush;").await?;
+    sleep(Duration::from_secs(30)).await;
+    let expected = cluster.run(select).await?;
+    for sql in drop {
+        cluster.run(*sql).await?;
+    }
+    sleep(Duration::from_secs(5)).await;
+
+    // replay the same batches, killing nodes at random points in between
+    for sql in create {
+        cluster.run(*sql).await?;
+    }
+    let mut rng = SmallRng::seed_from_u64(114514);
+    for batch in insert_batches {
+        if rng.gen_bool(0.3) {
+            cluster.kill_node(&KillOpts::ALL_FAST).await;
+            sleep(Duration::from_secs(2)).await;
+        }
+        run_with_retry(&mut cluster, batch).await?;
+    }
+    run_with_retry(&mut cluster, "flush;").await?;
+    sleep(Duration::from_secs(30)).await;
+
+    cluster.run(select).await?.assert_result_eq(&expected);
+
+    Ok(())
+}
+
+/// Runs a statement, retrying while the cluster is recovering from a crash.
+async fn run_with_retry(cluster: &mut Cluster, sql: &str) -> Result<String> {
+    const MAX_ATTEMPTS: usize = 10;
+    for i in 1..=MAX_ATTEMPTS {
+        match cluster.run(sql).await {
+            Ok(result) => return Ok(result),
+            Err(e) if i < MAX_ATTEMPTS => {
+                tracing::info!(error = %e.as_report(), "failed to run `{sql}`, retrying");
+                sleep(Duration::from_secs(2)).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    unreachable!()
+}
+
+/// Timestamps advance monotonically across batches so that the watermark closes
+/// one-hour tumble windows as batches arrive. The last batch pushes the watermark
+/// far ahead to close all remaining windows.
+fn hash_agg_eowc_inserts() -> Vec<String> {
+    let mut batches = (0..30)
+        .map(|i| {
+            let values = (0..16)
+                .map(|j| {
+                    format!(
+                        "('2023-01-01 00:00:00'::timestamp + interval '{}' minute, {})",
+                        i * 20 + j,
+                        (i * 31 + j * 17) % 97
+                    )
+                })
+                .join(", ");
+            format!("insert into t values {values};")
+        })
+        .collect_vec();
+    batches.push("insert into t values ('2030-01-01 00:00:00', 0);".to_owned());
+    batches
+}
+
+fn distinct_dedup_inserts() -> Vec<String> {
+    (0..30)
+        .map(|i| {
+            let values = (0..16)
+                .map(|j| format!("({}, {})", (i + j) % 10, (i * j) % 23))
+                .join(", ");
+            format!("insert into t values {values};")
+        })
+        .collect_vec()
+}
+
+#[tokio::test]
+async fn recovery_state_equivalence_hash_agg_eowc() -> Result<()> {
+    assert_state_equivalence_under_recovery(
+        &[
+            "create table t (tm timestamp, v int, watermark for tm as tm - interval '5 minutes') append only;",
+            "create materialized view mv as \
+             select window_start, max(v) as max_v, min(v) as min_v \
+             from tumble(t, tm, interval '1 hour') \
+             group by window_start emit on window close;",
+        ],
+        &hash_agg_eowc_inserts(),
+        "select * from mv order by window_start;",
+        &["drop materialized view mv;", "drop table t;"],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn recovery_state_equivalence_distinct_dedup() -> Result<()> {
+    assert_state_equivalence_under_recovery(
+        &[
+            "create table t (v1 int, v2 int);",
+            "create materialized view mv as \
+             select v1, count(distinct v2) as cnt from t group by v1;",
+        ],
+        &distinct_dedup_inserts(),
+        "select * from mv order by v1;",
+        &["drop materialized view mv;", "drop table t;"],
+    )
+    .await
+}
@@ -0,0 +1,103 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::Ordering::Relaxed;
+use std::time::Duration;
+
+use anyhow::Result;
+use risingwave_simulation::cluster::KillOpts;
+use tokio::time::sleep;
+
+use crate::sink::utils::{
+    start_sink_test_cluster, SimulationTestSink, SimulationTestSource, CREATE_SINK, CREATE_SOURCE,
+    DROP_SINK, DROP_SOURCE,
+};
+use crate::{assert_eq_with_err_returned as assert_eq, assert_with_err_returned as assert};
+
+/// Runs the coordinated (two-phase commit) sink path while killing nodes with `kill_opts`,
+/// and checks that every source row is committed by the coordinator exactly once.
+async fn coordinated_sink_test_inner(kill_opts: KillOpts) -> Result<()> {
+    let mut cluster = start_sink_test_cluster().await?;
+
+    let source_parallelism = 6;
+
+    let test_sink = SimulationTestSink::register_new_coordinated();
+    let test_source = SimulationTestSource::register_new(source_parallelism, 0..100000, 0.2, 20);
+
+    let mut session = cluster.start_session();
+
+    session.run("set streaming_parallelism = 6").await?;
+    session.run("set sink_decouple = false").await?;
+    session.run(CREATE_SOURCE).await?;
+    session.run(CREATE_SINK).await?;
+    test_sink.wait_initial_parallelism(6).await?;
+
+    let count = test_source.id_list.len();
+
+    let mut prev_count = 0;
+    sleep(Duration::from_secs(2)).await;
+    for i in 0..5 {
+        let curr_count = test_sink.store.id_count();
+        if curr_count == count {
+            assert!(i > 0, "test finish without kill");
+            break;
+        }
+        assert!(
+            curr_count >= prev_count,
+            "not make progress between kill. Prev count {}, curr count {}, i {}",
+            prev_count,
+            curr_count,
+            i
+        );
+        prev_count = curr_count;
+        cluster.kill_node(&kill_opts).await;
+        sleep(Duration::from_secs(10)).await;
+    }
+
+    test_sink.store.wait_for_count(count).await?;
+
+    let mut session = cluster.start_session();
+    session.run(DROP_SINK).await?;
+    session.run(DROP_SOURCE).await?;
+
+    assert_eq!(0, test_sink.parallelism_counter.load(Relaxed));
+    assert!(test_sink.store.inner().checkpoint_count > 0);
+
+    // No data loss: every sampled source row made it through the coordinator.
+    test_sink.store.check_simple_result(&test_source.id_list)?;
+    // No duplication: the coordinator never committed the same epoch twice.
+    assert_eq!(0, test_sink.store.inner().duplicate_commit_count);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_coordinated_sink_recovery() -> Result<()> {
+    coordinated_sink_test_inner(KillOpts::ALL).await
+}
+
+/// Kills only compactor nodes, so that the shared compactor dispatch path keeps
+/// re-establishing its event streams while the sink two-phase commit path is running.
+#[tokio::test]
+async fn test_coordinated_sink_compactor_crash() -> Result<()> {
+    coordinated_sink_test_inner(KillOpts {
+        kill_rate: 1.0,
+        kill_meta: false,
+        kill_frontend: false,
+        kill_compute: false,
+        kill_compactor: true,
+        restart_delay_secs: 20,
+    })
+    .await
+}
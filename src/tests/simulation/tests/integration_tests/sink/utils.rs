@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::iter::once;
 use std::sync::atomic::Ordering::Relaxed;
@@ -32,9 +32,13 @@ use risingwave_common::array::{Op, StreamChunk};
 use risingwave_common::row::Row;
 use risingwave_common::types::{DataType, ScalarImpl, Serial};
 use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
-use risingwave_connector::sink::test_sink::{registry_build_sink, TestSinkRegistryGuard};
+use risingwave_connector::sink::test_sink::{
+    registry_build_coordinated_sink, registry_build_sink, TestSinkRegistryGuard,
+};
 use risingwave_connector::sink::writer::SinkWriter;
-use risingwave_connector::sink::SinkError;
+use risingwave_connector::sink::{SinkCommitCoordinator, SinkError};
+use risingwave_pb::connector_service::sink_metadata::{Metadata, SerializedMetadata};
+use risingwave_pb::connector_service::SinkMetadata;
 use risingwave_connector::source::test_source::{
     registry_test_source, BoxSource, TestSourceRegistryGuard, TestSourceSplit,
 };
@@ -54,6 +58,8 @@ pub struct TestSinkStoreInner {
     pub epochs: Vec<u64>,
     pub checkpoint_count: usize,
     pub err_count: usize,
+    pub committed_epochs: HashSet<u64>,
+    pub duplicate_commit_count: usize,
 }
 
 #[derive(Clone)]
@@ -69,6 +75,8 @@ impl TestSinkStore {
                 epochs: Vec::new(),
                 checkpoint_count: 0,
                 err_count: 0,
+                committed_epochs: HashSet::new(),
+                duplicate_commit_count: 0,
             })),
         }
     }
@@ -98,6 +106,20 @@ impl TestSinkStore {
         Ok(())
     }
 
+    /// Commits the rows of `epoch` collected by the coordinator. Rows of an epoch that has
+    /// been committed before are dropped and counted, so that a test can assert that the
+    /// coordinator never commits the same epoch twice.
+    pub fn commit_epoch(&self, epoch: u64, rows: Vec<(i32, String)>) {
+        let mut inner = self.inner();
+        if !inner.committed_epochs.insert(epoch) {
+            inner.duplicate_commit_count += 1;
+            return;
+        }
+        for (id, name) in rows {
+            inner.id_name.entry(id).or_default().push(name);
+        }
+    }
+
     pub fn id_count(&self) -> usize {
         self.inner().id_name.len()
     }
@@ -202,6 +224,105 @@ impl Drop for TestWriter {
     }
 }
 
+/// A sink writer for the two-phase commit path: rows are buffered locally and only become
+/// visible in the [`TestSinkStore`] after [`TestCoordinator`] commits the metadata of the
+/// checkpoint epoch, mirroring how coordinated sinks publish data externally.
+pub struct CoordinatedTestWriter {
+    store: TestSinkStore,
+    parallelism_counter: Arc<AtomicUsize>,
+    buffer: Vec<(i32, String)>,
+}
+
+impl CoordinatedTestWriter {
+    pub fn new(store: TestSinkStore, parallelism_counter: Arc<AtomicUsize>) -> Self {
+        Self {
+            store,
+            parallelism_counter,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl SinkWriter for CoordinatedTestWriter {
+    type CommitMetadata = Option<SinkMetadata>;
+
+    async fn begin_epoch(&mut self, epoch: u64) -> risingwave_connector::sink::Result<()> {
+        self.store.begin_epoch(epoch);
+        Ok(())
+    }
+
+    async fn write_batch(&mut self, chunk: StreamChunk) -> risingwave_connector::sink::Result<()> {
+        for (op, row) in chunk.rows() {
+            assert_eq!(op, Op::Insert);
+            assert_eq!(row.len(), 2);
+            let id = row.datum_at(0).unwrap().into_int32();
+            let name = row.datum_at(1).unwrap().into_utf8().to_string();
+            self.buffer.push((id, name));
+        }
+        Ok(())
+    }
+
+    async fn barrier(
+        &mut self,
+        is_checkpoint: bool,
+    ) -> risingwave_connector::sink::Result<Self::CommitMetadata> {
+        if !is_checkpoint {
+            return Ok(None);
+        }
+        self.store.inner().checkpoint_count += 1;
+        let metadata = serde_json::to_vec(&std::mem::take(&mut self.buffer))
+            .map_err(|e| SinkError::Internal(e.into()))?;
+        Ok(Some(SinkMetadata {
+            metadata: Some(Metadata::Serialized(SerializedMetadata { metadata })),
+        }))
+    }
+}
+
+impl Drop for CoordinatedTestWriter {
+    fn drop(&mut self) {
+        self.parallelism_counter.fetch_sub(1, Relaxed);
+    }
+}
+
+pub struct TestCoordinator {
+    store: TestSinkStore,
+}
+
+impl TestCoordinator {
+    pub fn new(store: TestSinkStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl SinkCommitCoordinator for TestCoordinator {
+    async fn init(&mut self) -> risingwave_connector::sink::Result<()> {
+        Ok(())
+    }
+
+    async fn commit(
+        &mut self,
+        epoch: u64,
+        metadata: Vec<SinkMetadata>,
+    ) -> risingwave_connector::sink::Result<()> {
+        let mut rows: Vec<(i32, String)> = Vec::new();
+        for metadata in metadata {
+            let Some(Metadata::Serialized(serialized)) = metadata.metadata else {
+                return Err(SinkError::Coordinator(anyhow::anyhow!(
+                    "expect serialized metadata"
+                )));
+            };
+            rows.extend(
+                serde_json::from_slice::<Vec<(i32, String)>>(&serialized.metadata)
+                    .map_err(|e| SinkError::Coordinator(e.into()))?,
+            );
+        }
+        self.store.commit_epoch(epoch, rows);
+        Ok(())
+    }
+}
+
 pub fn simple_name_of_id(id: i32) -> String {
     format!("name-{}", id)
 }
@@ -241,6 +362,40 @@ impl SimulationTestSink {
         }
     }
 
+    /// Like [`SimulationTestSink::register_new`], but the registered sink writes through the
+    /// coordinated two-phase commit path: writers emit metadata on checkpoint barriers, and
+    /// rows reach the store only when [`TestCoordinator`] commits them on the meta node.
+    pub fn register_new_coordinated() -> Self {
+        let parallelism_counter = Arc::new(AtomicUsize::new(0));
+        let err_rate = Arc::new(AtomicU32::new(0));
+        let store = TestSinkStore::new();
+
+        let _sink_guard = registry_build_coordinated_sink(
+            {
+                let parallelism_counter = parallelism_counter.clone();
+                let store = store.clone();
+                move |_, _| {
+                    parallelism_counter.fetch_add(1, Relaxed);
+                    Box::new(CoordinatedTestWriter::new(
+                        store.clone(),
+                        parallelism_counter.clone(),
+                    ))
+                }
+            },
+            {
+                let store = store.clone();
+                move |_| Box::new(TestCoordinator::new(store.clone()))
+            },
+        );
+
+        Self {
+            _sink_guard,
+            parallelism_counter,
+            store,
+            err_rate,
+        }
+    }
+
     pub fn set_err_rate(&self, err_rate: f64) {
         let err_rate = u32::MAX as f64 * err_rate;
         self.err_rate.store(err_rate as _, Relaxed);
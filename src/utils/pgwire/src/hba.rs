@@ -0,0 +1,188 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use crate::net::Address;
+
+/// HBA-style rules restricting which client hosts may connect, in the spirit of
+/// `pg_hba.conf` host entries.
+///
+/// Rules are evaluated in order and the first one whose CIDR range contains the client
+/// address wins. If no rule is configured at all, every host is permitted; if rules are
+/// configured but none matches, the connection is rejected, like PostgreSQL does.
+/// Connections over unix domain sockets are always permitted.
+#[derive(Debug, Clone, Default)]
+pub struct HbaRules {
+    rules: Vec<HbaRule>,
+}
+
+#[derive(Debug, Clone)]
+struct HbaRule {
+    action: HbaAction,
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HbaAction {
+    Allow,
+    Reject,
+}
+
+impl FromStr for HbaRules {
+    type Err = String;
+
+    /// Parses a comma-separated list of rules, each in the form `allow <CIDR>` or
+    /// `reject <CIDR>`, e.g. `allow 10.0.0.0/8,reject 0.0.0.0/0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules = s
+            .split(',')
+            .map(|r| r.trim())
+            .filter(|r| !r.is_empty())
+            .map(HbaRule::from_str)
+            .try_collect()?;
+        Ok(Self { rules })
+    }
+}
+
+impl FromStr for HbaRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (action, cidr) = s
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| format!("expect `allow <CIDR>` or `reject <CIDR>`, got `{s}`"))?;
+        let action = match action {
+            "allow" => HbaAction::Allow,
+            "reject" => HbaAction::Reject,
+            _ => return Err(format!("expect `allow` or `reject`, got `{action}`")),
+        };
+        let (addr, prefix_len) = match cidr.trim().split_once('/') {
+            Some((addr, prefix_len)) => {
+                let addr: IpAddr = addr
+                    .parse()
+                    .map_err(|_| format!("invalid IP address `{addr}`"))?;
+                let prefix_len: u8 = prefix_len
+                    .parse()
+                    .map_err(|_| format!("invalid prefix length `{prefix_len}`"))?;
+                let max_len = match addr {
+                    IpAddr::V4(_) => 32,
+                    IpAddr::V6(_) => 128,
+                };
+                if prefix_len > max_len {
+                    return Err(format!("prefix length `{prefix_len}` out of range"));
+                }
+                (addr, prefix_len)
+            }
+            None => {
+                let addr: IpAddr = cidr
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("invalid IP address `{cidr}`"))?;
+                let prefix_len = match addr {
+                    IpAddr::V4(_) => 32,
+                    IpAddr::V6(_) => 128,
+                };
+                (addr, prefix_len)
+            }
+        };
+        Ok(Self {
+            action,
+            addr,
+            prefix_len,
+        })
+    }
+}
+
+impl HbaRules {
+    /// Returns whether a client at the given address is permitted to connect.
+    pub fn permits(&self, addr: &Address) -> bool {
+        // Connections over unix domain sockets are always local, hence always permitted.
+        let Address::Tcp(socket_addr) = addr else {
+            return true;
+        };
+        if self.rules.is_empty() {
+            return true;
+        }
+        let ip = socket_addr.ip();
+        for rule in &self.rules {
+            if rule.matches(ip) {
+                return rule.action == HbaAction::Allow;
+            }
+        }
+        false
+    }
+}
+
+impl HbaRule {
+    fn matches(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                (u32::from(net) & mask) == (u32::from(ip) & mask)
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                (u128::from(net) & mask) == (u128::from(ip) & mask)
+            }
+            // Rules never match addresses of a different family.
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tcp(addr: &str) -> Address {
+        Address::Tcp(addr.parse().unwrap())
+    }
+
+    #[test]
+    fn test_hba_rules() {
+        let rules: HbaRules = "allow 10.0.0.0/8, reject 0.0.0.0/0".parse().unwrap();
+        assert!(rules.permits(&tcp("10.1.2.3:4566")));
+        assert!(!rules.permits(&tcp("192.168.1.1:4566")));
+
+        // First match wins.
+        let rules: HbaRules = "reject 10.1.0.0/16, allow 10.0.0.0/8".parse().unwrap();
+        assert!(!rules.permits(&tcp("10.1.2.3:4566")));
+        assert!(rules.permits(&tcp("10.2.2.3:4566")));
+
+        // No rules at all: permit everything.
+        let rules = HbaRules::default();
+        assert!(rules.permits(&tcp("192.168.1.1:4566")));
+
+        // Rules configured but none matched: reject.
+        let rules: HbaRules = "allow 127.0.0.1".parse().unwrap();
+        assert!(rules.permits(&tcp("127.0.0.1:4566")));
+        assert!(!rules.permits(&tcp("192.168.1.1:4566")));
+
+        // IPv6.
+        let rules: HbaRules = "allow ::1/128".parse().unwrap();
+        assert!(rules.permits(&tcp("[::1]:4566")));
+        assert!(!rules.permits(&tcp("[2001:db8::1]:4566")));
+
+        "allow".parse::<HbaRules>().unwrap_err();
+        "deny 10.0.0.0/8".parse::<HbaRules>().unwrap_err();
+        "allow 10.0.0.0/33".parse::<HbaRules>().unwrap_err();
+    }
+}
@@ -22,6 +22,7 @@
 
 pub mod error;
 pub mod error_or_notice;
+pub mod hba;
 pub mod net;
 pub mod pg_extended;
 pub mod pg_field_descriptor;
@@ -29,4 +30,5 @@ pub mod pg_message;
 pub mod pg_protocol;
 pub mod pg_response;
 pub mod pg_server;
+pub mod scram;
 pub mod types;
@@ -109,7 +109,10 @@ pub struct FeParseMessage {
 
 #[derive(Debug)]
 pub struct FePasswordMessage {
-    pub password: Bytes,
+    /// The raw message body. During a SASL exchange the same message tag carries the
+    /// SASL payload (`SASLInitialResponse` or `SASLResponse`) instead of a password, so
+    /// the interpretation is up to the protocol state.
+    pub body: Bytes,
 }
 
 #[derive(Debug)]
@@ -231,10 +234,14 @@ impl FeParseMessage {
 }
 
 impl FePasswordMessage {
-    pub fn parse(mut buf: Bytes) -> Result<FeMessage> {
-        let password = read_null_terminated(&mut buf)?;
+    pub fn parse(buf: Bytes) -> Result<FeMessage> {
+        Ok(FeMessage::Password(FePasswordMessage { body: buf }))
+    }
 
-        Ok(FeMessage::Password(FePasswordMessage { password }))
+    /// Interpret the body as a `PasswordMessage`, i.e. a null-terminated password.
+    pub fn password(&self) -> Result<Bytes> {
+        let mut buf = self.body.clone();
+        read_null_terminated(&mut buf)
     }
 }
 
@@ -381,6 +388,12 @@ pub enum BeMessage<'a> {
     AuthenticationOk,
     AuthenticationCleartextPassword,
     AuthenticationMd5Password(&'a [u8; 4]),
+    // Request SASL authentication with the given list of mechanisms.
+    AuthenticationSasl(&'a [&'a str]),
+    // SASL challenge data, `server-first-message` for SCRAM.
+    AuthenticationSaslContinue(&'a [u8]),
+    // SASL outcome data, `server-final-message` for SCRAM.
+    AuthenticationSaslFinal(&'a [u8]),
     CommandComplete(BeCommandCompleteMessage),
     NoticeResponse(&'a str),
     // Single byte - used in response to SSLRequest/GSSENCRequest.
@@ -464,6 +477,45 @@ impl BeMessage<'_> {
                 buf.put_slice(&salt[..]);
             }
 
+            // AuthenticationSASL
+            // +-----+-----------+-----------+----------------+------+------+
+            // | 'R' | int32 len | int32(10) | str mechanism1 | '\0' | ...  | '\0' |
+            // +-----+-----------+-----------+----------------+------+------+
+            //
+            // The list of mechanisms is terminated by an extra zero byte.
+            BeMessage::AuthenticationSasl(mechanisms) => {
+                buf.put_u8(b'R');
+                let len: i32 = 8 + mechanisms.iter().map(|m| m.len() as i32 + 1).sum::<i32>() + 1;
+                buf.put_i32(len);
+                buf.put_i32(10);
+                for mechanism in *mechanisms {
+                    write_cstr(buf, mechanism.as_bytes())?;
+                }
+                buf.put_u8(0);
+            }
+
+            // AuthenticationSASLContinue
+            // +-----+-----------+-----------+------------+
+            // | 'R' | int32 len | int32(11) | byte* data |
+            // +-----+-----------+-----------+------------+
+            BeMessage::AuthenticationSaslContinue(data) => {
+                buf.put_u8(b'R');
+                buf.put_i32(8 + data.len() as i32);
+                buf.put_i32(11);
+                buf.put_slice(data);
+            }
+
+            // AuthenticationSASLFinal
+            // +-----+-----------+-----------+------------+
+            // | 'R' | int32 len | int32(12) | byte* data |
+            // +-----+-----------+-----------+------------+
+            BeMessage::AuthenticationSaslFinal(data) => {
+                buf.put_u8(b'R');
+                buf.put_i32(8 + data.len() as i32);
+                buf.put_i32(12);
+                buf.put_slice(data);
+            }
+
             // ParameterStatus
             // +-----+-----------+----------+------+-----------+------+
             // | 'S' | int32 len | str name | '\0' | str value | '\0' |
@@ -45,6 +45,7 @@ use crate::pg_message::{
     FePasswordMessage, FeStartupMessage, TransactionStatus,
 };
 use crate::pg_server::{Session, SessionManager, UserAuthenticator};
+use crate::scram::{self, ScramServer};
 use crate::types::Format;
 
 /// Truncates query log if it's longer than `RW_QUERY_LOG_TRUNCATE_LEN`, to avoid log file being too
@@ -103,6 +104,9 @@ where
     peer_addr: AddressRef,
 
     redact_sql_option_keywords: Option<RedactSqlOptionKeywordsRef>,
+
+    // The state of an ongoing SCRAM-SHA-256 exchange, if any.
+    scram_server: Option<ScramServer>,
 }
 
 /// Configures TLS encryption for connections.
@@ -212,6 +216,7 @@ where
             ignore_util_sync: false,
             peer_addr,
             redact_sql_option_keywords,
+            scram_server: None,
         }
     }
 
@@ -368,7 +373,9 @@ where
                         self.ready_for_query().ok()?;
                     }
 
-                    PsqlError::IdleInTxnTimeout | PsqlError::Panic(_) => {
+                    PsqlError::IdleInTxnTimeout
+                    | PsqlError::IdleSessionTimeout
+                    | PsqlError::Panic(_) => {
                         self.stream
                             .write_no_flush(&BeMessage::ErrorResponse(Box::new(e)))
                             .ok()?;
@@ -377,7 +384,8 @@ where
                         // 1. Catching the panic during message processing may leave the session in an
                         // inconsistent state. We forcefully close the connection (then end the
                         // session) here for safety.
-                        // 2. Idle in transaction timeout should also close the connection.
+                        // 2. Idle in transaction and idle session timeouts should also close the
+                        // connection.
                         return None;
                     }
 
@@ -544,6 +552,10 @@ where
                 self.stream
                     .write_no_flush(&BeMessage::AuthenticationMd5Password(salt))?;
             }
+            UserAuthenticator::ScramSha256(_) => {
+                self.stream
+                    .write_no_flush(&BeMessage::AuthenticationSasl(&[scram::SCRAM_SHA_256]))?;
+            }
         }
 
         self.session = Some(session);
@@ -553,7 +565,38 @@ where
 
     async fn process_password_msg(&mut self, msg: FePasswordMessage) -> PsqlResult<()> {
         let authenticator = self.session.as_ref().unwrap().user_authenticator();
-        authenticator.authenticate(&msg.password).await?;
+        if let UserAuthenticator::ScramSha256(verifier) = authenticator {
+            match self.scram_server.as_mut() {
+                None => {
+                    // `SASLInitialResponse`: start the exchange and challenge the client
+                    // with the server-first-message, expecting another password message.
+                    let mut server = ScramServer::new(verifier.clone());
+                    let server_first = scram::parse_sasl_initial_response(&msg.body)
+                        .and_then(|client_first| server.handle_client_first(client_first))
+                        .map_err(|e| PsqlError::StartupError(e.into()))?;
+                    self.scram_server = Some(server);
+                    self.stream
+                        .write_no_flush(&BeMessage::AuthenticationSaslContinue(
+                            server_first.as_bytes(),
+                        ))?;
+                    return Ok(());
+                }
+                Some(server) => {
+                    // `SASLResponse`: verify the client proof in the client-final-message.
+                    let server_final = server
+                        .handle_client_final(&msg.body)
+                        .map_err(|e| PsqlError::StartupError(e.into()))?;
+                    self.scram_server = None;
+                    let server_final = server_final.ok_or(PsqlError::PasswordError)?;
+                    self.stream
+                        .write_no_flush(&BeMessage::AuthenticationSaslFinal(
+                            server_final.as_bytes(),
+                        ))?;
+                }
+            }
+        } else {
+            authenticator.authenticate(&msg.password()?).await?;
+        }
         self.stream.write_no_flush(&BeMessage::AuthenticationOk)?;
         self.stream
             .write_parameter_status_msg_no_flush(&ParameterStatus::default())?;
@@ -578,6 +621,7 @@ where
         let session = self.session.clone().unwrap();
 
         session.check_idle_in_transaction_timeout()?;
+        session.check_idle_session_timeout()?;
         let _exec_context_guard = session.init_exec_context(sql.clone());
         self.inner_process_query_msg(sql.clone(), session.clone())
             .await
@@ -613,12 +657,27 @@ where
     ) -> PsqlResult<()> {
         let session = session.clone();
 
-        // execute query
-        let res = session
-            .clone()
-            .run_one_query(stmt.clone(), Format::Text)
-            .await;
-        for notice in session.take_notices() {
+        // Execute the query, delivering notices generated by the running statement (e.g. DDL
+        // progress reports) to the client timely instead of after the statement finishes.
+        let mut query = std::pin::pin!(session.clone().run_one_query(stmt.clone(), Format::Text));
+        let mut notice_interval = tokio::time::interval(Duration::from_secs(1));
+        notice_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let res = loop {
+            tokio::select! {
+                res = &mut query => break res,
+                _ = notice_interval.tick() => {
+                    let notices = session.clone().take_notices();
+                    if !notices.is_empty() {
+                        for notice in notices {
+                            self.stream
+                                .write_no_flush(&BeMessage::NoticeResponse(&notice))?;
+                        }
+                        self.stream.flush().await?;
+                    }
+                }
+            }
+        };
+        for notice in session.clone().take_notices() {
             self.stream
                 .write_no_flush(&BeMessage::NoticeResponse(&notice))?;
         }
@@ -850,6 +909,7 @@ where
             record_sql_in_span(&sql, self.redact_sql_option_keywords.clone());
 
             session.check_idle_in_transaction_timeout()?;
+            session.check_idle_session_timeout()?;
             let _exec_context_guard = session.init_exec_context(sql.clone());
             let result = session.clone().execute(portal).await;
 
@@ -58,6 +58,7 @@ pub enum StatementType {
     CREATE_FUNCTION,
     CREATE_CONNECTION,
     CREATE_SECRET,
+    VALIDATE_SOURCE,
     COMMENT,
     DECLARE_CURSOR,
     DESCRIBE,
@@ -269,6 +270,7 @@ impl StatementType {
             Statement::CreateIndex { .. } => Ok(StatementType::CREATE_INDEX),
             Statement::CreateSchema { .. } => Ok(StatementType::CREATE_SCHEMA),
             Statement::CreateSource { .. } => Ok(StatementType::CREATE_SOURCE),
+            Statement::ValidateSource { .. } => Ok(StatementType::VALIDATE_SOURCE),
             Statement::CreateSink { .. } => Ok(StatementType::CREATE_SINK),
             Statement::CreateFunction { .. } => Ok(StatementType::CREATE_FUNCTION),
             Statement::CreateDatabase { .. } => Ok(StatementType::CREATE_DATABASE),
@@ -294,6 +296,7 @@ impl StatementType {
             Statement::Grant { .. } => Ok(StatementType::GRANT_PRIVILEGE),
             Statement::Revoke { .. } => Ok(StatementType::REVOKE_PRIVILEGE),
             Statement::Describe { .. } => Ok(StatementType::DESCRIBE),
+            Statement::DescribeExternalTable { .. } => Ok(StatementType::DESCRIBE),
             Statement::ShowCreateObject { .. } | Statement::ShowObjects { .. } => {
                 Ok(StatementType::SHOW_COMMAND)
             }
@@ -369,6 +372,7 @@ impl StatementType {
                 | StatementType::UPDATE_RETURNING
                 | StatementType::CANCEL_COMMAND
                 | StatementType::FETCH_CURSOR
+                | StatementType::VALIDATE_SOURCE
         )
     }
 
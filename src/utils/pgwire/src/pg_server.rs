@@ -30,11 +30,13 @@ use thiserror_ext::AsReport;
 use tokio::io::{AsyncRead, AsyncWrite};
 
 use crate::error::{PsqlError, PsqlResult};
+use crate::hba::HbaRules;
 use crate::net::{AddressRef, Listener, TcpKeepalive};
 use crate::pg_field_descriptor::PgFieldDescriptor;
 use crate::pg_message::TransactionStatus;
 use crate::pg_protocol::{PgProtocol, TlsConfig};
 use crate::pg_response::{PgResponse, ValuesStream};
+use crate::scram::ScramVerifier;
 use crate::types::Format;
 
 pub type BoxedError = Box<dyn std::error::Error + Send + Sync>;
@@ -133,6 +135,8 @@ pub trait Session: Send + Sync {
     fn init_exec_context(&self, sql: Arc<str>) -> ExecContextGuard;
 
     fn check_idle_in_transaction_timeout(&self) -> PsqlResult<()>;
+
+    fn check_idle_session_timeout(&self) -> PsqlResult<()>;
 }
 
 /// Each session could run different SQLs multiple times.
@@ -173,6 +177,8 @@ pub enum UserAuthenticator {
         salt: [u8; 4],
     },
     OAuth(HashMap<String, String>),
+    // SCRAM-SHA-256 verifier, authenticated via the SASL exchange in the protocol layer.
+    ScramSha256(ScramVerifier),
 }
 
 /// A JWK Set is a JSON object that represents a set of JWKs.
@@ -252,6 +258,13 @@ impl UserAuthenticator {
                 .await
                 .map_err(PsqlError::StartupError)?
             }
+            // The multi-step SASL exchange is driven by the protocol layer and never goes
+            // through a plain password message.
+            UserAuthenticator::ScramSha256(_) => {
+                return Err(PsqlError::StartupError(
+                    "SCRAM-SHA-256 authentication requires a SASL exchange".into(),
+                ));
+            }
         };
         if !success {
             return Err(PsqlError::PasswordError);
@@ -268,6 +281,7 @@ pub async fn pg_serve(
     tcp_keepalive: TcpKeepalive,
     session_mgr: Arc<impl SessionManager>,
     tls_config: Option<TlsConfig>,
+    hba_rules: HbaRules,
     redact_sql_option_keywords: Option<RedactSqlOptionKeywordsRef>,
     shutdown: CancellationToken,
 ) -> Result<(), BoxedError> {
@@ -295,6 +309,10 @@ pub async fn pg_serve(
             let conn_ret = listener.accept(&tcp_keepalive).await;
             match conn_ret {
                 Ok((stream, peer_addr)) => {
+                    if !hba_rules.permits(&peer_addr) {
+                        tracing::warn!(%peer_addr, "connection rejected by hba rules");
+                        continue;
+                    }
                     tracing::info!(%peer_addr, "accept connection");
                     worker_runtime.spawn(handle_connection(
                         stream,
@@ -525,6 +543,10 @@ mod tests {
         fn check_idle_in_transaction_timeout(&self) -> PsqlResult<()> {
             Ok(())
         }
+
+        fn check_idle_session_timeout(&self) -> PsqlResult<()> {
+            Ok(())
+        }
     }
 
     async fn do_test_query(bind_addr: impl Into<String>, pg_config: impl Into<String>) {
@@ -538,6 +560,7 @@ mod tests {
                 socket2::TcpKeepalive::new(),
                 Arc::new(session_mgr),
                 None,
+                Default::default(),
                 None,
                 CancellationToken::new(), // dummy
             )
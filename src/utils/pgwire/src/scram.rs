@@ -0,0 +1,364 @@
+// Copyright 2025 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Server-side SCRAM-SHA-256 authentication, following RFC 5802 and the PostgreSQL
+//! SASL exchange in the startup protocol.
+
+use std::fmt;
+use std::str::FromStr;
+
+use openssl::base64::{decode_block, encode_block};
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+
+/// The only SASL mechanism we support. Channel binding (`SCRAM-SHA-256-PLUS`) is not
+/// offered.
+pub const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+
+/// The number of PBKDF2 iterations used when building a new verifier, same as the
+/// PostgreSQL default.
+const SCRAM_DEFAULT_ITERATIONS: u32 = 4096;
+const SCRAM_SALT_LEN: usize = 16;
+const SCRAM_NONCE_LEN: usize = 18;
+
+type ScramError = String;
+
+fn hmac_sha_256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let key = PKey::hmac(key).unwrap();
+    let mut signer = Signer::new(MessageDigest::sha256(), &key).unwrap();
+    signer.update(data).unwrap();
+    signer.sign_to_vec().unwrap()
+}
+
+fn sha_256(data: &[u8]) -> Vec<u8> {
+    openssl::hash::hash(MessageDigest::sha256(), data)
+        .unwrap()
+        .to_vec()
+}
+
+/// A SCRAM-SHA-256 verifier as stored in the user catalog, in the PostgreSQL
+/// `rolpassword` format:
+///
+/// ```text
+/// SCRAM-SHA-256$<iterations>:<salt>$<StoredKey>:<ServerKey>
+/// ```
+///
+/// where `salt`, `StoredKey` and `ServerKey` are base64-encoded. The plain password
+/// cannot be recovered from the verifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScramVerifier {
+    iterations: u32,
+    salt: Vec<u8>,
+    stored_key: Vec<u8>,
+    server_key: Vec<u8>,
+}
+
+impl ScramVerifier {
+    /// Build a new verifier for the given plain password with a random salt.
+    pub fn build(password: &str) -> Self {
+        let mut salt = [0; SCRAM_SALT_LEN];
+        openssl::rand::rand_bytes(&mut salt).unwrap();
+
+        let mut salted_password = [0; 32];
+        openssl::pkcs5::pbkdf2_hmac(
+            password.as_bytes(),
+            &salt,
+            SCRAM_DEFAULT_ITERATIONS as usize,
+            MessageDigest::sha256(),
+            &mut salted_password,
+        )
+        .unwrap();
+
+        let client_key = hmac_sha_256(&salted_password, b"Client Key");
+        let server_key = hmac_sha_256(&salted_password, b"Server Key");
+        Self {
+            iterations: SCRAM_DEFAULT_ITERATIONS,
+            salt: salt.to_vec(),
+            stored_key: sha_256(&client_key),
+            server_key,
+        }
+    }
+}
+
+impl fmt::Display for ScramVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}${}:{}${}:{}",
+            SCRAM_SHA_256,
+            self.iterations,
+            encode_block(&self.salt),
+            encode_block(&self.stored_key),
+            encode_block(&self.server_key),
+        )
+    }
+}
+
+impl FromStr for ScramVerifier {
+    type Err = ScramError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid SCRAM-SHA-256 verifier `{s}`");
+
+        let (mechanism, rest) = s.split_once('$').ok_or_else(invalid)?;
+        if mechanism != SCRAM_SHA_256 {
+            return Err(invalid());
+        }
+        let (params, keys) = rest.split_once('$').ok_or_else(invalid)?;
+        let (iterations, salt) = params.split_once(':').ok_or_else(invalid)?;
+        let (stored_key, server_key) = keys.split_once(':').ok_or_else(invalid)?;
+
+        let iterations: u32 = iterations.parse().map_err(|_| invalid())?;
+        if iterations == 0 {
+            return Err(invalid());
+        }
+        let salt = decode_block(salt).map_err(|_| invalid())?;
+        let stored_key = decode_block(stored_key).map_err(|_| invalid())?;
+        let server_key = decode_block(server_key).map_err(|_| invalid())?;
+        if stored_key.len() != 32 || server_key.len() != 32 {
+            return Err(invalid());
+        }
+        Ok(Self {
+            iterations,
+            salt,
+            stored_key,
+            server_key,
+        })
+    }
+}
+
+/// Returns whether the given password is a pre-computed SCRAM-SHA-256 verifier, to be
+/// stored as-is like md5- or SHA-256-encrypted passwords.
+pub fn is_scram_sha_256_verifier(password: &str) -> bool {
+    ScramVerifier::from_str(password).is_ok()
+}
+
+/// Extracts the `client-first-message` from a `SASLInitialResponse` message body and
+/// checks that the selected mechanism is `SCRAM-SHA-256`.
+pub fn parse_sasl_initial_response(body: &[u8]) -> Result<&[u8], ScramError> {
+    let nul = body
+        .iter()
+        .position(|&b| b == 0)
+        .ok_or("malformed SASLInitialResponse")?;
+    let mechanism = std::str::from_utf8(&body[..nul]).map_err(|e| e.to_string())?;
+    if mechanism != SCRAM_SHA_256 {
+        return Err(format!("unsupported SASL mechanism `{mechanism}`"));
+    }
+    // The mechanism name is followed by the length of the initial response, which we can
+    // simply check against the remainder of the message.
+    let rest = &body[nul + 1..];
+    if rest.len() < 4 {
+        return Err("malformed SASLInitialResponse".to_owned());
+    }
+    let len = i32::from_be_bytes(rest[..4].try_into().unwrap());
+    let data = &rest[4..];
+    if len < 0 || data.len() != len as usize {
+        return Err("malformed SASLInitialResponse".to_owned());
+    }
+    Ok(data)
+}
+
+/// The server side of one SCRAM-SHA-256 exchange.
+///
+/// The exchange consists of two round trips: [`Self::handle_client_first`] produces the
+/// `server-first-message` carried in an `AuthenticationSASLContinue`, and
+/// [`Self::handle_client_final`] verifies the client proof and produces the
+/// `server-final-message` carried in an `AuthenticationSASLFinal`.
+pub struct ScramServer {
+    verifier: ScramVerifier,
+    /// `client-first-message-bare,server-first-message`, the prefix of the auth message
+    /// signed by both sides. Set by `handle_client_first`.
+    auth_message_prefix: Option<String>,
+    nonce: String,
+}
+
+impl ScramServer {
+    pub fn new(verifier: ScramVerifier) -> Self {
+        Self {
+            verifier,
+            auth_message_prefix: None,
+            nonce: String::new(),
+        }
+    }
+
+    /// Handles the `client-first-message` and returns the `server-first-message`.
+    pub fn handle_client_first(&mut self, client_first: &[u8]) -> Result<String, ScramError> {
+        let client_first = std::str::from_utf8(client_first).map_err(|e| e.to_string())?;
+
+        // gs2 header: `n` (no channel binding) or `y` (client supports it but assumes
+        // the server does not), followed by an empty authzid.
+        let client_first_bare = client_first
+            .strip_prefix("n,,")
+            .or_else(|| client_first.strip_prefix("y,,"))
+            .ok_or("channel binding is not supported")?;
+        let client_nonce = client_first_bare
+            .split(',')
+            .find_map(|attr| attr.strip_prefix("r="))
+            .ok_or("malformed client-first-message")?;
+
+        let mut server_nonce = [0; SCRAM_NONCE_LEN];
+        openssl::rand::rand_bytes(&mut server_nonce).unwrap();
+        self.nonce = format!("{}{}", client_nonce, encode_block(&server_nonce));
+
+        let server_first = format!(
+            "r={},s={},i={}",
+            self.nonce,
+            encode_block(&self.verifier.salt),
+            self.verifier.iterations
+        );
+        self.auth_message_prefix = Some(format!("{client_first_bare},{server_first}"));
+        Ok(server_first)
+    }
+
+    /// Handles the `client-final-message`. Returns the `server-final-message` if the
+    /// client proof is valid, i.e. the client knows the password, or `None` if it does
+    /// not.
+    pub fn handle_client_final(
+        &mut self,
+        client_final: &[u8],
+    ) -> Result<Option<String>, ScramError> {
+        let client_final = std::str::from_utf8(client_final).map_err(|e| e.to_string())?;
+        let auth_message_prefix = self
+            .auth_message_prefix
+            .take()
+            .ok_or("unexpected client-final-message")?;
+
+        let (client_final_without_proof, proof) = client_final
+            .rsplit_once(",p=")
+            .ok_or("malformed client-final-message")?;
+        let nonce = client_final_without_proof
+            .split(',')
+            .find_map(|attr| attr.strip_prefix("r="))
+            .ok_or("malformed client-final-message")?;
+        if nonce != self.nonce {
+            return Err("nonce mismatch".to_owned());
+        }
+        let proof = decode_block(proof).map_err(|_| "malformed client proof")?;
+        if proof.len() != 32 {
+            return Err("malformed client proof".to_owned());
+        }
+
+        let auth_message = format!("{auth_message_prefix},{client_final_without_proof}");
+        // `ClientKey = ClientProof XOR ClientSignature`, and the client is authenticated
+        // iff `H(ClientKey)` matches the stored key.
+        let client_signature = hmac_sha_256(&self.verifier.stored_key, auth_message.as_bytes());
+        let client_key: Vec<u8> = proof
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(p, s)| p ^ s)
+            .collect();
+        if !openssl::memcmp::eq(&sha_256(&client_key), &self.verifier.stored_key) {
+            return Ok(None);
+        }
+
+        let server_signature = hmac_sha_256(&self.verifier.server_key, auth_message.as_bytes());
+        Ok(Some(format!("v={}", encode_block(&server_signature))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run the client side of the exchange against [`ScramServer`], as a driver would.
+    fn client_exchange(password: &str, server: &mut ScramServer) -> bool {
+        let client_nonce = "rOprNGfwEbeRWgbNEkqO";
+        let client_first_bare = format!("n=,r={client_nonce}");
+
+        let server_first = server
+            .handle_client_first(format!("n,,{client_first_bare}").as_bytes())
+            .unwrap();
+        let mut salt = None;
+        let mut iterations = None;
+        let mut nonce = None;
+        for attr in server_first.split(',') {
+            match attr.split_at(2) {
+                ("r=", v) => nonce = Some(v.to_owned()),
+                ("s=", v) => salt = Some(decode_block(v).unwrap()),
+                ("i=", v) => iterations = Some(v.parse::<usize>().unwrap()),
+                _ => panic!("unexpected attribute in server-first-message"),
+            }
+        }
+        let (salt, iterations, nonce) = (salt.unwrap(), iterations.unwrap(), nonce.unwrap());
+        assert!(nonce.starts_with(client_nonce));
+
+        let mut salted_password = [0; 32];
+        openssl::pkcs5::pbkdf2_hmac(
+            password.as_bytes(),
+            &salt,
+            iterations,
+            MessageDigest::sha256(),
+            &mut salted_password,
+        )
+        .unwrap();
+        let client_key = hmac_sha_256(&salted_password, b"Client Key");
+        let server_key = hmac_sha_256(&salted_password, b"Server Key");
+
+        let client_final_without_proof = format!("c=biws,r={nonce}");
+        let auth_message =
+            format!("{client_first_bare},{server_first},{client_final_without_proof}");
+        let client_signature = hmac_sha_256(&sha_256(&client_key), auth_message.as_bytes());
+        let proof: Vec<u8> = client_key
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(k, s)| k ^ s)
+            .collect();
+        let client_final = format!("{client_final_without_proof},p={}", encode_block(&proof));
+
+        match server.handle_client_final(client_final.as_bytes()).unwrap() {
+            Some(server_final) => {
+                // The client verifies the server the same way.
+                let server_signature = hmac_sha_256(&server_key, auth_message.as_bytes());
+                let expected = format!("v={}", encode_block(&server_signature));
+                assert_eq!(server_final, expected);
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn test_verifier_roundtrip() {
+        let verifier = ScramVerifier::build("secret");
+        let serialized = verifier.to_string();
+        assert!(is_scram_sha_256_verifier(&serialized));
+        assert_eq!(serialized.parse::<ScramVerifier>().unwrap(), verifier);
+
+        assert!(!is_scram_sha_256_verifier("md596948aad3fcae80c08a35c9b5958cd89"));
+        assert!(!is_scram_sha_256_verifier("SCRAM-SHA-256$not-a-verifier"));
+    }
+
+    #[test]
+    fn test_scram_exchange() {
+        let verifier = ScramVerifier::build("secret");
+
+        let mut server = ScramServer::new(verifier.clone());
+        assert!(client_exchange("secret", &mut server));
+
+        let mut server = ScramServer::new(verifier);
+        assert!(!client_exchange("wrong", &mut server));
+    }
+
+    #[test]
+    fn test_parse_sasl_initial_response() {
+        let mut body = b"SCRAM-SHA-256\0".to_vec();
+        body.extend_from_slice(&8_i32.to_be_bytes());
+        body.extend_from_slice(b"n,,n=,r=");
+        assert_eq!(parse_sasl_initial_response(&body).unwrap(), b"n,,n=,r=");
+
+        parse_sasl_initial_response(b"SCRAM-SHA-256-PLUS\0\0\0\0\0").unwrap_err();
+        parse_sasl_initial_response(b"SCRAM-SHA-256").unwrap_err();
+    }
+}